@@ -52,3 +52,127 @@ impl From<AdapterType> for Dialect {
         }
     }
 }
+
+/// Unit in which a warehouse measures the length of an identifier
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IdentifierLengthUnit {
+    /// UTF-8 bytes (e.g. Postgres' NAMEDATALEN)
+    Bytes,
+    /// Unicode characters
+    Chars,
+}
+
+/// Maximum lengths of the identifiers of a warehouse
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct IdentifierLimits {
+    /// Tables, views and other relations
+    pub relation: usize,
+    /// Columns
+    pub column: usize,
+    /// Schemas (datasets on BigQuery)
+    pub schema: usize,
+    /// Unit the limits are expressed in
+    pub unit: IdentifierLengthUnit,
+}
+
+/// Length of the hash appended by [IdentifierLimits::truncate_with_hash]
+const TRUNCATION_HASH_LEN: usize = 8;
+
+impl IdentifierLimits {
+    /// Length of `name` in the unit of the warehouse
+    pub fn length_of(&self, name: &str) -> usize {
+        match self.unit {
+            IdentifierLengthUnit::Bytes => name.len(),
+            IdentifierLengthUnit::Chars => name.chars().count(),
+        }
+    }
+
+    /// The relation identifier `identifier` followed by `suffix`, shortened to
+    /// fit the relation limit if needed.
+    ///
+    /// A name that is too long keeps as much of `identifier` as fits, followed
+    /// by `_` and a hash of the full name, followed by `suffix`. The hash keeps
+    /// the result stable across runs and distinct for identifiers sharing a
+    /// prefix.
+    pub fn truncate_with_hash(&self, identifier: &str, suffix: &str) -> String {
+        let full_name = format!("{identifier}{suffix}");
+        if self.length_of(&full_name) <= self.relation {
+            return full_name;
+        }
+        let hash = format!("{:x}", md5::compute(&full_name));
+        let hash = &hash[..TRUNCATION_HASH_LEN];
+        // `_` + hash + suffix, the suffix is dropped if even that does not fit
+        let suffix = if 1 + TRUNCATION_HASH_LEN + self.length_of(suffix) <= self.relation {
+            suffix
+        } else {
+            ""
+        };
+        let budget = self
+            .relation
+            .saturating_sub(1 + TRUNCATION_HASH_LEN + self.length_of(suffix));
+        let mut prefix = String::new();
+        for c in identifier.chars() {
+            let next = match self.unit {
+                IdentifierLengthUnit::Bytes => prefix.len() + c.len_utf8(),
+                IdentifierLengthUnit::Chars => prefix.chars().count() + 1,
+            };
+            if next > budget {
+                break;
+            }
+            prefix.push(c);
+        }
+        format!("{prefix}_{hash}{suffix}")
+    }
+}
+
+impl AdapterType {
+    /// Maximum identifier lengths of the warehouse
+    pub fn identifier_limits(&self) -> IdentifierLimits {
+        let (relation, column, schema, unit) = match self {
+            // NAMEDATALEN - 1
+            AdapterType::Postgres => (63, 63, 63, IdentifierLengthUnit::Bytes),
+            AdapterType::Redshift => (127, 127, 127, IdentifierLengthUnit::Bytes),
+            AdapterType::Snowflake => (255, 255, 255, IdentifierLengthUnit::Chars),
+            // tables, columns and datasets respectively
+            AdapterType::Bigquery => (1024, 300, 1024, IdentifierLengthUnit::Chars),
+            AdapterType::Databricks => (255, 255, 255, IdentifierLengthUnit::Chars),
+            // API names of Data Cloud objects and fields
+            AdapterType::Salesforce => (80, 80, 80, IdentifierLengthUnit::Chars),
+        };
+        IdentifierLimits {
+            relation,
+            column,
+            schema,
+            unit,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_truncate_with_hash() {
+        let limits = AdapterType::Postgres.identifier_limits();
+        assert_eq!(
+            limits.truncate_with_hash("my_model", "__dbt_tmp"),
+            "my_model__dbt_tmp"
+        );
+
+        let long = "a".repeat(70);
+        let truncated = limits.truncate_with_hash(&long, "__dbt_tmp");
+        assert_eq!(limits.length_of(&truncated), 63);
+        assert!(truncated.ends_with("__dbt_tmp"));
+        // deterministic, and distinct for names sharing the kept prefix
+        assert_eq!(truncated, limits.truncate_with_hash(&long, "__dbt_tmp"));
+        assert_ne!(
+            truncated,
+            limits.truncate_with_hash(&format!("{long}b"), "__dbt_tmp")
+        );
+
+        // multi-byte characters are never split
+        let truncated = limits.truncate_with_hash(&"é".repeat(40), "__dbt_backup");
+        assert!(limits.length_of(&truncated) <= 63);
+    }
+}
@@ -83,6 +83,7 @@ pub const RESOLVING: &str = " Resolving";
 pub const PARSING: &str = "   Parsing";
 pub const REMOVING: &str = "  Removing";
 pub const CACHING: &str = "   Caching";
+pub const CONVERTING: &str = "Converting";
 // not being issued right now
 pub const SCHEDULING: &str = "Scheduling";
 //
@@ -270,6 +270,10 @@ pub struct EvalArgs {
     pub full_refresh: bool,
     pub favor_state: bool,
     pub refresh_sources: bool,
+    /// Persist the relation cache across invocations
+    pub persist_relation_cache: bool,
+    /// Ignore the persisted relation cache and list schemas again
+    pub refresh_relation_cache: bool,
     pub send_anonymous_usage_stats: bool,
     pub check_all: bool,
     // todo: temporary, until Sampling is public, maps (source) unique_id to renamed (database, schema, table)
@@ -585,6 +589,7 @@ pub enum StaticAnalysisOffReason {
     ConfiguredOff,
     UnableToFetchSchema,
     NoDownstream,
+    PythonModel,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, ValueEnum, Serialize, Deserialize, Default)]
@@ -11,6 +11,7 @@ pub mod io_utils;
 pub mod node_selector;
 pub mod pretty_string;
 pub mod pretty_table;
+pub mod redaction;
 pub mod stats;
 pub mod stdfs;
 pub mod string_utils;
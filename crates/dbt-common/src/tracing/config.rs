@@ -14,7 +14,10 @@ use super::{
         query_log::build_query_log_layer_with_background_writer,
         tui_layer::build_tui_layer,
     },
-    middlewares::metric_aggregator::TelemetryMetricAggregator,
+    middlewares::{
+        credential_redaction::TelemetryCredentialRedactor,
+        metric_aggregator::TelemetryMetricAggregator,
+    },
     shutdown::TelemetryShutdownItem,
 };
 use crate::{
@@ -408,7 +411,10 @@ impl FsTraceConfig {
         };
 
         Ok((
-            vec![Box::new(TelemetryMetricAggregator)],
+            vec![
+                Box::new(TelemetryCredentialRedactor),
+                Box::new(TelemetryMetricAggregator),
+            ],
             consumer_layers,
             shutdown_items,
         ))
@@ -27,7 +27,10 @@ pub fn format_query_log_event(record: TelemetryRecordRef, _is_tty: bool) -> Opti
         )
         .unwrap();
 
-        if query_data.query_outcome() == QueryOutcome::Error {
+        if matches!(
+            query_data.query_outcome(),
+            QueryOutcome::Error | QueryOutcome::TimedOut
+        ) {
             if let Some(vendor_code) = query_data.query_error_vendor_code {
                 writeln!(&mut buf, "-- error vendor code: {vendor_code}").unwrap();
             }
@@ -36,6 +39,26 @@ pub fn format_query_log_event(record: TelemetryRecordRef, _is_tty: bool) -> Opti
                 writeln!(&mut buf, "-- error message: {adapter_message}").unwrap();
             }
         }
+        if let Some(attempt) = query_data.attempt {
+            writeln!(&mut buf, "-- attempt: {attempt}").unwrap();
+        }
+        if let Some(cache_hit) = query_data.cache_hit {
+            let cache_status = if cache_hit { "hit" } else { "miss" };
+            writeln!(&mut buf, "-- query cache: {cache_status}").unwrap();
+        }
+        for (stat, value) in [
+            ("bytes processed", query_data.bytes_processed),
+            ("bytes billed", query_data.bytes_billed),
+            ("slot ms", query_data.slot_ms),
+            ("rows inserted", query_data.rows_inserted),
+            ("rows updated", query_data.rows_updated),
+            ("rows deleted", query_data.rows_deleted),
+            ("permit wait ms", query_data.permit_wait_ms),
+        ] {
+            if let Some(value) = value {
+                writeln!(&mut buf, "-- {stat}: {value}").unwrap();
+            }
+        }
         writeln!(&mut buf, "-- dialect: {}", query_data.adapter_type.as_str()).unwrap();
 
         let node_id = query_data.unique_id.as_deref().unwrap_or("not available");
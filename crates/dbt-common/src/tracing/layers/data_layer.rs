@@ -5,7 +5,7 @@ use super::super::{
     filter::FilterMask,
     init::process_span,
     layer::{ConsumerLayer, MiddlewareLayer},
-    metrics::MetricCounters,
+    metrics::{MetricCounters, NodeQueryStats},
     span_info::get_span_debug_extra_attrs,
 };
 use rand::RngCore;
@@ -234,7 +234,9 @@ where
         // If this is the root span, initialize invocation-level metrics storage.
         // We have to do it early to ensure it is available to middlewares
         if span.parent().is_none() {
-            span.extensions_mut().insert(MetricCounters::new());
+            let mut extensions = span.extensions_mut();
+            extensions.insert(MetricCounters::new());
+            extensions.insert(NodeQueryStats::default());
         }
 
         // Get root_span for data provider
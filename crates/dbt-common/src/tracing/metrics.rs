@@ -1,4 +1,4 @@
-use dbt_telemetry::{NodeOutcome, NodeSkipReason, NodeType, TestOutcome};
+use dbt_telemetry::{NodeOutcome, NodeSkipReason, NodeType, QueryExecuted, TestOutcome};
 use strum::EnumCount as _;
 #[cfg(test)]
 use strum_macros::EnumIter;
@@ -23,6 +23,15 @@ pub enum InvocationMetricKey {
     NodeTotalsReused,
     NodeTotalsSkipped,
     NodeTotalsCanceled,
+    // Warehouse execution statistics summed over all queries
+    QueryBytesProcessed,
+    QueryBytesBilled,
+    QuerySlotMs,
+    QueryRowsInserted,
+    QueryRowsUpdated,
+    QueryRowsDeleted,
+    // Time queries waited for a concurrency permit
+    QueryPermitWaitMs,
 }
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
@@ -112,6 +121,78 @@ impl MetricCounters {
     }
 }
 
+/// Warehouse execution statistics of one or more queries
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, serde::Serialize, serde::Deserialize)]
+pub struct QueryStats {
+    pub query_count: u64,
+    pub bytes_processed: u64,
+    pub bytes_billed: u64,
+    pub slot_ms: u64,
+    pub rows_inserted: u64,
+    pub rows_updated: u64,
+    pub rows_deleted: u64,
+    pub permit_wait_ms: u64,
+}
+
+impl QueryStats {
+    pub fn from_query(query: &QueryExecuted) -> Self {
+        Self {
+            query_count: 1,
+            bytes_processed: query.bytes_processed.unwrap_or_default(),
+            bytes_billed: query.bytes_billed.unwrap_or_default(),
+            slot_ms: query.slot_ms.unwrap_or_default(),
+            rows_inserted: query.rows_inserted.unwrap_or_default(),
+            rows_updated: query.rows_updated.unwrap_or_default(),
+            rows_deleted: query.rows_deleted.unwrap_or_default(),
+            permit_wait_ms: query.permit_wait_ms.unwrap_or_default(),
+        }
+    }
+
+    pub fn add(&mut self, other: &Self) {
+        self.query_count = self.query_count.saturating_add(other.query_count);
+        self.bytes_processed = self.bytes_processed.saturating_add(other.bytes_processed);
+        self.bytes_billed = self.bytes_billed.saturating_add(other.bytes_billed);
+        self.slot_ms = self.slot_ms.saturating_add(other.slot_ms);
+        self.rows_inserted = self.rows_inserted.saturating_add(other.rows_inserted);
+        self.rows_updated = self.rows_updated.saturating_add(other.rows_updated);
+        self.rows_deleted = self.rows_deleted.saturating_add(other.rows_deleted);
+        self.permit_wait_ms = self.permit_wait_ms.saturating_add(other.permit_wait_ms);
+    }
+
+    /// The invocation metrics these statistics add to
+    pub fn invocation_metrics(&self) -> [(InvocationMetricKey, u64); 7] {
+        [
+            (
+                InvocationMetricKey::QueryBytesProcessed,
+                self.bytes_processed,
+            ),
+            (InvocationMetricKey::QueryBytesBilled, self.bytes_billed),
+            (InvocationMetricKey::QuerySlotMs, self.slot_ms),
+            (InvocationMetricKey::QueryRowsInserted, self.rows_inserted),
+            (InvocationMetricKey::QueryRowsUpdated, self.rows_updated),
+            (InvocationMetricKey::QueryRowsDeleted, self.rows_deleted),
+            (InvocationMetricKey::QueryPermitWaitMs, self.permit_wait_ms),
+        ]
+    }
+}
+
+/// Query statistics summed per node unique_id, stored in the root span
+#[derive(Debug, Default)]
+pub(super) struct NodeQueryStats(scc::HashMap<String, QueryStats>);
+
+impl NodeQueryStats {
+    pub(super) fn record(&self, unique_id: &str, stats: &QueryStats) {
+        self.0
+            .entry_sync(unique_id.to_string())
+            .and_modify(|total| total.add(stats))
+            .or_insert(*stats);
+    }
+
+    fn get(&self, unique_id: &str) -> Option<QueryStats> {
+        self.0.read_sync(unique_id, |_, stats| *stats)
+    }
+}
+
 /// Increments an invocation metric counter
 pub fn increment_metric(key: MetricKey, value: u64) {
     with_root_span(|root_span| {
@@ -157,6 +238,18 @@ pub(super) fn get_all_metrics_from_span_extension(
         .unwrap_or_default()
 }
 
+/// Gets the query statistics of a node summed over all queries it executed
+/// in the current invocation.
+pub fn get_node_query_stats(unique_id: &str) -> Option<QueryStats> {
+    with_root_span(|root_span| {
+        root_span
+            .extensions()
+            .get::<NodeQueryStats>()
+            .and_then(|stats| stats.get(unique_id))
+    })
+    .flatten()
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
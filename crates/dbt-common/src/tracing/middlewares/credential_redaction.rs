@@ -0,0 +1,72 @@
+use std::{borrow::Cow, str::FromStr as _};
+
+use dbt_telemetry::{LogRecordInfo, QueryExecuted, SpanEndInfo, SpanStartInfo};
+
+use crate::{
+    adapter::AdapterType,
+    redaction::{redact_credentials, redact_credentials_any_dialect},
+};
+
+use super::super::{data_provider::DataProviderMut, layer::TelemetryMiddleware};
+
+/// Middleware that redacts credentials from executed SQL, adapter error
+/// messages and log messages before they reach any consumer (query log,
+/// jsonl, otlp, parquet, ...).
+pub struct TelemetryCredentialRedactor;
+
+impl TelemetryMiddleware for TelemetryCredentialRedactor {
+    fn on_span_start(
+        &self,
+        mut span: SpanStartInfo,
+        _data_provider: &mut DataProviderMut<'_>,
+    ) -> Option<SpanStartInfo> {
+        if let Some(query) = span.attributes.downcast_mut::<QueryExecuted>() {
+            redact_query(query);
+        }
+        Some(span)
+    }
+
+    fn on_span_end(
+        &self,
+        mut span: SpanEndInfo,
+        _data_provider: &mut DataProviderMut<'_>,
+    ) -> Option<SpanEndInfo> {
+        if let Some(query) = span.attributes.downcast_mut::<QueryExecuted>() {
+            redact_query(query);
+        }
+        Some(span)
+    }
+
+    fn on_log_record(
+        &self,
+        mut record: LogRecordInfo,
+        _data_provider: &mut DataProviderMut<'_>,
+    ) -> Option<LogRecordInfo> {
+        redact_in_place(&mut record.body, redact_credentials_any_dialect);
+        Some(record)
+    }
+}
+
+fn redact_query(query: &mut QueryExecuted) {
+    let adapter_type = AdapterType::from_str(&query.adapter_type).ok();
+    let redact = |text: &mut String| match adapter_type {
+        Some(adapter_type) => redact_in_place(text, |t| redact_credentials(adapter_type, t)),
+        None => redact_in_place(text, redact_credentials_any_dialect),
+    };
+
+    redact(&mut query.sql);
+    if let Some(message) = query.query_error_adapter_message.as_mut() {
+        redact(message);
+    }
+}
+
+fn redact_in_place<F>(text: &mut String, redact: F)
+where
+    F: for<'a> Fn(&'a str) -> Cow<'a, str>,
+{
+    let redacted = match redact(text) {
+        Cow::Owned(redacted) => redacted,
+        Cow::Borrowed(_) => return,
+    };
+    *text = redacted;
+}
@@ -1,12 +1,14 @@
 use dbt_telemetry::{
-    LogMessage, LogRecordInfo, NodeOutcome, NodeSkipReason, SeverityNumber, SpanEndInfo,
-    TestOutcome,
+    LogMessage, LogRecordInfo, NodeOutcome, NodeSkipReason, QueryExecuted, SeverityNumber,
+    SpanEndInfo, TestOutcome,
 };
 
 use crate::tracing::metrics::InvocationMetricKey;
 
 use super::super::{
-    data_provider::DataProviderMut, layer::TelemetryMiddleware, metrics::MetricKey,
+    data_provider::DataProviderMut,
+    layer::TelemetryMiddleware,
+    metrics::{MetricKey, NodeQueryStats, QueryStats},
 };
 
 /// Middleware that aggregates telemetry metrics from span and log records.
@@ -18,6 +20,10 @@ impl TelemetryMiddleware for TelemetryMetricAggregator {
         span: SpanEndInfo,
         data_provider: &mut DataProviderMut<'_>,
     ) -> Option<SpanEndInfo> {
+        if let Some(query) = span.attributes.downcast_ref::<QueryExecuted>() {
+            aggregate_query_stats(query, data_provider);
+        }
+
         // We could have checked for Invocation here, but check for root makes it
         // more generic in case other root spans are added in the future
         if span.parent_span_id.is_none() {
@@ -114,3 +120,17 @@ impl TelemetryMiddleware for TelemetryMetricAggregator {
         Some(log_record)
     }
 }
+
+/// Add the warehouse statistics of a query to the invocation totals and to
+/// the totals of the node that issued it
+fn aggregate_query_stats(query: &QueryExecuted, data_provider: &DataProviderMut<'_>) {
+    let stats = QueryStats::from_query(query);
+    for (key, value) in stats.invocation_metrics() {
+        if value > 0 {
+            data_provider.increment_metric(MetricKey::InvocationMetric(key), value);
+        }
+    }
+    if let Some(unique_id) = query.unique_id.as_deref() {
+        data_provider.with::<NodeQueryStats>(|node_stats| node_stats.record(unique_id, &stats));
+    }
+}
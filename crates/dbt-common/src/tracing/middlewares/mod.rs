@@ -1,2 +1,3 @@
 // Composable middleware layers
+pub mod credential_redaction;
 pub mod metric_aggregator;
@@ -0,0 +1,81 @@
+use super::super::{
+    init::create_tracing_subcriber_with_layer,
+    layer::{ConsumerLayer, MiddlewareLayer},
+    layers::data_layer::TelemetryDataLayer,
+    middlewares::credential_redaction::TelemetryCredentialRedactor,
+    tests::mocks::{MockDynLogEvent, MockDynSpanEvent, TestLayer},
+};
+use crate::{create_info_span, create_root_info_span, emit_tracing_event};
+
+use dbt_telemetry::{QueryExecuted, TelemetryOutputFlags};
+use tracing::level_filters::LevelFilter;
+
+#[test]
+fn credentials_are_redacted_before_reaching_consumers() {
+    let trace_id = rand::random::<u128>();
+    let (test_layer, span_starts, span_ends, log_records) = TestLayer::new();
+
+    let subscriber = create_tracing_subcriber_with_layer(
+        LevelFilter::TRACE,
+        TelemetryDataLayer::new(
+            trace_id,
+            false,
+            std::iter::once(Box::new(TelemetryCredentialRedactor) as MiddlewareLayer),
+            std::iter::once(Box::new(test_layer) as ConsumerLayer),
+        ),
+    );
+
+    tracing::subscriber::with_default(subscriber, || {
+        let _root_guard = create_root_info_span!(
+            MockDynSpanEvent {
+                name: "root".to_string(),
+                flags: TelemetryOutputFlags::ALL,
+                ..Default::default()
+            }
+            .into()
+        )
+        .entered();
+
+        create_info_span!(
+            QueryExecuted::start(
+                "copy t from 's3://b' ACCESS_KEY_ID 'AKIA' SECRET_ACCESS_KEY 'shh'".to_string(),
+                "hash".to_string(),
+                "redshift".to_string(),
+                None,
+                None,
+            )
+            .into()
+        )
+        .in_scope(|| {
+            emit_tracing_event!(
+                MockDynLogEvent {
+                    code: 1,
+                    flags: TelemetryOutputFlags::ALL,
+                    ..Default::default()
+                }
+                .into(),
+                "failed: alter user u password 'hunter2'"
+            );
+        });
+    });
+
+    let expected_sql =
+        "copy t from 's3://b' ACCESS_KEY_ID '[REDACTED]' SECRET_ACCESS_KEY '[REDACTED]'";
+    let query_sql = |attrs: &dbt_telemetry::TelemetryAttributes| {
+        attrs
+            .downcast_ref::<QueryExecuted>()
+            .map(|query| query.sql.clone())
+    };
+
+    let starts = span_starts.lock().unwrap();
+    let start_sql = starts.iter().find_map(|span| query_sql(&span.attributes));
+    assert_eq!(start_sql.as_deref(), Some(expected_sql));
+
+    let ends = span_ends.lock().unwrap();
+    let end_sql = ends.iter().find_map(|span| query_sql(&span.attributes));
+    assert_eq!(end_sql.as_deref(), Some(expected_sql));
+
+    let logs = log_records.lock().unwrap();
+    assert_eq!(logs.len(), 1);
+    assert_eq!(logs[0].body, "failed: alter user u password '[REDACTED]'");
+}
@@ -4,11 +4,12 @@ use super::super::{
     init::create_tracing_subcriber_with_layer,
     layer::{ConsumerLayer, MiddlewareLayer},
     layers::data_layer::TelemetryDataLayer,
-    metrics::{MetricKey, get_metric},
+    metrics::{MetricKey, QueryStats, get_metric, get_node_query_stats},
     middlewares::metric_aggregator::TelemetryMetricAggregator,
     tests::mocks::{MockDynSpanEvent, TestLayer},
 };
-use dbt_telemetry::TelemetryOutputFlags;
+use crate::create_info_span;
+use dbt_telemetry::{QueryExecuted, TelemetryOutputFlags};
 
 #[test]
 fn warning_logs_increment_warning_metric() {
@@ -50,3 +51,69 @@ fn warning_logs_increment_warning_metric() {
 
     assert_eq!(get_metric(test_metric_key), 0);
 }
+
+#[test]
+fn query_stats_are_aggregated_per_node() {
+    let trace_id = rand::random::<u128>();
+
+    let (test_layer, ..) = TestLayer::new();
+
+    let subscriber = create_tracing_subcriber_with_layer(
+        tracing::level_filters::LevelFilter::TRACE,
+        TelemetryDataLayer::new(
+            trace_id,
+            false,
+            std::iter::once(Box::new(TelemetryMetricAggregator) as MiddlewareLayer),
+            std::iter::once(Box::new(test_layer) as ConsumerLayer),
+        ),
+    );
+
+    let query = |unique_id: &str, bytes_processed: u64, rows_inserted: Option<u64>| QueryExecuted {
+        bytes_processed: Some(bytes_processed),
+        rows_inserted,
+        ..QueryExecuted::start(
+            "select 1".to_string(),
+            "hash".to_string(),
+            "bigquery".to_string(),
+            Some(unique_id.to_string()),
+            None,
+        )
+    };
+
+    tracing::subscriber::with_default(subscriber, || {
+        let _root_span_guard = create_root_info_span!(
+            MockDynSpanEvent {
+                name: "root".to_string(),
+                flags: TelemetryOutputFlags::empty(),
+                ..Default::default()
+            }
+            .into()
+        )
+        .entered();
+
+        for query in [
+            query("model.a", 100, None),
+            query("model.a", 50, Some(7)),
+            query("model.b", 1, None),
+        ] {
+            create_info_span!(query.into()).in_scope(|| {});
+        }
+
+        assert_eq!(
+            get_node_query_stats("model.a"),
+            Some(QueryStats {
+                query_count: 2,
+                bytes_processed: 150,
+                rows_inserted: 7,
+                ..Default::default()
+            })
+        );
+        assert_eq!(get_node_query_stats("model.c"), None);
+        assert_eq!(
+            get_metric(MetricKey::InvocationMetric(
+                InvocationMetricKey::QueryBytesProcessed
+            )),
+            151
+        );
+    });
+}
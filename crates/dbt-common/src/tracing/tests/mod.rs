@@ -1,3 +1,4 @@
+mod credential_redaction_tests;
 mod data_provider_tests;
 mod filter_tests;
 mod infra_tests;
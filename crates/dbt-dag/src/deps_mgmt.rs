@@ -622,6 +622,53 @@ where
     grouped.into_values().collect()
 }
 
+/// Computes a scheduling priority for every node of `deps` (node -> upstreams):
+/// the number of nodes on the longest chain of downstream dependents.
+///
+/// Nodes on the critical path of the DAG get the highest priorities, so
+/// preferring them when resources are contended shortens the overall run.
+/// Chains are not extended through cycles, so the input may contain cycles.
+pub fn critical_path_priorities<T>(deps: &BTreeMap<T, BTreeSet<T>>) -> BTreeMap<T, u32>
+where
+    T: Hash + PartialEq + Eq + Clone + Ord + std::fmt::Debug,
+{
+    fn compute_priority<T>(
+        node: &T,
+        downstreams: &BTreeMap<T, BTreeSet<T>>,
+        priorities: &mut BTreeMap<T, u32>,
+        stack: &mut BTreeSet<T>,
+    ) -> u32
+    where
+        T: Hash + PartialEq + Eq + Clone + Ord + std::fmt::Debug,
+    {
+        if let Some(&priority) = priorities.get(node) {
+            return priority;
+        }
+        if !stack.insert(node.clone()) {
+            // Cycle detected, the chain ends here
+            return 0;
+        }
+        let priority = downstreams.get(node).map_or(0, |children| {
+            children
+                .iter()
+                .map(|child| 1 + compute_priority(child, downstreams, priorities, stack))
+                .max()
+                .unwrap_or(0)
+        });
+        stack.remove(node);
+        priorities.insert(node.clone(), priority);
+        priority
+    }
+
+    let all_deps = ensure_all_nodes_defined(deps);
+    let downstreams = reverse(&all_deps);
+    let mut priorities = BTreeMap::new();
+    for node in all_deps.keys() {
+        compute_priority(node, &downstreams, &mut priorities, &mut BTreeSet::new());
+    }
+    priorities
+}
+
 pub fn prune_self_deps<T, U>(deps: &mut HashMap<T, HashSet<U>>)
 where
     T: Hash + PartialEq + Eq + Clone + Ord + Into<U> + std::fmt::Debug,
@@ -806,4 +853,31 @@ mod tests {
         let result = get_all_upstream_deps(&deps, &subset);
         assert_eq!(result.len(), 0); // Should be empty
     }
+
+    #[test]
+    fn test_critical_path_priorities() {
+        // a -> b -> c -> d
+        //      e -> d
+        // f (isolated)
+        let mut deps: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        deps.insert("b", BTreeSet::from(["a"]));
+        deps.insert("c", BTreeSet::from(["b"]));
+        deps.insert("d", BTreeSet::from(["c", "e"]));
+        deps.insert("f", BTreeSet::new());
+
+        let priorities = critical_path_priorities(&deps);
+        assert_eq!(
+            priorities,
+            BTreeMap::from([("a", 3), ("b", 2), ("c", 1), ("d", 0), ("e", 1), ("f", 0)])
+        );
+
+        // Cycles terminate instead of recursing forever
+        let mut deps: BTreeMap<&str, BTreeSet<&str>> = BTreeMap::new();
+        deps.insert("a", BTreeSet::from(["b"]));
+        deps.insert("b", BTreeSet::from(["a"]));
+        deps.insert("c", BTreeSet::from(["a"]));
+        let priorities = critical_path_priorities(&deps);
+        assert_eq!(priorities.len(), 3);
+        assert_eq!(priorities["c"], 0);
+    }
 }
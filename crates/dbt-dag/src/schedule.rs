@@ -3,12 +3,18 @@ use std::{
     fmt,
 };
 
+use dbt_common::adapter::AdapterType;
 use dbt_common::{node_selector::SelectExpression, pretty_table::DisplayFormat};
 use dbt_schemas::schemas::Nodes;
 use serde_json::Map;
 
+use crate::deps_mgmt::critical_path_priorities;
+
 type JsonValue = serde_json::Value;
 
+/// Output key of `list` showing the adapter support of the constraints of a model
+const CONSTRAINTS_ENFORCED: &str = "constraints_enforced";
+
 #[derive(Debug, Clone, Default)]
 pub struct Schedule<T> {
     // This is the dependency DAG including selected nodes and frontier nodes
@@ -111,11 +117,15 @@ impl Schedule<String> {
     /// Show the selected nodes in the specified format.
     /// For JSON output, each node is a separate JSON object on a new line,
     /// containing keys specified in `output_keys`.
+    ///
+    /// The derived `constraints_enforced` key lists the constraints of a model
+    /// with how `adapter_type` supports them.
     pub fn show_dbt_nodes(
         &self,
         nodes: &Nodes,
         output_format: &DisplayFormat,
         output_keys: &[String],
+        adapter_type: AdapterType,
     ) -> Vec<String> {
         let mut res = Vec::new();
         for selected_id in &self.selected_nodes {
@@ -125,7 +135,16 @@ impl Schedule<String> {
             match output_format {
                 DisplayFormat::Json => {
                     let node_yaml_value = dbt_serde_yaml::to_value(node.serialize()).unwrap();
-                    let node_value = serde_json::to_value(node_yaml_value).unwrap();
+                    let mut node_value = serde_json::to_value(node_yaml_value).unwrap();
+                    if output_keys.iter().any(|key| key == CONSTRAINTS_ENFORCED)
+                        && let Some(model) = nodes.models.get(selected_id)
+                        && let Some(node_map) = node_value.as_object_mut()
+                    {
+                        node_map.insert(
+                            CONSTRAINTS_ENFORCED.to_string(),
+                            serde_json::to_value(model.constraint_support(adapter_type)).unwrap(),
+                        );
+                    }
                     let json_string = Self::generate_json_output(&node_value, output_keys);
                     res.push(json_string);
                 }
@@ -148,6 +167,28 @@ impl Schedule<String> {
         }
         res
     }
+
+    /// The priorities with which the selected nodes should acquire contended
+    /// resources, i.e. the length of their critical path through the DAG of
+    /// selected nodes (see [critical_path_priorities]).
+    ///
+    /// Frontier nodes are not executed, so they do not lengthen any path.
+    pub fn critical_path_priorities(&self) -> BTreeMap<String, u32> {
+        let selected_deps = self
+            .deps
+            .iter()
+            .filter(|(node, _)| self.selected_nodes.contains(*node))
+            .map(|(node, deps)| {
+                let deps = deps
+                    .iter()
+                    .filter(|dep| self.selected_nodes.contains(*dep))
+                    .cloned()
+                    .collect();
+                (node.clone(), deps)
+            })
+            .collect();
+        critical_path_priorities(&selected_deps)
+    }
 }
 
 impl fmt::Display for Schedule<String> {
@@ -336,4 +377,33 @@ mod tests {
                 .contains(&"unit_test.project.unit_test1".to_string())
         );
     }
+
+    #[test]
+    fn test_critical_path_priorities_of_selected_nodes() {
+        // source -> stg -> fct -> test, only stg and fct are selected
+        let deps = BTreeMap::from([
+            ("source.p.raw".to_string(), BTreeSet::new()),
+            (
+                "model.p.stg".to_string(),
+                BTreeSet::from(["source.p.raw".to_string()]),
+            ),
+            (
+                "model.p.fct".to_string(),
+                BTreeSet::from(["model.p.stg".to_string()]),
+            ),
+        ]);
+        let schedule = Schedule {
+            deps,
+            selected_nodes: BTreeSet::from(["model.p.stg".to_string(), "model.p.fct".to_string()]),
+            frontier_nodes: BTreeSet::from(["source.p.raw".to_string()]),
+            ..Default::default()
+        };
+        assert_eq!(
+            schedule.critical_path_priorities(),
+            BTreeMap::from([
+                ("model.p.fct".to_string(), 0),
+                ("model.p.stg".to_string(), 1)
+            ])
+        );
+    }
 }
@@ -37,6 +37,8 @@ pub enum AdapterErrorKind {
     UnexpectedDbReference,
     /// Cancelled operation
     Cancelled,
+    /// Operation cancelled after exceeding its configured timeout
+    TimedOut,
     /// Missing information
     Incomplete,
     /// Unsupported type
@@ -73,6 +75,7 @@ impl fmt::Display for AdapterErrorKind {
                 AdapterErrorKind::UnexpectedResult => "Unexpected Result",
                 AdapterErrorKind::UnexpectedDbReference => "Unexpected Database Reference",
                 AdapterErrorKind::Cancelled => "Cancelled",
+                AdapterErrorKind::TimedOut => "Timed Out",
                 AdapterErrorKind::Incomplete => "Incomplete",
                 AdapterErrorKind::UnsupportedType => "Unsupported Type",
                 AdapterErrorKind::Io => "IoError",
@@ -101,6 +104,7 @@ impl From<AdapterErrorKind> for ErrorCode {
             // When DB is configured incorrectly, e.g. RA3
             AdapterErrorKind::UnexpectedDbReference => ErrorCode::InvalidConfig,
             AdapterErrorKind::Cancelled => ErrorCode::OperationCanceled,
+            AdapterErrorKind::TimedOut => ErrorCode::ExecutionError,
             AdapterErrorKind::UnsupportedType => ErrorCode::InvalidType,
             AdapterErrorKind::Io => ErrorCode::IoError,
             AdapterErrorKind::SerdeJSON => ErrorCode::SerializationError,
@@ -110,6 +110,15 @@ pub enum ErrorCode {
     AccessDenied = 1066,
 
     GenericExecError = 1067,
+
+    InvalidPythonModel = 1068,
+
+    /// Warning codes for models past (or approaching) their deprecation date
+    DeprecatedModel = 1069,
+    DeprecatedReference = 1070,
+    UpcomingReferenceDeprecation = 1071,
+
+    UnsupportedManifestVersion = 1072,
     // --------------------------------------------------------------------------------------------
     // Jinja
     MacroUnsupportedValueType = 1500,
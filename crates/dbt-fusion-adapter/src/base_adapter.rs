@@ -1,7 +1,7 @@
 use crate::cache::RelationCache;
 use crate::columns::StdColumnType;
 use crate::metadata::*;
-use crate::query_cache::QueryCache;
+use crate::query_cache::QueryCacheConfig;
 use crate::sql_engine::SqlEngine;
 use crate::typed_adapter::{ReplayAdapter, TypedBaseAdapter};
 use crate::{AdapterResponse, AdapterResult};
@@ -10,7 +10,7 @@ use dbt_agate::AgateTable;
 use dbt_common::FsResult;
 use dbt_common::adapter::SchemaRegistry;
 use dbt_common::cancellation::CancellationToken;
-use dbt_common::io_args::ReplayMode;
+use dbt_common::io_args::{EvalArgs, ReplayMode};
 use dbt_schemas::schemas::InternalDbtNodeAttributes;
 use dbt_schemas::schemas::common::ResolvedQuoting;
 use dbt_schemas::schemas::project::QueryComment;
@@ -98,12 +98,19 @@ pub trait AdapterTyping {
     fn cancellation_token(&self) -> CancellationToken {
         self.engine().cancellation_token()
     }
+
+    /// Install the priorities (by node unique_id) with which statements of
+    /// the scheduled nodes acquire contended permits. Must be called before
+    /// nodes are executed, typically with `Schedule::critical_path_priorities`.
+    fn set_node_priorities(&self, priorities: BTreeMap<String, u32>) -> AdapterResult<()> {
+        self.engine().set_node_priorities(priorities)
+    }
 }
 
 /// Base adapter
 pub trait BaseAdapter: fmt::Display + fmt::Debug + AdapterTyping + Send + Sync {
-    /// Commit
-    fn commit(&self, _args: &[Value]) -> Result<Value, MinijinjaError> {
+    /// Commit the transaction open on the connection, if any
+    fn commit(&self, _state: &State, _args: &[Value]) -> Result<Value, MinijinjaError> {
         Ok(Value::from(true))
     }
 
@@ -136,6 +143,9 @@ pub trait BaseAdapter: fmt::Display + fmt::Debug + AdapterTyping + Send + Sync {
         _args: &[Value],
     ) -> Result<Value, MinijinjaError>;
 
+    /// Returns the grants and revokes reconciling current grants with a `grants` config.
+    fn diff_grants(&self, state: &State, args: &[Value]) -> Result<Value, MinijinjaError>;
+
     /// Encloses identifier in the correct quotes for the adapter when escaping reserved column names etc.
     ///
     /// https://github.com/dbt-labs/dbt-adapters/blob/5fba80c621c3f0f732dba71aa6cf9055792b6495/dbt-adapters/src/dbt/adapters/base/impl.py#L1064
@@ -268,6 +278,25 @@ pub trait BaseAdapter: fmt::Display + fmt::Debug + AdapterTyping + Send + Sync {
         options: Option<HashMap<String, String>>,
     ) -> AdapterResult<(AdapterResponse, AgateTable)>;
 
+    /// Execute the given SQL with `bindings` bound to its `%s` placeholders.
+    ///
+    /// Unlike [BaseAdapter::add_query], the values are never formatted into
+    /// the SQL text: they are bound to the statement as Arrow parameters.
+    /// `bindings` is either a list of values (one execution) or a list of
+    /// rows (one execution per row). `auto_begin`, `fetch`, `limit` and
+    /// `options` have the same meaning as in [BaseAdapter::execute].
+    #[allow(clippy::too_many_arguments)]
+    fn execute_with_bindings(
+        &self,
+        state: &State,
+        sql: &str,
+        bindings: &Value,
+        auto_begin: bool,
+        fetch: bool,
+        limit: Option<i64>,
+        options: Option<HashMap<String, String>>,
+    ) -> AdapterResult<(AdapterResponse, AgateTable)>;
+
     /// Execute a statement, expect no results.
     fn exec_stmt(
         &self,
@@ -440,6 +469,9 @@ pub trait BaseAdapter: fmt::Display + fmt::Debug + AdapterTyping + Send + Sync {
     /// Returns a list of columns.
     fn get_missing_columns(&self, state: &State, args: &[Value]) -> Result<Value, MinijinjaError>;
 
+    /// Returns the schema changes `on_schema_change` makes to an incremental relation.
+    fn plan_schema_changes(&self, state: &State, args: &[Value]) -> Result<Value, MinijinjaError>;
+
     /// Get columns in relation.
     ///
     /// https://github.com/dbt-labs/dbt-adapters/blob/main/dbt-adapters/src/dbt/adapters/base/impl.py#L741
@@ -733,7 +765,7 @@ pub trait AdapterFactory: Send + Sync {
         replay_mode: Option<ReplayMode>,
         flags: BTreeMap<String, Value>,
         db: Option<Arc<dyn SchemaRegistry>>,
-        query_cache: Option<Arc<dyn QueryCache>>,
+        query_cache: Option<QueryCacheConfig>,
         quoting: ResolvedQuoting,
         query_comment: Option<QueryComment>,
         token: CancellationToken,
@@ -748,6 +780,23 @@ pub trait AdapterFactory: Send + Sync {
 
     /// Return a new instance of the factory with a different relation cache.
     fn with_relation_cache(&self, relation_cache: Arc<RelationCache>) -> Arc<dyn AdapterFactory>;
+
+    /// Return a new instance of the factory whose adapters share the relation
+    /// cache of an invocation, see [RelationCache::for_invocation].
+    fn with_invocation_relation_cache(
+        &self,
+        args: &EvalArgs,
+        adapter_type: AdapterType,
+        profile: &str,
+        target: &str,
+    ) -> Arc<dyn AdapterFactory> {
+        self.with_relation_cache(Arc::new(RelationCache::for_invocation(
+            args,
+            adapter_type,
+            profile,
+            target,
+        )))
+    }
 }
 
 /// Check if the adapter type is supported
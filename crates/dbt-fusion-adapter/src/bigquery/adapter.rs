@@ -6,6 +6,7 @@ use crate::bigquery::relation_config::{
     partitions_match,
 };
 use crate::cast_util::downcast_value_to_dyn_base_relation;
+use crate::catalog_relation::CatalogRelation;
 use crate::column::ColumnBuilder;
 use crate::columns::{BigqueryColumnMode, StdColumn};
 use crate::errors::{
@@ -13,6 +14,7 @@ use crate::errors::{
     arrow_error_to_adapter_error,
 };
 use crate::funcs::{execute_macro, none_value};
+use crate::load_catalogs;
 use crate::metadata::*;
 use crate::query_ctx::{query_ctx_from_state, query_ctx_from_state_with_sql};
 use crate::record_batch_utils::get_column_values;
@@ -28,7 +30,7 @@ use dashmap::DashMap;
 use dbt_agate::AgateTable;
 use dbt_schemas::dbt_types::RelationType;
 use dbt_schemas::schemas::CommonAttributes;
-use dbt_schemas::schemas::common::{ConstraintSupport, ConstraintType};
+use dbt_schemas::schemas::dbt_catalogs::CatalogType;
 use dbt_schemas::schemas::dbt_column::DbtColumn;
 use dbt_schemas::schemas::manifest::{
     BigqueryClusterConfig, BigqueryPartitionConfig, PartitionConfig,
@@ -714,6 +716,16 @@ impl TypedBaseAdapter for BigqueryAdapter {
         Ok(Value::from_object(validated_config))
     }
 
+    fn build_catalog_relation(&self, model_config: &Value) -> AdapterResult<Value> {
+        Ok(Value::from_object(
+            CatalogRelation::from_model_config_and_catalogs(
+                &self.adapter_type(),
+                model_config,
+                load_catalogs::fetch_catalogs(),
+            )?,
+        ))
+    }
+
     /// get_table_options
     fn get_table_options(
         &self,
@@ -766,6 +778,36 @@ impl TypedBaseAdapter for BigqueryAdapter {
             opts.insert("partition_expiration_days".to_string(), Value::from(days));
         }
 
+        // Tables of a BigLake metastore catalog are Iceberg tables on Cloud Storage
+        if !temporary && config.catalog_name.is_some() {
+            let identifier = config
+                .alias
+                .clone()
+                .unwrap_or_else(|| common_attr.name.clone());
+            let mut model_config = serde_json::to_value(&config).map_err(|e| {
+                AdapterError::new(
+                    AdapterErrorKind::Internal,
+                    format!("get_table_options: Failed to serialize config: {e}"),
+                )
+            })?;
+            model_config["identifier"] = serde_json::Value::from(identifier);
+            let catalog_relation = CatalogRelation::from_model_config_and_catalogs(
+                &self.adapter_type(),
+                &Value::from_serialize(model_config),
+                load_catalogs::fetch_catalogs(),
+            )?;
+            if catalog_relation.catalog_type == CatalogType::BiglakeMetastore.as_str()
+                && let Some(storage_uri) = catalog_relation.storage_uri()
+            {
+                opts.insert("file_format".to_string(), Value::from("'PARQUET'"));
+                opts.insert("table_format".to_string(), Value::from("'ICEBERG'"));
+                opts.insert(
+                    "storage_uri".to_string(),
+                    Value::from(format!("'{storage_uri}'")),
+                );
+            }
+        }
+
         Ok(opts)
     }
 
@@ -954,18 +996,6 @@ impl TypedBaseAdapter for BigqueryAdapter {
             }
         }
     }
-
-    /// https://github.com/dbt-labs/dbt-adapters/blob/4a00354a497214d9043bf4122810fe2d04de17bb/dbt-bigquery/src/dbt/adapters/bigquery/impl.py#L132
-    fn get_constraint_support(&self, ct: ConstraintType) -> ConstraintSupport {
-        match ct {
-            ConstraintType::Check | ConstraintType::Unique => ConstraintSupport::NotSupported,
-            ConstraintType::NotNull => ConstraintSupport::Enforced,
-            ConstraintType::PrimaryKey | ConstraintType::ForeignKey => {
-                ConstraintSupport::NotEnforced
-            }
-            _ => ConstraintSupport::NotSupported,
-        }
-    }
 }
 
 fn get_table_schema(
@@ -1089,7 +1119,7 @@ impl TrieNode {
 #[cfg(test)] // win ADBC
 mod tests {
     use super::*;
-    use crate::stmt_splitter::NaiveStmtSplitter;
+    use crate::stmt_splitter::DialectStmtSplitter;
 
     use crate::config::AdapterConfig;
     use crate::query_comment::QueryCommentConfig;
@@ -1108,7 +1138,7 @@ mod tests {
             auth.into(),
             AdapterConfig::new(config),
             DEFAULT_RESOLVED_QUOTING,
-            Arc::new(NaiveStmtSplitter), // XXX: may cause bugs if these tests run SQL
+            Arc::new(DialectStmtSplitter),
             None,
             QueryCommentConfig::from_query_comment(None, AdapterType::Bigquery, false),
             Box::new(NaiveTypeOpsImpl::new(AdapterType::Postgres)),
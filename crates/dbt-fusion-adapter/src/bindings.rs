@@ -0,0 +1,459 @@
+//! Conversion of Jinja query bindings into Arrow parameters.
+//!
+//! Macros write parameterized SQL with `%s` placeholders (see the
+//! `get_binding_char` macro). When a statement is executed with bindings,
+//! the placeholders are rewritten into the driver's native syntax and the
+//! values are bound to the statement as an Arrow [RecordBatch] instead of
+//! being formatted into the SQL text.
+
+use crate::AdapterType;
+use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult};
+
+use arrow::array::{
+    ArrayRef, BooleanArray, Date32Array, Float64Array, Int64Array, NullArray, RecordBatch,
+    RecordBatchOptions, StringArray, TimestampMicrosecondArray,
+};
+use arrow_schema::{DataType, Field, Schema, TimeUnit};
+use chrono::{NaiveDate, Utc};
+use minijinja::Value;
+use minijinja::value::ValueKind;
+use minijinja_contrib::modules::py_datetime::date::PyDate;
+use minijinja_contrib::modules::py_datetime::datetime::{DateTimeState, PyDateTime};
+
+use std::sync::Arc;
+
+/// Rewrite `%s` placeholders into the syntax expected by the ADBC driver
+/// of the given adapter and return the rewritten SQL along with the number
+/// of placeholders found.
+///
+/// Placeholders inside string literals, quoted identifiers and comments are
+/// left alone. As with psycopg, `%%` is an escaped `%` everywhere, so
+/// `like '100%%'` becomes `like '100%'`.
+pub fn rewrite_placeholders(adapter_type: AdapterType, sql: &str) -> (String, usize) {
+    // Backslash escapes a quote in single-quoted strings on these warehouses
+    let backslash_escapes = matches!(
+        adapter_type,
+        AdapterType::Bigquery | AdapterType::Databricks
+    );
+    let mut result = String::with_capacity(sql.len());
+    let mut count = 0;
+    let mut rest = sql;
+    while let Some(c) = rest.chars().next() {
+        let len = match c {
+            '%' if rest[1..].starts_with('%') => {
+                result.push('%');
+                rest = &rest[2..];
+                continue;
+            }
+            '%' if rest[1..].starts_with('s') => {
+                count += 1;
+                match adapter_type {
+                    AdapterType::Postgres | AdapterType::Redshift => {
+                        result.push('$');
+                        result.push_str(&count.to_string());
+                    }
+                    _ => result.push('?'),
+                }
+                rest = &rest[2..];
+                continue;
+            }
+            '\'' => quoted_len(rest, c, backslash_escapes),
+            '"' | '`' => quoted_len(rest, c, false),
+            '-' if rest.starts_with("--") => rest.find('\n').unwrap_or(rest.len()),
+            '/' if rest.starts_with("/*") => rest.find("*/").map_or(rest.len(), |end| end + 2),
+            '$' => dollar_quoted_len(rest).unwrap_or(1),
+            _ => c.len_utf8(),
+        };
+        // Literal spans are copied verbatim apart from the `%%` escape
+        result.push_str(&rest[..len].replace("%%", "%"));
+        rest = &rest[len..];
+    }
+    (result, count)
+}
+
+/// Length of the quoted span at the start of `sql`, where a doubled quote
+/// is an escaped quote. Unterminated spans run to the end of the input.
+fn quoted_len(sql: &str, quote: char, backslash_escapes: bool) -> usize {
+    let mut chars = sql.char_indices().skip(1).peekable();
+    while let Some((i, c)) = chars.next() {
+        if c == '\\' && backslash_escapes {
+            chars.next();
+        } else if c == quote {
+            if chars.peek().is_some_and(|(_, next)| *next == quote) {
+                chars.next();
+            } else {
+                return i + 1;
+            }
+        }
+    }
+    sql.len()
+}
+
+/// Length of the Postgres dollar-quoted string (`$$...$$`, `$tag$...$tag$`)
+/// at the start of `sql`, if any.
+fn dollar_quoted_len(sql: &str) -> Option<usize> {
+    let tag_len = sql[1..].find('$')? + 2;
+    let tag = &sql[..tag_len];
+    if !tag[1..tag_len - 1]
+        .chars()
+        .all(|c| c.is_alphanumeric() || c == '_')
+        || tag[1..].starts_with(|c: char| c.is_ascii_digit())
+    {
+        return None;
+    }
+    let end = sql[tag_len..]
+        .find(tag)
+        .map_or(sql.len(), |end| tag_len + end + tag_len);
+    Some(end)
+}
+
+/// A single parameter value extracted from a Jinja binding.
+#[derive(Debug, Clone, PartialEq)]
+enum Param {
+    Null,
+    Bool(bool),
+    Int(i64),
+    Float(f64),
+    Str(String),
+    Date(NaiveDate),
+    /// Microseconds since the epoch and whether the value was timezone-aware.
+    Timestamp(i64, bool),
+}
+
+impl Param {
+    fn from_value(value: &Value) -> AdapterResult<Self> {
+        let param = match value.kind() {
+            ValueKind::Undefined | ValueKind::None => Param::Null,
+            ValueKind::Bool => Param::Bool(value.is_true()),
+            ValueKind::String => Param::Str(value.as_str().unwrap_or_default().to_string()),
+            ValueKind::Number if value.is_integer() => {
+                Param::Int(i64::try_from(value.clone()).map_err(|e| {
+                    AdapterError::new(
+                        AdapterErrorKind::UnsupportedType,
+                        format!("Integer binding {value} is out of range: {e}"),
+                    )
+                })?)
+            }
+            ValueKind::Number => Param::Float(f64::try_from(value.clone()).map_err(|e| {
+                AdapterError::new(
+                    AdapterErrorKind::UnsupportedType,
+                    format!("Invalid numeric binding {value}: {e}"),
+                )
+            })?),
+            _ => {
+                if let Some(date) = value.downcast_object::<PyDate>() {
+                    Param::Date(date.date)
+                } else if let Some(datetime) = value.downcast_object::<PyDateTime>() {
+                    match &datetime.state {
+                        DateTimeState::Naive(dt) => {
+                            Param::Timestamp(dt.and_utc().timestamp_micros(), false)
+                        }
+                        DateTimeState::Aware(dt) => {
+                            Param::Timestamp(dt.with_timezone(&Utc).timestamp_micros(), true)
+                        }
+                        DateTimeState::FixedOffset(dt) => {
+                            Param::Timestamp(dt.with_timezone(&Utc).timestamp_micros(), true)
+                        }
+                    }
+                } else {
+                    return Err(AdapterError::new(
+                        AdapterErrorKind::UnsupportedType,
+                        format!("Unsupported binding of type {}: {value}", value.kind()),
+                    ));
+                }
+            }
+        };
+        Ok(param)
+    }
+
+    fn data_type(&self) -> DataType {
+        match self {
+            Param::Null => DataType::Null,
+            Param::Bool(_) => DataType::Boolean,
+            Param::Int(_) => DataType::Int64,
+            Param::Float(_) => DataType::Float64,
+            Param::Str(_) => DataType::Utf8,
+            Param::Date(_) => DataType::Date32,
+            Param::Timestamp(_, false) => DataType::Timestamp(TimeUnit::Microsecond, None),
+            Param::Timestamp(_, true) => {
+                DataType::Timestamp(TimeUnit::Microsecond, Some("UTC".into()))
+            }
+        }
+    }
+}
+
+/// Unify the types of two values bound to the same placeholder.
+fn unify(left: DataType, right: DataType) -> Option<DataType> {
+    match (left, right) {
+        (l, r) if l == r => Some(l),
+        (DataType::Null, other) | (other, DataType::Null) => Some(other),
+        (DataType::Int64, DataType::Float64) | (DataType::Float64, DataType::Int64) => {
+            Some(DataType::Float64)
+        }
+        _ => None,
+    }
+}
+
+fn build_column(data_type: &DataType, values: &[Param]) -> ArrayRef {
+    match data_type {
+        DataType::Null => Arc::new(NullArray::new(values.len())),
+        DataType::Boolean => Arc::new(
+            values
+                .iter()
+                .map(|p| match p {
+                    Param::Bool(b) => Some(*b),
+                    _ => None,
+                })
+                .collect::<BooleanArray>(),
+        ),
+        DataType::Int64 => Arc::new(
+            values
+                .iter()
+                .map(|p| match p {
+                    Param::Int(i) => Some(*i),
+                    _ => None,
+                })
+                .collect::<Int64Array>(),
+        ),
+        DataType::Float64 => Arc::new(
+            values
+                .iter()
+                .map(|p| match p {
+                    Param::Float(f) => Some(*f),
+                    Param::Int(i) => Some(*i as f64),
+                    _ => None,
+                })
+                .collect::<Float64Array>(),
+        ),
+        DataType::Utf8 => Arc::new(
+            values
+                .iter()
+                .map(|p| match p {
+                    Param::Str(s) => Some(s.as_str()),
+                    _ => None,
+                })
+                .collect::<StringArray>(),
+        ),
+        DataType::Date32 => {
+            let epoch = NaiveDate::from_ymd_opt(1970, 1, 1).expect("valid date");
+            Arc::new(
+                values
+                    .iter()
+                    .map(|p| match p {
+                        Param::Date(d) => Some((*d - epoch).num_days() as i32),
+                        _ => None,
+                    })
+                    .collect::<Date32Array>(),
+            )
+        }
+        DataType::Timestamp(_, tz) => {
+            let array = values
+                .iter()
+                .map(|p| match p {
+                    Param::Timestamp(micros, _) => Some(*micros),
+                    _ => None,
+                })
+                .collect::<TimestampMicrosecondArray>();
+            Arc::new(match tz {
+                Some(tz) => array.with_timezone(tz.clone()),
+                None => array,
+            })
+        }
+        _ => unreachable!("unexpected parameter type {data_type}"),
+    }
+}
+
+/// Convert Jinja bindings into a [RecordBatch] with one column per placeholder.
+///
+/// A flat sequence of values binds a single row. A sequence of sequences
+/// binds one row per inner sequence, which executes the statement once per
+/// row. Columns are named `p1`, `p2`, ... in placeholder order.
+pub fn bindings_to_record_batch(bindings: &Value) -> AdapterResult<RecordBatch> {
+    let items = bindings
+        .try_iter()
+        .map_err(|e| {
+            AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!("Bindings must be a list of values or a list of rows: {e}"),
+            )
+        })?
+        .collect::<Vec<_>>();
+
+    let is_multi_row = !items.is_empty() && items.iter().all(|item| item.kind() == ValueKind::Seq);
+    let rows = if is_multi_row {
+        items
+            .iter()
+            .map(|row| {
+                row.try_iter()
+                    .expect("sequence is iterable")
+                    .map(|value| Param::from_value(&value))
+                    .collect::<AdapterResult<Vec<_>>>()
+            })
+            .collect::<AdapterResult<Vec<_>>>()?
+    } else {
+        vec![
+            items
+                .iter()
+                .map(Param::from_value)
+                .collect::<AdapterResult<Vec<_>>>()?,
+        ]
+    };
+
+    let width = rows.first().map_or(0, Vec::len);
+    if let Some(pos) = rows.iter().position(|row| row.len() != width) {
+        return Err(AdapterError::new(
+            AdapterErrorKind::Configuration,
+            format!(
+                "Binding row {} has {} values, expected {width}",
+                pos + 1,
+                rows[pos].len()
+            ),
+        ));
+    }
+
+    let mut fields = Vec::with_capacity(width);
+    let mut columns = Vec::with_capacity(width);
+    for i in 0..width {
+        let values = rows.iter().map(|row| row[i].clone()).collect::<Vec<_>>();
+        let data_type = values
+            .iter()
+            .map(Param::data_type)
+            .try_fold(DataType::Null, unify)
+            .ok_or_else(|| {
+                AdapterError::new(
+                    AdapterErrorKind::UnsupportedType,
+                    format!("Bindings for parameter {} have mixed types", i + 1),
+                )
+            })?;
+        columns.push(build_column(&data_type, &values));
+        fields.push(Field::new(format!("p{}", i + 1), data_type, true));
+    }
+
+    let options = RecordBatchOptions::new().with_row_count(Some(rows.len()));
+    RecordBatch::try_new_with_options(Arc::new(Schema::new(fields)), columns, &options).map_err(
+        |e| {
+            AdapterError::new(
+                AdapterErrorKind::Arrow,
+                format!("Failed to build parameter batch: {e}"),
+            )
+        },
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_rewrite_placeholders() {
+        let sql = "select * from t where a = %s and b = %s";
+        assert_eq!(
+            rewrite_placeholders(AdapterType::Postgres, sql),
+            ("select * from t where a = $1 and b = $2".to_string(), 2)
+        );
+        assert_eq!(
+            rewrite_placeholders(AdapterType::Snowflake, sql),
+            ("select * from t where a = ? and b = ?".to_string(), 2)
+        );
+        assert_eq!(
+            rewrite_placeholders(AdapterType::Snowflake, "select 1"),
+            ("select 1".to_string(), 0)
+        );
+    }
+
+    #[test]
+    fn test_rewrite_placeholders_skips_literals_and_comments() {
+        let sql = "select '%s', \"%s\" -- %s\n/* %s */ from t where a like '%sales%' and b = %s";
+        assert_eq!(
+            rewrite_placeholders(AdapterType::Postgres, sql),
+            (
+                "select '%s', \"%s\" -- %s\n/* %s */ from t where a like '%sales%' and b = $1"
+                    .to_string(),
+                1
+            )
+        );
+        assert_eq!(
+            rewrite_placeholders(
+                AdapterType::Postgres,
+                "select $$ %s $$, $tag$ it's %s $tag$, %s"
+            ),
+            ("select $$ %s $$, $tag$ it's %s $tag$, $1".to_string(), 1)
+        );
+        assert_eq!(
+            rewrite_placeholders(AdapterType::Snowflake, "select 'it''s %s', %s"),
+            ("select 'it''s %s', ?".to_string(), 1)
+        );
+        assert_eq!(
+            rewrite_placeholders(AdapterType::Databricks, r"select 'it\'s %s', `%s`, %s"),
+            (r"select 'it\'s %s', `%s`, ?".to_string(), 1)
+        );
+    }
+
+    #[test]
+    fn test_rewrite_placeholders_unescapes_percent() {
+        assert_eq!(
+            rewrite_placeholders(
+                AdapterType::Snowflake,
+                "select 5 %% 2, '100%%' where a = %s"
+            ),
+            ("select 5 % 2, '100%' where a = ?".to_string(), 1)
+        );
+        // `%%s` is an escaped percent followed by a literal `s`
+        assert_eq!(
+            rewrite_placeholders(AdapterType::Postgres, "select '%%s', 1 %%s"),
+            ("select '%s', 1 %s".to_string(), 0)
+        );
+    }
+
+    #[test]
+    fn test_bindings_to_record_batch_single_row() {
+        let bindings = Value::from(vec![
+            Value::from("a"),
+            Value::from(1),
+            Value::from(true),
+            Value::from(()),
+        ]);
+        let batch = bindings_to_record_batch(&bindings).unwrap();
+        assert_eq!(batch.num_rows(), 1);
+        let types = batch
+            .schema()
+            .fields()
+            .iter()
+            .map(|f| f.data_type().clone())
+            .collect::<Vec<_>>();
+        assert_eq!(
+            types,
+            vec![
+                DataType::Utf8,
+                DataType::Int64,
+                DataType::Boolean,
+                DataType::Null
+            ]
+        );
+    }
+
+    #[test]
+    fn test_bindings_to_record_batch_multi_row() {
+        let bindings = Value::from(vec![
+            Value::from(vec![Value::from(1), Value::from("x")]),
+            Value::from(vec![Value::from(2.5), Value::from(())]),
+        ]);
+        let batch = bindings_to_record_batch(&bindings).unwrap();
+        assert_eq!(batch.num_rows(), 2);
+        assert_eq!(batch.schema().field(0).data_type(), &DataType::Float64);
+        assert_eq!(batch.schema().field(1).data_type(), &DataType::Utf8);
+        assert_eq!(batch.column(1).null_count(), 1);
+
+        let ragged = Value::from(vec![
+            Value::from(vec![Value::from(1)]),
+            Value::from(vec![Value::from(1), Value::from(2)]),
+        ]);
+        assert!(bindings_to_record_batch(&ragged).is_err());
+
+        let mixed = Value::from(vec![
+            Value::from(vec![Value::from(1)]),
+            Value::from(vec![Value::from("a")]),
+        ]);
+        assert!(bindings_to_record_batch(&mixed).is_err());
+    }
+}
@@ -1,12 +1,15 @@
 use crate::base_adapter::{AdapterType, AdapterTyping};
+use crate::bindings::{bindings_to_record_batch, rewrite_placeholders};
 use crate::cache::RelationCache;
 use crate::cast_util::downcast_value_to_dyn_base_relation;
 use crate::columns::StdColumn;
+use crate::errors::{AdapterError, AdapterErrorKind};
 use crate::funcs::{
     dispatch_adapter_calls, dispatch_adapter_get_value, execute_macro, execute_macro_wrapper,
     none_value,
 };
 use crate::funcs::{execute_macro_wrapper_with_package, format_sql_with_bindings};
+use crate::grants::GrantsDict;
 use crate::information_schema::InformationSchema;
 use crate::metadata::*;
 use crate::query_ctx::{node_id_from_state, query_ctx_from_state, query_ctx_from_state_with_sql};
@@ -14,6 +17,7 @@ use crate::record_batch_utils::extract_first_value_as_i64;
 use crate::relation_object::RelationObject;
 use crate::render_constraint::render_model_constraint;
 use crate::snapshots::SnapshotStrategy;
+use crate::transactions;
 use crate::typed_adapter::TypedBaseAdapter;
 use crate::{AdapterResponse, AdapterResult, BaseAdapter, SqlEngine, relation_object};
 
@@ -23,7 +27,7 @@ use dbt_common::behavior_flags::{Behavior, BehaviorFlag};
 use dbt_common::cancellation::CancellationToken;
 use dbt_common::{FsError, FsResult, current_function_name};
 use dbt_schemas::schemas::InternalDbtNodeWrapper;
-use dbt_schemas::schemas::common::{DbtIncrementalStrategy, ResolvedQuoting};
+use dbt_schemas::schemas::common::{DbtIncrementalStrategy, OnSchemaChange, ResolvedQuoting};
 use dbt_schemas::schemas::dbt_column::{DbtColumn, DbtColumnRef};
 use dbt_schemas::schemas::manifest::{
     BigqueryClusterConfig, BigqueryPartitionConfig, GrantAccessToTarget, PartitionConfig,
@@ -31,8 +35,10 @@ use dbt_schemas::schemas::manifest::{
 use dbt_schemas::schemas::project::ModelConfig;
 use dbt_schemas::schemas::properties::ModelConstraint;
 use dbt_schemas::schemas::relations::base::{BaseRelation, ComponentName};
-use dbt_schemas::schemas::serde::{minijinja_value_to_typed_struct, yml_value_to_minijinja};
-use dbt_xdbc::Connection;
+use dbt_schemas::schemas::serde::{
+    StringOrArrayOfStrings, minijinja_value_to_typed_struct, yml_value_to_minijinja,
+};
+use dbt_xdbc::{Connection, QueryCtx};
 use minijinja::arg_utils::{ArgParser, ArgsIter, check_num_args};
 use minijinja::dispatch_object::DispatchObject;
 use minijinja::listener::RenderingEventListener;
@@ -184,6 +190,22 @@ impl BridgeAdapter {
     pub fn typed_adapter(&self) -> &dyn TypedBaseAdapter {
         self.typed_adapter.as_ref()
     }
+
+    /// Cache the schemas of the relation cache snapshot that are still up to date
+    ///
+    /// Runs once, before the cache is first looked up.
+    fn validate_relation_cache_snapshot(&self, state: Option<&State>) {
+        if !self.relation_cache.has_unvalidated_snapshot() {
+            return;
+        }
+        self.relation_cache.validate_snapshot(|db_schemas| {
+            let mut conn = self.typed_adapter.new_connection(state, None)?;
+            let query_ctx = QueryCtx::new(self.adapter_type().to_string())
+                .with_desc("Validate relation cache snapshot");
+            self.typed_adapter
+                .list_schemas_last_altered(&query_ctx, conn.as_mut(), db_schemas)
+        });
+    }
 }
 
 impl AdapterTyping for BridgeAdapter {
@@ -231,6 +253,14 @@ impl BaseAdapter for BridgeAdapter {
         Ok(conn)
     }
 
+    #[tracing::instrument(skip(self, state), level = "trace")]
+    fn commit(&self, state: &State, _args: &[Value]) -> Result<Value, MinijinjaError> {
+        let mut conn = self.borrow_tlocal_connection(Some(state), node_id_from_state(state))?;
+        let query_ctx = query_ctx_from_state(state)?.with_desc("commit adapter call");
+        transactions::commit(self.engine(), Some(state), conn.as_mut(), &query_ctx)?;
+        Ok(Value::from(true))
+    }
+
     fn update_relation_cache(
         &self,
         schema_to_relations_map: BTreeMap<CatalogAndSchema, RelationVec>,
@@ -246,6 +276,7 @@ impl BaseAdapter for BridgeAdapter {
     }
 
     fn is_already_fully_cached(&self, schema: &CatalogAndSchema) -> bool {
+        self.validate_relation_cache_snapshot(None);
         self.relation_cache.contains_full_schema(schema)
     }
 
@@ -254,7 +285,10 @@ impl BaseAdapter for BridgeAdapter {
         let iter = ArgsIter::new(current_function_name!(), &["relation"], args);
         let relation = iter.next_arg::<&RelationObject>()?;
         iter.finish()?;
-        self.relation_cache.insert_relation(relation.inner(), None);
+        let relation = relation.inner();
+        self.relation_cache
+            .invalidate_schema_for_relation(&relation);
+        self.relation_cache.insert_relation(relation, None);
         Ok(none_value())
     }
 
@@ -302,6 +336,47 @@ impl BaseAdapter for BridgeAdapter {
         }
     }
 
+    #[tracing::instrument(skip_all, level = "trace")]
+    fn diff_grants(&self, state: &State, args: &[Value]) -> Result<Value, MinijinjaError> {
+        let mut parser = ArgParser::new(args, None);
+        check_num_args(current_function_name!(), &parser, 2, 2)?;
+
+        let current_grants = parser.get::<Value>("current_grants")?;
+        let grant_config = parser.get::<Value>("grant_config")?;
+
+        let to_grants_dict = |name: &str, value: Value| -> Result<GrantsDict, MinijinjaError> {
+            if value.is_none() || value.is_undefined() {
+                return Ok(GrantsDict::new());
+            }
+            let grants: BTreeMap<String, StringOrArrayOfStrings> =
+                minijinja_value_to_typed_struct(value).map_err(|e| {
+                    MinijinjaError::new(
+                        MinijinjaErrorKind::SerdeDeserializeError,
+                        format!("diff_grants: invalid {name}: {e}"),
+                    )
+                })?;
+            Ok(grants
+                .into_iter()
+                .map(|(privilege, grantees)| (privilege, grantees.into()))
+                .collect())
+        };
+        let current_grants = to_grants_dict("current_grants", current_grants)?;
+        let grant_config = to_grants_dict("grant_config", grant_config)?;
+
+        let plan = self
+            .typed_adapter
+            .diff_grants(&current_grants, &grant_config)?;
+        if plan.has_changes() {
+            let node_id = node_id_from_state(state).unwrap_or_default();
+            log::info!(
+                name = "GrantsPlan", data:serde = serde_json::json!({ "node_info": { "unique_id": node_id } });
+                "Grant changes planned:\n{}",
+                plan.summary()
+            );
+        }
+        Ok(Value::from_object(plan))
+    }
+
     #[tracing::instrument(skip_all, level = "trace")]
     fn quote(&self, state: &State, identifier: &str) -> Result<Value, MinijinjaError> {
         let quoted_identifier = self.typed_adapter.quote(state, identifier)?;
@@ -430,6 +505,54 @@ impl BaseAdapter for BridgeAdapter {
         Ok((response, table))
     }
 
+    #[tracing::instrument(skip(self, state, bindings), level = "trace")]
+    #[allow(clippy::too_many_arguments)]
+    fn execute_with_bindings(
+        &self,
+        state: &State,
+        sql: &str,
+        bindings: &Value,
+        auto_begin: bool,
+        fetch: bool,
+        limit: Option<i64>,
+        options: Option<HashMap<String, String>>,
+    ) -> AdapterResult<(AdapterResponse, AgateTable)> {
+        let adapter_type = self.typed_adapter.adapter_type();
+        if self.typed_adapter.as_replay().is_some() {
+            // Replay adapters match the recorded SQL text, which has the
+            // bindings formatted in.
+            let sql = format_sql_with_bindings(adapter_type, sql, bindings)?;
+            return self.execute(state, &sql, auto_begin, fetch, limit, options);
+        }
+
+        let (sql, placeholders) = rewrite_placeholders(adapter_type, sql);
+        let params = bindings_to_record_batch(bindings)?;
+        if params.num_columns() != placeholders {
+            return Err(AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!(
+                    "Expected {placeholders} bindings for SQL template, got {}",
+                    params.num_columns()
+                ),
+            ));
+        }
+
+        let mut conn = self.borrow_tlocal_connection(Some(state), node_id_from_state(state))?;
+        let query_ctx =
+            query_ctx_from_state_with_sql(state, sql)?.with_desc("execute adapter call");
+        let (response, table) = self.typed_adapter.execute_with_params(
+            Some(state),
+            conn.as_mut(),
+            &query_ctx,
+            params,
+            auto_begin,
+            fetch,
+            limit,
+            options,
+        )?;
+        Ok((response, table))
+    }
+
     #[tracing::instrument(skip(self, state, bindings), level = "trace")]
     fn add_query(
         &self,
@@ -694,6 +817,7 @@ impl BaseAdapter for BridgeAdapter {
         // Skip cache in replay mode
         let is_replay = self.typed_adapter.as_replay().is_some();
         if !is_replay {
+            self.validate_relation_cache_snapshot(Some(state));
             let temp_relation = relation_object::create_relation(
                 self.typed_adapter.adapter_type(),
                 database.to_string(),
@@ -783,6 +907,45 @@ impl BaseAdapter for BridgeAdapter {
         Ok(Value::from_object(result))
     }
 
+    #[tracing::instrument(skip(self, state), level = "trace")]
+    fn plan_schema_changes(&self, state: &State, args: &[Value]) -> Result<Value, MinijinjaError> {
+        let mut parser = ArgParser::new(args, None);
+        check_num_args(current_function_name!(), &parser, 4, 4)?;
+
+        let target_relation = parser.get::<Value>("target_relation")?;
+        let source_columns = parser.get::<Value>("source_columns")?;
+        let target_columns = parser.get::<Value>("target_columns")?;
+        let on_schema_change = parser.get::<Value>("on_schema_change")?;
+
+        let target_relation = downcast_value_to_dyn_base_relation(&target_relation)?;
+        // the columns check_for_schema_changes already fetched
+        let source_columns = StdColumn::vec_from_jinja_value(self.adapter_type(), source_columns)?;
+        let target_columns = StdColumn::vec_from_jinja_value(self.adapter_type(), target_columns)?;
+        let on_schema_change: OnSchemaChange = minijinja_value_to_typed_struct(on_schema_change)
+            .map_err(|e| {
+                MinijinjaError::new(
+                    MinijinjaErrorKind::SerdeDeserializeError,
+                    format!("plan_schema_changes: invalid on_schema_change: {e}"),
+                )
+            })?;
+
+        let target = target_relation.render_self_as_str();
+        let plan = self.typed_adapter.plan_schema_changes(
+            &source_columns,
+            &target_columns,
+            on_schema_change,
+        );
+        if plan.has_changes() {
+            let node_id = node_id_from_state(state).unwrap_or_default();
+            log::info!(
+                name = "SchemaChangePlan", data:serde = serde_json::json!({ "node_info": { "unique_id": node_id } });
+                "Schema changes planned for {target}:\n{}",
+                plan.summary()
+            );
+        }
+        Ok(Value::from_object(plan))
+    }
+
     #[tracing::instrument(skip(self, state), level = "trace")]
     fn get_columns_in_relation(
         &self,
@@ -1459,6 +1622,7 @@ impl BaseAdapter for BridgeAdapter {
         let source = downcast_value_to_dyn_base_relation(&source)?;
         let dest = downcast_value_to_dyn_base_relation(&dest)?;
 
+        self.relation_cache.invalidate_schema_for_relation(&dest);
         self.relation_cache.insert_relation(dest.clone(), None);
 
         let mut conn = self.borrow_tlocal_connection(Some(state), node_id_from_state(state))?;
@@ -1,9 +1,24 @@
-use std::sync::Arc;
-
-use dashmap::DashMap;
+use std::collections::BTreeMap;
+use std::io;
+use std::path::{Path, PathBuf};
+use std::sync::{Arc, Mutex};
+
+use dashmap::{DashMap, DashSet};
+use dbt_common::adapter::AdapterType;
+use dbt_common::io_args::EvalArgs;
+use dbt_schemas::dbt_types::RelationType;
+use dbt_schemas::schemas::common::ResolvedQuoting;
 use dbt_schemas::schemas::relations::{base::BaseRelation, relation_configs::BaseRelationConfig};
+use serde::{Deserialize, Serialize};
 
+use crate::errors::AdapterResult;
 use crate::metadata::{CatalogAndSchema, RelationVec};
+use crate::relation_object::create_relation;
+
+/// Directory, relative to the target path, that holds relation cache snapshots
+pub const RELATION_CACHE_DIR: &str = "relation_cache";
+/// Bumped whenever the snapshot layout changes, older snapshots are ignored
+const RELATION_CACHE_SNAPSHOT_VERSION: u32 = 1;
 
 type RelationCacheKey = String;
 /// Represents a [BaseRelation] and any associated [BaseRelationConfig] if available
@@ -44,6 +59,17 @@ impl RelationCacheEntry {
     }
 }
 
+/// What the warehouse reports about the relations of a schema
+///
+/// Used to tell whether a schema changed since a snapshot of it was taken.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub struct SchemaLastAltered {
+    /// Timestamp in milliseconds when a relation of the schema was last altered
+    pub last_altered: u128,
+    /// Number of relations in the schema, which also changes when one is dropped
+    pub num_relations: usize,
+}
+
 #[derive(Debug, Clone, Default)]
 struct SchemaEntry {
     relations: DashMap<RelationCacheKey, RelationCacheEntry>,
@@ -51,6 +77,8 @@ struct SchemaEntry {
     is_complete: bool,
     // Timestamp when this schema was cached (for complete schemas)
     cached_at: u128,
+    // The schema itself, known for complete schemas
+    schema: Option<CatalogAndSchema>,
 }
 
 /// A dialect agnostic cache of [RelationCacheEntry]
@@ -70,7 +98,7 @@ struct SchemaEntry {
 /// // Retrieve cached relation
 /// let cached: Option<RelationCacheEntry> = cache.get_relation(relation);
 /// ```
-#[derive(Debug, Clone, Default)]
+#[derive(Debug, Default)]
 pub struct RelationCache {
     // This structure loosely represents remote warehouse state
     // Outer key represents a database schema
@@ -80,9 +108,57 @@ pub struct RelationCache {
     // The inner key is a unique key generated from a relation's fully qualified name
     // We also differentiate using [SchemaEntry] to see what information we actually know about that schema
     schemas_and_relations: DashMap<String, SchemaEntry>,
+    // Schemas in which DDL was observed during this invocation, these are not persisted
+    invalidated_schemas: DashSet<String>,
+    // Where the snapshot is written when the cache is dropped, see [RelationCache::for_invocation]
+    snapshot_target: Option<(PathBuf, AdapterType)>,
+    // Schemas loaded from a snapshot that were not validated against the warehouse yet
+    unvalidated_snapshot: Mutex<Vec<UnvalidatedSchema>>,
+}
+
+impl Clone for RelationCache {
+    /// Clones the cached state. Only the original cache writes its snapshot.
+    fn clone(&self) -> Self {
+        Self {
+            schemas_and_relations: self.schemas_and_relations.clone(),
+            invalidated_schemas: self.invalidated_schemas.clone(),
+            snapshot_target: None,
+            unvalidated_snapshot: Mutex::default(),
+        }
+    }
 }
 
 impl RelationCache {
+    /// Creates the relation cache of an invocation against the given profile target
+    ///
+    /// Snapshots are opt-in through `--persist-relation-cache`; without it the
+    /// cache starts empty and nothing is written. With it, the snapshot
+    /// persisted by the previous invocation is loaded, unless
+    /// `--refresh-relation-cache` was passed, and its schemas are used once
+    /// [RelationCache::validate_snapshot] confirmed they did not change. A new
+    /// snapshot is written to the target path when the cache is dropped at the
+    /// end of the invocation.
+    pub fn for_invocation(
+        args: &EvalArgs,
+        adapter_type: AdapterType,
+        profile: &str,
+        target: &str,
+    ) -> Self {
+        if !args.persist_relation_cache {
+            return Self::default();
+        }
+        let path = Self::snapshot_path(&args.io.out_dir, profile, target);
+        let cache = Self {
+            snapshot_target: Some((path.clone(), adapter_type)),
+            ..Default::default()
+        };
+        if !args.refresh_relation_cache {
+            let loaded = cache.load_snapshot(&path, adapter_type);
+            log::debug!("Loaded {loaded} schemas from relation cache snapshot");
+        }
+        cache
+    }
+
     /// Retrieves a cached entry by relation
     pub fn get_relation(&self, relation: &Arc<dyn BaseRelation>) -> Option<RelationCacheEntry> {
         let (schema_key, relation_key) = Self::get_relation_cache_keys(relation);
@@ -129,11 +205,20 @@ impl RelationCache {
     /// Removes and returns a cached entry by relation
     pub fn evict_relation(&self, relation: &Arc<dyn BaseRelation>) -> Option<RelationCacheEntry> {
         let (schema_key, relation_key) = Self::get_relation_cache_keys(relation);
+        self.invalidated_schemas.insert(schema_key.clone());
         self.evict(&schema_key, &relation_key)
     }
 
     /// Inserts a schema and its relations into the cache
     pub fn insert_schema(&self, schema: CatalogAndSchema, relations: RelationVec) {
+        let cached_at = std::time::UNIX_EPOCH
+            .elapsed()
+            .map(|d| d.as_millis())
+            .unwrap_or(0);
+        self.insert_schema_at(schema, relations, cached_at);
+    }
+
+    fn insert_schema_at(&self, schema: CatalogAndSchema, relations: RelationVec, cached_at: u128) {
         let cached_relations: DashMap<_, _> = relations
             .iter()
             .map(|r| {
@@ -144,17 +229,13 @@ impl RelationCache {
             })
             .collect();
 
-        let cached_at = std::time::UNIX_EPOCH
-            .elapsed()
-            .map(|d| d.as_millis())
-            .unwrap_or(0);
-
         self.schemas_and_relations.insert(
             schema.to_string(),
             SchemaEntry {
                 relations: cached_relations,
                 is_complete: true,
                 cached_at,
+                schema: Some(schema),
             },
         );
     }
@@ -162,9 +243,18 @@ impl RelationCache {
     /// Drops an entire schema
     pub fn evict_schema_for_relation(&self, relation: &Arc<dyn BaseRelation>) {
         let schema_key = Self::get_schema_cache_key_from_relation(relation);
+        self.invalidated_schemas.insert(schema_key.clone());
         self.schemas_and_relations.remove(&schema_key);
     }
 
+    /// Excludes the schema of the given relation from the persisted snapshot
+    ///
+    /// Called when DDL is observed in the schema, e.g. when a relation is created
+    pub fn invalidate_schema_for_relation(&self, relation: &Arc<dyn BaseRelation>) {
+        self.invalidated_schemas
+            .insert(Self::get_schema_cache_key_from_relation(relation));
+    }
+
     /// Checks if the entire schema was cached
     ///
     /// If relation provided does not contain catalog/database and schema information
@@ -201,6 +291,7 @@ impl RelationCache {
         old: &Arc<dyn BaseRelation>,
         new: Arc<dyn BaseRelation>,
     ) -> Option<RelationCacheEntry> {
+        self.invalidate_schema_for_relation(&new);
         if let Some(original_entry) = self.evict_relation(old) {
             self.insert_relation(new, original_entry.relation_config)
         } else {
@@ -213,6 +304,161 @@ impl RelationCache {
         self.schemas_and_relations.clear();
     }
 
+    /// Loads complete schemas from a snapshot written by [RelationCache::save_snapshot]
+    ///
+    /// The schemas are not cached until [RelationCache::validate_snapshot]
+    /// confirmed they are up to date. The snapshot file is removed once
+    /// loaded, so that an invocation that does not finish cleanly cannot leave
+    /// behind a snapshot that misses its DDL. Returns the number of schemas
+    /// loaded; a missing, unreadable or incompatible snapshot loads nothing.
+    pub fn load_snapshot(&self, path: &Path, adapter_type: AdapterType) -> usize {
+        let Ok(contents) = std::fs::read_to_string(path) else {
+            return 0;
+        };
+        let _ = std::fs::remove_file(path);
+        let snapshot = match serde_json::from_str::<RelationCacheSnapshot>(&contents) {
+            Ok(snapshot)
+                if snapshot.version == RELATION_CACHE_SNAPSHOT_VERSION
+                    && snapshot.adapter_type == adapter_type =>
+            {
+                snapshot
+            }
+            Ok(_) => return 0,
+            Err(e) => {
+                log::debug!(
+                    "Ignoring invalid relation cache snapshot {}: {e}",
+                    path.display()
+                );
+                return 0;
+            }
+        };
+
+        let mut unvalidated = self.unvalidated_snapshot.lock().unwrap();
+        let mut loaded = 0;
+        for schema in snapshot.schemas {
+            let relations = schema
+                .relations
+                .into_iter()
+                .map(|r| {
+                    create_relation(
+                        adapter_type,
+                        r.database,
+                        r.schema,
+                        Some(r.identifier),
+                        r.relation_type,
+                        r.quoting,
+                    )
+                })
+                .collect::<Result<RelationVec, _>>();
+            match relations {
+                Ok(relations) => {
+                    unvalidated.push(UnvalidatedSchema {
+                        schema: schema.schema,
+                        captured_at: schema.captured_at,
+                        relations,
+                    });
+                    loaded += 1;
+                }
+                Err(e) => log::debug!("Skipping cached schema from snapshot: {e}"),
+            }
+        }
+        loaded
+    }
+
+    /// Whether schemas loaded from a snapshot are waiting for [RelationCache::validate_snapshot]
+    pub fn has_unvalidated_snapshot(&self) -> bool {
+        !self.unvalidated_snapshot.lock().unwrap().is_empty()
+    }
+
+    /// Caches the schemas loaded from a snapshot that did not change since
+    ///
+    /// `last_altered` is called once with the loaded schemas and reports what
+    /// the warehouse knows about them. A schema is cached only if none of its
+    /// relations was altered after it was captured and it has as many
+    /// relations as it had then. Schemas the warehouse reports nothing about,
+    /// and all of them if `last_altered` fails, are listed again instead.
+    /// Returns the number of schemas cached.
+    pub fn validate_snapshot<F>(&self, last_altered: F) -> usize
+    where
+        F: FnOnce(
+            &[CatalogAndSchema],
+        ) -> AdapterResult<BTreeMap<CatalogAndSchema, SchemaLastAltered>>,
+    {
+        // Hold the lock while validating, so concurrent lookups wait for the
+        // snapshot rather than list the schemas again
+        let mut unvalidated = self.unvalidated_snapshot.lock().unwrap();
+        let snapshot = std::mem::take(&mut *unvalidated);
+        if snapshot.is_empty() {
+            return 0;
+        }
+        let db_schemas = snapshot
+            .iter()
+            .map(|schema| schema.schema.clone())
+            .collect::<Vec<_>>();
+        let last_altered = match last_altered(&db_schemas) {
+            Ok(last_altered) => last_altered,
+            Err(e) => {
+                log::debug!("Discarding relation cache snapshot: {e}");
+                return 0;
+            }
+        };
+
+        let mut loaded = 0;
+        for schema in snapshot {
+            let is_up_to_date = last_altered.get(&schema.schema).is_some_and(|current| {
+                current.last_altered <= schema.captured_at
+                    && current.num_relations == schema.relations.len()
+            });
+            let key = schema.schema.to_string();
+            if !is_up_to_date
+                || self.invalidated_schemas.contains(&key)
+                || self.contains_full_schema(&schema.schema)
+            {
+                continue;
+            }
+            self.insert_schema_at(schema.schema, schema.relations, schema.captured_at);
+            loaded += 1;
+        }
+        log::debug!("Cached {loaded} up to date schemas from relation cache snapshot");
+        loaded
+    }
+
+    /// Writes complete schemas without observed DDL to a snapshot at `path`
+    ///
+    /// Returns the number of schemas written
+    pub fn save_snapshot(&self, path: &Path, adapter_type: AdapterType) -> io::Result<usize> {
+        let mut schemas = self
+            .schemas_and_relations
+            .iter()
+            .filter(|entry| {
+                entry.value().is_complete && !self.invalidated_schemas.contains(entry.key())
+            })
+            .filter_map(|entry| SchemaSnapshot::try_from_entry(entry.value()))
+            .collect::<Vec<_>>();
+        schemas.sort_by(|a, b| a.schema.cmp(&b.schema));
+        let num_schemas = schemas.len();
+
+        let snapshot = RelationCacheSnapshot {
+            version: RELATION_CACHE_SNAPSHOT_VERSION,
+            adapter_type,
+            schemas,
+        };
+        if let Some(parent) = path.parent() {
+            std::fs::create_dir_all(parent)?;
+        }
+        let tmp_path = path.with_extension("json.tmp");
+        std::fs::write(&tmp_path, serde_json::to_vec(&snapshot)?)?;
+        std::fs::rename(tmp_path, path)?;
+        Ok(num_schemas)
+    }
+
+    /// Path of the relation cache snapshot of a target
+    pub fn snapshot_path(target_path: &Path, profile: &str, target: &str) -> PathBuf {
+        target_path
+            .join(RELATION_CACHE_DIR)
+            .join(format!("{profile}.{target}.json"))
+    }
+
     /// Number of total relations cached
     pub fn num_relations(&self) -> usize {
         self.schemas_and_relations
@@ -343,9 +589,77 @@ impl RelationCache {
     }
 }
 
+/// On-disk representation of the complete schemas of a [RelationCache]
+#[derive(Debug, Serialize, Deserialize)]
+struct RelationCacheSnapshot {
+    version: u32,
+    adapter_type: AdapterType,
+    schemas: Vec<SchemaSnapshot>,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct SchemaSnapshot {
+    schema: CatalogAndSchema,
+    /// Timestamp in milliseconds when the schema was listed
+    captured_at: u128,
+    relations: Vec<RelationSnapshot>,
+}
+
+impl SchemaSnapshot {
+    fn try_from_entry(entry: &SchemaEntry) -> Option<Self> {
+        let relations = entry
+            .relations
+            .iter()
+            .map(|r| RelationSnapshot::try_from_relation(&r.value().relation))
+            .collect::<Option<Vec<_>>>()?;
+        Some(Self {
+            schema: entry.schema.clone()?,
+            captured_at: entry.cached_at,
+            relations,
+        })
+    }
+}
+
+/// A schema loaded from a snapshot, see [RelationCache::validate_snapshot]
+#[derive(Debug)]
+struct UnvalidatedSchema {
+    schema: CatalogAndSchema,
+    captured_at: u128,
+    relations: RelationVec,
+}
+
+#[derive(Debug, Serialize, Deserialize)]
+struct RelationSnapshot {
+    database: String,
+    schema: String,
+    identifier: String,
+    relation_type: Option<RelationType>,
+    quoting: ResolvedQuoting,
+}
+
+impl RelationSnapshot {
+    fn try_from_relation(relation: &Arc<dyn BaseRelation>) -> Option<Self> {
+        Some(Self {
+            database: relation.get_database().ok()?,
+            schema: relation.get_schema().ok()?,
+            identifier: relation.get_identifier().ok()?,
+            relation_type: relation.relation_type(),
+            quoting: relation.quote_policy(),
+        })
+    }
+}
+
 impl Drop for RelationCache {
     fn drop(&mut self) {
         self.log_final_state();
+        if let Some((path, adapter_type)) = self.snapshot_target.take() {
+            if let Err(e) = self.save_snapshot(&path, adapter_type) {
+                log::debug!(
+                    "Failed to write relation cache snapshot {}: {e}",
+                    path.display()
+                );
+            }
+        }
     }
 }
 
@@ -354,7 +668,7 @@ mod tests {
     use crate::AdapterType;
 
     use super::*;
-    use dbt_schemas::schemas::{common::ResolvedQuoting, relations::DEFAULT_RESOLVED_QUOTING};
+    use dbt_schemas::schemas::relations::DEFAULT_RESOLVED_QUOTING;
 
     #[test]
     fn test_different_key_creation() {
@@ -638,4 +952,190 @@ mod tests {
         }
         // Test survived all concurrent operations without panicking or corrupting
     }
+
+    #[test]
+    fn test_snapshot_round_trip_skips_invalidated_schemas() {
+        use crate::relation_object::create_relation;
+
+        let relation = |schema: &str, identifier: &str| {
+            create_relation(
+                AdapterType::Postgres,
+                "db".to_string(),
+                schema.to_string(),
+                Some(identifier.to_string()),
+                Some(RelationType::Table),
+                DEFAULT_RESOLVED_QUOTING,
+            )
+            .unwrap()
+        };
+        let kept = relation("kept", "t1");
+        let altered = relation("altered", "t2");
+        let empty = relation("empty", "t3");
+
+        let cache = RelationCache::default();
+        cache.insert_schema(CatalogAndSchema::from(&kept), vec![kept.clone()]);
+        cache.insert_schema(CatalogAndSchema::from(&altered), vec![altered.clone()]);
+        cache.insert_schema(CatalogAndSchema::from(&empty), vec![]);
+        cache.evict_relation(&altered);
+
+        let dir = tempfile::tempdir().unwrap();
+        let path = RelationCache::snapshot_path(dir.path(), "profile", "dev");
+        assert_eq!(
+            cache.save_snapshot(&path, AdapterType::Postgres).unwrap(),
+            2
+        );
+
+        // Snapshots of another adapter type are ignored
+        let restored = RelationCache::default();
+        assert_eq!(restored.load_snapshot(&path, AdapterType::Snowflake), 0);
+        cache.save_snapshot(&path, AdapterType::Postgres).unwrap();
+
+        assert_eq!(restored.load_snapshot(&path, AdapterType::Postgres), 2);
+        assert!(!path.exists());
+        // Nothing is cached until the snapshot is validated
+        assert!(!restored.contains_full_schema_for_relation(&kept));
+        let unchanged = |db_schemas: &[CatalogAndSchema]| {
+            Ok(db_schemas
+                .iter()
+                .map(|db_schema| {
+                    let num_relations = usize::from(db_schema.resolved_schema == "kept");
+                    let current = SchemaLastAltered {
+                        last_altered: 0,
+                        num_relations,
+                    };
+                    (db_schema.clone(), current)
+                })
+                .collect())
+        };
+        assert_eq!(restored.validate_snapshot(unchanged), 2);
+        assert!(!restored.has_unvalidated_snapshot());
+        let entry = restored.get_relation(&kept).unwrap();
+        assert_eq!(entry.relation().relation_type(), Some(RelationType::Table));
+        assert!(restored.contains_full_schema_for_relation(&empty));
+        assert!(!restored.contains_full_schema_for_relation(&altered));
+    }
+
+    #[test]
+    fn test_invocation_cache_persists_on_drop() {
+        use crate::relation_object::create_relation;
+        use dbt_common::io_args::IoArgs;
+
+        let relation = create_relation(
+            AdapterType::Postgres,
+            "db".to_string(),
+            "analytics".to_string(),
+            Some("t1".to_string()),
+            Some(RelationType::Table),
+            DEFAULT_RESOLVED_QUOTING,
+        )
+        .unwrap();
+        let dir = tempfile::tempdir().unwrap();
+        let args = EvalArgs {
+            io: IoArgs {
+                out_dir: dir.path().to_path_buf(),
+                ..Default::default()
+            },
+            persist_relation_cache: true,
+            ..Default::default()
+        };
+        let unchanged = |db_schemas: &[CatalogAndSchema]| {
+            Ok(db_schemas
+                .iter()
+                .map(|db_schema| {
+                    let current = SchemaLastAltered {
+                        last_altered: 0,
+                        num_relations: 1,
+                    };
+                    (db_schema.clone(), current)
+                })
+                .collect())
+        };
+
+        // Snapshots are opt-in
+        let cache = RelationCache::for_invocation(
+            &EvalArgs {
+                persist_relation_cache: false,
+                ..args.clone()
+            },
+            AdapterType::Postgres,
+            "profile",
+            "dev",
+        );
+        cache.insert_schema(CatalogAndSchema::from(&relation), vec![relation.clone()]);
+        drop(cache);
+        assert!(!RelationCache::snapshot_path(dir.path(), "profile", "dev").exists());
+
+        let cache = RelationCache::for_invocation(&args, AdapterType::Postgres, "profile", "dev");
+        cache.insert_schema(CatalogAndSchema::from(&relation), vec![relation.clone()]);
+        // Clones do not write the snapshot
+        drop(cache.clone());
+        assert!(!RelationCache::snapshot_path(dir.path(), "profile", "dev").exists());
+        drop(cache);
+
+        let cache = RelationCache::for_invocation(&args, AdapterType::Postgres, "profile", "dev");
+        assert_eq!(cache.validate_snapshot(unchanged), 1);
+        assert!(cache.contains_full_schema_for_relation(&relation));
+        drop(cache);
+
+        let args = EvalArgs {
+            refresh_relation_cache: true,
+            ..args
+        };
+        let cache = RelationCache::for_invocation(&args, AdapterType::Postgres, "profile", "dev");
+        assert!(!cache.has_unvalidated_snapshot());
+        assert!(!cache.contains_full_schema_for_relation(&relation));
+    }
+
+    #[test]
+    fn test_snapshot_schemas_altered_since_capture_are_not_cached() {
+        use crate::relation_object::create_relation;
+
+        let relation = |schema: &str| {
+            create_relation(
+                AdapterType::Postgres,
+                "db".to_string(),
+                schema.to_string(),
+                Some("t1".to_string()),
+                Some(RelationType::Table),
+                DEFAULT_RESOLVED_QUOTING,
+            )
+            .unwrap()
+        };
+        let schemas = ["unchanged", "altered", "dropped", "unknown"];
+        let cache = RelationCache::default();
+        for schema in schemas {
+            let relation = relation(schema);
+            cache.insert_schema(CatalogAndSchema::from(&relation), vec![relation]);
+        }
+        let dir = tempfile::tempdir().unwrap();
+        let path = RelationCache::snapshot_path(dir.path(), "profile", "dev");
+        cache.save_snapshot(&path, AdapterType::Postgres).unwrap();
+
+        let restored = RelationCache::default();
+        assert_eq!(restored.load_snapshot(&path, AdapterType::Postgres), 4);
+        let loaded = restored.validate_snapshot(|db_schemas| {
+            assert_eq!(db_schemas.len(), 4);
+            let current = |schema: &str, last_altered, num_relations| {
+                (
+                    CatalogAndSchema::from(&relation(schema)),
+                    SchemaLastAltered {
+                        last_altered,
+                        num_relations,
+                    },
+                )
+            };
+            Ok(BTreeMap::from([
+                current("unchanged", 0, 1),
+                current("altered", u128::MAX, 1),
+                current("dropped", 0, 0),
+            ]))
+        });
+        assert_eq!(loaded, 1);
+        assert!(restored.contains_full_schema_for_relation(&relation("unchanged")));
+        for schema in ["altered", "dropped", "unknown"] {
+            assert!(!restored.contains_full_schema_for_relation(&relation(schema)));
+        }
+        // The snapshot is validated once
+        assert_eq!(restored.validate_snapshot(|_| unreachable!()), 0);
+    }
 }
@@ -42,6 +42,12 @@ const ICEBERG_TABLE_FORMAT: &str = "ICEBERG";
 const ALLOWED_TABLE_FORMATS_SNOWFLAKE: [&str; 2] = [DEFAULT_TABLE_FORMAT, ICEBERG_TABLE_FORMAT];
 const ALLOWED_TABLE_FORMATS_DISPLAY_SNOWFLAKE: &str = "DEFAULT|ICEBERG";
 
+// BigQuery tables live in the BigQuery-managed store unless a BigLake metastore catalog is selected
+const BIGQUERY_RELATION_STORE: &str = "info_schema";
+const BIGQUERY_DEFAULT_TABLE_FORMAT: &str = "default";
+const BIGLAKE_TABLE_FORMAT: &str = "iceberg";
+const BIGLAKE_FILE_FORMAT: &str = "parquet";
+
 #[derive(Debug, serde::Serialize)]
 pub struct CatalogRelation {
     pub adapter_type: AdapterType,
@@ -68,7 +74,7 @@ pub struct CatalogRelation {
     // built_in only: synthesized base_location_root and base_location_subpath model attributes
     pub base_location: Option<String>,
 
-    // === Databricks
+    // === Databricks, BigQuery
     pub file_format: Option<String>,
     // TODO: be the owner of tblproperties for model config resolution
 }
@@ -86,6 +92,9 @@ impl CatalogRelation {
             AdapterType::Snowflake => {
                 Self::from_model_config_and_catalogs_snowflake(model_config, catalogs)
             }
+            AdapterType::Bigquery => {
+                Self::from_model_config_and_catalogs_bigquery(model_config, catalogs)
+            }
             _ => Err(AdapterError::new(
                 AdapterErrorKind::Internal,
                 format!("build_relation_catalog cannot be invoked by an adapter {adapter_type:?}"),
@@ -620,14 +629,24 @@ impl CatalogRelation {
                 )
             })?;
 
-        let catalog_type = CatalogType::parse_strict(&raw_catalog_type)
-            .map_err(|e| {
-                AdapterError::new(
-                    AdapterErrorKind::Configuration,
-                    format!("Invalid catalog_type '{raw_catalog_type}': {e}"),
-                )
-            })?
-            .as_str();
+        let catalog_type = CatalogType::parse_strict(&raw_catalog_type).map_err(|e| {
+            AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!("Invalid catalog_type '{raw_catalog_type}': {e}"),
+            )
+        })?;
+        if !matches!(catalog_type, CatalogType::SnowflakeBuiltIn)
+            && !catalog_type.is_snowflake_catalog_linked()
+        {
+            return Err(AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!(
+                    "catalog_type '{raw_catalog_type}' of catalog '{catalog_name}' is not supported on Snowflake. \
+                     Must be one of (built_in|iceberg_rest|glue|polaris)."
+                ),
+            ));
+        }
+        let catalog_type = catalog_type.as_str();
 
         let table_format = Self::get_model_config_value(model_config, "table_format")
             .or_else(|| Self::yml_str(write_integration, "table_format".to_string()))
@@ -700,6 +719,172 @@ impl CatalogRelation {
         })
     }
 
+    // ========
+    // BigQuery
+    // ========
+
+    fn from_model_config_and_catalogs_bigquery(
+        model_config: &Value,
+        catalogs: Option<Arc<DbtCatalogs>>,
+    ) -> AdapterResult<CatalogRelation> {
+        let model_catalog_name = Self::get_model_config_value(model_config, "catalog_name")
+            .map(|s| s.trim().to_string())
+            .filter(|s| !s.eq_ignore_ascii_case("none"));
+
+        match (model_catalog_name.as_deref(), catalogs.as_ref()) {
+            (None, _) => Ok(Self::default_catalog_relation_bigquery()),
+            (Some(catalog_name), None) => Err(AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!(
+                    "Model specifies catalog_name '{catalog_name}', but catalogs.yml was not found"
+                ),
+            )),
+            (Some(catalog_name), Some(catalogs)) => {
+                Self::build_bigquery_with_catalogs(model_config, catalogs.mapping(), catalog_name)
+            }
+        }
+    }
+
+    fn default_catalog_relation_bigquery() -> CatalogRelation {
+        CatalogRelation {
+            adapter_type: AdapterType::Bigquery,
+            catalog_name: None,
+            integration_name: None,
+            catalog_type: BIGQUERY_RELATION_STORE.to_string(),
+            table_format: BIGQUERY_DEFAULT_TABLE_FORMAT.to_string(),
+            file_format: None,
+            external_volume: None,
+            base_location: None,
+            adapter_properties: BTreeMap::new(),
+            is_transient: None,
+        }
+    }
+
+    fn build_bigquery_with_catalogs(
+        model_config: &Value,
+        catalogs: &YmlMapping,
+        catalog_name: &str,
+    ) -> AdapterResult<CatalogRelation> {
+        let catalog = find_catalog(catalogs, catalog_name).ok_or_else(|| {
+            AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!("Catalog '{catalog_name}' not found in catalogs.yml"),
+            )
+        })?;
+        let integration_name =
+            lookup_integration_name(catalogs, catalog_name).ok_or_else(|| {
+                AdapterError::new(
+                    AdapterErrorKind::Configuration,
+                    format!("Catalog '{catalog_name}' missing 'active_write_integration'"),
+                )
+            })?;
+        let write_integration = Self::lookup_write_integration(catalog, &integration_name);
+
+        if Self::get_model_config_value(model_config, "catalog_type").is_some() {
+            return Err(AdapterError::new(
+                AdapterErrorKind::Configuration,
+                "catalog_type may only be specified in write integration entries of catalogs.yml",
+            ));
+        }
+
+        let raw_catalog_type = Self::yml_str(write_integration, "catalog_type".to_owned())
+            .ok_or_else(|| {
+                AdapterError::new(
+                    AdapterErrorKind::Configuration,
+                    "catalog_type missing from catalogs.yml (should be impossible by schema)",
+                )
+            })?;
+        if !matches!(
+            CatalogType::parse_strict(&raw_catalog_type),
+            Ok(CatalogType::BiglakeMetastore)
+        ) {
+            return Err(AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!(
+                    "catalog_type '{raw_catalog_type}' of catalog '{catalog_name}' is not supported on BigQuery. \
+                     Must be biglake_metastore."
+                ),
+            ));
+        }
+
+        // BigLake tables are always Iceberg on Parquet; a model may only restate that
+        if let Some(table_format) = Self::get_model_config_value(model_config, "table_format")
+            && !table_format.eq_ignore_ascii_case(BIGLAKE_TABLE_FORMAT)
+        {
+            return Err(AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!(
+                    "Unsupported table_format '{table_format}' in catalog '{catalog_name}'. \
+                     BigLake metastore catalogs only write iceberg tables."
+                ),
+            ));
+        }
+        if let Some(file_format) = Self::get_model_config_value(model_config, "file_format")
+            && !file_format.eq_ignore_ascii_case(BIGLAKE_FILE_FORMAT)
+        {
+            return Err(AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!(
+                    "Unsupported file_format '{file_format}' in catalog '{catalog_name}'. \
+                     BigLake metastore catalogs only write parquet files."
+                ),
+            ));
+        }
+
+        let external_volume = Self::get_model_config_value(model_config, "external_volume")
+            .or_else(|| Self::yml_str(write_integration, "external_volume".to_string()))
+            .ok_or_else(|| {
+                AdapterError::new(
+                    AdapterErrorKind::Configuration,
+                    format!("Missing required external_volume for catalog '{catalog_name}'"),
+                )
+            })?;
+        if !external_volume.starts_with("gs://") {
+            return Err(AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!(
+                    "external_volume '{external_volume}' of catalog '{catalog_name}' must be a Cloud Storage URI (gs://<bucket>)"
+                ),
+            ));
+        }
+
+        let base_location_root = Self::get_model_config_value(model_config, "base_location_root")
+            .or_else(|| Self::yml_str(write_integration, "base_location_root".to_string()));
+        let base_location_subpath =
+            Self::get_model_config_value(model_config, "base_location_subpath")
+                .or_else(|| Self::yml_str(write_integration, "base_location_subpath".to_string()));
+        let schema = Self::get_model_config_value(model_config, "schema");
+        let identifier = Self::get_model_config_value(model_config, "identifier");
+        let base_location = Self::build_base_location(
+            &base_location_root,
+            &base_location_subpath,
+            &schema,
+            &identifier,
+        );
+
+        Ok(CatalogRelation {
+            adapter_type: AdapterType::Bigquery,
+            catalog_name: Some(catalog_name.to_string()),
+            integration_name: Some(integration_name),
+            catalog_type: CatalogType::BiglakeMetastore.as_str().to_string(),
+            table_format: BIGLAKE_TABLE_FORMAT.to_string(),
+            file_format: Some(BIGLAKE_FILE_FORMAT.to_string()),
+            external_volume: Some(external_volume),
+            base_location: Some(base_location),
+            adapter_properties: Self::merged_adapter_properties(model_config, write_integration),
+            is_transient: None,
+        })
+    }
+
+    /// Cloud Storage URI of a BigLake table: the external volume joined with the base location
+    pub fn storage_uri(&self) -> Option<String> {
+        let volume = self.external_volume.as_deref()?.trim_end_matches('/');
+        match self.base_location.as_deref().map(|s| s.trim_matches('/')) {
+            Some(location) if !location.is_empty() => Some(format!("{volume}/{location}")),
+            _ => Some(volume.to_string()),
+        }
+    }
+
     // [DELIBERATE CHANGE] Core always has schema and identifier in model config,
     // but we do not apparently. This can subtly change location paths in external volumes.
     // https://github.com/dbt-labs/dbt-adapters/blob/c16cc7047e8678f8bb88ae294f43da2c68e9f5cc/dbt-snowflake/src/dbt/adapters/snowflake/parse_model.py#L34
@@ -903,7 +1088,8 @@ impl CatalogRelation {
 
     // === begin HACK
     /// Returns true if `db_name` appears under any write_integration whose
-    /// `catalog_type` is `iceberg_rest` and whose
+    /// `catalog_type` is written through a catalog-linked database
+    /// (`iceberg_rest`, `glue`, `polaris`) and whose
     /// `adapter_properties.catalog_linked_database` equals `db_name`.
     fn cld_exists_in_iceberg_rest(catalogs: &YmlMapping, db_name: &str) -> bool {
         let Some(seq) = catalogs
@@ -924,7 +1110,8 @@ impl CatalogRelation {
                 if let Some(ct) = write_integration
                     .get(key("catalog_type".into()))
                     .and_then(|v| v.as_str())
-                    && !ct.eq_ignore_ascii_case("iceberg_rest")
+                    && !CatalogType::parse_strict(ct)
+                        .is_ok_and(|catalog_type| catalog_type.is_snowflake_catalog_linked())
                 {
                     continue;
                 }
@@ -1052,14 +1239,27 @@ impl Object for CatalogRelation {
                 Self::map_opt_str(self.external_volume.clone())
             }),
 
+            // === Databricks, BigQuery
+            "file_format" => match self.adapter_type {
+                AdapterType::Databricks | AdapterType::Bigquery => {
+                    Self::map_opt_str(self.file_format.clone())
+                }
+                _ => Value::from(()),
+            },
+
             // === Databricks
-            "file_format" => self.gate_by_adapter(AdapterType::Databricks, || {
-                Self::map_opt_str(self.file_format.clone())
-            }),
             "location" => self.gate_by_adapter(AdapterType::Databricks, || {
                 Self::map_opt_str(self.external_volume.clone())
             }),
 
+            // === BigQuery
+            "storage_uri" => self.gate_by_adapter(AdapterType::Bigquery, || {
+                Self::map_opt_str(self.storage_uri())
+            }),
+            "connection" => self.gate_by_adapter(AdapterType::Bigquery, || {
+                Self::map_properties_str(&self.adapter_properties, "connection")
+            }),
+
             _ => Value::from(()),
         })
     }
@@ -1855,4 +2055,113 @@ mod tests {
         let msg = format!("{err}");
         assert!(msg.contains("catalog_type"));
     }
+
+    #[test]
+    fn snowflake_glue_and_polaris_use_catalog_linked_ddl() {
+        for (catalog_type, expected) in [("glue", "GLUE"), ("polaris", "POLARIS")] {
+            let cats = catalogs_yaml_one(
+                "CAT",
+                "WIN",
+                catalog_type,
+                "ICEBERG",
+                &[(
+                    "adapter_properties",
+                    map(&[("catalog_linked_database", s("CLD"))]),
+                )],
+            );
+            let m = model(json!({ "catalog_name": "CAT" }));
+            let r = CatalogRelation::build_with_catalogs(&m, &cats, "CAT").unwrap();
+            assert_eq!(r.catalog_type, expected);
+
+            let r = Arc::new(r);
+            assert_eq!(
+                r.get_value(&JVal::from("catalog_linked_database")),
+                Some(JVal::from("CLD"))
+            );
+
+            assert!(CatalogRelation::cld_exists_in_iceberg_rest(&cats, "cld"));
+        }
+    }
+
+    #[test]
+    fn snowflake_rejects_other_warehouse_catalog_types() {
+        let cats = catalogs_yaml_one("CAT", "WIN", "biglake_metastore", "ICEBERG", &[]);
+        let m = model(json!({ "catalog_name": "CAT" }));
+        let err = CatalogRelation::build_with_catalogs(&m, &cats, "CAT").unwrap_err();
+        assert!(format!("{err}").contains("is not supported on Snowflake"));
+    }
+
+    #[test]
+    fn bigquery_default_relation_without_catalog_name() {
+        let r = CatalogRelation::from_model_config_and_catalogs(
+            &AdapterType::Bigquery,
+            &model(json!({})),
+            None,
+        )
+        .unwrap();
+        assert_eq!(r.catalog_type, BIGQUERY_RELATION_STORE);
+        assert_eq!(r.table_format, BIGQUERY_DEFAULT_TABLE_FORMAT);
+        assert!(r.storage_uri().is_none());
+    }
+
+    #[test]
+    fn bigquery_biglake_relation() {
+        let cats = catalogs_yaml_one(
+            "LAKE",
+            "WIN",
+            "biglake_metastore",
+            "iceberg",
+            &[
+                ("file_format", s("parquet")),
+                ("external_volume", s("gs://bucket/")),
+                ("base_location_root", s("warehouse")),
+                (
+                    "adapter_properties",
+                    map(&[("connection", s("proj.us.lake"))]),
+                ),
+            ],
+        );
+        let m = model(json!({ "catalog_name": "LAKE", "schema": "S", "identifier": "I" }));
+        let r = CatalogRelation::from_model_config_and_catalogs(
+            &AdapterType::Bigquery,
+            &m,
+            Some(Arc::new(DbtCatalogs {
+                repr: cats,
+                span: Default::default(),
+            })),
+        )
+        .unwrap();
+
+        assert_eq!(r.catalog_type, "biglake_metastore");
+        assert_eq!(r.table_format, "iceberg");
+        assert_eq!(
+            r.storage_uri().as_deref(),
+            Some("gs://bucket/warehouse/S/I")
+        );
+
+        let r = Arc::new(r);
+        assert_eq!(
+            r.get_value(&JVal::from("file_format")),
+            Some(JVal::from("parquet"))
+        );
+        assert_eq!(
+            r.get_value(&JVal::from("connection")),
+            Some(JVal::from("proj.us.lake"))
+        );
+        assert!(r.get_value(&JVal::from("location")).unwrap().is_none());
+    }
+
+    #[test]
+    fn bigquery_biglake_rejects_table_format_override() {
+        let cats = catalogs_yaml_one(
+            "LAKE",
+            "WIN",
+            "biglake_metastore",
+            "iceberg",
+            &[("external_volume", s("gs://bucket"))],
+        );
+        let m = model(json!({ "catalog_name": "LAKE", "table_format": "default" }));
+        let err = CatalogRelation::build_bigquery_with_catalogs(&m, &cats, "LAKE").unwrap_err();
+        assert!(format!("{err}").contains("only write iceberg tables"));
+    }
 }
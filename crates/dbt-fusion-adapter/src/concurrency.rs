@@ -0,0 +1,308 @@
+//! Per-backend limits on the number of concurrently executing statements.
+//!
+//! A target's statements are bounded by the profile's `max_concurrent_queries`.
+//! On Snowflake and Databricks, `concurrency_limits` sets different bounds per
+//! warehouse (Snowflake) or per SQL warehouse `http_path` (Databricks), so that
+//! nodes routed to a small warehouse don't overload it while a big one idles:
+//!
+//! ```yaml
+//! max_concurrent_queries: 8
+//! concurrency_limits:
+//!   TRANSFORMING_XS: 2
+//!   TRANSFORMING_XL: 16
+//! ```
+//!
+//! Each backend gets its own [PrioritySemaphore]. Nodes with a higher priority,
+//! e.g. those on the critical path of the DAG, acquire contended permits first.
+
+use crate::AdapterType;
+use crate::config::AdapterConfig;
+use crate::databricks::databricks_compute_from_state;
+use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult};
+
+use dbt_serde_yaml::{Mapping as YmlMapping, Value as YmlValue};
+use dbt_xdbc::semaphore::PrioritySemaphore;
+use minijinja::State;
+
+use std::collections::{BTreeMap, HashMap};
+use std::sync::{Arc, RwLock};
+
+/// Profile key with the limit on concurrent statements of the target.
+const MAX_CONCURRENT_QUERIES: &str = "max_concurrent_queries";
+/// Profile key with the limits on concurrent statements per warehouse or `http_path`.
+const CONCURRENCY_LIMITS: &str = "concurrency_limits";
+/// Node config that routes a Snowflake node to a different warehouse.
+const SNOWFLAKE_WAREHOUSE: &str = "snowflake_warehouse";
+
+/// Concurrency limits of the backends a target executes statements on.
+pub struct ConcurrencyLimits {
+    adapter_type: AdapterType,
+    /// Limit of backends that have no entry in `limits`.
+    default_limit: Option<u32>,
+    /// Limits keyed by normalized backend key.
+    limits: BTreeMap<String, u32>,
+    /// Lazily created semaphores keyed by backend key.
+    semaphores: RwLock<HashMap<String, Arc<PrioritySemaphore>>>,
+    /// Priorities keyed by node unique_id.
+    priorities: RwLock<HashMap<String, u32>>,
+}
+
+impl ConcurrencyLimits {
+    /// Read the limits from the profile of a target.
+    ///
+    /// For backwards compatibility, Redshift targets limit concurrent
+    /// statements to `threads` unless `max_concurrent_queries` is set.
+    pub fn from_config(adapter_type: AdapterType, config: &AdapterConfig) -> AdapterResult<Self> {
+        let default_limit = match config.get(MAX_CONCURRENT_QUERIES) {
+            Some(value) => Some(parse_limit(MAX_CONCURRENT_QUERIES, value)?),
+            None if adapter_type == AdapterType::Redshift => config
+                .get("threads")
+                .and_then(|t| t.as_u64())
+                .filter(|t| *t > 0)
+                .map(|t| u32::try_from(t).unwrap_or(u32::MAX)),
+            None => None,
+        };
+
+        let mut limits = BTreeMap::new();
+        if let Some(value) = config.get(CONCURRENCY_LIMITS) {
+            let YmlValue::Mapping(mapping, _) = value else {
+                return Err(AdapterError::new(
+                    AdapterErrorKind::Configuration,
+                    format!("{CONCURRENCY_LIMITS} must be a mapping of warehouses to limits"),
+                ));
+            };
+            for (key, limit) in mapping.iter() {
+                let Some(key) = key.as_str() else {
+                    return Err(AdapterError::new(
+                        AdapterErrorKind::Configuration,
+                        format!("{CONCURRENCY_LIMITS} keys must be strings"),
+                    ));
+                };
+                let limit = parse_limit(&format!("{CONCURRENCY_LIMITS}.{key}"), limit)?;
+                limits.insert(normalize_key(adapter_type, config, key), limit);
+            }
+        }
+
+        Ok(Self {
+            adapter_type,
+            default_limit,
+            limits,
+            semaphores: RwLock::new(HashMap::new()),
+            priorities: RwLock::new(HashMap::new()),
+        })
+    }
+
+    /// Whether no statement is ever limited.
+    pub fn is_unlimited(&self) -> bool {
+        self.default_limit.is_none() && self.limits.is_empty()
+    }
+
+    /// The limit of the given backend, if it is limited.
+    pub fn limit_for(&self, backend_key: &str) -> Option<u32> {
+        self.limits.get(backend_key).copied().or(self.default_limit)
+    }
+
+    /// The backend statements run on in the context of the current node.
+    ///
+    /// This is the Snowflake warehouse or the Databricks `http_path` the node
+    /// is routed to. Targets of other adapters have a single backend.
+    pub fn backend_key(&self, config: &AdapterConfig, state: Option<&State>) -> String {
+        match self.adapter_type {
+            AdapterType::Snowflake => {
+                let node_warehouse = state
+                    .and_then(|state| state.lookup("model"))
+                    .and_then(|model| model.get_attr("config").ok())
+                    .and_then(|config| config.get_attr(SNOWFLAKE_WAREHOUSE).ok())
+                    .filter(|value| !value.is_undefined() && !value.is_none())
+                    .map(|value| value.to_string());
+                let warehouse = node_warehouse
+                    .or_else(|| config.get_string("warehouse").map(|w| w.into_owned()))
+                    .unwrap_or_default();
+                normalize_key(self.adapter_type, config, &warehouse)
+            }
+            AdapterType::Databricks => state
+                .and_then(databricks_compute_from_state)
+                .and_then(|compute| compute_http_path(config, &compute))
+                .or_else(|| config.get_string("http_path").map(|p| p.into_owned()))
+                .unwrap_or_default(),
+            _ => String::new(),
+        }
+    }
+
+    /// Set the priorities of nodes by unique_id, replacing any previous ones.
+    ///
+    /// Nodes without a priority acquire permits with priority `0`.
+    pub fn set_node_priorities(&self, priorities: impl IntoIterator<Item = (String, u32)>) {
+        let mut guard = self.priorities.write().unwrap();
+        guard.clear();
+        guard.extend(priorities);
+    }
+
+    /// The priority statements of the given node acquire permits with.
+    pub fn priority_of(&self, node_id: Option<&str>) -> u32 {
+        node_id
+            .and_then(|id| self.priorities.read().unwrap().get(id).copied())
+            .unwrap_or(0)
+    }
+
+    /// The semaphore bounding statements on the given backend, if it is limited.
+    pub fn semaphore_for(&self, backend_key: &str) -> Option<Arc<PrioritySemaphore>> {
+        let limit = self.limit_for(backend_key)?;
+        if let Some(semaphore) = self.semaphores.read().unwrap().get(backend_key) {
+            return Some(semaphore.clone());
+        }
+        let mut guard = self.semaphores.write().unwrap();
+        let semaphore = guard
+            .entry(backend_key.to_string())
+            .or_insert_with(|| Arc::new(PrioritySemaphore::new(limit)));
+        Some(semaphore.clone())
+    }
+}
+
+fn parse_limit(key: &str, value: &YmlValue) -> AdapterResult<u32> {
+    let parsed = match value {
+        YmlValue::Number(n, _) => n.as_u64(),
+        YmlValue::String(s, _) => s.trim().parse::<u64>().ok(),
+        _ => None,
+    };
+    parsed
+        .filter(|limit| *limit > 0)
+        .map(|limit| u32::try_from(limit).unwrap_or(u32::MAX))
+        .ok_or_else(|| {
+            AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!("{key} must be a positive integer"),
+            )
+        })
+}
+
+/// Normalize a `concurrency_limits` key or a resolved backend so they compare equal.
+///
+/// Snowflake warehouse names are case-insensitive unless quoted. Databricks
+/// keys may name a `compute` of the profile instead of its `http_path`.
+fn normalize_key(adapter_type: AdapterType, config: &AdapterConfig, key: &str) -> String {
+    match adapter_type {
+        AdapterType::Snowflake if key.starts_with('"') && key.ends_with('"') => {
+            key.trim_matches('"').to_string()
+        }
+        AdapterType::Snowflake => key.to_uppercase(),
+        AdapterType::Databricks => {
+            compute_http_path(config, key).unwrap_or_else(|| key.to_string())
+        }
+        _ => key.to_string(),
+    }
+}
+
+/// Resolve `compute.<name>.http_path` of a Databricks profile.
+fn compute_http_path(config: &AdapterConfig, compute: &str) -> Option<String> {
+    let YmlValue::Mapping(computes, _) = config.get("compute")? else {
+        return None;
+    };
+    let YmlValue::Mapping(compute, _) = get(computes, compute)? else {
+        return None;
+    };
+    get(compute, "http_path")?.as_str().map(str::to_string)
+}
+
+fn get<'a>(mapping: &'a YmlMapping, key: &str) -> Option<&'a YmlValue> {
+    mapping
+        .iter()
+        .find_map(|(k, v)| (k.as_str() == Some(key)).then_some(v))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn config(yaml: &str) -> AdapterConfig {
+        AdapterConfig::new(dbt_serde_yaml::from_str(yaml).unwrap())
+    }
+
+    #[test]
+    fn test_snowflake_limits_per_warehouse() {
+        let config = config(
+            r#"
+warehouse: transforming_xl
+max_concurrent_queries: 8
+concurrency_limits:
+  transforming_xs: 2
+  '"Quoted_Wh"': "3"
+"#,
+        );
+        let limits = ConcurrencyLimits::from_config(AdapterType::Snowflake, &config).unwrap();
+        assert!(!limits.is_unlimited());
+        assert_eq!(limits.backend_key(&config, None), "TRANSFORMING_XL");
+        assert_eq!(limits.limit_for("TRANSFORMING_XL"), Some(8));
+        assert_eq!(limits.limit_for("TRANSFORMING_XS"), Some(2));
+        assert_eq!(limits.limit_for("Quoted_Wh"), Some(3));
+
+        let semaphore = limits.semaphore_for("TRANSFORMING_XS").unwrap();
+        assert_eq!(semaphore.max(), 2);
+        assert!(Arc::ptr_eq(
+            &semaphore,
+            &limits.semaphore_for("TRANSFORMING_XS").unwrap()
+        ));
+    }
+
+    #[test]
+    fn test_databricks_limits_by_compute_name() {
+        let config = config(
+            r#"
+http_path: /sql/1.0/warehouses/default
+compute:
+  small:
+    http_path: /sql/1.0/warehouses/small
+concurrency_limits:
+  small: 1
+"#,
+        );
+        let limits = ConcurrencyLimits::from_config(AdapterType::Databricks, &config).unwrap();
+        assert_eq!(limits.limit_for("/sql/1.0/warehouses/small"), Some(1));
+        assert_eq!(
+            limits.backend_key(&config, None),
+            "/sql/1.0/warehouses/default"
+        );
+        assert_eq!(limits.limit_for("/sql/1.0/warehouses/default"), None);
+        assert!(
+            limits
+                .semaphore_for("/sql/1.0/warehouses/default")
+                .is_none()
+        );
+    }
+
+    #[test]
+    fn test_redshift_defaults_to_threads() {
+        let limits =
+            ConcurrencyLimits::from_config(AdapterType::Redshift, &config("threads: 4")).unwrap();
+        assert_eq!(limits.limit_for(""), Some(4));
+
+        let limits =
+            ConcurrencyLimits::from_config(AdapterType::Postgres, &config("threads: 4")).unwrap();
+        assert!(limits.is_unlimited());
+    }
+
+    #[test]
+    fn test_invalid_limits() {
+        for yaml in [
+            "max_concurrent_queries: 0",
+            "max_concurrent_queries: many",
+            "concurrency_limits: [1, 2]",
+            "concurrency_limits: {wh: -1}",
+        ] {
+            let err = ConcurrencyLimits::from_config(AdapterType::Snowflake, &config(yaml))
+                .err()
+                .unwrap_or_else(|| panic!("expected an error for {yaml}"));
+            assert_eq!(err.kind(), AdapterErrorKind::Configuration);
+        }
+    }
+
+    #[test]
+    fn test_node_priorities() {
+        let limits =
+            ConcurrencyLimits::from_config(AdapterType::Postgres, &config("threads: 1")).unwrap();
+        limits.set_node_priorities([("model.a".to_string(), 3)]);
+        assert_eq!(limits.priority_of(Some("model.a")), 3);
+        assert_eq!(limits.priority_of(Some("model.b")), 0);
+        assert_eq!(limits.priority_of(None), 0);
+    }
+}
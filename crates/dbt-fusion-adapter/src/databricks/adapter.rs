@@ -28,16 +28,12 @@ use dbt_agate::AgateTable;
 use dbt_common::behavior_flags::BehaviorFlag;
 
 use dbt_schemas::dbt_types::RelationType;
-use dbt_schemas::schemas::common::{
-    ConstraintSupport, ConstraintType, DbtIncrementalStrategy, DbtMaterialization,
-};
+use dbt_schemas::schemas::common::{DbtIncrementalStrategy, DbtMaterialization};
 use dbt_schemas::schemas::project::ModelConfig;
 use dbt_schemas::schemas::relations::base::BaseRelation;
 use dbt_schemas::schemas::{BaseRelationConfig, InternalDbtNodeAttributes};
 use dbt_xdbc::{Connection, QueryCtx};
 use minijinja::{State, Value};
-use once_cell::sync::Lazy;
-use regex::Regex;
 use uuid::Uuid;
 
 use std::collections::BTreeMap;
@@ -46,14 +42,6 @@ use std::ops::DerefMut;
 use std::sync::Arc;
 use std::sync::OnceLock;
 
-static CREDENTIAL_IN_COPY_INTO_REGEX: Lazy<Regex> = Lazy::new(|| {
-    // This is NOT the same as the Python regex used in dbt-databricks. Rust lacks lookaround.
-    // This achieves the same result for the proper structure.  See original at time of port:
-    // https://github.com/databricks/dbt-databricks/blob/66f513b960c62ee21c4c399264a41a56853f3d82/dbt/adapters/databricks/utils.py#L19
-    Regex::new(r"credential\s*(\(\s*'[\w\-]+'\s*=\s*'.*?'\s*(?:,\s*'[\w\-]+'\s*=\s*'.*?'\s*)*\))")
-        .expect("CREDENTIALS_IN_COPY_INTO_REGEX invalid")
-});
-
 /// An adapter for interacting with Databricks.
 #[derive(Clone)]
 pub struct DatabricksAdapter {
@@ -606,50 +594,12 @@ impl TypedBaseAdapter for DatabricksAdapter {
         Ok(result)
     }
 
-    /// https://github.com/databricks/dbt-databricks/blob/822b105b15e644676d9e1f47cbfd765cd4c1541f/dbt/adapters/databricks/constraints.py#L17
-    fn get_constraint_support(&self, ct: ConstraintType) -> ConstraintSupport {
-        match ct {
-            ConstraintType::Check | ConstraintType::NotNull => ConstraintSupport::Enforced,
-            ConstraintType::Unique => ConstraintSupport::NotSupported,
-            ConstraintType::PrimaryKey | ConstraintType::ForeignKey => {
-                ConstraintSupport::NotEnforced
-            }
-            _ => ConstraintSupport::NotSupported,
-        }
-    }
-
     /// https://github.com/databricks/dbt-databricks/blob/4d82bd225df81296165b540d34ad5be43b45e44a/dbt/adapters/databricks/impl.py#L831
     /// TODO: implement if necessary, currently its noop
     fn clean_sql(&self, sql: &str) -> AdapterResult<String> {
         Ok(sql.to_string())
     }
 
-    /// https://github.com/databricks/dbt-databricks/blob/66f513b960c62ee21c4c399264a41a56853f3d82/dbt/adapters/databricks/impl.py#L717
-    fn redact_credentials(&self, sql: &str) -> AdapterResult<String> {
-        let Some(caps) = CREDENTIAL_IN_COPY_INTO_REGEX.captures(sql) else {
-            // WARN: Malformed input by user means credentials may leak.
-            // However, this _is_ the fallback strategy implemented in Python.
-            return Ok(sql.to_string());
-        };
-
-        // Capture the full matched credential(...) string, including the surrounding parentheses.
-        // Then extract only the inner key-value content
-        let full_parens = caps.get(1).unwrap().as_str();
-        let inner = &full_parens[1..full_parens.len() - 1];
-
-        let redacted_pairs = inner
-            .split(',')
-            .map(|pair| {
-                let key = pair.split('=').next().unwrap_or("").trim();
-                format!("{key} = '[REDACTED]'")
-            })
-            .collect::<Vec<_>>()
-            .join(", ");
-
-        let redacted_sql = sql.replacen(full_parens, &format!("({redacted_pairs})"), 1);
-
-        Ok(redacted_sql)
-    }
     /// https://github.com/dbt-labs/dbt-adapters/blob/c16cc7047e8678f8bb88ae294f43da2c68e9f5cc/dbt-spark/src/dbt/adapters/spark/impl.py#L500
     fn standardize_grants_dict(
         &self,
@@ -58,6 +58,7 @@ pub fn adbc_error_to_adapter_error(err: adbc_core::error::Error) -> AdapterError
         adbc_core::error::Status::Unauthenticated => AdapterErrorKind::Authentication,
         adbc_core::error::Status::Unauthorized => AdapterErrorKind::Authentication,
         adbc_core::error::Status::NotFound => AdapterErrorKind::NotFound,
+        adbc_core::error::Status::NotImplemented => AdapterErrorKind::NotSupported,
         _ => AdapterErrorKind::Driver,
     };
 
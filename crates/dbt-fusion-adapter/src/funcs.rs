@@ -34,7 +34,8 @@ pub fn dispatch_adapter_calls(
     match name {
         "dispatch" => adapter.dispatch(state, args),
         "execute" => {
-            // sql: str, auto_begin: bool = False, fetch: bool = False, limit: Optional[int] = None
+            // sql: str, auto_begin: bool = False, fetch: bool = False, limit: Optional[int] = None,
+            // options: Optional[Dict[str, str]] = None, bindings: Optional[Any] = None
             let iter = ArgsIter::new(name, &["sql"], args);
             let sql = iter.next_arg::<&str>()?;
             let auto_begin = iter
@@ -49,10 +50,15 @@ pub fn dispatch_adapter_calls(
             } else {
                 None
             };
+            let bindings = iter.next_kwarg::<Option<&Value>>("bindings")?;
             // TODO(harry): add iter.finish() and fix the tests
 
-            let (response, table) =
-                adapter.execute(state, sql, auto_begin, fetch, limit, options)?;
+            let (response, table) = match bindings {
+                Some(bindings) if !bindings.is_none() => adapter.execute_with_bindings(
+                    state, sql, bindings, auto_begin, fetch, limit, options,
+                )?,
+                _ => adapter.execute(state, sql, auto_begin, fetch, limit, options)?,
+            };
             Ok(Value::from_iter([
                 Value::from_object(response),
                 Value::from_object(table),
@@ -200,12 +206,14 @@ pub fn dispatch_adapter_calls(
             adapter.assert_valid_snapshot_target_given_strategy(state, args)
         }
         "get_missing_columns" => adapter.get_missing_columns(state, args),
+        "plan_schema_changes" => adapter.plan_schema_changes(state, args),
         "render_raw_model_constraints" => adapter.render_raw_model_constraints(state, args),
         "standardize_grants_dict" => adapter.standardize_grants_dict(state, args),
+        "diff_grants" => adapter.diff_grants(state, args),
         "convert_type" => adapter.convert_type(state, args),
         "render_raw_columns_constraints" => adapter.render_raw_columns_constraints(state, args),
         "verify_database" => adapter.verify_database(state, args),
-        "commit" => adapter.commit(args),
+        "commit" => adapter.commit(state, args),
         "get_incremental_strategy_macro" => adapter.get_incremental_strategy_macro(state, args),
         "check_schema_exists" => adapter.check_schema_exists(state, args),
         "get_relations_by_pattern" => adapter.get_relations_by_pattern(state, args),
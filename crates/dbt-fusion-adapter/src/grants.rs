@@ -0,0 +1,420 @@
+//! Diffing of the grants of a relation against the `grants` config of its node.
+//!
+//! The current grants come from `adapter.standardize_grants_dict` and the
+//! desired grants from the `grants` config, both as maps from privilege to
+//! grantees. [GrantsPlan] compares them the way the warehouse does — privileges
+//! are matched through their per-adapter aliases and grantees are matched case
+//! insensitively where the warehouse folds identifiers — and produces the
+//! minimal set of `revoke` and `grant` statements reconciling the two.
+//!
+//! A `+` prefix on a privilege (e.g. `+select`) only tells the config merge in
+//! `default_to_grants` to append to the parent's grantees. A prefix left on a
+//! config without a parent is ignored, as in dbt.
+
+use std::collections::BTreeMap;
+use std::sync::Arc;
+
+use dbt_common::adapter::AdapterType;
+use minijinja::Value;
+use minijinja::listener::RenderingEventListener;
+use minijinja::value::Object;
+use serde::Serialize;
+
+/// Map from privilege to grantees, as returned by `standardize_grants_dict`
+pub type GrantsDict = BTreeMap<String, Vec<String>>;
+
+/// Whether a [GrantChange] grants or revokes a privilege
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum GrantAction {
+    Grant,
+    Revoke,
+}
+
+/// A privilege granted to or revoked from a set of grantees
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+pub struct GrantChange {
+    pub action: GrantAction,
+    pub privilege: String,
+    pub grantees: Vec<String>,
+}
+
+impl std::fmt::Display for GrantChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self.action {
+            GrantAction::Grant => write!(
+                f,
+                "grant {} to {}",
+                self.privilege,
+                self.grantees.join(", ")
+            ),
+            GrantAction::Revoke => write!(
+                f,
+                "revoke {} from {}",
+                self.privilege,
+                self.grantees.join(", ")
+            ),
+        }
+    }
+}
+
+/// The revokes and grants bringing the current grants of a relation in line
+/// with its `grants` config. Revokes come first, as in `default__apply_grants`.
+#[derive(Debug, Clone)]
+pub struct GrantsPlan {
+    adapter_type: AdapterType,
+    changes: Vec<GrantChange>,
+}
+
+impl GrantsPlan {
+    pub fn new(adapter_type: AdapterType, current: &GrantsDict, desired: &GrantsDict) -> Self {
+        let current = normalize(adapter_type, current);
+        let desired = normalize(adapter_type, desired);
+
+        let mut revokes = Vec::new();
+        for (privilege, held) in &current {
+            let missing = match desired.get(privilege) {
+                Some(wanted) => held.missing_from(wanted),
+                // privileges absent from the config are revoked entirely
+                None => held.grantees.iter().map(|(_, g)| g.clone()).collect(),
+            };
+            if !missing.is_empty() {
+                revokes.push(GrantChange {
+                    action: GrantAction::Revoke,
+                    privilege: privilege.clone(),
+                    grantees: missing,
+                });
+            }
+        }
+
+        let mut grants = Vec::new();
+        for (privilege, wanted) in &desired {
+            let missing = match current.get(privilege) {
+                Some(held) => wanted.missing_from(held),
+                None => wanted.grantees.iter().map(|(_, g)| g.clone()).collect(),
+            };
+            if !missing.is_empty() {
+                grants.push(GrantChange {
+                    action: GrantAction::Grant,
+                    privilege: privilege.clone(),
+                    grantees: missing,
+                });
+            }
+        }
+
+        revokes.extend(grants);
+        Self {
+            adapter_type,
+            changes: revokes,
+        }
+    }
+
+    pub fn changes(&self) -> &[GrantChange] {
+        &self.changes
+    }
+
+    pub fn has_changes(&self) -> bool {
+        !self.changes.is_empty()
+    }
+
+    /// Grantees to grant each privilege to, in the shape of `diff_of_two_dicts`
+    pub fn needs_granting(&self) -> GrantsDict {
+        self.by_action(GrantAction::Grant)
+    }
+
+    /// Grantees to revoke each privilege from, in the shape of `diff_of_two_dicts`
+    pub fn needs_revoking(&self) -> GrantsDict {
+        self.by_action(GrantAction::Revoke)
+    }
+
+    fn by_action(&self, action: GrantAction) -> GrantsDict {
+        self.changes
+            .iter()
+            .filter(|change| change.action == action)
+            .map(|change| (change.privilege.clone(), change.grantees.clone()))
+            .collect()
+    }
+
+    /// Render the DCL applying the plan to `relation` of type `relation_type`
+    pub fn render(&self, relation: &str, relation_type: &str) -> Vec<String> {
+        self.changes
+            .iter()
+            .flat_map(|change| render_change(self.adapter_type, relation, relation_type, change))
+            .collect()
+    }
+
+    /// Human readable summary of the plan, one change per line
+    pub fn summary(&self) -> String {
+        if self.changes.is_empty() {
+            return "no grant changes".to_string();
+        }
+        self.changes
+            .iter()
+            .map(|change| format!("  - {change}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Grantees of a privilege, keyed for comparison
+#[derive(Debug, Default)]
+struct Grantees {
+    /// `(comparison key, grantee as written)`, without duplicates
+    grantees: Vec<(String, String)>,
+}
+
+impl Grantees {
+    fn push(&mut self, key: String, grantee: String) {
+        if !self.grantees.iter().any(|(k, _)| *k == key) {
+            self.grantees.push((key, grantee));
+        }
+    }
+
+    /// Grantees of self that `other` does not have
+    fn missing_from(&self, other: &Grantees) -> Vec<String> {
+        self.grantees
+            .iter()
+            .filter(|(key, _)| !other.grantees.iter().any(|(k, _)| k == key))
+            .map(|(_, grantee)| grantee.clone())
+            .collect()
+    }
+}
+
+fn normalize(adapter_type: AdapterType, grants: &GrantsDict) -> BTreeMap<String, Grantees> {
+    let mut result: BTreeMap<String, Grantees> = BTreeMap::new();
+    for (privilege, grantees) in grants {
+        let privilege = privilege.strip_prefix('+').unwrap_or(privilege);
+        let Some(privilege) = canonical_privilege(adapter_type, privilege) else {
+            continue;
+        };
+        let entry = result.entry(privilege).or_default();
+        for grantee in grantees {
+            let grantee = grantee.trim();
+            if !grantee.is_empty() {
+                entry.push(grantee_key(adapter_type, grantee), grantee.to_string());
+            }
+        }
+    }
+    result
+}
+
+/// The spelling a privilege is compared and rendered with, `None` for
+/// privileges dbt never manages (e.g. the ownership of the relation)
+fn canonical_privilege(adapter_type: AdapterType, privilege: &str) -> Option<String> {
+    let privilege = privilege.split_whitespace().collect::<Vec<_>>().join(" ");
+    if privilege.is_empty() {
+        return None;
+    }
+    match adapter_type {
+        // IAM roles are case sensitive, `dataViewer` and `bigquery.dataViewer`
+        // are accepted as short forms of `roles/bigquery.dataViewer`
+        AdapterType::Bigquery => Some(if privilege.contains('/') {
+            privilege
+        } else if privilege.contains('.') {
+            format!("roles/{privilege}")
+        } else {
+            format!("roles/bigquery.{privilege}")
+        }),
+        AdapterType::Snowflake | AdapterType::Databricks => {
+            let privilege = privilege.to_uppercase();
+            match (adapter_type, privilege.as_str()) {
+                (AdapterType::Snowflake, "OWNERSHIP") | (AdapterType::Databricks, "OWN") => None,
+                (_, "ALL") => Some("ALL PRIVILEGES".to_string()),
+                _ => Some(privilege),
+            }
+        }
+        AdapterType::Postgres | AdapterType::Redshift | AdapterType::Salesforce => {
+            let privilege = privilege.to_lowercase();
+            Some(if privilege == "all" {
+                "all privileges".to_string()
+            } else {
+                privilege
+            })
+        }
+    }
+}
+
+/// The key grantees are compared by: unquoted identifiers are folded to the
+/// case the warehouse stores them in
+fn grantee_key(adapter_type: AdapterType, grantee: &str) -> String {
+    let quoted = grantee.len() > 1 && grantee.starts_with('"') && grantee.ends_with('"');
+    match adapter_type {
+        _ if quoted => grantee[1..grantee.len() - 1].to_string(),
+        AdapterType::Snowflake => grantee.to_uppercase(),
+        AdapterType::Postgres | AdapterType::Redshift => grantee.to_lowercase(),
+        // IAM members are `user:`, `group:`, ... followed by an email address
+        AdapterType::Bigquery => grantee.to_lowercase(),
+        AdapterType::Databricks | AdapterType::Salesforce => grantee.to_string(),
+    }
+}
+
+fn render_change(
+    adapter_type: AdapterType,
+    relation: &str,
+    relation_type: &str,
+    change: &GrantChange,
+) -> Vec<String> {
+    let GrantChange {
+        action,
+        privilege,
+        grantees,
+    } = change;
+    let (verb, preposition) = match action {
+        GrantAction::Grant => ("grant", "to"),
+        GrantAction::Revoke => ("revoke", "from"),
+    };
+    match adapter_type {
+        AdapterType::Bigquery => {
+            let grantees = grantees
+                .iter()
+                .map(|grantee| format!("\"{grantee}\""))
+                .collect::<Vec<_>>()
+                .join(", ");
+            vec![format!(
+                "{verb} `{privilege}` on {relation_type} {relation} {preposition} {grantees}"
+            )]
+        }
+        // one grantee per statement
+        AdapterType::Snowflake => grantees
+            .iter()
+            .map(|grantee| format!("{verb} {privilege} on {relation} {preposition} {grantee}"))
+            .collect(),
+        AdapterType::Databricks => grantees
+            .iter()
+            .map(|grantee| format!("{verb} {privilege} on {relation} {preposition} `{grantee}`"))
+            .collect(),
+        AdapterType::Postgres | AdapterType::Redshift | AdapterType::Salesforce => {
+            vec![format!(
+                "{verb} {privilege} on {relation} {preposition} {}",
+                grantees.join(", ")
+            )]
+        }
+    }
+}
+
+impl Object for GrantsPlan {
+    fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+        match key.as_str()? {
+            "changes" => Some(Value::from_serialize(&self.changes)),
+            "has_changes" => Some(Value::from(self.has_changes())),
+            "needs_granting" => Some(Value::from(self.needs_granting())),
+            "needs_revoking" => Some(Value::from(self.needs_revoking())),
+            "summary" => Some(Value::from(self.summary())),
+            _ => None,
+        }
+    }
+
+    fn call_method(
+        self: &Arc<Self>,
+        _state: &minijinja::State,
+        name: &str,
+        args: &[Value],
+        _listeners: &[std::rc::Rc<dyn RenderingEventListener>],
+    ) -> Result<Value, minijinja::Error> {
+        match name {
+            "render" => {
+                let [relation] = args else {
+                    return Err(minijinja::Error::new(
+                        minijinja::ErrorKind::InvalidOperation,
+                        "render() takes exactly one argument: the relation to grant on",
+                    ));
+                };
+                let relation_type = relation
+                    .get_attr("type")
+                    .ok()
+                    .filter(|t| !t.is_undefined() && !t.is_none())
+                    .map(|t| t.to_string())
+                    .unwrap_or_else(|| "table".to_string());
+                Ok(Value::from(
+                    self.render(&relation.to_string(), &relation_type),
+                ))
+            }
+            _ => Err(minijinja::Error::new(
+                minijinja::ErrorKind::InvalidOperation,
+                format!("Unknown method on GrantsPlan: '{name}'"),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn grants(entries: &[(&str, &[&str])]) -> GrantsDict {
+        entries
+            .iter()
+            .map(|(privilege, grantees)| {
+                (
+                    privilege.to_string(),
+                    grantees.iter().map(|g| g.to_string()).collect(),
+                )
+            })
+            .collect()
+    }
+
+    #[test]
+    fn test_minimal_changes_with_case_folding() {
+        let current = grants(&[
+            ("SELECT", &["REPORTER", "ANALYST"]),
+            ("INSERT", &["LOADER"]),
+        ]);
+        let desired = grants(&[("select", &["reporter", "bi_user"])]);
+        let plan = GrantsPlan::new(AdapterType::Snowflake, &current, &desired);
+
+        assert_eq!(
+            plan.needs_revoking(),
+            grants(&[("INSERT", &["LOADER"]), ("SELECT", &["ANALYST"])])
+        );
+        assert_eq!(plan.needs_granting(), grants(&[("SELECT", &["bi_user"])]));
+        assert_eq!(
+            plan.render("db.s.t", "table"),
+            vec![
+                "revoke INSERT on db.s.t from LOADER",
+                "revoke SELECT on db.s.t from ANALYST",
+                "grant SELECT on db.s.t to bi_user",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unmerged_plus_prefix_is_ignored() {
+        let current = grants(&[("select", &["alice"])]);
+        let desired = grants(&[("+select", &["bob"])]);
+        let plan = GrantsPlan::new(AdapterType::Postgres, &current, &desired);
+
+        assert_eq!(
+            plan.render("\"d\".\"s\".\"t\"", "table"),
+            vec![
+                "revoke select on \"d\".\"s\".\"t\" from alice",
+                "grant select on \"d\".\"s\".\"t\" to bob",
+            ]
+        );
+    }
+
+    #[test]
+    fn test_privilege_aliases() {
+        // ownership is never managed
+        let current = grants(&[("OWNERSHIP", &["SYSADMIN"]), ("SELECT", &["R"])]);
+        let desired = grants(&[("select", &["r"])]);
+        assert!(!GrantsPlan::new(AdapterType::Snowflake, &current, &desired).has_changes());
+
+        // short IAM role names match the full role, grantees are case insensitive
+        let current = grants(&[("roles/bigquery.dataViewer", &["user:Jane@example.com"])]);
+        let desired = grants(&[(
+            "dataViewer",
+            &["user:jane@example.com", "group:bi@example.com"],
+        )]);
+        let plan = GrantsPlan::new(AdapterType::Bigquery, &current, &desired);
+        assert_eq!(
+            plan.render("`p`.`d`.`t`", "view"),
+            vec![
+                "grant `roles/bigquery.dataViewer` on view `p`.`d`.`t` to \"group:bi@example.com\""
+            ]
+        );
+        assert_eq!(
+            plan.summary(),
+            "  - grant roles/bigquery.dataViewer to group:bi@example.com"
+        );
+    }
+}
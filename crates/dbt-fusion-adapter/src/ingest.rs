@@ -0,0 +1,371 @@
+//! Bulk loading of Arrow data into warehouse tables.
+//!
+//! Data is handed to the driver through ADBC bulk ingestion. When ingestion
+//! fails, the same data goes through batched parameterized `INSERT`
+//! statements, with the target table created from the Arrow schema using the
+//! adapter's [TypeOps]. Data appended to an existing table is first cast to
+//! the types of the table's columns.
+
+use crate::AdapterType;
+use crate::bindings::rewrite_placeholders;
+use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult, arrow_error_to_adapter_error};
+use crate::sql_engine::{Options, SqlEngine};
+use crate::sql_types::TypeOps;
+
+use adbc_core::options::OptionValue;
+use arrow::array::RecordBatch;
+use arrow::compute::{CastOptions, cast_with_options};
+use arrow_schema::Schema;
+use dbt_schemas::schemas::relations::base::BaseRelation;
+use dbt_xdbc::{Connection, QueryCtx};
+use minijinja::State;
+use std::sync::Arc;
+use tracing::warn;
+
+/// Name of the table to ingest into
+pub const INGEST_TARGET_TABLE: &str = "adbc.ingest.target_table";
+/// Schema of the table to ingest into
+pub const INGEST_TARGET_DB_SCHEMA: &str = "adbc.ingest.target_db_schema";
+/// Catalog of the table to ingest into
+pub const INGEST_TARGET_CATALOG: &str = "adbc.ingest.target_catalog";
+/// How ingestion treats the target table, see [IngestMode]
+pub const INGEST_MODE: &str = "adbc.ingest.mode";
+
+/// Number of rows bound per statement when falling back to `INSERT`s
+const INSERT_BATCH_ROWS: usize = 10_000;
+
+/// How ingestion treats the target table
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum IngestMode {
+    /// Create the table, failing if it already exists
+    Create,
+    /// Append to the table, failing if it does not exist
+    Append,
+    /// Drop the table if it exists and create it again
+    Replace,
+    /// Create the table if it does not exist and append to it
+    CreateAppend,
+}
+
+impl IngestMode {
+    /// The value of the [INGEST_MODE] option for this mode
+    pub fn as_option_value(&self) -> &'static str {
+        match self {
+            Self::Create => "adbc.ingest.mode.create",
+            Self::Append => "adbc.ingest.mode.append",
+            Self::Replace => "adbc.ingest.mode.replace",
+            Self::CreateAppend => "adbc.ingest.mode.create_append",
+        }
+    }
+}
+
+/// Load `batch` into `relation`.
+///
+/// Uses ADBC bulk ingestion and falls back to batched parameterized inserts
+/// when ingestion fails. When appending, `batch` is cast to the columns of
+/// the existing table, so that the types the table was created with (e.g. a
+/// seed's `column_types`) are kept.
+pub fn ingest_record_batch(
+    engine: &SqlEngine,
+    state: Option<&State>,
+    query_ctx: &QueryCtx,
+    conn: &'_ mut dyn Connection,
+    relation: &dyn BaseRelation,
+    batch: RecordBatch,
+    mode: IngestMode,
+) -> AdapterResult<()> {
+    let batch = if mode == IngestMode::Append {
+        let sql = format!(
+            "select * from {} where 1 = 0",
+            relation.render_self_as_str()
+        );
+        let query_ctx = query_ctx.with_sql(sql).with_desc("Get table columns");
+        let table = engine.execute(state, conn, &query_ctx)?;
+        cast_to_schema(batch, &table.schema())?
+    } else {
+        batch
+    };
+
+    match bulk_ingest(
+        engine,
+        state,
+        query_ctx,
+        conn,
+        relation,
+        batch.clone(),
+        mode,
+    ) {
+        Ok(()) => Ok(()),
+        Err(e) => {
+            warn!(
+                "Bulk ingest into {} failed, inserting rows instead: {e}",
+                relation.render_self_as_str()
+            );
+            insert_in_batches(engine, state, query_ctx, conn, relation, batch, mode)
+        }
+    }
+}
+
+/// Cast the columns of `batch` to the matching columns of `schema`
+///
+/// Columns are matched by name, case-insensitively as warehouses may fold
+/// the case of unquoted names, and take the name and type of the column of
+/// `schema`.
+fn cast_to_schema(batch: RecordBatch, schema: &Schema) -> AdapterResult<RecordBatch> {
+    let source = batch.schema();
+    let options = CastOptions {
+        safe: false,
+        ..Default::default()
+    };
+    let mut fields = Vec::with_capacity(source.fields().len());
+    let mut columns = Vec::with_capacity(source.fields().len());
+    for (field, column) in source.fields().iter().zip(batch.columns()) {
+        let target = schema
+            .fields()
+            .iter()
+            .find(|target| target.name() == field.name())
+            .or_else(|| {
+                schema
+                    .fields()
+                    .iter()
+                    .find(|target| target.name().eq_ignore_ascii_case(field.name()))
+            })
+            .ok_or_else(|| {
+                AdapterError::new(
+                    AdapterErrorKind::Configuration,
+                    format!(
+                        "Column '{}' does not exist in the target table",
+                        field.name()
+                    ),
+                )
+            })?;
+        columns.push(
+            cast_with_options(column, target.data_type(), &options)
+                .map_err(arrow_error_to_adapter_error)?,
+        );
+        fields.push(target.as_ref().clone().with_nullable(true));
+    }
+    RecordBatch::try_new(Arc::new(Schema::new(fields)), columns)
+        .map_err(arrow_error_to_adapter_error)
+}
+
+fn bulk_ingest(
+    engine: &SqlEngine,
+    state: Option<&State>,
+    query_ctx: &QueryCtx,
+    conn: &'_ mut dyn Connection,
+    relation: &dyn BaseRelation,
+    batch: RecordBatch,
+    mode: IngestMode,
+) -> AdapterResult<()> {
+    let mut options: Options = vec![
+        (
+            INGEST_TARGET_TABLE.to_string(),
+            OptionValue::String(relation.identifier_as_resolved_str()?),
+        ),
+        (
+            INGEST_TARGET_DB_SCHEMA.to_string(),
+            OptionValue::String(relation.schema_as_resolved_str()?),
+        ),
+        (
+            INGEST_MODE.to_string(),
+            OptionValue::String(mode.as_option_value().to_string()),
+        ),
+    ];
+    // A Postgres or Redshift connection is bound to a single database
+    if !matches!(
+        engine.adapter_type(),
+        AdapterType::Postgres | AdapterType::Redshift
+    ) {
+        options.push((
+            INGEST_TARGET_CATALOG.to_string(),
+            OptionValue::String(relation.database_as_resolved_str()?),
+        ));
+    }
+
+    let query_ctx = query_ctx.with_desc("Bulk ingest");
+    engine.ingest(state, &query_ctx, conn, batch, options)?;
+    Ok(())
+}
+
+fn insert_in_batches(
+    engine: &SqlEngine,
+    state: Option<&State>,
+    query_ctx: &QueryCtx,
+    conn: &'_ mut dyn Connection,
+    relation: &dyn BaseRelation,
+    batch: RecordBatch,
+    mode: IngestMode,
+) -> AdapterResult<()> {
+    for sql in prepare_table_sql(engine.type_ops(), relation, &batch, mode)? {
+        engine.execute(state, conn, &query_ctx.with_sql(sql))?;
+    }
+
+    let (sql, _) = rewrite_placeholders(engine.adapter_type(), &insert_sql(relation, &batch));
+    let query_ctx = query_ctx.with_sql(sql);
+    let mut offset = 0;
+    while offset < batch.num_rows() {
+        let len = INSERT_BATCH_ROWS.min(batch.num_rows() - offset);
+        engine.execute_with_params(
+            state,
+            &query_ctx,
+            conn,
+            batch.slice(offset, len),
+            Options::new(),
+            false,
+        )?;
+        offset += len;
+    }
+    Ok(())
+}
+
+/// Statements that bring the target table into the state `mode` expects
+/// before rows are inserted
+fn prepare_table_sql(
+    type_ops: &dyn TypeOps,
+    relation: &dyn BaseRelation,
+    batch: &RecordBatch,
+    mode: IngestMode,
+) -> AdapterResult<Vec<String>> {
+    let rendered = relation.render_self_as_str();
+    let create = |if_not_exists: bool| -> AdapterResult<String> {
+        let mut columns = Vec::with_capacity(batch.num_columns());
+        for field in batch.schema().fields() {
+            let mut column = format!("{} ", relation.quoted(field.name()));
+            type_ops.format_arrow_type_as_sql(field.data_type(), &mut column)?;
+            columns.push(column);
+        }
+        if columns.is_empty() {
+            return Err(AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!("Cannot create {rendered} from data without columns"),
+            ));
+        }
+        let if_not_exists = if if_not_exists { "if not exists " } else { "" };
+        Ok(format!(
+            "create table {if_not_exists}{rendered} ({})",
+            columns.join(", ")
+        ))
+    };
+
+    Ok(match mode {
+        IngestMode::Append => vec![],
+        IngestMode::Create => vec![create(false)?],
+        IngestMode::CreateAppend => vec![create(true)?],
+        IngestMode::Replace => vec![format!("drop table if exists {rendered}"), create(false)?],
+    })
+}
+
+/// A parameterized `INSERT` with one `%s` placeholder per column
+fn insert_sql(relation: &dyn BaseRelation, batch: &RecordBatch) -> String {
+    let schema = batch.schema();
+    let columns = schema
+        .fields()
+        .iter()
+        .map(|field| relation.quoted(field.name()))
+        .collect::<Vec<_>>();
+    let placeholders = vec!["%s"; columns.len()];
+    format!(
+        "insert into {} ({}) values ({})",
+        relation.render_self_as_str(),
+        columns.join(", "),
+        placeholders.join(", ")
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::relation_object::create_relation;
+    use crate::sql_types::NaiveTypeOpsImpl;
+    use arrow::array::{Array, Float64Array, Int64Array, StringArray};
+    use arrow_schema::{DataType, Field};
+    use dbt_schemas::schemas::relations::DEFAULT_RESOLVED_QUOTING;
+
+    fn batch() -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("id", DataType::Int64, false),
+            Field::new("name", DataType::Utf8, true),
+        ]));
+        RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(Int64Array::from(vec![1, 2])),
+                Arc::new(StringArray::from(vec![Some("a"), None])),
+            ],
+        )
+        .unwrap()
+    }
+
+    fn relation() -> Arc<dyn BaseRelation> {
+        create_relation(
+            AdapterType::Postgres,
+            "db".to_string(),
+            "seeds".to_string(),
+            Some("people".to_string()),
+            None,
+            DEFAULT_RESOLVED_QUOTING,
+        )
+        .unwrap()
+    }
+
+    #[test]
+    fn test_insert_sql() {
+        let relation = relation();
+        let sql = insert_sql(relation.as_ref(), &batch());
+        assert_eq!(
+            sql,
+            r#"insert into "db"."seeds"."people" ("id", "name") values (%s, %s)"#
+        );
+        let (sql, count) = rewrite_placeholders(AdapterType::Postgres, &sql);
+        assert_eq!(count, 2);
+        assert!(sql.ends_with("values ($1, $2)"));
+    }
+
+    #[test]
+    fn test_prepare_table_sql() {
+        let type_ops = NaiveTypeOpsImpl::new(AdapterType::Postgres);
+        let relation = relation();
+        let batch = batch();
+
+        let append = prepare_table_sql(&type_ops, relation.as_ref(), &batch, IngestMode::Append);
+        assert!(append.unwrap().is_empty());
+
+        let replace =
+            prepare_table_sql(&type_ops, relation.as_ref(), &batch, IngestMode::Replace).unwrap();
+        assert_eq!(replace.len(), 2);
+        assert_eq!(replace[0], r#"drop table if exists "db"."seeds"."people""#);
+        assert!(replace[1].starts_with(r#"create table "db"."seeds"."people" ("id" "#));
+
+        let create_append = prepare_table_sql(
+            &type_ops,
+            relation.as_ref(),
+            &batch,
+            IngestMode::CreateAppend,
+        )
+        .unwrap();
+        assert!(create_append[0].starts_with("create table if not exists "));
+    }
+
+    #[test]
+    fn test_cast_to_schema() {
+        // A seed table created with column_types overriding the inferred ones
+        let table = Schema::new(vec![
+            Field::new("ID", DataType::Float64, true),
+            Field::new("name", DataType::Utf8, true),
+        ]);
+        let cast = cast_to_schema(batch(), &table).unwrap();
+        assert_eq!(cast.schema().field(0).name(), "ID");
+        assert_eq!(cast.schema().field(0).data_type(), &DataType::Float64);
+        let ids = cast
+            .column(0)
+            .as_any()
+            .downcast_ref::<Float64Array>()
+            .unwrap();
+        assert_eq!(ids.value(1), 2.0);
+        assert!(cast.column(1).is_null(1));
+
+        let missing = Schema::new(vec![Field::new("id", DataType::Int64, true)]);
+        assert!(cast_to_schema(batch(), &missing).is_err());
+    }
+}
@@ -5,29 +5,37 @@
 mod macros;
 
 pub mod base_adapter;
+pub mod bindings;
 pub mod bridge_adapter;
 pub mod cache;
 pub mod catalog_relation;
 pub mod column;
 pub mod columns;
+pub mod concurrency;
 pub mod errors;
 pub mod factory;
 pub mod formatter;
 pub mod funcs;
+pub mod grants;
 pub mod information_schema;
+pub mod ingest;
 pub mod load_catalogs;
 pub mod metadata;
 pub mod need_quotes;
+pub mod query_attribution;
 pub mod query_cache;
 pub mod query_comment;
 pub mod query_ctx;
 pub mod record_and_replay;
 pub mod render_constraint;
 pub mod response;
+pub mod retry;
+pub mod schema_evolution;
 pub mod snapshots;
 pub mod sql_engine;
 pub mod sql_types;
 pub mod stmt_splitter;
+pub mod transactions;
 pub mod typed_adapter;
 
 // Re-export types and modules that were moved to dbt_auth
@@ -39,7 +47,10 @@ pub mod config {
 }
 
 mod statement;
-pub use statement::{StmtCancellationReport, TrackedStatement, cancel_all_tracked_statements};
+pub use statement::{
+    StatementWatchdog, StmtCancellationReport, StmtInterruption, TrackedStatement,
+    cancel_all_tracked_statements, cancel_tracked_statement,
+};
 
 // Adapters for warehouses / dbs
 /// Bigquery adapter
@@ -102,6 +102,27 @@ catalogs:
         );
     }
 
+    #[test]
+    fn build_catalogs_surfaces_biglake_validation_error() {
+        let path = Path::new("<test>/catalogs.yml");
+        let yaml = r#"
+catalogs:
+  - name: lake
+    active_write_integration: i1
+    write_integrations:
+      - name: i1
+        catalog_type: biglake_metastore
+        table_format: iceberg
+        file_format: parquet
+"#;
+        let err = do_load_catalogs(yaml, path).unwrap_err();
+        let msg = format!("{err:?}");
+        assert!(
+            msg.contains("'external_volume' is required for BigLake catalogs"),
+            "got: {msg}",
+        );
+    }
+
     #[test]
     fn build_catalogs_rejects_non_mapping_top_level() {
         let path = Path::new("<test>/catalogs.yml");
@@ -119,7 +119,7 @@ impl ResultStore {
                     .unwrap_or_default()
                     .parse::<i64>()
                     .unwrap_or(0),
-                query_id: None,
+                ..Default::default()
             };
 
             // Call store_result directly instead of using function
@@ -18,10 +18,12 @@ use dbt_schemas::schemas::{
     relations::base::{BaseRelation, BaseRelationProperties},
 };
 use minijinja::{State, Value};
+use serde::{Deserialize, Serialize};
 
 pub(crate) mod bigquery;
 pub(crate) mod databricks;
 pub(crate) mod metadata_adapter;
+pub(crate) mod postgres;
 pub(crate) mod redshift;
 pub mod snowflake; // XXX: temporarily pub before the refactor is complete
 
@@ -44,7 +46,7 @@ pub type RelationSchemaPair = (Arc<dyn BaseRelation>, Arc<Schema>);
 pub type RelationVec = Vec<Arc<dyn BaseRelation>>;
 
 /// A struct representing a catalog and a schema
-#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash)]
+#[derive(Debug, Clone, Ord, PartialOrd, Eq, PartialEq, Hash, Serialize, Deserialize)]
 pub struct CatalogAndSchema {
     pub rendered_catalog: String,
     pub rendered_schema: String,
@@ -0,0 +1,79 @@
+use std::sync::Arc;
+
+use arrow_array::StringArray;
+use dbt_schemas::dbt_types::RelationType;
+use dbt_schemas::schemas::relations::base::BaseRelation;
+use dbt_xdbc::{Connection, QueryCtx};
+
+use crate::postgres::relation::PostgresRelation;
+use crate::record_batch_utils::get_column_values;
+use crate::{AdapterResult, AdapterTyping};
+
+/// Reference: https://github.com/dbt-labs/dbt-adapters/blob/main/dbt-postgres/src/dbt/include/postgres/macros/adapters.sql#L130
+pub fn list_relations(
+    adapter: &dyn AdapterTyping,
+    query_ctx: &QueryCtx,
+    conn: &'_ mut dyn Connection,
+    db_schema: &super::CatalogAndSchema,
+) -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
+    let database = &db_schema.resolved_catalog;
+    let schema = &db_schema.resolved_schema;
+    let sql = format!(
+        "select
+    '{database}' as database,
+    tablename as name,
+    schemaname as schema,
+    'table' as type
+from pg_tables
+where schemaname ilike '{schema}'
+union all
+select
+    '{database}' as database,
+    viewname as name,
+    schemaname as schema,
+    'view' as type
+from pg_views
+where schemaname ilike '{schema}'
+union all
+select
+    '{database}' as database,
+    matviewname as name,
+    schemaname as schema,
+    'materialized_view' as type
+from pg_matviews
+where schemaname ilike '{schema}'"
+    );
+
+    let query_ctx = query_ctx.with_sql(sql);
+
+    let batch = adapter.engine().execute(None, conn, &query_ctx)?;
+
+    if batch.num_rows() == 0 {
+        return Ok(Vec::new());
+    }
+
+    let mut relations = Vec::new();
+
+    let table_name = get_column_values::<StringArray>(&batch, "name")?;
+    let database_name = get_column_values::<StringArray>(&batch, "database")?;
+    let schema_name = get_column_values::<StringArray>(&batch, "schema")?;
+    let table_type = get_column_values::<StringArray>(&batch, "type")?;
+
+    for i in 0..batch.num_rows() {
+        let table_name = table_name.value(i);
+        let database_name = database_name.value(i);
+        let schema_name = schema_name.value(i);
+        let table_type = table_type.value(i);
+
+        let relation = Arc::new(PostgresRelation::try_new(
+            Some(database_name.to_string()),
+            Some(schema_name.to_string()),
+            Some(table_name.to_string()),
+            Some(RelationType::from(table_type)),
+            adapter.quoting(),
+        )?) as Arc<dyn BaseRelation>;
+        relations.push(relation);
+    }
+
+    Ok(relations)
+}
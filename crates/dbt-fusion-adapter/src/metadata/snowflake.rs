@@ -1,13 +1,19 @@
+use std::collections::BTreeMap;
 use std::sync::Arc;
 
-use arrow_array::{Array, RecordBatch, StringArray};
+use arrow::compute::cast;
+use arrow_array::types::Int64Type;
+use arrow_array::{Array, AsArray, RecordBatch, StringArray, TimestampMillisecondArray};
+use arrow_schema::DataType;
 use dbt_schemas::dbt_types::RelationType;
 use dbt_schemas::schemas::common::ResolvedQuoting;
 use dbt_schemas::schemas::relations::base::{BaseRelation, TableFormat};
 use dbt_xdbc::{Connection, QueryCtx};
 
-use crate::errors::{AdapterError, AdapterErrorKind};
-use crate::record_batch_utils::get_column_values;
+use crate::cache::SchemaLastAltered;
+use crate::errors::{AdapterError, AdapterErrorKind, arrow_error_to_adapter_error};
+use crate::metadata::CatalogAndSchema;
+use crate::record_batch_utils::{column_by_name, get_column_values};
 use crate::snowflake::relation::SnowflakeRelation;
 use crate::{AdapterResult, AdapterTyping};
 
@@ -28,6 +34,11 @@ pub fn relation_type_from_table_flags(is_dynamic: &str) -> Result<RelationType,
     }
 }
 
+/// Whether the `kind` of `show objects` output is a materialized view.
+pub fn is_materialized_view_kind(kind: &str) -> bool {
+    kind.eq_ignore_ascii_case("materialized_view") || kind.eq_ignore_ascii_case("materialized view")
+}
+
 // Helper for serializing query results within `list_relations`
 fn build_relations_from_show_objects(
     show_objects_result: &RecordBatch,
@@ -54,6 +65,8 @@ fn build_relations_from_show_objects(
             Some(relation_type_from_table_flags(is_dynamic)?)
         } else if table_kind.eq_ignore_ascii_case("view") {
             Some(RelationType::View)
+        } else if is_materialized_view_kind(table_kind) {
+            Some(RelationType::MaterializedView)
         } else {
             Some(RelationType::from(table_kind))
         };
@@ -125,3 +138,63 @@ pub fn list_relations(
     }
     Ok(relations)
 }
+
+/// Reports when the relations of each of `db_schemas` were last altered, and
+/// how many there are, from the information schema of their databases
+pub fn list_schemas_last_altered(
+    adapter: &dyn AdapterTyping,
+    query_ctx: &QueryCtx,
+    conn: &'_ mut dyn Connection,
+    db_schemas: &[CatalogAndSchema],
+) -> AdapterResult<BTreeMap<CatalogAndSchema, SchemaLastAltered>> {
+    let mut db_schemas_by_database = BTreeMap::<&str, Vec<&CatalogAndSchema>>::new();
+    for db_schema in db_schemas {
+        db_schemas_by_database
+            .entry(&db_schema.rendered_catalog)
+            .or_default()
+            .push(db_schema);
+    }
+
+    let mut result = BTreeMap::new();
+    for (database, db_schemas) in db_schemas_by_database {
+        let schema_names = db_schemas
+            .iter()
+            .map(|db_schema| format!("'{}'", db_schema.resolved_schema.replace('\'', "''")))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let sql = format!(
+            "SELECT
+                table_schema,
+                count(*) AS num_relations,
+                max(last_altered) AS last_altered
+             FROM {database}.INFORMATION_SCHEMA.TABLES
+             WHERE table_schema IN ({schema_names})
+             GROUP BY table_schema"
+        );
+        let batch = adapter
+            .engine()
+            .execute(None, conn, &query_ctx.with_sql(sql))?;
+
+        let schema_names = get_column_values::<StringArray>(&batch, "TABLE_SCHEMA")?;
+        let num_relations = cast(column_by_name(&batch, "NUM_RELATIONS")?, &DataType::Int64)
+            .map_err(arrow_error_to_adapter_error)?;
+        let num_relations = num_relations.as_primitive::<Int64Type>();
+        let last_altered = get_column_values::<TimestampMillisecondArray>(&batch, "LAST_ALTERED")?;
+        for i in 0..batch.num_rows() {
+            let Some(db_schema) = db_schemas
+                .iter()
+                .find(|db_schema| db_schema.resolved_schema == schema_names.value(i))
+            else {
+                continue;
+            };
+            result.insert(
+                (*db_schema).clone(),
+                SchemaLastAltered {
+                    last_altered: u128::try_from(last_altered.value(i)).unwrap_or_default(),
+                    num_relations: usize::try_from(num_relations.value(i)).unwrap_or_default(),
+                },
+            );
+        }
+    }
+    Ok(result)
+}
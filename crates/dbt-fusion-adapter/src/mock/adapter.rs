@@ -4,6 +4,8 @@ use crate::columns::StdColumn;
 use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult};
 use crate::funcs::none_value;
 use crate::metadata::*;
+use crate::mock::fixture::{MockFixture, MockRelation};
+use crate::relation_object::create_relation;
 use crate::response::AdapterResponse;
 use crate::snowflake::relation::SnowflakeRelation;
 use crate::typed_adapter::TypedBaseAdapter;
@@ -22,9 +24,12 @@ use std::collections::{BTreeMap, HashMap};
 use std::fmt;
 use std::sync::Arc;
 
-/// An adapter intended to be used in tests only. This adapter
-/// hardcodes values used in tests. In the future this adapter could
-/// provide a feature to set values per test.
+/// An adapter intended to be used in tests only.
+///
+/// Without a fixture this adapter hardcodes values used in tests. With a
+/// [MockFixture] (see [MockAdapter::with_fixture]) relations, columns and
+/// query results are served from the fixture, which lets macros that depend
+/// on the adapter be tested deterministically.
 #[derive(Clone)]
 pub struct MockAdapter {
     /// Adapter type
@@ -36,6 +41,8 @@ pub struct MockAdapter {
     quoting: ResolvedQuoting,
     /// Global CLI cancellation token
     cancellation_token: CancellationToken,
+    /// Scripted warehouse state
+    fixture: Option<Arc<MockFixture>>,
 }
 
 impl fmt::Debug for MockAdapter {
@@ -44,6 +51,7 @@ impl fmt::Debug for MockAdapter {
             .field("adapter_type", &self.adapter_type)
             .field("flags", &self.flags)
             .field("quoting", &self.quoting)
+            .field("fixture", &self.fixture)
             .finish()
     }
 }
@@ -62,8 +70,48 @@ impl MockAdapter {
             flags,
             quoting,
             cancellation_token: token,
+            fixture: None,
         }
     }
+
+    /// Serve relations, columns and query results from `fixture`
+    pub fn with_fixture(mut self, fixture: MockFixture) -> Self {
+        self.fixture = Some(Arc::new(fixture));
+        self
+    }
+
+    /// The fixture, if this adapter is scripted
+    pub fn fixture(&self) -> Option<&MockFixture> {
+        self.fixture.as_deref()
+    }
+
+    /// A relation of the fixture as a [BaseRelation]
+    pub(crate) fn fixture_relation(
+        &self,
+        relation: &MockRelation,
+    ) -> AdapterResult<Arc<dyn BaseRelation>> {
+        Ok(create_relation(
+            self.adapter_type,
+            relation.database.clone(),
+            relation.schema.clone(),
+            Some(relation.identifier.clone()),
+            relation.relation_type(),
+            self.quoting,
+        )?)
+    }
+
+    /// Relations of the fixture in the given schema
+    pub(crate) fn fixture_relations_in(
+        &self,
+        fixture: &MockFixture,
+        database: &str,
+        schema: &str,
+    ) -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
+        fixture
+            .relations_in(database, schema)
+            .map(|relation| self.fixture_relation(relation))
+            .collect()
+    }
 }
 
 impl AdapterTyping for MockAdapter {
@@ -72,7 +120,7 @@ impl AdapterTyping for MockAdapter {
     }
 
     fn as_metadata_adapter(&self) -> Option<&dyn MetadataAdapter> {
-        None // TODO: implement metadata_adapter() for MockAdapter
+        Some(self)
     }
 
     fn as_typed_base_adapter(&self) -> &dyn TypedBaseAdapter {
@@ -111,11 +159,22 @@ impl TypedBaseAdapter for MockAdapter {
             AdapterError::new(AdapterErrorKind::Internal, "Missing query in the context")
         })?;
 
+        if let Some(fixture) = self.fixture() {
+            let query = fixture.find_query(&sql).ok_or_else(|| {
+                AdapterError::new(
+                    AdapterErrorKind::NotFound,
+                    format!("No query in the fixture matches: {sql}"),
+                )
+            })?;
+            let (response, batch) = query.result(self.engine.type_ops())?;
+            return Ok((response, AgateTable::from_record_batch(Arc::new(batch))));
+        }
+
         let response = AdapterResponse {
             message: "execute".to_string(),
             code: sql,
             rows_affected: 1,
-            query_id: None,
+            ..Default::default()
         };
 
         let schema = Arc::new(Schema::new(vec![Field::new(
@@ -156,6 +215,13 @@ impl TypedBaseAdapter for MockAdapter {
         schema: &str,
         identifier: &str,
     ) -> AdapterResult<Option<Arc<dyn BaseRelation>>> {
+        if let Some(fixture) = self.fixture() {
+            return fixture
+                .find_relation(database, schema, identifier)
+                .map(|relation| self.fixture_relation(relation))
+                .transpose();
+        }
+
         Ok(Some(Arc::new(SnowflakeRelation::new(
             Some(database.to_string()),
             Some(schema.to_string()),
@@ -179,8 +245,16 @@ impl TypedBaseAdapter for MockAdapter {
         &self,
         _query_ctx: &QueryCtx,
         _conn: &'_ mut dyn Connection,
-        _db_schema: &CatalogAndSchema,
+        db_schema: &CatalogAndSchema,
     ) -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
+        if let Some(fixture) = self.fixture() {
+            return self.fixture_relations_in(
+                fixture,
+                &db_schema.resolved_catalog,
+                &db_schema.resolved_schema,
+            );
+        }
+
         Err(AdapterError::new(
             AdapterErrorKind::Internal,
             format!(
@@ -193,8 +267,20 @@ impl TypedBaseAdapter for MockAdapter {
     fn get_columns_in_relation(
         &self,
         _state: &State,
-        _relation: Arc<dyn BaseRelation>,
+        relation: Arc<dyn BaseRelation>,
     ) -> AdapterResult<Vec<StdColumn>> {
+        if let Some(fixture) = self.fixture() {
+            let columns = fixture
+                .find_relation(
+                    &relation.database_as_str()?,
+                    &relation.schema_as_str()?,
+                    &relation.identifier_as_str()?,
+                )
+                .map(|relation| relation.std_columns(self.adapter_type))
+                .unwrap_or_default();
+            return Ok(columns);
+        }
+
         Ok(vec![StdColumn::new(
             self.adapter_type(),
             "one".to_string(),  // name
@@ -269,4 +355,48 @@ mod tests {
         let state = State::new_for_env(&env);
         assert_eq!(adapter.quote(&state, "abc").unwrap(), "\"abc\"");
     }
+
+    #[test]
+    fn test_columns_from_fixture() {
+        let fixture = MockFixture::from_yaml(
+            r#"
+relations:
+  - database: db
+    schema: s
+    identifier: t
+    columns:
+      - name: id
+        data_type: integer
+"#,
+        )
+        .unwrap();
+        let adapter = MockAdapter::new(
+            AdapterType::Snowflake,
+            BTreeMap::new(),
+            SNOWFLAKE_RESOLVED_QUOTING,
+            never_cancels(),
+        )
+        .with_fixture(fixture);
+        assert!(adapter.as_metadata_adapter().is_some());
+
+        let env = minijinja::Environment::new();
+        let state = State::new_for_env(&env);
+        let relation = adapter.fixture_relation(&adapter.fixture().unwrap().relations[0]);
+        let columns = adapter
+            .get_columns_in_relation(&state, relation.unwrap())
+            .unwrap();
+        assert_eq!(columns.len(), 1);
+        assert_eq!(columns[0].name(), "id");
+
+        let missing = Arc::new(SnowflakeRelation::new(
+            Some("db".to_string()),
+            Some("s".to_string()),
+            Some("missing".to_string()),
+            None,
+            TableFormat::Default,
+            SNOWFLAKE_RESOLVED_QUOTING,
+        ));
+        let columns = adapter.get_columns_in_relation(&state, missing).unwrap();
+        assert!(columns.is_empty());
+    }
 }
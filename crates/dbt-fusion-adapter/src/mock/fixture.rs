@@ -0,0 +1,371 @@
+//! Fixtures that script the behavior of a [MockAdapter](super::adapter::MockAdapter).
+//!
+//! A fixture declares the relations that exist in the mocked warehouse and how
+//! queries are answered. Queries are matched against the `pattern` regexes in
+//! declaration order and the first match wins.
+//!
+//! ```yaml
+//! relations:
+//!   - database: analytics
+//!     schema: staging
+//!     identifier: customers
+//!     type: table
+//!     last_altered: 2025-01-01T00:00:00Z
+//!     columns:
+//!       - name: id
+//!         data_type: integer
+//!       - name: email
+//!         data_type: varchar(256)
+//!         char_size: 256
+//! queries:
+//!   - pattern: "(?i)select count\\(\\*\\) .* from .*customers"
+//!     columns:
+//!       - name: n
+//!         data_type: bigint
+//!     rows:
+//!       - [42]
+//!     response:
+//!       message: SELECT 1
+//!       code: SELECT
+//!       rows_affected: 1
+//!   - pattern: "(?i)^drop "
+//!     error: permission denied for schema staging
+//! ```
+
+use crate::AdapterType;
+use crate::columns::StdColumn;
+use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult, arrow_error_to_adapter_error};
+use crate::response::AdapterResponse;
+use crate::sql_types::{TypeOps, make_arrow_field};
+
+use arrow::array::RecordBatch;
+use arrow::json::ReaderBuilder;
+use arrow_schema::{Schema, SchemaRef};
+use chrono::{DateTime, Utc};
+use dbt_schemas::dbt_types::RelationType;
+use regex::Regex;
+use serde::Deserialize;
+use serde_json::{Map as JsonMap, Value as JsonValue};
+use std::path::Path;
+use std::sync::Arc;
+
+/// The scripted state of a mocked warehouse
+#[derive(Debug, Clone, Default, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MockFixture {
+    /// Relations that exist in the warehouse
+    #[serde(default)]
+    pub relations: Vec<MockRelation>,
+    /// Responses to queries, tried in order
+    #[serde(default)]
+    pub queries: Vec<MockQuery>,
+    /// Compiled `queries[i].pattern`
+    #[serde(skip)]
+    patterns: Vec<Regex>,
+}
+
+/// A relation that exists in the mocked warehouse
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MockRelation {
+    pub database: String,
+    pub schema: String,
+    pub identifier: String,
+    /// Relation type as dbt names it (table, view, materialized_view, ...)
+    #[serde(rename = "type", default)]
+    pub relation_type: Option<String>,
+    #[serde(default)]
+    pub columns: Vec<MockColumn>,
+    /// Reported by metadata freshness checks
+    #[serde(default)]
+    pub last_altered: Option<DateTime<Utc>>,
+}
+
+/// A column of a fixture relation or query result
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MockColumn {
+    pub name: String,
+    /// SQL type in the dialect of the mocked adapter
+    pub data_type: String,
+    #[serde(default)]
+    pub nullable: Option<bool>,
+    #[serde(default)]
+    pub char_size: Option<u32>,
+    #[serde(default)]
+    pub numeric_precision: Option<u64>,
+    #[serde(default)]
+    pub numeric_scale: Option<u64>,
+    #[serde(default)]
+    pub comment: Option<String>,
+}
+
+/// How a query matching `pattern` is answered
+#[derive(Debug, Clone, Deserialize)]
+#[serde(deny_unknown_fields)]
+pub struct MockQuery {
+    /// Regex searched for in the SQL of the query
+    pub pattern: String,
+    /// Columns of the result set
+    #[serde(default)]
+    pub columns: Vec<MockColumn>,
+    /// Rows of the result set, one value per column
+    #[serde(default)]
+    pub rows: Vec<Vec<JsonValue>>,
+    /// When set, the query fails with this message instead
+    #[serde(default)]
+    pub error: Option<String>,
+    /// Defaults to a response reporting the number of rows
+    #[serde(default)]
+    pub response: Option<AdapterResponse>,
+}
+
+impl MockFixture {
+    /// Load a fixture from a `.json` file or a YAML file
+    pub fn from_path(path: &Path) -> AdapterResult<Self> {
+        let contents = std::fs::read_to_string(path)?;
+        match path.extension().and_then(|ext| ext.to_str()) {
+            Some("json") => Self::from_json(&contents),
+            _ => Self::from_yaml(&contents),
+        }
+    }
+
+    pub fn from_yaml(contents: &str) -> AdapterResult<Self> {
+        dbt_serde_yaml::from_str::<Self>(contents)?.compile()
+    }
+
+    pub fn from_json(contents: &str) -> AdapterResult<Self> {
+        serde_json::from_str::<Self>(contents)?.compile()
+    }
+
+    fn compile(mut self) -> AdapterResult<Self> {
+        self.patterns = self
+            .queries
+            .iter()
+            .map(|query| {
+                Regex::new(&query.pattern).map_err(|e| {
+                    AdapterError::new(
+                        AdapterErrorKind::Configuration,
+                        format!("Invalid query pattern '{}' in fixture: {e}", query.pattern),
+                    )
+                })
+            })
+            .collect::<AdapterResult<_>>()?;
+        Ok(self)
+    }
+
+    /// The first query whose pattern matches `sql`
+    pub fn find_query(&self, sql: &str) -> Option<&MockQuery> {
+        self.patterns
+            .iter()
+            .position(|pattern| pattern.is_match(sql))
+            .map(|i| &self.queries[i])
+    }
+
+    /// Look up a relation, ignoring case like unquoted identifiers do
+    pub fn find_relation(
+        &self,
+        database: &str,
+        schema: &str,
+        identifier: &str,
+    ) -> Option<&MockRelation> {
+        self.relations.iter().find(|relation| {
+            relation.database.eq_ignore_ascii_case(database)
+                && relation.schema.eq_ignore_ascii_case(schema)
+                && relation.identifier.eq_ignore_ascii_case(identifier)
+        })
+    }
+
+    /// All relations in the given schema
+    pub fn relations_in<'a>(
+        &'a self,
+        database: &'a str,
+        schema: &'a str,
+    ) -> impl Iterator<Item = &'a MockRelation> {
+        self.relations.iter().filter(move |relation| {
+            relation.database.eq_ignore_ascii_case(database)
+                && relation.schema.eq_ignore_ascii_case(schema)
+        })
+    }
+}
+
+impl MockRelation {
+    pub fn relation_type(&self) -> Option<RelationType> {
+        self.relation_type.as_deref().map(RelationType::from)
+    }
+
+    pub fn std_columns(&self, adapter_type: AdapterType) -> Vec<StdColumn> {
+        self.columns
+            .iter()
+            .map(|column| {
+                StdColumn::new(
+                    adapter_type,
+                    column.name.clone(),
+                    column.data_type.clone(),
+                    column.char_size,
+                    column.numeric_precision,
+                    column.numeric_scale,
+                )
+            })
+            .collect()
+    }
+
+    pub fn schema(&self, type_ops: &dyn TypeOps) -> AdapterResult<SchemaRef> {
+        columns_to_schema(type_ops, &self.columns)
+    }
+}
+
+impl MockQuery {
+    /// The scripted response and result set, or the scripted error
+    pub fn result(&self, type_ops: &dyn TypeOps) -> AdapterResult<(AdapterResponse, RecordBatch)> {
+        if let Some(error) = &self.error {
+            return Err(AdapterError::new(
+                AdapterErrorKind::SqlExecution,
+                error.clone(),
+            ));
+        }
+
+        let schema = columns_to_schema(type_ops, &self.columns)?;
+        let mut rows = Vec::with_capacity(self.rows.len());
+        for row in &self.rows {
+            if row.len() != self.columns.len() {
+                return Err(AdapterError::new(
+                    AdapterErrorKind::Configuration,
+                    format!(
+                        "Fixture row {row:?} has {} values, expected {} for query '{}'",
+                        row.len(),
+                        self.columns.len(),
+                        self.pattern
+                    ),
+                ));
+            }
+            let row = self
+                .columns
+                .iter()
+                .zip(row)
+                .map(|(column, value)| (column.name.clone(), value.clone()))
+                .collect::<JsonMap<_, _>>();
+            rows.push(row);
+        }
+
+        let mut decoder = ReaderBuilder::new(schema.clone())
+            .with_batch_size(rows.len().max(1))
+            .build_decoder()
+            .map_err(arrow_error_to_adapter_error)?;
+        decoder
+            .serialize(&rows)
+            .map_err(arrow_error_to_adapter_error)?;
+        let batch = decoder
+            .flush()
+            .map_err(arrow_error_to_adapter_error)?
+            .unwrap_or_else(|| RecordBatch::new_empty(schema));
+
+        let response = self.response.clone().unwrap_or_else(|| AdapterResponse {
+            message: "OK".to_string(),
+            rows_affected: batch.num_rows() as i64,
+            ..Default::default()
+        });
+        Ok((response, batch))
+    }
+}
+
+fn columns_to_schema(type_ops: &dyn TypeOps, columns: &[MockColumn]) -> AdapterResult<SchemaRef> {
+    let fields = columns
+        .iter()
+        .map(|column| {
+            make_arrow_field(
+                type_ops,
+                column.name.clone(),
+                &column.data_type,
+                column.nullable,
+                column.comment.clone(),
+            )
+        })
+        .collect::<AdapterResult<Vec<_>>>()?;
+    Ok(Arc::new(Schema::new(fields)))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql_types::NaiveTypeOpsImpl;
+    use arrow::array::{Int64Array, StringArray};
+
+    const FIXTURE: &str = r#"
+relations:
+  - database: analytics
+    schema: staging
+    identifier: customers
+    type: table
+    columns:
+      - name: id
+        data_type: bigint
+      - name: email
+        data_type: varchar(256)
+        char_size: 256
+queries:
+  - pattern: "(?i)^drop "
+    error: permission denied
+  - pattern: "(?i)from .*customers"
+    columns:
+      - name: id
+        data_type: bigint
+      - name: email
+        data_type: text
+    rows:
+      - [1, a@example.com]
+      - [2, null]
+"#;
+
+    #[test]
+    fn test_find_relation_ignores_case() {
+        let fixture = MockFixture::from_yaml(FIXTURE).unwrap();
+        let relation = fixture
+            .find_relation("ANALYTICS", "Staging", "customers")
+            .unwrap();
+        assert_eq!(relation.relation_type(), Some(RelationType::Table));
+        assert_eq!(relation.std_columns(AdapterType::Postgres).len(), 2);
+        assert!(
+            fixture
+                .find_relation("analytics", "staging", "orders")
+                .is_none()
+        );
+        assert_eq!(fixture.relations_in("analytics", "staging").count(), 1);
+    }
+
+    #[test]
+    fn test_query_result() {
+        let fixture = MockFixture::from_yaml(FIXTURE).unwrap();
+        let type_ops = NaiveTypeOpsImpl::new(AdapterType::Postgres);
+
+        let query = fixture.find_query("select * from analytics.staging.customers");
+        let (response, batch) = query.unwrap().result(&type_ops).unwrap();
+        assert_eq!(response.rows_affected, 2);
+        assert_eq!(batch.num_rows(), 2);
+        let ids = batch
+            .column(0)
+            .as_any()
+            .downcast_ref::<Int64Array>()
+            .unwrap();
+        assert_eq!(ids.values(), &[1, 2]);
+        let emails = batch
+            .column(1)
+            .as_any()
+            .downcast_ref::<StringArray>()
+            .unwrap();
+        assert_eq!(emails.value(0), "a@example.com");
+        assert!(emails.is_null(1));
+
+        let query = fixture.find_query("DROP table customers").unwrap();
+        let err = query.result(&type_ops).unwrap_err();
+        assert_eq!(err.kind(), AdapterErrorKind::SqlExecution);
+
+        assert!(fixture.find_query("select 1").is_none());
+    }
+
+    #[test]
+    fn test_invalid_pattern() {
+        let err = MockFixture::from_json(r#"{"queries": [{"pattern": "("}]}"#).unwrap_err();
+        assert_eq!(err.kind(), AdapterErrorKind::Configuration);
+    }
+}
@@ -1,54 +1,184 @@
-use crate::errors::{AdapterResult, AsyncAdapterResult};
+use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult, AsyncAdapterResult};
 use crate::metadata::*;
 use crate::mock::adapter::MockAdapter;
+use crate::mock::fixture::MockFixture;
 use arrow_schema::Schema;
 
+use dbt_schemas::dbt_types::RelationType;
 use dbt_schemas::schemas::relations::base::{BaseRelation, RelationPattern};
 use dbt_xdbc::query_ctx::ExecutionPhase;
+use regex::Regex;
 use std::collections::{BTreeMap, BTreeSet, HashMap};
 use std::sync::Arc;
 
+impl MockAdapter {
+    fn fixture_schema(
+        &self,
+        fixture: &MockFixture,
+        relation: &dyn BaseRelation,
+    ) -> AdapterResult<Arc<Schema>> {
+        let found = fixture.find_relation(
+            &relation.database_as_str()?,
+            &relation.schema_as_str()?,
+            &relation.identifier_as_str()?,
+        );
+        match found {
+            Some(found) => found.schema(self.engine().type_ops()),
+            None => Err(AdapterError::new(
+                AdapterErrorKind::NotFound,
+                format!(
+                    "Relation {} not found in the fixture",
+                    relation.semantic_fqn()
+                ),
+            )),
+        }
+    }
+}
+
 impl MetadataAdapter for MockAdapter {
     fn list_relations_schemas(
         &self,
         _unique_id: Option<String>,
         _phase: Option<ExecutionPhase>,
-        _relations: &[Arc<dyn BaseRelation>],
+        relations: &[Arc<dyn BaseRelation>],
     ) -> AsyncAdapterResult<'_, HashMap<String, AdapterResult<Arc<Schema>>>> {
-        let schemas = HashMap::new();
+        let schemas = match self.fixture() {
+            Some(fixture) => relations
+                .iter()
+                .map(|relation| {
+                    let schema = self.fixture_schema(fixture, relation.as_ref());
+                    (relation.semantic_fqn(), schema)
+                })
+                .collect(),
+            None => HashMap::new(),
+        };
         let future = async move { Ok(schemas) };
         Box::pin(future)
     }
 
     fn list_relations_schemas_by_patterns(
         &self,
-        _patterns: &[RelationPattern],
+        patterns: &[RelationPattern],
     ) -> AsyncAdapterResult<'_, Vec<(String, AdapterResult<RelationSchemaPair>)>> {
-        let future = async move { Ok(Vec::new()) };
+        let mut result = Vec::new();
+        if let Some(fixture) = self.fixture() {
+            for relation in &fixture.relations {
+                let matched = patterns.iter().any(|pattern| {
+                    pattern.database.eq_ignore_ascii_case(&relation.database)
+                        && like(&pattern.schema_pattern, &relation.schema)
+                        && like(&pattern.table_pattern, &relation.identifier)
+                });
+                if !matched {
+                    continue;
+                }
+                let fqn = format!(
+                    "{}.{}.{}",
+                    relation.database, relation.schema, relation.identifier
+                );
+                let pair = self.fixture_relation(relation).and_then(|base| {
+                    let schema = relation.schema(self.engine().type_ops())?;
+                    Ok((base, schema))
+                });
+                result.push((fqn, pair));
+            }
+        }
+        let future = async move { Ok(result) };
         Box::pin(future)
     }
 
     fn freshness(
         &self,
-        _relations: &[Arc<dyn BaseRelation>],
+        relations: &[Arc<dyn BaseRelation>],
     ) -> AsyncAdapterResult<'_, BTreeMap<String, MetadataFreshness>> {
-        let future = async move { Ok(BTreeMap::new()) };
+        let mut result = BTreeMap::new();
+        if let Some(fixture) = self.fixture() {
+            for relation in relations {
+                let (Ok(database), Ok(schema), Ok(identifier)) = (
+                    relation.database_as_str(),
+                    relation.schema_as_str(),
+                    relation.identifier_as_str(),
+                ) else {
+                    continue;
+                };
+                let Some(found) = fixture.find_relation(&database, &schema, &identifier) else {
+                    continue;
+                };
+                if let Some(last_altered) = found.last_altered {
+                    let freshness = MetadataFreshness {
+                        last_altered,
+                        is_view: found.relation_type() == Some(RelationType::View),
+                    };
+                    result.insert(relation.semantic_fqn(), freshness);
+                }
+            }
+        }
+        let future = async move { Ok(result) };
         Box::pin(future)
     }
 
     fn create_schemas_if_not_exists(
         &self,
         _state: &minijinja::State<'_, '_>,
-        _catalog_schemas: &BTreeMap<String, BTreeSet<String>>,
+        catalog_schemas: &BTreeMap<String, BTreeSet<String>>,
     ) -> AdapterResult<Vec<(String, String, AdapterResult<()>)>> {
-        Ok(Vec::new())
+        Ok(catalog_schemas
+            .iter()
+            .flat_map(|(catalog, schemas)| {
+                schemas
+                    .iter()
+                    .map(|schema| (catalog.clone(), schema.clone(), Ok(())))
+            })
+            .collect())
     }
 
     fn list_relations_in_parallel(
         &self,
-        _db_schemas: &[CatalogAndSchema],
+        db_schemas: &[CatalogAndSchema],
     ) -> AsyncAdapterResult<'_, BTreeMap<CatalogAndSchema, AdapterResult<RelationVec>>> {
-        let future = async move { Ok(BTreeMap::new()) };
+        let result = match self.fixture() {
+            Some(fixture) => db_schemas
+                .iter()
+                .map(|db_schema| {
+                    let relations = self.fixture_relations_in(
+                        fixture,
+                        &db_schema.resolved_catalog,
+                        &db_schema.resolved_schema,
+                    );
+                    (db_schema.clone(), relations)
+                })
+                .collect(),
+            None => BTreeMap::new(),
+        };
+        let future = async move { Ok(result) };
         Box::pin(future)
     }
 }
+
+/// Case-insensitive SQL `LIKE` matching, with `%` and `_` wildcards
+fn like(pattern: &str, value: &str) -> bool {
+    let mut expr = String::from("(?is)^");
+    for c in pattern.chars() {
+        match c {
+            '%' => expr.push_str(".*"),
+            '_' => expr.push('.'),
+            c => expr.push_str(&regex::escape(&c.to_string())),
+        }
+    }
+    expr.push('$');
+    Regex::new(&expr).is_ok_and(|re| re.is_match(value))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_like() {
+        assert!(like("%", "anything"));
+        assert!(like("stg_%", "STG_customers"));
+        assert!(like("ord_rs", "orders"));
+        assert!(!like("ord_rs", "ordrs"));
+        assert!(like("a.b", "A.B"));
+        assert!(!like("a.b", "axb"));
+    }
+}
@@ -1,2 +1,3 @@
 pub mod adapter;
+pub mod fixture;
 pub mod metadata;
@@ -11,7 +11,7 @@ use crate::query_comment::QueryCommentConfig;
 use crate::relation_object::{RelationObject, create_relation};
 use crate::response::AdapterResponse;
 use crate::sql_types::TypeOps;
-use crate::stmt_splitter::NaiveStmtSplitter;
+use crate::stmt_splitter::DialectStmtSplitter;
 use crate::typed_adapter::TypedBaseAdapter;
 use crate::{AdapterResult, SqlEngine};
 
@@ -108,7 +108,7 @@ impl ParseAdapter {
         let quoting = package_quoting
             .try_into()
             .expect("Failed to convert quoting to resolved quoting");
-        let stmt_splitter = Arc::new(NaiveStmtSplitter {});
+        let stmt_splitter = Arc::new(DialectStmtSplitter);
         let query_comment = QueryCommentConfig::from_query_comment(None, adapter_type, false);
 
         let engine = SqlEngine::new(
@@ -309,6 +309,20 @@ impl BaseAdapter for ParseAdapter {
         Ok((response, table))
     }
 
+    #[allow(clippy::too_many_arguments)]
+    fn execute_with_bindings(
+        &self,
+        state: &State,
+        sql: &str,
+        _bindings: &Value,
+        auto_begin: bool,
+        fetch: bool,
+        limit: Option<i64>,
+        options: Option<HashMap<String, String>>,
+    ) -> AdapterResult<(AdapterResponse, AgateTable)> {
+        self.execute(state, sql, auto_begin, fetch, limit, options)
+    }
+
     fn add_query(
         &self,
         _state: &State,
@@ -442,6 +456,14 @@ impl BaseAdapter for ParseAdapter {
         Ok(empty_vec_value())
     }
 
+    fn plan_schema_changes(
+        &self,
+        _state: &State,
+        _args: &[Value],
+    ) -> Result<Value, MinijinjaError> {
+        Ok(none_value())
+    }
+
     fn quote(&self, _state: &State, _identifier: &str) -> Result<Value, MinijinjaError> {
         Ok(empty_vec_value())
     }
@@ -509,6 +531,10 @@ impl BaseAdapter for ParseAdapter {
         Ok(Value::from(BTreeMap::<Value, Vec<Value>>::new()))
     }
 
+    fn diff_grants(&self, _state: &State, _args: &[Value]) -> Result<Value, MinijinjaError> {
+        Ok(none_value())
+    }
+
     fn get_column_schema_from_query(
         &self,
         _state: &State,
@@ -113,6 +113,14 @@ impl BaseRelation for EmptyRelation {
         Ok(none_value())
     }
 
+    fn iceberg_table_config_changeset(&self, _args: &[Value]) -> Result<Value, MinijinjaError> {
+        Ok(none_value())
+    }
+
+    fn table_config_changeset(&self, _args: &[Value]) -> Result<Value, MinijinjaError> {
+        Ok(none_value())
+    }
+
     fn from_config(&self, _args: &[Value]) -> Result<Value, MinijinjaError> {
         Ok(none_value())
     }
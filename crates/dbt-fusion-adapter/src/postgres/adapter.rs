@@ -9,7 +9,7 @@ use crate::sql_engine::SqlEngine;
 use crate::typed_adapter::TypedBaseAdapter;
 use arrow::array::{Array, StringArray};
 use dbt_schemas::dbt_types::RelationType;
-use dbt_schemas::schemas::common::{ConstraintSupport, ConstraintType, DbtIncrementalStrategy};
+use dbt_schemas::schemas::common::DbtIncrementalStrategy;
 use dbt_schemas::schemas::relations::base::BaseRelation;
 use dbt_xdbc::{Connection, QueryCtx};
 
@@ -32,7 +32,7 @@ impl PostgresAdapter {
 
 impl AdapterTyping for PostgresAdapter {
     fn as_metadata_adapter(&self) -> Option<&dyn MetadataAdapter> {
-        None // TODO: implement metadata_adapter() for PostgresAdapter
+        Some(self)
     }
 
     fn as_typed_base_adapter(&self) -> &dyn TypedBaseAdapter {
@@ -201,15 +201,6 @@ impl TypedBaseAdapter for PostgresAdapter {
             DbtIncrementalStrategy::Microbatch,
         ]
     }
-
-    fn get_constraint_support(&self, ct: ConstraintType) -> ConstraintSupport {
-        match ct {
-            ConstraintType::Check => ConstraintSupport::NotSupported,
-            ConstraintType::NotNull | ConstraintType::ForeignKey => ConstraintSupport::Enforced,
-            ConstraintType::Unique | ConstraintType::PrimaryKey => ConstraintSupport::NotEnforced,
-            _ => ConstraintSupport::NotSupported,
-        }
-    }
 }
 
 impl fmt::Display for PostgresAdapter {
@@ -1,16 +1,26 @@
 use crate::postgres::adapter::PostgresAdapter;
+use crate::postgres::relation::PostgresRelation;
+use crate::sql_types::{TypeOps, make_arrow_field};
 use crate::{
-    AdapterResult, errors::AsyncAdapterResult, metadata::*, record_batch_utils::get_column_values,
+    AdapterResult, AdapterType, AdapterTyping, TypedBaseAdapter,
+    errors::{AdapterError, AdapterErrorKind, AsyncAdapterResult},
+    metadata::*,
+    record_batch_utils::get_column_values,
 };
-use arrow_schema::Schema;
+use arrow_schema::{Field, Schema};
 
-use arrow_array::{Array, Decimal128Array, RecordBatch, StringArray};
+use arrow_array::{
+    Array, BooleanArray, Decimal128Array, RecordBatch, StringArray, TimestampMicrosecondArray,
+};
 
+use dbt_common::cancellation::Cancellable;
+use dbt_schemas::dbt_types::RelationType;
 use dbt_schemas::schemas::{
     legacy_catalog::{CatalogNodeStats, CatalogTable, ColumnMetadata, TableMetadata},
     relations::base::{BaseRelation, RelationPattern},
 };
 use dbt_xdbc::query_ctx::ExecutionPhase;
+use dbt_xdbc::{Connection, MapReduce, QueryCtx};
 
 use std::collections::btree_map::Entry;
 use std::collections::{BTreeMap, BTreeSet, HashMap};
@@ -126,44 +136,860 @@ impl MetadataAdapter for PostgresAdapter {
         Ok(columns_by_relation)
     }
 
+    fn list_user_defined_functions(
+        &self,
+        catalog_schemas: &BTreeMap<String, BTreeSet<String>>,
+    ) -> AsyncAdapterResult<'_, Vec<UDF>> {
+        type Acc = Vec<UDF>;
+
+        // Postgres cannot query across databases, so a single query per catalog
+        // covers all of its schemas and only returns rows for the database the
+        // connection is bound to. Functions implemented in C or built into the
+        // server are not user-defined and are left out.
+        let queries = catalog_schemas
+            .iter()
+            .map(|(catalog, schemas)| {
+                let schema_list = schemas
+                    .iter()
+                    .map(|schema| format!("'{schema}'"))
+                    .collect::<Vec<_>>()
+                    .join(", ");
+                format!(
+                    "select
+    '{catalog}' as catalog_name,
+    ns.nspname as schema_name,
+    p.proname as name,
+    coalesce(obj_description(p.oid, 'pg_proc'), '') as description,
+    p.prokind::text as kind,
+    p.proretset as returns_set,
+    pg_get_function_identity_arguments(p.oid) as arguments,
+    pg_get_function_result(p.oid) as result_type
+from pg_catalog.pg_proc p
+join pg_catalog.pg_namespace ns
+    on ns.oid = p.pronamespace
+join pg_catalog.pg_language l
+    on l.oid = p.prolang
+where current_database() = '{catalog}'
+and ns.nspname in ({schema_list})
+and p.prokind in ('f', 'a', 'w')
+and l.lanname not in ('internal', 'c')"
+                )
+            })
+            .collect::<Vec<_>>();
+
+        let adapter = self.clone();
+        let new_connection_f = move || {
+            adapter
+                .new_connection(None, None)
+                .map_err(Cancellable::Error)
+        };
+
+        let adapter = self.clone();
+        let map_f =
+            move |conn: &'_ mut dyn Connection, sql: &String| -> AdapterResult<Arc<RecordBatch>> {
+                let query_ctx = QueryCtx::new(adapter.adapter_type().to_string())
+                    .with_sql(sql)
+                    .with_desc("List user functions");
+                let (_, table) = adapter.query(conn, &query_ctx, None)?;
+                let batch = table.original_record_batch();
+                Ok(batch)
+            };
+
+        let reduce_f = |acc: &mut Acc,
+                        _sql: String,
+                        batch_res: AdapterResult<Arc<RecordBatch>>|
+         -> Result<(), Cancellable<AdapterError>> {
+            let batch = batch_res?;
+
+            if batch.num_rows() == 0 {
+                return Ok(());
+            }
+
+            let catalog_names = get_column_values::<StringArray>(&batch, "catalog_name")?;
+            let schema_names = get_column_values::<StringArray>(&batch, "schema_name")?;
+            let names = get_column_values::<StringArray>(&batch, "name")?;
+            let descriptions = get_column_values::<StringArray>(&batch, "description")?;
+            let kinds = get_column_values::<StringArray>(&batch, "kind")?;
+            let returns_set = get_column_values::<BooleanArray>(&batch, "returns_set")?;
+            let arguments = get_column_values::<StringArray>(&batch, "arguments")?;
+            let result_types = get_column_values::<StringArray>(&batch, "result_type")?;
+
+            for i in 0..batch.num_rows() {
+                let catalog = catalog_names.value(i);
+                let schema = schema_names.value(i);
+                let name = names.value(i);
+
+                // `prokind` is 'a' for aggregates and 'w' for window functions,
+                // set-returning functions are the Postgres flavour of table functions
+                let kind = if kinds.value(i) == "a" {
+                    UDFKind::Aggregate
+                } else if returns_set.value(i) {
+                    UDFKind::Table
+                } else {
+                    UDFKind::Scalar
+                };
+
+                let signature = format!(
+                    "{name}({}) RETURNS {}",
+                    arguments.value(i),
+                    result_types.value(i)
+                );
+
+                acc.push(UDF {
+                    name: format!("{catalog}.{schema}.{name}"),
+                    description: descriptions.value(i).to_string(),
+                    signature,
+                    adapter_type: AdapterType::Postgres,
+                    kind,
+                });
+            }
+
+            Ok(())
+        };
+
+        let map_reduce = MapReduce::new(
+            Box::new(new_connection_f),
+            Box::new(map_f),
+            Box::new(reduce_f),
+            MAX_CONNECTIONS,
+        );
+        let token = self.cancellation_token();
+        map_reduce.run(Arc::new(queries), token)
+    }
+
     fn list_relations_schemas(
         &self,
         _unique_id: Option<String>,
         _phase: Option<ExecutionPhase>,
-        _relations: &[Arc<dyn BaseRelation>],
+        relations: &[Arc<dyn BaseRelation>],
     ) -> AsyncAdapterResult<'_, HashMap<String, AdapterResult<Arc<Schema>>>> {
-        let future = async move { todo!("PostgreSQL's list_relations_schemas") };
-        Box::pin(future)
+        type Acc = HashMap<String, AdapterResult<Arc<Schema>>>;
+
+        let adapter = self.clone();
+        let new_connection_f = Box::new(move || {
+            adapter
+                .new_connection(None, None)
+                .map_err(Cancellable::Error)
+        });
+
+        let adapter = self.clone();
+        let map_f = move |conn: &'_ mut dyn Connection,
+                          relation: &Arc<dyn BaseRelation>|
+              -> AdapterResult<Arc<Schema>> {
+            let catalog = relation.database_as_resolved_str()?;
+            let schema = relation.schema_as_resolved_str()?;
+            let identifier = relation.identifier_as_resolved_str()?;
+
+            // information_schema.columns drops the type modifiers (e.g. varchar
+            // lengths and numeric precision), format_type keeps them
+            let sql = format!(
+                "select
+    a.attname as column_name,
+    format_type(a.atttypid, a.atttypmod) as data_type,
+    not a.attnotnull as is_nullable,
+    coalesce(col_description(a.attrelid, a.attnum), '') as comment
+from pg_catalog.pg_attribute a
+join pg_catalog.pg_class c
+    on c.oid = a.attrelid
+join pg_catalog.pg_namespace ns
+    on ns.oid = c.relnamespace
+where ns.nspname = '{schema}'
+and c.relname = '{identifier}'
+and a.attnum > 0
+and not a.attisdropped
+order by a.attnum"
+            );
+
+            let query_ctx = QueryCtx::new(adapter.adapter_type().to_string())
+                .with_sql(sql)
+                .with_desc("Get table schema");
+            let (_, table) = adapter.query(&mut *conn, &query_ctx, None)?;
+            let batch = table.original_record_batch();
+
+            let column_names = get_column_values::<StringArray>(&batch, "column_name")?;
+            let data_types = get_column_values::<StringArray>(&batch, "data_type")?;
+            let is_nullables = get_column_values::<BooleanArray>(&batch, "is_nullable")?;
+            let comments = get_column_values::<StringArray>(&batch, "comment")?;
+
+            let mut fields = Vec::with_capacity(batch.num_rows());
+            for i in 0..batch.num_rows() {
+                fields.push(make_pg_attribute_field(
+                    adapter.engine().type_ops(),
+                    column_names.value(i),
+                    data_types.value(i),
+                    is_nullables.value(i),
+                    comments.value(i),
+                )?);
+            }
+
+            if fields.is_empty() {
+                Err(AdapterError::new(
+                    AdapterErrorKind::UnexpectedResult,
+                    format!("No columns in pg_attribute for {catalog}.{schema}.{identifier}"),
+                ))
+            } else {
+                Ok(Arc::new(Schema::new(fields)))
+            }
+        };
+        let reduce_f = |acc: &mut Acc,
+                        relation: Arc<dyn BaseRelation>,
+                        schema: AdapterResult<Arc<Schema>>|
+         -> Result<(), Cancellable<AdapterError>> {
+            acc.insert(relation.semantic_fqn(), schema);
+            Ok(())
+        };
+        let map_reduce = MapReduce::new(
+            Box::new(new_connection_f),
+            Box::new(map_f),
+            Box::new(reduce_f),
+            MAX_CONNECTIONS,
+        );
+        let token = self.cancellation_token();
+        map_reduce.run(Arc::new(relations.to_vec()), token)
     }
 
     fn list_relations_schemas_by_patterns(
         &self,
-        _patterns: &[RelationPattern],
+        relations_pattern: &[RelationPattern],
     ) -> AsyncAdapterResult<'_, Vec<(String, AdapterResult<RelationSchemaPair>)>> {
-        todo!("PostgresAdapter::list_relations_schemas_by_patterns")
+        type Acc = Vec<(String, AdapterResult<RelationSchemaPair>)>;
+
+        let queries = build_pattern_queries(relations_pattern);
+
+        let adapter = self.clone();
+        let new_connection_f = move || {
+            adapter
+                .new_connection(None, None)
+                .map_err(Cancellable::Error)
+        };
+
+        let adapter = self.clone();
+        let map_f =
+            move |conn: &'_ mut dyn Connection, sql: &String| -> AdapterResult<Arc<RecordBatch>> {
+                let query_ctx = QueryCtx::new(adapter.adapter_type().to_string())
+                    .with_sql(sql)
+                    .with_desc("Get schema by pattern");
+                let (_, table) = adapter.query(conn, &query_ctx, None)?;
+                let batch = table.original_record_batch();
+                Ok(batch)
+            };
+
+        let adapter = self.clone();
+        let reduce_f = move |acc: &mut Acc,
+                             _sql: String,
+                             batch_res: AdapterResult<Arc<RecordBatch>>|
+              -> Result<(), Cancellable<AdapterError>> {
+            let batch = batch_res?;
+            let mut schemas_from_batch = build_schemas_from_pg_attributes(batch, &adapter)?;
+            acc.append(&mut schemas_from_batch);
+            Ok(())
+        };
+        let map_reduce = MapReduce::new(
+            Box::new(new_connection_f),
+            Box::new(map_f),
+            Box::new(reduce_f),
+            MAX_CONNECTIONS,
+        );
+        let token = self.cancellation_token();
+        map_reduce.run(Arc::new(queries), token)
     }
 
     fn freshness(
         &self,
-        _relations: &[Arc<dyn BaseRelation>],
+        relations: &[Arc<dyn BaseRelation>],
     ) -> AsyncAdapterResult<'_, BTreeMap<String, MetadataFreshness>> {
-        todo!("PostgresAdapter::freshness")
+        type Acc = BTreeMap<String, MetadataFreshness>;
+
+        let adapter = self.clone();
+        let new_connection_f = move || {
+            adapter
+                .new_connection(None, None)
+                .map_err(Cancellable::Error)
+        };
+
+        // Postgres keeps no last-modified time per relation. The commit that
+        // last touched a view's pg_class row is when it was last (re)defined,
+        // but only when `track_commit_timestamp` is enabled on the server,
+        // which it is not by default. Row changes don't touch pg_class, and
+        // finding the latest commit among a table's rows would scan it, so
+        // tables get no metadata freshness and fall back to `loaded_at_field`.
+        let adapter = self.clone();
+        let map_f = move |conn: &'_ mut dyn Connection,
+                          relation: &Arc<dyn BaseRelation>|
+              -> AdapterResult<Option<MetadataFreshness>> {
+            let schema = relation.schema_as_resolved_str()?;
+            let identifier = relation.identifier_as_resolved_str()?;
+
+            // pg_xact_commit_timestamp raises an error unless commit
+            // timestamps are tracked, the case guards the call
+            let sql = format!(
+                "select
+    case when current_setting('track_commit_timestamp') = 'on'
+        then pg_xact_commit_timestamp(c.xmin)
+    end as last_altered
+from pg_catalog.pg_class c
+join pg_catalog.pg_namespace ns
+    on ns.oid = c.relnamespace
+where ns.nspname = '{schema}'
+and c.relname = '{identifier}'
+and c.relkind = 'v'"
+            );
+            let query_ctx = QueryCtx::new(adapter.adapter_type().to_string())
+                .with_sql(sql)
+                .with_desc("Extracting freshness from pg_catalog");
+            let (_, table) = adapter.query(&mut *conn, &query_ctx, None)?;
+            let batch = table.original_record_batch();
+            if batch.num_rows() == 0 {
+                return Ok(None);
+            }
+
+            let last_altered =
+                get_column_values::<TimestampMicrosecondArray>(&batch, "last_altered")?;
+            (!last_altered.is_null(0))
+                .then(|| MetadataFreshness::from_micros(last_altered.value(0), true))
+                .transpose()
+        };
+
+        let reduce_f = |acc: &mut Acc,
+                        relation: Arc<dyn BaseRelation>,
+                        freshness: AdapterResult<Option<MetadataFreshness>>|
+         -> Result<(), Cancellable<AdapterError>> {
+            if let Some(freshness) = freshness? {
+                acc.insert(relation.semantic_fqn(), freshness);
+            }
+            Ok(())
+        };
+
+        let map_reduce = MapReduce::new(
+            Box::new(new_connection_f),
+            Box::new(map_f),
+            Box::new(reduce_f),
+            MAX_CONNECTIONS,
+        );
+        let token = self.cancellation_token();
+        map_reduce.run(Arc::new(relations.to_vec()), token)
     }
 
     fn create_schemas_if_not_exists(
         &self,
-        _state: &minijinja::State<'_, '_>,
-        _catalog_schemas: &BTreeMap<String, BTreeSet<String>>,
+        state: &minijinja::State<'_, '_>,
+        catalog_schemas: &BTreeMap<String, BTreeSet<String>>,
     ) -> AdapterResult<Vec<(String, String, AdapterResult<()>)>> {
-        todo!("PostgresAdapter::create_schemas_if_not_exists")
+        create_schemas_if_not_exists(Arc::new(self.clone()), state, catalog_schemas)
     }
 
     fn list_relations_in_parallel(
         &self,
-        _db_schemas: &[CatalogAndSchema],
+        db_schemas: &[CatalogAndSchema],
     ) -> AsyncAdapterResult<'_, BTreeMap<CatalogAndSchema, AdapterResult<RelationVec>>> {
-        // FIXME: Implement cache hydration
-        let future = async move { Ok(BTreeMap::new()) };
-        Box::pin(future)
+        type Acc = BTreeMap<CatalogAndSchema, AdapterResult<RelationVec>>;
+        let adapter = self.clone();
+        let new_connection_f = move || {
+            adapter
+                .new_connection(None, None)
+                .map_err(Cancellable::Error)
+        };
+
+        let adapter = self.clone();
+
+        let map_f = move |conn: &'_ mut dyn Connection,
+                          db_schema: &CatalogAndSchema|
+              -> AdapterResult<Vec<Arc<dyn BaseRelation>>> {
+            let query_ctx = QueryCtx::new(adapter.adapter_type().to_string())
+                .with_desc("list_relations_in_parallel");
+            adapter.list_relations(&query_ctx, conn, db_schema)
+        };
+
+        let reduce_f = move |acc: &mut Acc,
+                             db_schema: CatalogAndSchema,
+                             relations: AdapterResult<Vec<Arc<dyn BaseRelation>>>|
+              -> Result<(), Cancellable<AdapterError>> {
+            acc.insert(db_schema, relations);
+            Ok(())
+        };
+
+        let map_reduce = MapReduce::new(
+            Box::new(new_connection_f),
+            Box::new(map_f),
+            Box::new(reduce_f),
+            MAX_CONNECTIONS,
+        );
+        let token = self.cancellation_token();
+        map_reduce.run(Arc::new(db_schemas.to_vec()), token)
+    }
+}
+
+/// Builds one pg_catalog query per database, selecting the columns of all
+/// relations matched by that database's patterns
+fn build_pattern_queries(relations_pattern: &[RelationPattern]) -> Vec<String> {
+    let mut patterns_by_database = BTreeMap::new();
+    for pat in relations_pattern {
+        patterns_by_database
+            .entry(pat.database.clone())
+            .or_insert_with(Vec::new)
+            .push(pat);
+    }
+
+    patterns_by_database
+        .into_iter()
+        .map(|(database, patterns)| {
+            let predicates_union = patterns
+                .iter()
+                .map(|pat| {
+                    format!(
+                        "(ns.nspname ilike '{}' and c.relname ilike '{}')",
+                        pat.schema_pattern, pat.table_pattern
+                    )
+                })
+                .collect::<Vec<_>>()
+                .join(" or ");
+            format!(
+                "select
+    '{database}' as table_catalog,
+    ns.nspname as table_schema,
+    c.relname as table_name,
+    c.relkind::text as relkind,
+    a.attname as column_name,
+    format_type(a.atttypid, a.atttypmod) as data_type,
+    not a.attnotnull as is_nullable,
+    coalesce(col_description(a.attrelid, a.attnum), '') as comment
+from pg_catalog.pg_attribute a
+join pg_catalog.pg_class c
+    on c.oid = a.attrelid
+join pg_catalog.pg_namespace ns
+    on ns.oid = c.relnamespace
+where c.relkind in ('r', 'p', 'v', 'm', 'f')
+and a.attnum > 0
+and not a.attisdropped
+and ({predicates_union})
+order by ns.nspname, c.relname, a.attnum"
+            )
+        })
+        .collect()
+}
+
+/// Maps a `pg_class.relkind` to the dbt relation type
+fn relation_type_from_relkind(relkind: &str) -> Option<RelationType> {
+    match relkind {
+        "r" | "p" | "f" => Some(RelationType::Table),
+        "v" => Some(RelationType::View),
+        "m" => Some(RelationType::MaterializedView),
+        _ => None,
+    }
+}
+
+fn make_pg_attribute_field(
+    type_ops: &dyn TypeOps,
+    name: &str,
+    data_type: &str,
+    is_nullable: bool,
+    comment: &str,
+) -> AdapterResult<Field> {
+    let comment = match comment {
+        "" => None,
+        c => Some(c.to_string()),
+    };
+    make_arrow_field(
+        type_ops,
+        name.to_string(),
+        data_type,
+        Some(is_nullable),
+        comment,
+    )
+}
+
+/// Groups the rows of a [build_pattern_queries] result (ordered by relation)
+/// into one Arrow schema per relation
+fn build_schemas_from_pg_attributes(
+    batch: Arc<RecordBatch>,
+    adapter: &PostgresAdapter,
+) -> AdapterResult<Vec<(String, AdapterResult<RelationSchemaPair>)>> {
+    if batch.num_rows() == 0 {
+        return Ok(Vec::new());
+    }
+
+    let table_catalogs = get_column_values::<StringArray>(&batch, "table_catalog")?;
+    let table_schemas = get_column_values::<StringArray>(&batch, "table_schema")?;
+    let table_names = get_column_values::<StringArray>(&batch, "table_name")?;
+    let relkinds = get_column_values::<StringArray>(&batch, "relkind")?;
+    let column_names = get_column_values::<StringArray>(&batch, "column_name")?;
+    let data_types = get_column_values::<StringArray>(&batch, "data_type")?;
+    let is_nullables = get_column_values::<BooleanArray>(&batch, "is_nullable")?;
+    let comments = get_column_values::<StringArray>(&batch, "comment")?;
+
+    let mut result = Vec::<(String, AdapterResult<RelationSchemaPair>)>::new();
+    let mut current: Option<(String, Arc<dyn BaseRelation>, Vec<Field>)> = None;
+
+    for i in 0..batch.num_rows() {
+        let catalog = table_catalogs.value(i);
+        let schema = table_schemas.value(i);
+        let table = table_names.value(i);
+        let fully_qualified_name = format!("{catalog}.{schema}.{table}");
+
+        if current
+            .as_ref()
+            .is_none_or(|(fqn, _, _)| *fqn != fully_qualified_name)
+        {
+            if let Some((fqn, relation, fields)) = current.take() {
+                result.push((fqn, Ok((relation, Arc::new(Schema::new(fields))))));
+            }
+            let relation = PostgresRelation::try_new(
+                Some(catalog.to_string()),
+                Some(schema.to_string()),
+                Some(table.to_string()),
+                relation_type_from_relkind(relkinds.value(i)),
+                adapter.quoting(),
+            )?;
+            current = Some((fully_qualified_name, Arc::new(relation), Vec::new()));
+        }
+
+        let field = make_pg_attribute_field(
+            adapter.engine().type_ops(),
+            column_names.value(i),
+            data_types.value(i),
+            is_nullables.value(i),
+            comments.value(i),
+        )?;
+        if let Some((_, _, fields)) = current.as_mut() {
+            fields.push(field);
+        }
+    }
+    if let Some((fqn, relation, fields)) = current {
+        result.push((fqn, Ok((relation, Arc::new(Schema::new(fields))))));
+    }
+
+    Ok(result)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AdapterConfig;
+    use crate::query_comment::QueryCommentConfig;
+    use crate::sql_engine::SqlEngine;
+    use crate::sql_types::NaiveTypeOpsImpl;
+    use crate::stmt_splitter::DialectStmtSplitter;
+    use dbt_auth::auth_for_backend;
+    use dbt_common::cancellation::never_cancels;
+    use dbt_schemas::schemas::relations::DEFAULT_RESOLVED_QUOTING;
+    use dbt_test_containers::container::docker::{
+        ContainerConfig, PortBinding, initialize_container,
+    };
+    use dbt_xdbc::Backend;
+    use std::path::PathBuf;
+
+    #[test]
+    fn test_build_pattern_queries_groups_by_database() {
+        let pattern = |database: &str, schema: &str, table: &str| {
+            RelationPattern::new(database.to_string(), schema.to_string(), table.to_string())
+        };
+        let queries = build_pattern_queries(&[
+            pattern("analytics", "staging", "stg_%"),
+            pattern("raw", "public", "%"),
+            pattern("analytics", "marts", "dim_%"),
+        ]);
+
+        assert_eq!(queries.len(), 2);
+        assert!(queries[0].contains("'analytics' as table_catalog"));
+        assert!(queries[0].contains(
+            "(ns.nspname ilike 'staging' and c.relname ilike 'stg_%') or \
+             (ns.nspname ilike 'marts' and c.relname ilike 'dim_%')"
+        ));
+        assert!(queries[1].contains("'raw' as table_catalog"));
+        assert!(queries[1].contains("(ns.nspname ilike 'public' and c.relname ilike '%')"));
+    }
+
+    #[test]
+    fn test_relation_type_from_relkind() {
+        assert_eq!(relation_type_from_relkind("r"), Some(RelationType::Table));
+        assert_eq!(relation_type_from_relkind("p"), Some(RelationType::Table));
+        assert_eq!(relation_type_from_relkind("v"), Some(RelationType::View));
+        assert_eq!(
+            relation_type_from_relkind("m"),
+            Some(RelationType::MaterializedView)
+        );
+        assert_eq!(relation_type_from_relkind("S"), None);
+    }
+
+    /// Started once for all tests of this module, which share its port
+    static CONTAINER: tokio::sync::OnceCell<()> = tokio::sync::OnceCell::const_new();
+
+    /// An adapter connected to the Postgres container of dbt-test-containers,
+    /// after running `setup` on it
+    async fn postgres_adapter(setup: &[&str]) -> PostgresAdapter {
+        CONTAINER
+            .get_or_init(|| async {
+                let dockerfile = PathBuf::from(env!("CARGO_MANIFEST_DIR"))
+                    .parent()
+                    .unwrap()
+                    .join("dbt-test-containers")
+                    .join("docker")
+                    .join("postgres")
+                    .join("Dockerfile");
+                initialize_container(ContainerConfig {
+                    image_name_base: "postgres-fs-tests".to_string(),
+                    image_uri: None,
+                    dockerfile_path: Some(dockerfile),
+                    ro_mount_paths: vec![],
+                    rw_mount_path: None,
+                    port_bindings: HashMap::from_iter([(
+                        "5432/tcp".to_string(),
+                        Some(vec![PortBinding {
+                            host_ip: Some("0.0.0.0".to_string()),
+                            host_port: Some("5499/tcp".to_string()),
+                        }]),
+                    )]),
+                    network_mode: None,
+                    reuse_latest: true,
+                    container_id: None,
+                    cmd: None,
+                    env: vec![
+                        ("POSTGRES_USER".to_string(), "postgres".to_string()),
+                        ("POSTGRES_PASSWORD".to_string(), "postgres".to_string()),
+                        ("POSTGRES_DB".to_string(), "dbt".to_string()),
+                    ],
+                    build_args: vec![],
+                    bind_user: false,
+                })
+                .await
+                .unwrap();
+            })
+            .await;
+
+        let config = dbt_serde_yaml::from_str(
+            r#"
+host: localhost
+port: "5499"
+user: postgres
+password: postgres
+database: dbt
+"#,
+        )
+        .unwrap();
+        let engine = SqlEngine::new(
+            AdapterType::Postgres,
+            auth_for_backend(Backend::Postgres).into(),
+            AdapterConfig::new(config),
+            DEFAULT_RESOLVED_QUOTING,
+            Arc::new(DialectStmtSplitter),
+            None,
+            QueryCommentConfig::from_query_comment(None, AdapterType::Postgres, false),
+            Box::new(NaiveTypeOpsImpl::new(AdapterType::Postgres)),
+            never_cancels(),
+        );
+        let adapter = PostgresAdapter::new(engine.clone());
+        let mut conn = adapter.new_connection(None, None).unwrap();
+        for sql in setup {
+            let query_ctx = QueryCtx::new("postgres").with_sql(*sql);
+            engine.execute(None, &mut *conn, &query_ctx).unwrap();
+        }
+        adapter
+    }
+
+    fn relation(schema: &str, identifier: &str) -> Arc<dyn BaseRelation> {
+        Arc::new(
+            PostgresRelation::try_new(
+                Some("dbt".to_string()),
+                Some(schema.to_string()),
+                Some(identifier.to_string()),
+                None,
+                DEFAULT_RESOLVED_QUOTING,
+            )
+            .unwrap(),
+        )
+    }
+
+    fn comment(field: &Field) -> Option<&str> {
+        field
+            .metadata()
+            .get(ARROW_FIELD_COMMENT_METADATA_KEY)
+            .map(String::as_str)
+    }
+
+    /// Runs against the Postgres container of dbt-test-containers, which
+    /// leaves `track_commit_timestamp` at its default (off)
+    #[tokio::test]
+    async fn test_freshness_without_commit_timestamps() {
+        if std::env::var("IS_DOCKER_ENABLED").is_err() {
+            return;
+        }
+        let adapter = postgres_adapter(&[
+            "drop schema if exists freshness_test cascade",
+            "create schema freshness_test",
+            "create table freshness_test.orders as select 1 as id",
+            "create view freshness_test.recent_orders as select * from freshness_test.orders",
+        ])
+        .await;
+
+        let relations =
+            ["orders", "recent_orders"].map(|identifier| relation("freshness_test", identifier));
+        // No error from pg_xact_commit_timestamp, and no freshness to report
+        let freshness = adapter.freshness(&relations).await.unwrap();
+        assert!(freshness.is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_list_user_defined_functions() {
+        if std::env::var("IS_DOCKER_ENABLED").is_err() {
+            return;
+        }
+        let adapter = postgres_adapter(&[
+            "drop schema if exists udf_test cascade",
+            "create schema udf_test",
+            "create function udf_test.add_one(x integer) returns integer language sql as 'select x + 1'",
+            "comment on function udf_test.add_one(integer) is 'Adds one'",
+            "create function udf_test.order_ids() returns setof integer language sql as 'select 1'",
+        ])
+        .await;
+
+        let catalog_schemas =
+            BTreeMap::from([("dbt".to_string(), BTreeSet::from(["udf_test".to_string()]))]);
+        let mut udfs = adapter
+            .list_user_defined_functions(&catalog_schemas)
+            .await
+            .unwrap();
+        udfs.sort_by(|a, b| a.name.cmp(&b.name));
+
+        assert_eq!(udfs.len(), 2);
+        assert_eq!(udfs[0].name, "dbt.udf_test.add_one");
+        assert_eq!(udfs[0].description, "Adds one");
+        assert_eq!(udfs[0].signature, "add_one(x integer) RETURNS integer");
+        assert!(matches!(udfs[0].kind, UDFKind::Scalar));
+        assert_eq!(udfs[1].name, "dbt.udf_test.order_ids");
+        assert_eq!(udfs[1].signature, "order_ids() RETURNS SETOF integer");
+        assert!(matches!(udfs[1].kind, UDFKind::Table));
+    }
+
+    #[tokio::test]
+    async fn test_list_relations_schemas() {
+        if std::env::var("IS_DOCKER_ENABLED").is_err() {
+            return;
+        }
+        let adapter = postgres_adapter(&[
+            "drop schema if exists schemas_test cascade",
+            "create schema schemas_test",
+            "create table schemas_test.orders (id integer not null, note varchar(20))",
+            "alter table schemas_test.orders drop column note",
+            "alter table schemas_test.orders add column status varchar(20)",
+            "comment on column schemas_test.orders.status is 'Order status'",
+        ])
+        .await;
+
+        let orders = relation("schemas_test", "orders");
+        let missing = relation("schemas_test", "missing");
+        let schemas = adapter
+            .list_relations_schemas(None, None, &[orders.clone(), missing.clone()])
+            .await
+            .unwrap();
+
+        let schema = schemas[&orders.semantic_fqn()].as_ref().unwrap();
+        // Dropped columns are left out
+        let names = schema
+            .fields()
+            .iter()
+            .map(|f| f.name().as_str())
+            .collect::<Vec<_>>();
+        assert_eq!(names, ["id", "status"]);
+        assert!(!schema.field(0).is_nullable());
+        assert!(schema.field(1).is_nullable());
+        assert_eq!(comment(schema.field(0)), None);
+        assert_eq!(comment(schema.field(1)), Some("Order status"));
+        assert!(schemas[&missing.semantic_fqn()].is_err());
+    }
+
+    #[tokio::test]
+    async fn test_list_relations_schemas_by_patterns() {
+        if std::env::var("IS_DOCKER_ENABLED").is_err() {
+            return;
+        }
+        let adapter = postgres_adapter(&[
+            "drop schema if exists patterns_test cascade",
+            "create schema patterns_test",
+            "create table patterns_test.stg_orders (id integer not null)",
+            "create view patterns_test.stg_recent_orders as select * from patterns_test.stg_orders",
+            "create table patterns_test.dim_customers (id integer)",
+        ])
+        .await;
+
+        let patterns = [RelationPattern::new(
+            "dbt".to_string(),
+            "patterns_test".to_string(),
+            "stg_%".to_string(),
+        )];
+        let mut schemas = adapter
+            .list_relations_schemas_by_patterns(&patterns)
+            .await
+            .unwrap();
+        schemas.sort_by(|a, b| a.0.cmp(&b.0));
+
+        let found = schemas
+            .iter()
+            .map(|(fqn, result)| {
+                let (relation, schema) = result.as_ref().unwrap();
+                (
+                    fqn.as_str(),
+                    relation.relation_type(),
+                    schema.fields().len(),
+                )
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            [
+                ("dbt.patterns_test.stg_orders", Some(RelationType::Table), 1),
+                (
+                    "dbt.patterns_test.stg_recent_orders",
+                    Some(RelationType::View),
+                    1
+                ),
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_list_relations_in_parallel() {
+        if std::env::var("IS_DOCKER_ENABLED").is_err() {
+            return;
+        }
+        let adapter = postgres_adapter(&[
+            "drop schema if exists parallel_test_a cascade",
+            "drop schema if exists parallel_test_b cascade",
+            "create schema parallel_test_a",
+            "create schema parallel_test_b",
+            "create table parallel_test_a.orders as select 1 as id",
+            "create view parallel_test_b.recent_orders as select * from parallel_test_a.orders",
+        ])
+        .await;
+
+        let db_schemas = ["parallel_test_a", "parallel_test_b"]
+            .map(|schema| CatalogAndSchema::from(&relation(schema, "orders")));
+        let relations = adapter
+            .list_relations_in_parallel(&db_schemas)
+            .await
+            .unwrap();
+
+        let found = db_schemas
+            .iter()
+            .map(|db_schema| {
+                relations[db_schema]
+                    .as_ref()
+                    .unwrap()
+                    .iter()
+                    .map(|relation| {
+                        (
+                            relation.identifier_as_resolved_str().unwrap(),
+                            relation.relation_type(),
+                        )
+                    })
+                    .collect::<Vec<_>>()
+            })
+            .collect::<Vec<_>>();
+        assert_eq!(
+            found,
+            [
+                vec![("orders".to_string(), Some(RelationType::Table))],
+                vec![("recent_orders".to_string(), Some(RelationType::View))],
+            ]
+        );
     }
 }
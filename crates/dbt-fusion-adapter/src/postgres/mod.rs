@@ -1,3 +1,4 @@
 pub mod adapter;
 pub mod metadata;
 pub mod relation;
+pub mod relation_configs;
@@ -1,20 +1,25 @@
+use crate::AdapterType;
 use crate::information_schema::InformationSchema;
+use crate::postgres::relation_configs::materialized_view_config::{
+    DescribeMaterializedViewResults, PostgresMaterializedViewConfig,
+    PostgresMaterializedViewConfigChangeset,
+};
 use crate::relation_object::{RelationObject, StaticBaseRelation};
 
 use dbt_common::{ErrorCode, FsResult, current_function_name, fs_err};
 use dbt_schemas::dbt_types::RelationType;
-use dbt_schemas::schemas::common::ResolvedQuoting;
+use dbt_schemas::schemas::InternalDbtNodeWrapper;
+use dbt_schemas::schemas::common::{DbtMaterialization, ResolvedQuoting};
 use dbt_schemas::schemas::relations::base::{
     BaseRelation, BaseRelationProperties, Policy, RelationPath,
 };
-use minijinja::arg_utils::{ArgParser, check_num_args};
-use minijinja::{Error as MinijinjaError, State, Value};
+use minijinja::arg_utils::ArgsIter;
+use minijinja::{Error as MinijinjaError, ErrorKind as MinijinjaErrorKind, State, Value};
+use serde::Deserialize;
 
 use std::any::Any;
 use std::sync::Arc;
 
-const MAX_CHARACTERS_IN_IDENTIFIER: usize = 63;
-
 /// A struct representing the Postgres relation type for use with static methods
 #[derive(Clone, Debug)]
 pub struct PostgresRelationType(pub ResolvedQuoting);
@@ -86,15 +91,16 @@ impl PostgresRelation {
     ) -> Result<Self, MinijinjaError> {
         // Check identifier length limit
         // TODO (Ani): dbt ignores this check if the relation.type is none (indicating a test relation)
-        if path.identifier.is_some()
-            && path.identifier.as_deref().map(|i| i.len()).unwrap() > MAX_CHARACTERS_IN_IDENTIFIER
+        let limits = AdapterType::Postgres.identifier_limits();
+        if let Some(identifier) = path.identifier.as_deref()
+            && limits.length_of(identifier) > limits.relation
             && relation_type.is_some()
         {
             return Err(MinijinjaError::new(
                 minijinja::ErrorKind::InvalidOperation,
                 format!(
                     "Relation name '{:?}' is longer than {} characters",
-                    path.identifier, MAX_CHARACTERS_IN_IDENTIFIER
+                    path.identifier, limits.relation
                 ),
             ));
         }
@@ -191,12 +197,6 @@ impl BaseRelation for PostgresRelation {
         Ok(relation.as_value())
     }
 
-    fn relation_max_name_length(&self, args: &[Value]) -> Result<Value, MinijinjaError> {
-        let args = ArgParser::new(args, None);
-        check_num_args(current_function_name!(), &args, 0, 0)?;
-        Ok(Value::from(MAX_CHARACTERS_IN_IDENTIFIER))
-    }
-
     fn normalize_component(&self, component: &str) -> String {
         component.to_lowercase()
     }
@@ -226,6 +226,88 @@ impl BaseRelation for PostgresRelation {
         let result = InformationSchema::try_from_relation(database, view_name)?;
         Ok(RelationObject::new(Arc::new(result)).into_value())
     }
+
+    // https://github.com/dbt-labs/dbt-adapters/blob/main/dbt-postgres/src/dbt/adapters/postgres/relation.py#L62
+    fn get_materialized_view_config_change_collection(
+        &self,
+        args: &[Value],
+    ) -> Result<Value, MinijinjaError> {
+        let iter = ArgsIter::new(
+            current_function_name!(),
+            &["relation_results", "relation_config"],
+            args,
+        );
+
+        let relation_results_value = iter.next_arg::<&Value>()?;
+        let new_config_value = iter.next_arg::<&Value>()?;
+        iter.finish()?;
+
+        let relation_results = DescribeMaterializedViewResults::try_from(relation_results_value)
+            .map_err(|e| {
+                MinijinjaError::new(
+                    MinijinjaErrorKind::SerdeDeserializeError,
+                    format!("Failed to deserialize DescribeMaterializedViewResults: {e}"),
+                )
+            })?;
+
+        let existing_config =
+            PostgresMaterializedViewConfig::from_describe_results(self, relation_results).map_err(
+                |e| {
+                    MinijinjaError::new(
+                        MinijinjaErrorKind::SerdeDeserializeError,
+                        format!("Failed to deserialize PostgresMaterializedViewConfig: {e}"),
+                    )
+                },
+            )?;
+
+        let new_config = node_value_to_postgres_materialized_view(new_config_value)?;
+
+        let changeset = PostgresMaterializedViewConfigChangeset::new(existing_config, new_config);
+
+        if changeset.has_changes() {
+            Ok(Value::from_object(changeset))
+        } else {
+            Ok(Value::from(None::<()>))
+        }
+    }
+}
+
+fn node_value_to_postgres_materialized_view(
+    node_value: &Value,
+) -> Result<PostgresMaterializedViewConfig, MinijinjaError> {
+    let config_wrapper = InternalDbtNodeWrapper::deserialize(node_value).map_err(|e| {
+        MinijinjaError::new(
+            MinijinjaErrorKind::SerdeDeserializeError,
+            format!("Failed to deserialize InternalDbtNodeWrapper: {e}"),
+        )
+    })?;
+
+    let model = match config_wrapper {
+        InternalDbtNodeWrapper::Model(model) => model,
+        _ => {
+            return Err(MinijinjaError::new(
+                MinijinjaErrorKind::InvalidOperation,
+                "Expected a model node",
+            ));
+        }
+    };
+
+    if model.__base_attr__.materialized != DbtMaterialization::MaterializedView {
+        return Err(MinijinjaError::new(
+            MinijinjaErrorKind::InvalidOperation,
+            format!(
+                "Unsupported operation for materialization type {}",
+                &model.__base_attr__.materialized
+            ),
+        ));
+    }
+
+    PostgresMaterializedViewConfig::try_from(&*model).map_err(|e| {
+        MinijinjaError::new(
+            MinijinjaErrorKind::SerdeDeserializeError,
+            format!("Failed to deserialize PostgresMaterializedViewConfig: {e}"),
+        )
+    })
 }
 
 #[cfg(test)]
@@ -252,4 +334,24 @@ mod tests {
         );
         assert_eq!(relation.relation_type().unwrap(), RelationType::Table);
     }
+
+    #[test]
+    fn test_identifier_length_limit() {
+        let new_relation = |identifier: String| {
+            PostgresRelation::try_new(
+                Some("d".to_string()),
+                Some("s".to_string()),
+                Some(identifier),
+                Some(RelationType::Table),
+                DEFAULT_RESOLVED_QUOTING,
+            )
+        };
+        let relation = new_relation("i".repeat(63)).unwrap();
+        assert_eq!(
+            relation.relation_max_name_length(&[]).unwrap(),
+            Value::from(63)
+        );
+        // The limit is in bytes
+        assert!(new_relation("é".repeat(32)).is_err());
+    }
 }
@@ -0,0 +1,236 @@
+use dbt_schemas::schemas::manifest::postgres::PostgresIndex;
+use minijinja::Value;
+use minijinja::value::Object;
+use serde::{Deserialize, Serialize};
+use std::cmp::Ordering;
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use std::fmt::{Display, Formatter};
+use std::result::Result;
+use std::sync::Arc;
+
+/// Index access methods supported by Postgres
+// https://github.com/dbt-labs/dbt-adapters/blob/main/dbt-postgres/src/dbt/adapters/postgres/relation_configs/index.py#L17
+#[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, PartialOrd, Ord, Default)]
+#[serde(rename_all = "lowercase")]
+pub enum PostgresIndexMethod {
+    #[default]
+    Btree,
+    Hash,
+    Gist,
+    Spgist,
+    Gin,
+    Brin,
+}
+
+impl Display for PostgresIndexMethod {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Btree => write!(f, "btree"),
+            Self::Hash => write!(f, "hash"),
+            Self::Gist => write!(f, "gist"),
+            Self::Spgist => write!(f, "spgist"),
+            Self::Gin => write!(f, "gin"),
+            Self::Brin => write!(f, "brin"),
+        }
+    }
+}
+
+impl TryFrom<&str> for PostgresIndexMethod {
+    type Error = String;
+
+    fn try_from(s: &str) -> Result<Self, Self::Error> {
+        match s.to_ascii_lowercase().as_str() {
+            "btree" => Ok(Self::Btree),
+            "hash" => Ok(Self::Hash),
+            "gist" => Ok(Self::Gist),
+            "spgist" => Ok(Self::Spgist),
+            "gin" => Ok(Self::Gin),
+            "brin" => Ok(Self::Brin),
+            _ => Err(format!("unsupported index method: {s}")),
+        }
+    }
+}
+
+/// An index on a Postgres table or materialized view
+///
+/// Two indexes are considered the same if they are built on the same columns with
+/// the same method and uniqueness; the name is generated by dbt and ignored.
+// https://github.com/dbt-labs/dbt-adapters/blob/main/dbt-postgres/src/dbt/adapters/postgres/relation_configs/index.py#L30
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostgresIndexConfig {
+    /// Name of the index, only known for indexes that already exist
+    pub name: Option<String>,
+    /// Columns the index is built on
+    pub column_names: BTreeSet<String>,
+    /// Whether the index enforces uniqueness
+    pub unique: bool,
+    /// The index access method
+    pub method: PostgresIndexMethod,
+}
+
+impl PostgresIndexConfig {
+    fn key(&self) -> (&BTreeSet<String>, bool, PostgresIndexMethod) {
+        (&self.column_names, self.unique, self.method)
+    }
+
+    /// The index as a node config dict, the shape `postgres__get_create_index_sql` expects
+    pub fn as_node_config(&self) -> Value {
+        Value::from_serialize(PostgresIndex {
+            columns: self.column_names.iter().cloned().collect(),
+            unique: Some(self.unique),
+            index_type: Some(self.method.to_string()),
+        })
+    }
+}
+
+impl PartialEq for PostgresIndexConfig {
+    fn eq(&self, other: &Self) -> bool {
+        self.key() == other.key()
+    }
+}
+
+impl Eq for PostgresIndexConfig {}
+
+impl PartialOrd for PostgresIndexConfig {
+    fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+        Some(self.cmp(other))
+    }
+}
+
+impl Ord for PostgresIndexConfig {
+    fn cmp(&self, other: &Self) -> Ordering {
+        self.key().cmp(&other.key())
+    }
+}
+
+impl TryFrom<&PostgresIndex> for PostgresIndexConfig {
+    type Error = String;
+
+    fn try_from(index: &PostgresIndex) -> Result<Self, Self::Error> {
+        if index.columns.is_empty() {
+            return Err("Indexes require at least one column".to_string());
+        }
+        let method = match index.index_type.as_deref() {
+            Some(method) => PostgresIndexMethod::try_from(method)?,
+            None => PostgresIndexMethod::default(),
+        };
+        Ok(Self {
+            name: None,
+            column_names: index.columns.iter().cloned().collect(),
+            unique: index.unique.unwrap_or(false),
+            method,
+        })
+    }
+}
+
+impl TryFrom<&Value> for PostgresIndexConfig {
+    type Error = String;
+
+    /// Parses a row of `postgres__get_show_indexes_sql`
+    // https://github.com/dbt-labs/dbt-adapters/blob/main/dbt-postgres/src/dbt/adapters/postgres/relation_configs/index.py#L61
+    fn try_from(row: &Value) -> Result<Self, Self::Error> {
+        let get_str = |col_name: &str| {
+            row.get_attr(col_name)
+                .ok()
+                .and_then(|v| v.as_str().map(|s| s.to_string()))
+                .ok_or_else(|| format!("Failed to get `{col_name}` from index row"))
+        };
+
+        let name = get_str("name")?;
+        let method = PostgresIndexMethod::try_from(get_str("method")?.as_str())?;
+        let unique = row
+            .get_attr("unique")
+            .map(|v| match v.as_str() {
+                Some(s) => s == "t" || s.eq_ignore_ascii_case("true"),
+                None => v.is_true(),
+            })
+            .unwrap_or(false);
+        let column_names = get_str("column_names")?
+            .split(',')
+            .map(|c| c.trim().to_string())
+            .filter(|c| !c.is_empty())
+            .collect();
+
+        Ok(Self {
+            name: Some(name),
+            column_names,
+            unique,
+            method,
+        })
+    }
+}
+
+impl Object for PostgresIndexConfig {
+    fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+        match key.as_str() {
+            Some("name") => self.name.clone().map(Value::from),
+            Some("column_names") => Some(Value::from(
+                self.column_names.iter().cloned().collect::<Vec<_>>(),
+            )),
+            Some("unique") => Some(Value::from(self.unique)),
+            Some("method") => Some(Value::from(self.method.to_string())),
+            Some("as_node_config") => Some(self.as_node_config()),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum PostgresIndexAction {
+    Create,
+    Drop,
+}
+
+impl Display for PostgresIndexAction {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        match self {
+            Self::Create => write!(f, "create"),
+            Self::Drop => write!(f, "drop"),
+        }
+    }
+}
+
+/// A single index to create or drop on an existing relation
+// https://github.com/dbt-labs/dbt-adapters/blob/main/dbt-postgres/src/dbt/adapters/postgres/relation_configs/index.py#L105
+#[derive(Debug, Clone)]
+pub struct PostgresIndexConfigChange {
+    pub action: PostgresIndexAction,
+    pub context: PostgresIndexConfig,
+}
+
+impl PostgresIndexConfigChange {
+    /// Indexes can always be created and dropped in place
+    pub fn requires_full_refresh(&self) -> bool {
+        false
+    }
+
+    /// Computes the index changes that turn `existing` into `new`
+    ///
+    /// Drops come first so that a replaced index never coexists with its successor.
+    pub fn diff(
+        existing: &BTreeSet<PostgresIndexConfig>,
+        new: &BTreeSet<PostgresIndexConfig>,
+    ) -> Vec<Self> {
+        let drops = existing.difference(new).map(|index| Self {
+            action: PostgresIndexAction::Drop,
+            context: index.clone(),
+        });
+        let creates = new.difference(existing).map(|index| Self {
+            action: PostgresIndexAction::Create,
+            context: index.clone(),
+        });
+        drops.chain(creates).collect()
+    }
+}
+
+impl Object for PostgresIndexConfigChange {
+    fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+        match key.as_str() {
+            Some("action") => Some(Value::from(self.action.to_string())),
+            Some("context") => Some(Value::from_object(self.context.clone())),
+            Some("requires_full_refresh") => Some(Value::from(self.requires_full_refresh())),
+            _ => None,
+        }
+    }
+}
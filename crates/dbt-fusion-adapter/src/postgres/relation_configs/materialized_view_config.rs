@@ -0,0 +1,270 @@
+use crate::postgres::relation_configs::index_config::{
+    PostgresIndexConfig, PostgresIndexConfigChange,
+};
+
+use dbt_agate::AgateTable;
+use dbt_schemas::schemas::DbtModel;
+use dbt_schemas::schemas::relations::base::BaseRelation;
+use minijinja::Value;
+use minijinja::value::Object;
+use serde::{Deserialize, Serialize};
+use std::collections::BTreeSet;
+use std::convert::TryFrom;
+use std::result::Result;
+use std::sync::Arc;
+
+/// Deserialization target for macro postgres__describe_materialized_view
+// https://github.com/dbt-labs/dbt-adapters/blob/main/dbt-postgres/src/dbt/include/postgres/macros/relations/materialized_view/describe.sql
+#[derive(Debug, Clone)]
+pub struct DescribeMaterializedViewResults {
+    pub indexes: Arc<AgateTable>,
+}
+
+impl TryFrom<&Value> for DescribeMaterializedViewResults {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let indexes = value
+            .get_item(&Value::from_safe_string("indexes".into()))
+            .map_err(|e| format!("Expected key `indexes`: {e}"))?
+            .downcast_object::<AgateTable>()
+            .ok_or("Failed to convert indexes to AgateTable")?;
+
+        Ok(Self { indexes })
+    }
+}
+
+/// Reference:
+/// https://github.com/dbt-labs/dbt-adapters/blob/main/dbt-postgres/src/dbt/adapters/postgres/relation_configs/materialized_view.py#L22
+// XXX: Deviation from core: raw SQL is not used here - that is already available within the macro as {{ sql }}
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PostgresMaterializedViewConfig {
+    /// Name of the materialized view.
+    pub mv_name: String,
+    /// Name of the schema containing the materialized view.
+    pub schema_name: String,
+    /// Name of the database containing the materialized view.
+    pub database_name: String,
+    /// Indexes on the materialized view.
+    #[serde(default)]
+    pub indexes: BTreeSet<PostgresIndexConfig>,
+}
+
+impl TryFrom<&DbtModel> for PostgresMaterializedViewConfig {
+    type Error = String;
+
+    fn try_from(model: &DbtModel) -> Result<Self, Self::Error> {
+        let indexes = model
+            .deprecated_config
+            .__warehouse_specific_config__
+            .indexes
+            .iter()
+            .flatten()
+            .map(PostgresIndexConfig::try_from)
+            .collect::<Result<BTreeSet<_>, _>>()?;
+
+        Ok(Self {
+            mv_name: model.__common_attr__.name.clone(),
+            schema_name: model.__base_attr__.schema.clone(),
+            database_name: model.__base_attr__.database.clone(),
+            indexes,
+        })
+    }
+}
+
+impl PostgresMaterializedViewConfig {
+    /// Builds the config of an existing materialized view from the results of
+    /// postgres__describe_materialized_view
+    // https://github.com/dbt-labs/dbt-adapters/blob/main/dbt-postgres/src/dbt/adapters/postgres/relation_configs/materialized_view.py#L79
+    pub fn from_describe_results(
+        relation: &dyn BaseRelation,
+        results: DescribeMaterializedViewResults,
+    ) -> Result<Self, String> {
+        let indexes = results
+            .indexes
+            .rows()
+            .into_iter()
+            .map(|row| PostgresIndexConfig::try_from(&row))
+            .collect::<Result<BTreeSet<_>, _>>()?;
+
+        Ok(Self {
+            mv_name: relation.identifier_as_str().map_err(|e| e.to_string())?,
+            schema_name: relation.schema_as_str().map_err(|e| e.to_string())?,
+            database_name: relation.database_as_str().map_err(|e| e.to_string())?,
+            indexes,
+        })
+    }
+
+    /// Get the full path (database.schema.mv_name)
+    pub fn path(&self) -> String {
+        format!(
+            "{}.{}.{}",
+            self.database_name, self.schema_name, self.mv_name
+        )
+    }
+}
+
+impl Object for PostgresMaterializedViewConfig {
+    fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+        match key.as_str() {
+            Some("path") => Some(Value::from(self.path())),
+            Some("mv_name") => Some(Value::from(self.mv_name.clone())),
+            Some("schema_name") => Some(Value::from(self.schema_name.clone())),
+            Some("database_name") => Some(Value::from(self.database_name.clone())),
+            Some("indexes") => Some(Value::from(
+                self.indexes
+                    .iter()
+                    .map(|index| Value::from_object(index.clone()))
+                    .collect::<Vec<_>>(),
+            )),
+            _ => None,
+        }
+    }
+}
+
+#[derive(Debug)]
+// https://github.com/dbt-labs/dbt-adapters/blob/main/dbt-postgres/src/dbt/adapters/postgres/relation_configs/materialized_view.py#L95
+pub struct PostgresMaterializedViewConfigChangeset {
+    indexes: Vec<PostgresIndexConfigChange>,
+}
+
+impl PostgresMaterializedViewConfigChangeset {
+    pub fn new(old: PostgresMaterializedViewConfig, new: PostgresMaterializedViewConfig) -> Self {
+        Self {
+            indexes: PostgresIndexConfigChange::diff(&old.indexes, &new.indexes),
+        }
+    }
+
+    /// Whether applying the changes requires rebuilding the materialized view
+    pub fn requires_full_refresh(&self) -> bool {
+        self.indexes
+            .iter()
+            .any(|change| change.requires_full_refresh())
+    }
+
+    /// Whether the existing materialized view differs from the model
+    pub fn has_changes(&self) -> bool {
+        !self.indexes.is_empty()
+    }
+}
+
+impl Object for PostgresMaterializedViewConfigChangeset {
+    fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+        match key.as_str() {
+            Some("indexes") => Some(Value::from(
+                self.indexes
+                    .iter()
+                    .map(|change| Value::from_object(change.clone()))
+                    .collect::<Vec<_>>(),
+            )),
+            Some("requires_full_refresh") => Some(Value::from(self.requires_full_refresh())),
+            Some("has_changes") => Some(Value::from(self.has_changes())),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::postgres::relation_configs::index_config::{
+        PostgresIndexAction, PostgresIndexMethod,
+    };
+    use arrow::array::{BooleanArray, RecordBatch, StringArray};
+    use arrow_schema::{DataType, Field, Schema};
+    use dbt_schemas::schemas::manifest::postgres::PostgresIndex;
+
+    fn describe_results(rows: &[(&str, &str, bool, &str)]) -> DescribeMaterializedViewResults {
+        let schema = Arc::new(Schema::new(vec![
+            Field::new("name", DataType::Utf8, false),
+            Field::new("method", DataType::Utf8, false),
+            Field::new("unique", DataType::Boolean, false),
+            Field::new("column_names", DataType::Utf8, false),
+        ]));
+        let batch = RecordBatch::try_new(
+            schema,
+            vec![
+                Arc::new(StringArray::from_iter_values(rows.iter().map(|r| r.0))),
+                Arc::new(StringArray::from_iter_values(rows.iter().map(|r| r.1))),
+                Arc::new(BooleanArray::from_iter(rows.iter().map(|r| Some(r.2)))),
+                Arc::new(StringArray::from_iter_values(rows.iter().map(|r| r.3))),
+            ],
+        )
+        .unwrap();
+        DescribeMaterializedViewResults {
+            indexes: Arc::new(AgateTable::from_record_batch(Arc::new(batch))),
+        }
+    }
+
+    fn config(indexes: BTreeSet<PostgresIndexConfig>) -> PostgresMaterializedViewConfig {
+        PostgresMaterializedViewConfig {
+            mv_name: "mv".to_string(),
+            schema_name: "s".to_string(),
+            database_name: "d".to_string(),
+            indexes,
+        }
+    }
+
+    fn index(columns: &[&str], unique: bool, index_type: Option<&str>) -> PostgresIndexConfig {
+        PostgresIndexConfig::try_from(&PostgresIndex {
+            columns: columns.iter().map(|c| c.to_string()).collect(),
+            unique: Some(unique),
+            index_type: index_type.map(|t| t.to_string()),
+        })
+        .unwrap()
+    }
+
+    #[test]
+    fn test_index_changes_from_describe_results() {
+        let results = describe_results(&[
+            ("idx_a", "btree", false, "id"),
+            ("idx_b", "hash", false, "email"),
+        ]);
+        let existing = config(
+            results
+                .indexes
+                .rows()
+                .into_iter()
+                .map(|row| PostgresIndexConfig::try_from(&row).unwrap())
+                .collect(),
+        );
+        assert_eq!(existing.indexes.len(), 2);
+
+        // `id` is unchanged (names are ignored), `email` moves from hash to btree
+        let new = config(BTreeSet::from([
+            index(&["id"], false, None),
+            index(&["email"], false, Some("btree")),
+        ]));
+        let changeset = PostgresMaterializedViewConfigChangeset::new(existing, new);
+
+        assert!(changeset.has_changes());
+        assert!(!changeset.requires_full_refresh());
+        assert_eq!(changeset.indexes.len(), 2);
+        assert_eq!(changeset.indexes[0].action, PostgresIndexAction::Drop);
+        assert_eq!(changeset.indexes[0].context.name.as_deref(), Some("idx_b"));
+        assert_eq!(changeset.indexes[1].action, PostgresIndexAction::Create);
+        assert_eq!(
+            changeset.indexes[1].context.method,
+            PostgresIndexMethod::Btree
+        );
+    }
+
+    #[test]
+    fn test_no_changes_when_indexes_match() {
+        let existing = config(BTreeSet::from([index(&["a", "b"], true, Some("btree"))]));
+        let new = config(BTreeSet::from([index(&["b", "a"], true, None)]));
+        let changeset = PostgresMaterializedViewConfigChangeset::new(existing, new);
+        assert!(!changeset.has_changes());
+    }
+
+    #[test]
+    fn test_index_requires_columns() {
+        let err = PostgresIndexConfig::try_from(&PostgresIndex {
+            columns: vec![],
+            unique: None,
+            index_type: None,
+        })
+        .unwrap_err();
+        assert!(err.contains("at least one column"));
+    }
+}
@@ -0,0 +1,2 @@
+pub mod index_config;
+pub mod materialized_view_config;
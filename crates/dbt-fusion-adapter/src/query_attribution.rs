@@ -0,0 +1,322 @@
+//! Attribution of warehouse queries to the dbt node that issued them.
+//!
+//! When a model enables `query_attribution`, every statement executed on its
+//! behalf is labelled with the model's unique_id, the invocation_id, the
+//! model's group and owner (`meta.owner`) and any custom `tags`, so warehouse
+//! cost can be broken down by dbt model. Each warehouse gets the labels
+//! through its native mechanism:
+//!
+//! | Adapter            | Carrier                                                 |
+//! |--------------------|---------------------------------------------------------|
+//! | Snowflake          | statement `QUERY_TAG` (unless the model sets `query_tag`) |
+//! | BigQuery           | job labels                                              |
+//! | Postgres, Redshift | `application_name` and a comment on the statement       |
+//! | Others             | a comment on the statement                              |
+//!
+//! Databricks has no statement tags in its ADBC driver, so it falls back to
+//! the comment as well.
+
+use std::collections::BTreeMap;
+
+use dbt_common::adapter::AdapterType;
+use dbt_schemas::schemas::common::QueryAttributionConfig;
+use dbt_xdbc::connection::Session;
+use minijinja::{State, Value};
+use serde::Deserialize;
+
+use crate::query_comment::sanitize_label;
+
+const QUERY_ATTRIBUTION: &str = "query_attribution";
+
+/// Postgres truncates `application_name` to `NAMEDATALEN - 1` bytes
+const APPLICATION_NAME_MAX_LEN: usize = 63;
+
+pub const UNIQUE_ID_LABEL: &str = "dbt_unique_id";
+pub const INVOCATION_ID_LABEL: &str = "dbt_invocation_id";
+pub const GROUP_LABEL: &str = "dbt_group";
+pub const OWNER_LABEL: &str = "dbt_owner";
+
+/// The labels attributing a statement to the node that issued it
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct QueryAttribution {
+    unique_id: Option<String>,
+    labels: BTreeMap<String, String>,
+    /// The node sets its own Snowflake `query_tag`, which takes precedence
+    has_query_tag: bool,
+}
+
+impl QueryAttribution {
+    /// Resolve the attribution of the node being executed, if it enables it.
+    pub fn from_state(state: &State) -> Option<Self> {
+        let model = state.lookup("model")?;
+        let invocation_id = state
+            .lookup("invocation_id")
+            .and_then(|value| value.as_str().map(str::to_owned));
+        Self::from_model(&model, invocation_id)
+    }
+
+    fn from_model(model: &Value, invocation_id: Option<String>) -> Option<Self> {
+        let config = model.get_attr("config").ok().filter(is_set)?;
+        let attribution = config.get_attr(QUERY_ATTRIBUTION).ok().filter(is_set)?;
+        let attribution = dbt_serde_yaml::to_value(&attribution)
+            .ok()
+            .and_then(|value| QueryAttributionConfig::deserialize(value).ok())?;
+        if !attribution.is_enabled() {
+            return None;
+        }
+
+        // Custom tags cannot shadow the labels dbt derives from the node
+        let mut labels = attribution.tags.unwrap_or_default();
+        let unique_id = attr_string(model, "unique_id");
+        let owner = config
+            .get_attr("meta")
+            .ok()
+            .filter(is_set)
+            .and_then(|meta| attr_string(&meta, "owner"));
+        for (label, value) in [
+            (UNIQUE_ID_LABEL, unique_id.clone()),
+            (INVOCATION_ID_LABEL, invocation_id),
+            (GROUP_LABEL, attr_string(&config, "group")),
+            (OWNER_LABEL, owner),
+        ] {
+            if let Some(value) = value {
+                labels.insert(label.to_owned(), value);
+            }
+        }
+
+        Some(Self {
+            unique_id,
+            labels,
+            has_query_tag: attr_string(&config, "query_tag").is_some(),
+        })
+    }
+
+    pub fn labels(&self) -> &BTreeMap<String, String> {
+        &self.labels
+    }
+
+    /// The labels as a JSON object
+    pub fn to_json(&self) -> String {
+        serde_json::to_string(&self.labels).expect("Should be able to serialize labels")
+    }
+
+    /// Snowflake `QUERY_TAG` of the statement, unless the node configures its own
+    pub fn query_tag(&self) -> Option<String> {
+        (!self.has_query_tag).then(|| self.to_json())
+    }
+
+    /// The labels made legal as BigQuery job labels
+    pub fn job_labels(&self) -> BTreeMap<String, String> {
+        self.labels
+            .iter()
+            .map(|(key, value)| (sanitize_label(key), sanitize_label(value)))
+            .collect()
+    }
+
+    /// `application_name` identifying the node in `pg_stat_activity` and
+    /// Redshift's system tables
+    pub fn application_name(&self) -> Option<String> {
+        let mut name = format!("dbt:{}", self.unique_id.as_deref()?);
+        if name.len() > APPLICATION_NAME_MAX_LEN {
+            let mut end = APPLICATION_NAME_MAX_LEN;
+            while !name.is_char_boundary(end) {
+                end -= 1;
+            }
+            name.truncate(end);
+        }
+        Some(name)
+    }
+
+    /// Statement that sets the `application_name` of the session
+    pub fn set_application_name_sql(&self) -> Option<String> {
+        let name = self.application_name()?;
+        Some(format!(
+            "set application_name = '{}'",
+            name.replace('\'', "''")
+        ))
+    }
+
+    /// Statement that brings the `application_name` of `session` in line
+    /// with `attribution`
+    ///
+    /// Returns `None` when the session already carries the right name, so the
+    /// name is set once per node rather than before every statement, and reset
+    /// before the first statement no node is attributed for. The caller
+    /// records the executed change with [QueryAttribution::record_application_name].
+    pub fn application_name_change_sql(
+        session: &Session,
+        attribution: Option<&Self>,
+    ) -> Option<String> {
+        let name = attribution.and_then(Self::application_name);
+        match (name, session.application_name.as_ref()) {
+            (Some(name), Some(current)) if name == *current => None,
+            (Some(_), _) => attribution.and_then(Self::set_application_name_sql),
+            (None, Some(_)) => Some("reset application_name".to_owned()),
+            (None, None) => None,
+        }
+    }
+
+    /// Record the `application_name` of `session` after a statement from
+    /// [QueryAttribution::application_name_change_sql] ran
+    pub fn record_application_name(session: &mut Session, attribution: Option<&Self>) {
+        session.application_name = attribution.and_then(Self::application_name);
+    }
+
+    /// Comment carrying the labels, to be wrapped in `/* */`
+    pub fn comment(&self) -> String {
+        // Tags are user input and must neither terminate the comment early
+        // nor open a nested one, which Postgres would not close
+        format!(
+            "dbt_attribution: {}",
+            self.to_json().replace("*/", "* /").replace("/*", "/ *")
+        )
+    }
+
+    /// Whether the labels are carried in a comment on the statement
+    pub fn uses_comment(adapter_type: AdapterType) -> bool {
+        !matches!(adapter_type, AdapterType::Snowflake | AdapterType::Bigquery)
+    }
+
+    /// Whether the session's `application_name` carries the node
+    pub fn uses_application_name(adapter_type: AdapterType) -> bool {
+        matches!(adapter_type, AdapterType::Postgres | AdapterType::Redshift)
+    }
+}
+
+fn is_set(value: &Value) -> bool {
+    !value.is_undefined() && !value.is_none()
+}
+
+fn attr_string(value: &Value, name: &str) -> Option<String> {
+    let attr = value.get_attr(name).ok().filter(is_set)?;
+    match attr.as_str() {
+        Some(s) => Some(s.to_owned()),
+        None => Some(attr.to_string()),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde_json::json;
+
+    fn model(attribution: serde_json::Value) -> Value {
+        Value::from_serialize(json!({
+            "unique_id": "model.jaffle_shop.customers",
+            "config": {
+                "group": "finance",
+                "meta": {"owner": "data-eng@example.com"},
+                "query_attribution": attribution,
+            },
+        }))
+    }
+
+    #[test]
+    fn test_labels() {
+        let attribution = QueryAttribution::from_model(
+            &model(json!({"tags": {"cost_center": "42", "dbt_group": "spoofed"}})),
+            Some("b1946ac9".to_owned()),
+        )
+        .unwrap();
+        assert_eq!(
+            attribution.labels(),
+            &BTreeMap::from([
+                ("cost_center".to_owned(), "42".to_owned()),
+                ("dbt_group".to_owned(), "finance".to_owned()),
+                ("dbt_invocation_id".to_owned(), "b1946ac9".to_owned()),
+                ("dbt_owner".to_owned(), "data-eng@example.com".to_owned()),
+                (
+                    "dbt_unique_id".to_owned(),
+                    "model.jaffle_shop.customers".to_owned()
+                ),
+            ])
+        );
+        assert_eq!(
+            attribution.job_labels()["dbt_owner"],
+            "data-eng_example_com".to_owned()
+        );
+        assert_eq!(
+            attribution.job_labels()["dbt_unique_id"],
+            "model_jaffle_shop_customers".to_owned()
+        );
+    }
+
+    #[test]
+    fn test_disabled() {
+        assert!(QueryAttribution::from_model(&model(json!({"enabled": false})), None).is_none());
+        assert!(QueryAttribution::from_model(&model(json!(null)), None).is_none());
+        assert!(QueryAttribution::from_model(&model(json!({})), None).is_some());
+    }
+
+    #[test]
+    fn test_carriers() {
+        let mut attribution = QueryAttribution::from_model(
+            &model(json!({"tags": {"note": "*/ drop table x; /*"}})),
+            None,
+        )
+        .unwrap();
+        assert!(!attribution.comment().contains("*/"));
+        assert!(!attribution.comment().contains("/*"));
+        let nested = QueryAttribution::from_model(&model(json!({"tags": {"note": "/*/"}})), None);
+        let comment = nested.unwrap().comment();
+        assert!(!comment.contains("/*") && !comment.contains("*/"));
+        assert!(attribution.query_tag().is_some());
+        attribution.has_query_tag = true;
+        assert!(attribution.query_tag().is_none());
+
+        attribution.unique_id = Some(format!("model.pkg.o'{}", "é".repeat(40)));
+        let name = attribution.application_name().unwrap();
+        assert!(name.len() <= APPLICATION_NAME_MAX_LEN);
+        assert!(name.starts_with("dbt:model.pkg.o'"));
+        assert!(
+            attribution
+                .set_application_name_sql()
+                .unwrap()
+                .starts_with("set application_name = 'dbt:model.pkg.o''")
+        );
+    }
+
+    #[test]
+    fn test_application_name_changes() {
+        let customers = QueryAttribution::from_model(&model(json!({})), None).unwrap();
+        let mut orders = customers.clone();
+        orders.unique_id = Some("model.jaffle_shop.orders".to_owned());
+        let mut session = Session::default();
+
+        // Nothing to reset on a fresh session
+        assert_eq!(
+            QueryAttribution::application_name_change_sql(&session, None),
+            None
+        );
+
+        let set = QueryAttribution::application_name_change_sql(&session, Some(&customers));
+        assert_eq!(set, customers.set_application_name_sql());
+        QueryAttribution::record_application_name(&mut session, Some(&customers));
+        // Set once for all statements of the node
+        assert_eq!(
+            QueryAttribution::application_name_change_sql(&session, Some(&customers)),
+            None
+        );
+        // Other sessions are tracked separately
+        assert!(
+            QueryAttribution::application_name_change_sql(&Session::default(), Some(&customers))
+                .is_some()
+        );
+
+        assert_eq!(
+            QueryAttribution::application_name_change_sql(&session, Some(&orders)),
+            orders.set_application_name_sql()
+        );
+        QueryAttribution::record_application_name(&mut session, Some(&orders));
+
+        assert_eq!(
+            QueryAttribution::application_name_change_sql(&session, None).as_deref(),
+            Some("reset application_name")
+        );
+        QueryAttribution::record_application_name(&mut session, None);
+        assert_eq!(
+            QueryAttribution::application_name_change_sql(&session, None),
+            None
+        );
+    }
+}
@@ -1,22 +1,42 @@
 use once_cell::sync::Lazy;
 use regex::Regex;
 use scc::HashMap as SccHashMap;
-use std::hash::{DefaultHasher, Hash, Hasher};
+use serde::{Deserialize, Serialize};
+use std::collections::{BTreeMap, BTreeSet};
+use std::io;
 use std::path::{Path, PathBuf};
-use std::sync::Arc;
+use std::sync::{Arc, Mutex};
 use std::time::Duration;
 
 use adbc_core::error::{Error as AdbcError, Result as AdbcResult, Status as AdbcStatus};
 use adbc_core::options::{OptionStatement, OptionValue};
 use arrow::array::{RecordBatch, RecordBatchIterator, RecordBatchReader};
 use arrow_schema::{ArrowError, Field, Schema};
+use dbt_common::adapter::AdapterType;
+use dbt_common::tracing::span_info::record_current_span_status_from_attrs;
+use dbt_schemas::schemas::telemetry::QueryExecuted;
 use dbt_xdbc::query_ctx::ExecutionPhase;
 use dbt_xdbc::{QueryCtx, Statement};
 
+use crate::config::AdapterConfig;
+
 use parquet::arrow::ArrowWriter;
 use parquet::arrow::arrow_reader::ParquetRecordBatchReaderBuilder;
 use parquet::file::properties::WriterProperties;
 
+/// Default directory of the query cache, relative to the target path.
+pub const QUERY_CACHE_DIR: &str = "query_cache";
+/// Name of the index file kept at the root of the query cache.
+pub const QUERY_CACHE_INDEX_FILE: &str = "index.json";
+/// Bumped whenever the key derivation or the index layout changes.
+const QUERY_CACHE_INDEX_VERSION: u32 = 1;
+/// Number of hex characters of the blake3 digest used in file names.
+const CACHE_KEY_LEN: usize = 32;
+/// Profile key with the upper bound of the query cache size in bytes.
+const QUERY_CACHE_MAX_SIZE: &str = "query_cache_max_size";
+/// Profile keys identifying the warehouse of a target, besides its database.
+const WAREHOUSE_IDENTITY_KEYS: [&str; 4] = ["account", "host", "project", "http_path"];
+
 #[derive(Default, Clone)]
 pub enum QueryCacheMode {
     Read,
@@ -32,6 +52,7 @@ pub trait QueryCache: Send + Sync {
 pub struct QueryCacheStatement {
     query_cache_config: Arc<QueryCacheConfig>,
     counters: Arc<SccHashMap<String, usize>>,
+    index: Arc<Mutex<QueryCacheIndex>>,
     inner_stmt: Box<dyn Statement>,
     query_ctx: QueryCtx,
 }
@@ -51,10 +72,7 @@ impl QueryCacheStatement {
             Some(sql) => normalize_sql_for_comparison(&sql),
             None => "none".to_string(),
         };
-        let mut hasher = DefaultHasher::new();
-        sql.hash(&mut hasher);
-        let hash = hasher.finish();
-        format!("{hash:x}")[..8.min(format!("{hash:x}").len())].to_string()
+        compute_cache_key(&self.query_cache_config.adapter_identity, &sql)
     }
 
     fn compute_file_index(&self, node_id: &str, phase: &ExecutionPhase, cache_key: &str) -> usize {
@@ -142,21 +160,90 @@ impl QueryCacheStatement {
 
     fn check_ttl(&self, file_path: &Path) -> AdbcResult<bool> {
         if let Some(ttl) = self.query_cache_config.ttl {
-            if let Ok(metadata) = std::fs::metadata(file_path) {
-                // The TTL is a duration (i.e. 12 hours), check if the file was modified within the TTL
-                if let Ok(modified) = metadata.modified() {
-                    if modified < std::time::SystemTime::now() - ttl {
-                        return Ok(false);
-                    }
-                } else {
-                    return Ok(false);
-                }
-            } else {
+            // Prefer the creation time recorded in the index, fall back to the file itself
+            let created_at = self
+                .index
+                .lock()
+                .unwrap()
+                .entries
+                .get(&self.relative_path(file_path))
+                .map(|entry| entry.created_at);
+            let created_at = match created_at {
+                Some(created_at) => created_at,
+                None => match std::fs::metadata(file_path).and_then(|m| m.modified()) {
+                    Ok(modified) => modified
+                        .duration_since(std::time::UNIX_EPOCH)
+                        .map(|d| d.as_millis() as u64)
+                        .unwrap_or(0),
+                    Err(_) => return Ok(false),
+                },
+            };
+            // The TTL is a duration (i.e. 12 hours), check if the entry was created within the TTL
+            if created_at + (ttl.as_millis() as u64) < now_millis() {
                 return Ok(false);
             }
         }
         Ok(true)
     }
+
+    fn relative_path(&self, file_path: &Path) -> String {
+        relative_path(&self.query_cache_config.root_path, file_path)
+    }
+
+    fn record_hit(&self, file_path: &Path) {
+        let mut index = self.index.lock().unwrap();
+        index.hits += 1;
+        if let Some(entry) = index.entries.get_mut(&self.relative_path(file_path)) {
+            entry.last_accessed = now_millis();
+        }
+        drop(index);
+        record_cache_outcome(true);
+    }
+
+    /// Record a newly written entry in the index and evict entries beyond the size bound.
+    ///
+    /// Takes the fields it needs, as the inner statement is still borrowed by its reader.
+    fn record_miss(
+        config: &QueryCacheConfig,
+        index: &Mutex<QueryCacheIndex>,
+        node_id: &str,
+        cache_key: &str,
+        file_path: &Path,
+    ) {
+        let size_bytes = std::fs::metadata(file_path).map_or(0, |m| m.len());
+        let now = now_millis();
+        let relative_path = relative_path(&config.root_path, file_path);
+        let mut index = index.lock().unwrap();
+        index.misses += 1;
+        index.entries.insert(
+            relative_path.clone(),
+            QueryCacheEntry {
+                node_id: node_id.to_string(),
+                key: cache_key.to_string(),
+                size_bytes,
+                created_at: now,
+                last_accessed: now,
+            },
+        );
+        if let Some(max_size) = config.max_size {
+            index.evict_to_size(&config.root_path, max_size, Some(&relative_path));
+        }
+        if let Err(e) = index.save(&config.root_path) {
+            log::debug!("Failed to write the query cache index: {e}");
+        }
+        drop(index);
+        record_cache_outcome(false);
+    }
+
+    fn invalidate_node(&self, node_id: &str) {
+        // Remove all entries of the node, so that statement indexes stay consistent
+        let _ = std::fs::remove_dir_all(self.construct_output_dir(node_id));
+        self.index
+            .lock()
+            .unwrap()
+            .entries
+            .retain(|_, entry| entry.node_id != node_id);
+    }
 }
 
 impl Statement for QueryCacheStatement {
@@ -191,17 +278,29 @@ impl Statement for QueryCacheStatement {
                 if path.exists() {
                     if self.check_ttl(&path)? {
                         let cache_read = self.read_cache(&path);
+                        if cache_read.is_ok() {
+                            self.record_hit(&path);
+                        }
                         return cache_read;
                     } else {
-                        // Try to remove the parent directory if the file is stale
-                        let _ = std::fs::remove_dir_all(path.parent().unwrap());
+                        self.invalidate_node(&node_id);
                     }
                 }
                 // Execute on the actual engine's Statement
                 let result = self.inner_stmt.execute();
                 // TODO: Add invalidation logic to ensure when a cache hit is not found, we invalidate downstreams (in Render Phase)
                 match result {
-                    Ok(mut reader) => QueryCacheStatement::write_cache(&path, &mut reader),
+                    Ok(mut reader) => {
+                        let reader = QueryCacheStatement::write_cache(&path, &mut reader)?;
+                        QueryCacheStatement::record_miss(
+                            &self.query_cache_config,
+                            &self.index,
+                            &node_id,
+                            &sql_hash,
+                            &path,
+                        );
+                        Ok(reader)
+                    }
                     Err(err) => {
                         let err_msg = format!("{err}");
                         Err(AdbcError::with_message_and_status(
@@ -258,6 +357,10 @@ pub struct QueryCacheConfig {
     root_path: PathBuf,
     ttl: Option<Duration>,
     phases: Vec<ExecutionPhase>,
+    /// Identifies the warehouse the results came from (e.g. adapter type and account)
+    adapter_identity: String,
+    /// Upper bound of the cache size in bytes, least recently used entries are evicted first
+    max_size: Option<u64>,
 }
 
 impl QueryCacheConfig {
@@ -272,6 +375,41 @@ impl QueryCacheConfig {
             root_path,
             ttl,
             phases,
+            adapter_identity: String::new(),
+            max_size: None,
+        }
+    }
+
+    /// Mix the given adapter identity into the cache keys, so that results
+    /// of the same SQL against different warehouses are never shared.
+    pub fn with_adapter_identity(mut self, adapter_identity: impl Into<String>) -> Self {
+        self.adapter_identity = adapter_identity.into();
+        self
+    }
+
+    /// Bound the total size of the cache in bytes.
+    pub fn with_max_size(mut self, max_size: u64) -> Self {
+        self.max_size = Some(max_size);
+        self
+    }
+
+    /// Configure the cache for the target of an engine: keys are mixed with
+    /// the warehouse the target connects to, and the cache size is bounded
+    /// by the `query_cache_max_size` profile key, if set.
+    pub fn for_target(self, adapter_type: AdapterType, config: &AdapterConfig) -> Self {
+        let warehouse = WAREHOUSE_IDENTITY_KEYS
+            .iter()
+            .find_map(|key| config.get_string(key))
+            .unwrap_or_default();
+        let database = config.get_string("database").unwrap_or_default();
+        let max_size = config
+            .get_string(QUERY_CACHE_MAX_SIZE)
+            .and_then(|size| size.parse::<u64>().ok());
+        let cache_config =
+            self.with_adapter_identity(format!("{adapter_type}:{warehouse}:{database}"));
+        match max_size {
+            Some(max_size) => cache_config.with_max_size(max_size),
+            None => cache_config,
         }
     }
 }
@@ -280,13 +418,16 @@ pub struct QueryCacheImpl {
     config: Arc<QueryCacheConfig>,
     // We need to keep track of which index we are on per node id (NodeId, StatementCount)
     counters: Arc<SccHashMap<String, usize>>,
+    index: Arc<Mutex<QueryCacheIndex>>,
 }
 
 impl QueryCacheImpl {
     pub fn new(config: QueryCacheConfig) -> Self {
+        let index = QueryCacheIndex::load(&config.root_path);
         Self {
             config: Arc::new(config),
             counters: Arc::new(SccHashMap::new()),
+            index: Arc::new(Mutex::new(index)),
         }
     }
 }
@@ -296,12 +437,365 @@ impl QueryCache for QueryCacheImpl {
         Box::new(QueryCacheStatement {
             query_cache_config: self.config.clone(),
             counters: self.counters.clone(),
+            index: self.index.clone(),
             inner_stmt: stmt,
             query_ctx,
         })
     }
 }
 
+impl Drop for QueryCacheImpl {
+    fn drop(&mut self) {
+        // Persist access times and hit counters recorded since the last write
+        if let Ok(index) = self.index.lock()
+            && !index.entries.is_empty()
+            && let Err(e) = index.save(&self.config.root_path)
+        {
+            log::debug!("Failed to write the query cache index: {e}");
+        }
+    }
+}
+
+/// Compute the content-addressed key of a cached result.
+///
+/// The key is stable across Rust releases and platforms, unlike
+/// [std::hash::DefaultHasher].
+pub fn compute_cache_key(adapter_identity: &str, normalized_sql: &str) -> String {
+    let mut hasher = blake3::Hasher::new();
+    hasher.update(adapter_identity.as_bytes());
+    hasher.update(b"\0");
+    hasher.update(normalized_sql.as_bytes());
+    hasher.finalize().to_hex()[..CACHE_KEY_LEN].to_string()
+}
+
+/// A cached result file as recorded in the index.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct QueryCacheEntry {
+    pub node_id: String,
+    pub key: String,
+    pub size_bytes: u64,
+    /// Milliseconds since the epoch
+    pub created_at: u64,
+    /// Milliseconds since the epoch
+    pub last_accessed: u64,
+}
+
+/// The index of the query cache, persisted as [QUERY_CACHE_INDEX_FILE].
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct QueryCacheIndex {
+    version: u32,
+    /// Entries keyed by the path of the result file relative to the cache root
+    entries: BTreeMap<String, QueryCacheEntry>,
+    hits: u64,
+    misses: u64,
+}
+
+impl Default for QueryCacheIndex {
+    fn default() -> Self {
+        Self {
+            version: QUERY_CACHE_INDEX_VERSION,
+            entries: BTreeMap::new(),
+            hits: 0,
+            misses: 0,
+        }
+    }
+}
+
+impl QueryCacheIndex {
+    /// Load the index from the given cache root.
+    ///
+    /// A missing, unreadable or outdated index yields an empty one; files it
+    /// does not know about are removed by [prune_query_cache].
+    pub fn load(root_path: &Path) -> Self {
+        let path = root_path.join(QUERY_CACHE_INDEX_FILE);
+        let Ok(contents) = std::fs::read_to_string(&path) else {
+            return Self::default();
+        };
+        match serde_json::from_str::<Self>(&contents) {
+            Ok(index) if index.version == QUERY_CACHE_INDEX_VERSION => index,
+            Ok(_) => Self::default(),
+            Err(e) => {
+                log::debug!("Ignoring invalid query cache index {}: {e}", path.display());
+                Self::default()
+            }
+        }
+    }
+
+    /// Write the index to the given cache root, replacing the previous one atomically.
+    pub fn save(&self, root_path: &Path) -> io::Result<()> {
+        std::fs::create_dir_all(root_path)?;
+        let tmp_path = root_path.join(format!("{QUERY_CACHE_INDEX_FILE}.tmp"));
+        std::fs::write(&tmp_path, serde_json::to_vec(self)?)?;
+        std::fs::rename(tmp_path, root_path.join(QUERY_CACHE_INDEX_FILE))
+    }
+
+    pub fn entries(&self) -> &BTreeMap<String, QueryCacheEntry> {
+        &self.entries
+    }
+
+    pub fn total_size(&self) -> u64 {
+        self.entries.values().map(|entry| entry.size_bytes).sum()
+    }
+
+    fn remove_entry(&mut self, root_path: &Path, relative_path: &str) -> Option<QueryCacheEntry> {
+        let entry = self.entries.remove(relative_path)?;
+        let _ = std::fs::remove_file(root_path.join(relative_path));
+        Some(entry)
+    }
+
+    /// Evict least recently used entries until the cache fits in `max_size` bytes.
+    fn evict_to_size(
+        &mut self,
+        root_path: &Path,
+        max_size: u64,
+        keep: Option<&str>,
+    ) -> PruneSummary {
+        let mut summary = PruneSummary::default();
+        let mut total_size = self.total_size();
+        if total_size <= max_size {
+            return summary;
+        }
+        let mut by_access = self
+            .entries
+            .iter()
+            .filter(|(path, _)| Some(path.as_str()) != keep)
+            .map(|(path, entry)| (entry.last_accessed, path.clone()))
+            .collect::<Vec<_>>();
+        by_access.sort();
+        for (_, path) in by_access {
+            if total_size <= max_size {
+                break;
+            }
+            if let Some(entry) = self.remove_entry(root_path, &path) {
+                total_size -= entry.size_bytes;
+                summary.record(&entry);
+            }
+        }
+        summary
+    }
+
+    /// Evict entries created more than `ttl` ago.
+    fn evict_expired(&mut self, root_path: &Path, ttl: Duration, now: u64) -> PruneSummary {
+        let mut summary = PruneSummary::default();
+        let expired = self
+            .entries
+            .iter()
+            .filter(|(_, entry)| entry.created_at + (ttl.as_millis() as u64) < now)
+            .map(|(path, _)| path.clone())
+            .collect::<Vec<_>>();
+        for path in expired {
+            if let Some(entry) = self.remove_entry(root_path, &path) {
+                summary.record(&entry);
+            }
+        }
+        summary
+    }
+}
+
+/// Statistics about a query cache directory.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct QueryCacheStats {
+    pub entries: usize,
+    pub nodes: usize,
+    pub total_bytes: u64,
+    pub hits: u64,
+    pub misses: u64,
+    /// Milliseconds since the epoch of the least recently used entry
+    pub oldest_access: Option<u64>,
+    /// Milliseconds since the epoch of the most recently used entry
+    pub newest_access: Option<u64>,
+}
+
+/// What a prune or clear removed from the cache.
+#[derive(Debug, Clone, Default, PartialEq)]
+pub struct PruneSummary {
+    pub removed_entries: usize,
+    pub freed_bytes: u64,
+}
+
+impl PruneSummary {
+    fn record(&mut self, entry: &QueryCacheEntry) {
+        self.removed_entries += 1;
+        self.freed_bytes += entry.size_bytes;
+    }
+
+    fn merge(&mut self, other: PruneSummary) {
+        self.removed_entries += other.removed_entries;
+        self.freed_bytes += other.freed_bytes;
+    }
+}
+
+/// Compute statistics of the query cache at `root_path`.
+pub fn query_cache_stats(root_path: &Path) -> QueryCacheStats {
+    let index = QueryCacheIndex::load(root_path);
+    let accesses = index.entries.values().map(|entry| entry.last_accessed);
+    QueryCacheStats {
+        entries: index.entries.len(),
+        nodes: index
+            .entries
+            .values()
+            .map(|entry| entry.node_id.as_str())
+            .collect::<BTreeSet<_>>()
+            .len(),
+        total_bytes: index.total_size(),
+        hits: index.hits,
+        misses: index.misses,
+        oldest_access: accesses.clone().min(),
+        newest_access: accesses.max(),
+    }
+}
+
+/// Remove expired entries, entries beyond `max_size` (least recently used
+/// first), index entries whose file is gone, and result files that are not
+/// in the index (e.g. written by an older version of the cache).
+pub fn prune_query_cache(
+    root_path: &Path,
+    ttl: Option<Duration>,
+    max_size: Option<u64>,
+) -> io::Result<PruneSummary> {
+    let mut summary = PruneSummary::default();
+    if !root_path.exists() {
+        return Ok(summary);
+    }
+    let mut index = QueryCacheIndex::load(root_path);
+    index
+        .entries
+        .retain(|path, _| root_path.join(path).is_file());
+
+    for node_dir in std::fs::read_dir(root_path)? {
+        let node_dir = node_dir?.path();
+        if !node_dir.is_dir() {
+            continue;
+        }
+        for file in std::fs::read_dir(&node_dir)? {
+            let file = file?.path();
+            // Only result files of the cache are removed, never other files
+            // that happen to live in the cache directory
+            if is_cache_entry_file(&file)
+                && !index.entries.contains_key(&relative_path(root_path, &file))
+            {
+                summary.removed_entries += 1;
+                summary.freed_bytes += std::fs::metadata(&file).map_or(0, |m| m.len());
+                std::fs::remove_file(&file)?;
+            }
+        }
+    }
+
+    if let Some(ttl) = ttl {
+        summary.merge(index.evict_expired(root_path, ttl, now_millis()));
+    }
+    if let Some(max_size) = max_size {
+        summary.merge(index.evict_to_size(root_path, max_size, None));
+    }
+
+    remove_empty_node_dirs(root_path)?;
+    index.save(root_path)?;
+    Ok(summary)
+}
+
+/// Remove every entry of the query cache at `root_path`.
+///
+/// Only the index and the result files of the cache are removed, other files
+/// in the directory (which may be any `--cache-path`) are left alone.
+pub fn clear_query_cache(root_path: &Path) -> io::Result<PruneSummary> {
+    let mut summary = PruneSummary::default();
+    if !root_path.exists() {
+        return Ok(summary);
+    }
+    let mut index = QueryCacheIndex::load(root_path);
+    let paths = index.entries.keys().cloned().collect::<Vec<_>>();
+    for path in paths {
+        if let Some(entry) = index.remove_entry(root_path, &path) {
+            summary.record(&entry);
+        }
+    }
+    // Result files the index doesn't know about
+    for node_dir in std::fs::read_dir(root_path)? {
+        let node_dir = node_dir?.path();
+        if !node_dir.is_dir() {
+            continue;
+        }
+        for file in std::fs::read_dir(&node_dir)? {
+            let file = file?.path();
+            if is_cache_entry_file(&file) {
+                summary.removed_entries += 1;
+                summary.freed_bytes += std::fs::metadata(&file).map_or(0, |m| m.len());
+                std::fs::remove_file(&file)?;
+            }
+        }
+    }
+    remove_empty_node_dirs(root_path)?;
+
+    for file_name in [
+        QUERY_CACHE_INDEX_FILE.to_string(),
+        format!("{QUERY_CACHE_INDEX_FILE}.tmp"),
+    ] {
+        let path = root_path.join(file_name);
+        if path.is_file() {
+            std::fs::remove_file(path)?;
+        }
+    }
+    // The root goes too once it's empty, like the node directories
+    if std::fs::read_dir(root_path)?.next().is_none() {
+        std::fs::remove_dir(root_path)?;
+    }
+    Ok(summary)
+}
+
+/// Whether `path` is named like a result file of the cache, `<key>_<n>.parquet`.
+fn is_cache_entry_file(path: &Path) -> bool {
+    let Some(file_name) = path.file_name().and_then(|name| name.to_str()) else {
+        return false;
+    };
+    let Some((key, num)) = file_name
+        .strip_suffix(".parquet")
+        .and_then(|stem| stem.rsplit_once('_'))
+    else {
+        return false;
+    };
+    !key.is_empty()
+        && key.chars().all(|c| c.is_ascii_hexdigit())
+        && !num.is_empty()
+        && num.chars().all(|c| c.is_ascii_digit())
+}
+
+/// Remove node directories left empty.
+fn remove_empty_node_dirs(root_path: &Path) -> io::Result<()> {
+    for node_dir in std::fs::read_dir(root_path)? {
+        let node_dir = node_dir?.path();
+        if node_dir.is_dir() && std::fs::read_dir(&node_dir)?.next().is_none() {
+            std::fs::remove_dir(&node_dir)?;
+        }
+    }
+    Ok(())
+}
+
+/// Report a cache hit or miss on the current [QueryExecuted] span.
+fn record_cache_outcome(cache_hit: bool) {
+    record_current_span_status_from_attrs(|attrs| {
+        if let Some(attrs) = attrs.downcast_mut::<QueryExecuted>() {
+            attrs.cache_hit = Some(cache_hit);
+        }
+    });
+}
+
+/// Index key of a file: its path relative to the cache root, with `/` separators.
+fn relative_path(root_path: &Path, file_path: &Path) -> String {
+    let relative = file_path.strip_prefix(root_path).unwrap_or(file_path);
+    relative
+        .components()
+        .map(|c| c.as_os_str().to_string_lossy())
+        .collect::<Vec<_>>()
+        .join("/")
+}
+
+fn now_millis() -> u64 {
+    std::time::UNIX_EPOCH
+        .elapsed()
+        .map(|d| d.as_millis() as u64)
+        .unwrap_or(0)
+}
+
 fn from_io_error(e: std::io::Error, path: Option<&Path>) -> adbc_core::error::Error {
     let message = if let Some(path) = path {
         format!("IO error: {:?} ({:?})", e, path.display())
@@ -451,3 +945,115 @@ fn strip_sql_comments(sql: &str) -> String {
 
     result
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use dbt_schemas::schemas::profiles::DbConfig;
+
+    fn write_entry(root: &Path, index: &mut QueryCacheIndex, path: &str, size: usize, at: u64) {
+        let file_path = root.join(path);
+        std::fs::create_dir_all(file_path.parent().unwrap()).unwrap();
+        std::fs::write(&file_path, vec![0u8; size]).unwrap();
+        index.entries.insert(
+            path.to_string(),
+            QueryCacheEntry {
+                node_id: path.split('/').next().unwrap().to_string(),
+                key: "key".to_string(),
+                size_bytes: size as u64,
+                created_at: at,
+                last_accessed: at,
+            },
+        );
+    }
+
+    #[test]
+    fn test_compute_cache_key() {
+        let key = compute_cache_key("snowflake", "select 1");
+        assert_eq!(key.len(), CACHE_KEY_LEN);
+        assert_eq!(key, compute_cache_key("snowflake", "select 1"));
+        assert_ne!(key, compute_cache_key("postgres", "select 1"));
+        assert_ne!(key, compute_cache_key("snowflake", "select 2"));
+    }
+
+    #[test]
+    fn test_cache_key_ignores_attribution_comment() {
+        // Attribution comments carry the invocation_id, which changes every run
+        let sql = |invocation_id: &str| {
+            format!(
+                "/* dbt_attribution: {{\"dbt_invocation_id\":\"{invocation_id}\"}} */\nselect 1"
+            )
+        };
+        assert_eq!(
+            normalize_sql_for_comparison(&sql("b1946ac9")),
+            normalize_sql_for_comparison(&sql("591785b7"))
+        );
+        assert_eq!(
+            normalize_sql_for_comparison(&sql("b1946ac9")).trim(),
+            "select 1"
+        );
+    }
+
+    #[test]
+    fn test_prune_query_cache() {
+        let dir = tempfile::tempdir().unwrap();
+        let root = dir.path();
+        let now = now_millis();
+        let mut index = QueryCacheIndex::default();
+        write_entry(root, &mut index, "model.a/k1_1.parquet", 10, now - 3);
+        write_entry(root, &mut index, "model.a/k2_2.parquet", 10, now - 2);
+        write_entry(root, &mut index, "model.b/k3_1.parquet", 10, now - 1);
+        index.save(root).unwrap();
+        // Written by an older cache version, unknown to the index
+        std::fs::write(root.join("model.b/0123abcd_1.parquet"), [0u8; 5]).unwrap();
+        // Not the cache's own, e.g. a --cache-path shared with other files
+        std::fs::write(root.join("model.b/notes.txt"), [0u8; 7]).unwrap();
+        std::fs::write(root.join("README.md"), [0u8; 7]).unwrap();
+
+        let stats = query_cache_stats(root);
+        assert_eq!(stats.entries, 3);
+        assert_eq!(stats.nodes, 2);
+        assert_eq!(stats.total_bytes, 30);
+
+        let summary = prune_query_cache(root, None, Some(15)).unwrap();
+        assert_eq!(summary.removed_entries, 3);
+        assert_eq!(summary.freed_bytes, 25);
+        let index = QueryCacheIndex::load(root);
+        assert_eq!(
+            index.entries().keys().collect::<Vec<_>>(),
+            vec!["model.b/k3_1.parquet"]
+        );
+        assert!(!root.join("model.a").exists());
+
+        assert!(root.join("model.b/notes.txt").exists());
+
+        let summary = clear_query_cache(root).unwrap();
+        assert_eq!(summary.removed_entries, 1);
+        assert!(!root.join(QUERY_CACHE_INDEX_FILE).exists());
+        assert!(!root.join("model.b/k3_1.parquet").exists());
+        assert!(root.join("model.b/notes.txt").exists());
+        assert!(root.join("README.md").exists());
+    }
+
+    #[test]
+    fn test_config_for_target() {
+        // Built the way the adapter receives its profile: through DbConfig
+        let target = |yaml: &str| {
+            let db_config: DbConfig = dbt_serde_yaml::from_str(yaml).unwrap();
+            AdapterConfig::new(db_config.to_mapping().unwrap())
+        };
+        let config = |yaml: &str| {
+            QueryCacheConfig::new(QueryCacheMode::ReadWrite, PathBuf::new(), None, vec![])
+                .for_target(AdapterType::Snowflake, &target(yaml))
+        };
+        let prod =
+            config("type: snowflake\naccount: acme\ndatabase: prod\nquery_cache_max_size: 1024");
+        assert_eq!(prod.adapter_identity, "snowflake:acme:prod");
+        assert_eq!(prod.max_size, Some(1024));
+        let quoted = config("type: snowflake\naccount: acme\nquery_cache_max_size: '2048'");
+        assert_eq!(quoted.max_size, Some(2048));
+        let dev = config("type: snowflake\naccount: acme\ndatabase: dev");
+        assert_ne!(prod.adapter_identity, dev.adapter_identity);
+        assert_eq!(dev.max_size, None);
+    }
+}
@@ -145,7 +145,7 @@ const _VALIDATE_LABEL_LENGTH_LIMIT: usize = 63;
 
 /// Reference: https://github.com/dbt-labs/dbt-adapters/blob/b0223a88d67012bcc4c6cce5449c4fe10c6ed198/dbt-bigquery/src/dbt/adapters/bigquery/connections.py#L640
 /// Return a legal value for a BigQuery label.
-fn sanitize_label(label: &str) -> String {
+pub(crate) fn sanitize_label(label: &str) -> String {
     let value = label.to_lowercase();
 
     let re = Regex::new(_SANITIZE_LABEL_PATTERN).unwrap();
@@ -1,4 +1,5 @@
 use crate::base_adapter::backend_of;
+use crate::concurrency::ConcurrencyLimits;
 use crate::config::AdapterConfig;
 use crate::errors::AdapterResult;
 use crate::query_comment::QueryCommentConfig;
@@ -14,6 +15,7 @@ use dashmap::DashMap;
 use dbt_common::adapter::AdapterType;
 use dbt_common::cancellation::CancellationToken;
 use dbt_schemas::schemas::common::ResolvedQuoting;
+use dbt_xdbc::connection::Session;
 use dbt_xdbc::{Backend, Connection, QueryCtx, Statement};
 use minijinja::State;
 use once_cell::sync::Lazy;
@@ -216,6 +218,10 @@ impl RecordEngine {
     pub fn cancellation_token(&self) -> CancellationToken {
         self.0.engine.cancellation_token()
     }
+
+    pub fn concurrency(&self) -> AdapterResult<Option<&ConcurrencyLimits>> {
+        self.0.engine.concurrency()
+    }
 }
 
 struct RecordEngineConnection(Arc<RecordEngineInner>, Box<dyn Connection>, Option<String>);
@@ -302,12 +308,17 @@ impl Connection for RecordEngineConnection {
     fn update_node_id(&mut self, node_id: Option<String>) {
         self.2 = node_id
     }
+
+    fn session(&mut self) -> Option<&mut Session> {
+        self.1.session()
+    }
 }
 
 struct RecordEngineStatement {
     record_engine: Arc<RecordEngineInner>,
     inner_stmt: Box<dyn Statement>,
     query_ctx: Option<QueryCtx>,
+    params: Option<RecordBatch>,
 }
 
 impl RecordEngineStatement {
@@ -319,12 +330,14 @@ impl RecordEngineStatement {
             record_engine,
             inner_stmt,
             query_ctx: None,
+            params: None,
         }
     }
 }
 
 impl Statement for RecordEngineStatement {
     fn bind(&mut self, batch: RecordBatch) -> AdbcResult<()> {
+        self.params = Some(batch.clone());
         self.inner_stmt.bind(batch)
     }
 
@@ -353,9 +366,14 @@ impl Statement for RecordEngineStatement {
         let sql_path = path.join(format!("{file_name}.sql"));
         let err_path = path.join(format!("{file_name}.err"));
         let parquet_path = path.join(format!("{file_name}.parquet"));
+        let params_path = path.join(format!("{file_name}.params.parquet"));
 
         // store the query content (i.e., sql)
         fs::write(&sql_path, sql).map_err(|e| from_io_error(e, Some(&sql_path)))?;
+        // and the parameters bound to it, if any
+        if let Some(params) = &self.params {
+            write_parquet(&params_path, params)?;
+        }
 
         match result {
             Ok(mut reader) => {
@@ -487,7 +505,7 @@ impl ReplayEngine {
         _state: Option<&State>,
         node_id: Option<String>,
     ) -> AdapterResult<Box<dyn Connection>> {
-        let conn = ReplayEngineConnection(self.0.clone(), node_id);
+        let conn = ReplayEngineConnection(self.0.clone(), node_id, Session::default());
         Ok(Box::new(conn))
     }
 
@@ -529,7 +547,7 @@ impl ReplayEngine {
 }
 
 #[allow(dead_code)]
-struct ReplayEngineConnection(Arc<ReplayEngineInner>, Option<String>);
+struct ReplayEngineConnection(Arc<ReplayEngineInner>, Option<String>, Session);
 
 impl fmt::Debug for ReplayEngineConnection {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
@@ -602,11 +620,16 @@ impl Connection for ReplayEngineConnection {
     fn update_node_id(&mut self, node_id: Option<String>) {
         self.1 = node_id
     }
+
+    fn session(&mut self) -> Option<&mut Session> {
+        Some(&mut self.2)
+    }
 }
 
 struct ReplayEngineStatement {
     replay_engine: Arc<ReplayEngineInner>,
     query_ctx: Option<QueryCtx>,
+    params: Option<RecordBatch>,
 }
 
 impl ReplayEngineStatement {
@@ -614,10 +637,28 @@ impl ReplayEngineStatement {
         ReplayEngineStatement {
             replay_engine,
             query_ctx: None,
+            params: None,
         }
     }
 }
 
+fn write_parquet(path: &Path, batch: &RecordBatch) -> AdbcResult<()> {
+    let file = File::create(path).map_err(|e| from_io_error(e, Some(path)))?;
+    let props = WriterProperties::builder().build();
+    let mut writer =
+        ArrowWriter::try_new(file, batch.schema(), Some(props)).map_err(from_parquet_error)?;
+    writer.write(batch).map_err(from_parquet_error)?;
+    writer.close().map_err(from_parquet_error)?;
+    Ok(())
+}
+
+fn read_parquet(path: &Path) -> AdbcResult<Vec<RecordBatch>> {
+    let file = File::open(path).map_err(|e| from_io_error(e, Some(path)))?;
+    let builder = ParquetRecordBatchReaderBuilder::try_new(file).map_err(from_parquet_error)?;
+    let reader = builder.build().map_err(from_parquet_error)?;
+    Ok(reader.collect::<Result<_, _>>()?)
+}
+
 fn from_parquet_error(e: parquet::errors::ParquetError) -> adbc_core::error::Error {
     adbc_core::error::Error::with_message_and_status(
         format!("Parquet error: {e:?}"),
@@ -644,8 +685,9 @@ fn from_serde_error(e: serde_json::Error, path: Option<&Path>) -> adbc_core::err
 }
 
 impl Statement for ReplayEngineStatement {
-    fn bind(&mut self, _batch: RecordBatch) -> AdbcResult<()> {
-        todo!("ReplayEngineStatement::bind")
+    fn bind(&mut self, batch: RecordBatch) -> AdbcResult<()> {
+        self.params = Some(batch);
+        Ok(())
     }
 
     fn bind_stream(&mut self, _reader: Box<dyn RecordBatchReader + Send>) -> AdbcResult<()> {
@@ -687,6 +729,21 @@ impl Statement for ReplayEngineStatement {
             );
         }
 
+        // Bound parameters have to match the recorded ones as well
+        let params_path = path.join(format!("{file_name}.params.parquet"));
+        let record_params =
+            if fs::exists(&params_path).map_err(|e| from_io_error(e, Some(&params_path)))? {
+                Some(read_parquet(&params_path)?)
+            } else {
+                None
+            };
+        let replay_params = self.params.clone().map(|batch| vec![batch]);
+        if record_params != replay_params {
+            panic!(
+                "Recorded parameters ({record_params:?}) and actual parameters ({replay_params:?}) do not match ({params_path:?})"
+            );
+        }
+
         if err_path.exists() {
             // There was an error during recording, so we need to
             // replay now. TODO: Note that we do not at the moment
@@ -752,7 +809,8 @@ impl Statement for ReplayEngineStatement {
     }
 
     fn cancel(&mut self) -> AdbcResult<()> {
-        todo!("ReplayEngineStatement::cancel")
+        // Replayed results are read from disk, there is no server-side work to cancel.
+        Ok(())
     }
 
     fn set_option(&mut self, _key: OptionStatement, _value: OptionValue) -> AdbcResult<()> {
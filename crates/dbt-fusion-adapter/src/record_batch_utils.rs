@@ -10,6 +10,15 @@ use arrow::array::{
 use arrow::datatypes::DataType;
 use arrow::record_batch::RecordBatch;
 
+/// The first `limit` rows of `batch`, or all of them if `limit` is not set
+/// or negative
+pub fn limit_rows(batch: RecordBatch, limit: Option<i64>) -> RecordBatch {
+    match limit.and_then(|limit| usize::try_from(limit).ok()) {
+        Some(limit) if limit < batch.num_rows() => batch.slice(0, limit),
+        _ => batch,
+    }
+}
+
 pub fn extract_first_value_as_i64(batch: &RecordBatch) -> Option<i64> {
     let column = batch.column(0);
 
@@ -10,7 +10,7 @@ use crate::typed_adapter::TypedBaseAdapter;
 use arrow::array::{Array, StringArray};
 use dbt_common::adapter::AdapterType;
 use dbt_schemas::dbt_types::RelationType;
-use dbt_schemas::schemas::common::{ConstraintSupport, ConstraintType, DbtIncrementalStrategy};
+use dbt_schemas::schemas::common::DbtIncrementalStrategy;
 use dbt_schemas::schemas::relations::base::BaseRelation;
 use dbt_xdbc::{Connection, QueryCtx};
 use minijinja::{State, Value};
@@ -217,18 +217,6 @@ LEFT JOIN materialized_views mv
             result,
         )?)
     }
-
-    /// https://github.com/dbt-labs/dbt-adapters/blob/2a94cc75dba1f98fa5caff1f396f5af7ee444598/dbt-redshift/src/dbt/adapters/redshift/impl.py#L53
-    fn get_constraint_support(&self, ct: ConstraintType) -> ConstraintSupport {
-        match ct {
-            ConstraintType::Check => ConstraintSupport::NotSupported,
-            ConstraintType::NotNull => ConstraintSupport::Enforced,
-            ConstraintType::Unique | ConstraintType::PrimaryKey | ConstraintType::ForeignKey => {
-                ConstraintSupport::NotEnforced
-            }
-            _ => ConstraintSupport::NotSupported,
-        }
-    }
 }
 
 #[cfg(test)]
@@ -238,7 +226,7 @@ mod tests {
     use crate::config::AdapterConfig;
     use crate::query_comment::QueryCommentConfig;
     use crate::sql_types::NaiveTypeOpsImpl;
-    use crate::stmt_splitter::NaiveStmtSplitter;
+    use crate::stmt_splitter::DialectStmtSplitter;
     use dbt_auth::auth_for_backend;
     use dbt_common::adapter::AdapterType;
     use dbt_common::cancellation::never_cancels;
@@ -254,7 +242,7 @@ mod tests {
             auth.into(),
             AdapterConfig::new(config),
             DEFAULT_RESOLVED_QUOTING,
-            Arc::new(NaiveStmtSplitter),
+            Arc::new(DialectStmtSplitter),
             None,
             QueryCommentConfig::from_query_comment(None, AdapterType::Redshift, false),
             Box::new(NaiveTypeOpsImpl::new(AdapterType::Redshift)), // XXX: NaiveTypeOpsImpl
@@ -3,6 +3,9 @@ use crate::redshift::relation_configs::materialized_view_config::{
     DescribeMaterializedViewResults, RedshiftMaterializedViewConfig,
     RedshiftMaterializedViewConfigChangeset,
 };
+use crate::redshift::relation_configs::table_config::{
+    DescribeTableResults, RedshiftTableConfig, RedshiftTableConfigChangeset,
+};
 use crate::relation_object::{RelationObject, StaticBaseRelation};
 
 use arrow::array::RecordBatch;
@@ -13,15 +16,13 @@ use dbt_schemas::schemas::relations::base::{
     BaseRelation, BaseRelationProperties, Policy, RelationPath,
 };
 use dbt_schemas::schemas::{InternalDbtNodeWrapper, RelationChangeSet};
-use minijinja::arg_utils::{ArgParser, ArgsIter, check_num_args};
+use minijinja::arg_utils::ArgsIter;
 use minijinja::{Error as MinijinjaError, ErrorKind as MinijinjaErrorKind, State, Value};
 use serde::Deserialize;
 
 use std::any::Any;
 use std::sync::Arc;
 
-const MAX_CHARACTERS_IN_IDENTIFIER: u32 = 127;
-
 /// A struct representing the relation type for use with static methods
 #[derive(Clone, Debug)]
 pub struct RedshiftRelationType(pub ResolvedQuoting);
@@ -185,12 +186,6 @@ impl BaseRelation for RedshiftRelation {
         Ok(relation.as_value())
     }
 
-    fn relation_max_name_length(&self, args: &[Value]) -> Result<Value, MinijinjaError> {
-        let args = ArgParser::new(args, None);
-        check_num_args(current_function_name!(), &args, 0, 0)?;
-        Ok(Value::from(MAX_CHARACTERS_IN_IDENTIFIER))
-    }
-
     fn normalize_component(&self, component: &str) -> String {
         component.to_lowercase()
     }
@@ -290,6 +285,51 @@ impl BaseRelation for RedshiftRelation {
             Ok(Value::from(None::<()>))
         }
     }
+
+    /// Compare the dist and sort keys of an existing table with its model
+    fn table_config_changeset(&self, args: &[Value]) -> Result<Value, MinijinjaError> {
+        let iter = ArgsIter::new(
+            current_function_name!(),
+            &["relation_results", "relation_config"],
+            args,
+        );
+
+        let relation_results_value = iter.next_arg::<&Value>()?;
+        let new_config_value = iter.next_arg::<&Value>()?;
+        iter.finish()?;
+
+        let relation_results =
+            DescribeTableResults::try_from(relation_results_value).map_err(|e| {
+                MinijinjaError::new(
+                    MinijinjaErrorKind::SerdeDeserializeError,
+                    format!(
+                        "table_config_changeset: Failed to deserialize DescribeTableResults: {e}"
+                    ),
+                )
+            })?;
+
+        // Tables that Redshift hasn't populated yet are missing from svv_table_info
+        if relation_results.table.num_rows() == 0 {
+            return Ok(Value::from(None::<()>));
+        }
+
+        let existing_config = RedshiftTableConfig::try_from(relation_results).map_err(|e| {
+            MinijinjaError::new(
+                MinijinjaErrorKind::SerdeDeserializeError,
+                format!("table_config_changeset: Failed to deserialize RedshiftTableConfig: {e}"),
+            )
+        })?;
+
+        let new_table_config = node_value_to_redshift_table(new_config_value)?;
+
+        let changeset = RedshiftTableConfigChangeset::new(existing_config, new_table_config);
+
+        if changeset.has_changes() {
+            Ok(Value::from_object(changeset))
+        } else {
+            Ok(Value::from(None::<()>))
+        }
+    }
 }
 
 fn node_value_to_redshift_materialized_view(
@@ -330,6 +370,45 @@ fn node_value_to_redshift_materialized_view(
     })
 }
 
+fn node_value_to_redshift_table(node_value: &Value) -> Result<RedshiftTableConfig, MinijinjaError> {
+    let config_wrapper = InternalDbtNodeWrapper::deserialize(node_value).map_err(|e| {
+        MinijinjaError::new(
+            MinijinjaErrorKind::SerdeDeserializeError,
+            format!("Failed to deserialize InternalDbtNodeWrapper: {e}"),
+        )
+    })?;
+
+    let model = match config_wrapper {
+        InternalDbtNodeWrapper::Model(model) => model,
+        _ => {
+            return Err(MinijinjaError::new(
+                MinijinjaErrorKind::InvalidOperation,
+                "Expected a model node",
+            ));
+        }
+    };
+
+    if !matches!(
+        model.__base_attr__.materialized,
+        DbtMaterialization::Table | DbtMaterialization::Incremental
+    ) {
+        return Err(MinijinjaError::new(
+            MinijinjaErrorKind::InvalidOperation,
+            format!(
+                "Unsupported operation for materialization type {}",
+                &model.__base_attr__.materialized
+            ),
+        ));
+    }
+
+    RedshiftTableConfig::try_from(&*model).map_err(|e| {
+        MinijinjaError::new(
+            MinijinjaErrorKind::SerdeDeserializeError,
+            format!("Failed to deserialize RedshiftTableConfig: {e}"),
+        )
+    })
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
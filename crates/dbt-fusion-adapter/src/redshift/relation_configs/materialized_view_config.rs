@@ -300,7 +300,7 @@ impl TryFrom<&DbtModel> for RedshiftMaterializedViewConfig {
 }
 
 // Helper function to get a string value from an AgateTable Row by column name
-fn get_string_by_name_from_agate_row(row: &Value, col_name: &str) -> Option<String> {
+pub(crate) fn get_string_by_name_from_agate_row(row: &Value, col_name: &str) -> Option<String> {
     if let Ok(cell_value) = row.get_attr(col_name) {
         cell_value.as_str().map(|s| s.to_string())
     } else {
@@ -1 +1,2 @@
 pub mod materialized_view_config;
+pub mod table_config;
@@ -0,0 +1,362 @@
+use dbt_agate::AgateTable;
+use dbt_schemas::schemas::{ComponentConfig, DbtModel, RelationChangeSet};
+use minijinja::Value;
+use minijinja::value::Object;
+use std::collections::BTreeMap;
+use std::convert::TryFrom;
+use std::result::Result;
+use std::sync::Arc;
+
+use super::materialized_view_config::{
+    RedshiftDistConfig, RedshiftDistStyle, RedshiftSortConfig, RedshiftSortStyle,
+    get_string_by_name_from_agate_row,
+};
+
+/// Deserialization target for macro redshift__describe_table
+#[derive(Debug, Clone)]
+pub struct DescribeTableResults {
+    /// The row of the table in `svv_table_info`
+    pub table: Arc<AgateTable>,
+    /// The dist and sort key attributes of the table's columns
+    pub columns: Arc<AgateTable>,
+}
+
+impl TryFrom<&Value> for DescribeTableResults {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let table = value
+            .get_item(&Value::from_safe_string("table".into()))
+            .map_err(|e| format!("Expected key `table`: {e}"))?
+            .downcast_object::<AgateTable>()
+            .ok_or("Failed to convert table to AgateTable")?;
+
+        let columns = value
+            .get_item(&Value::from_safe_string("columns".into()))
+            .map_err(|e| format!("Expected key `columns`: {e}"))?
+            .downcast_object::<AgateTable>()
+            .ok_or("Failed to convert columns to AgateTable")?;
+
+        Ok(Self { table, columns })
+    }
+}
+
+/// The dist and sort configuration of a Redshift table.
+///
+/// `None` means the model leaves the setting to Redshift, so it is not compared.
+#[derive(Debug, Clone, PartialEq, Eq, Default)]
+pub struct RedshiftTableConfig {
+    pub dist: Option<RedshiftDistStyle>,
+    pub sort: Option<RedshiftSortStyle>,
+}
+
+impl TryFrom<&DbtModel> for RedshiftTableConfig {
+    type Error = String;
+
+    fn try_from(model: &DbtModel) -> Result<Self, Self::Error> {
+        let Some(redshift_config) = model.__adapter_attr__.redshift_attr.as_ref() else {
+            return Ok(Self::default());
+        };
+
+        let dist = RedshiftDistConfig::try_from(model)
+            .ok()
+            .map(|dist| normalize_dist_style(dist.diststyle));
+
+        let sort = if redshift_config.sort.is_some() || redshift_config.sort_type.is_some() {
+            let sort = RedshiftSortConfig::try_from(model)?;
+            Some(normalize_sort_style(sort.sortstyle))
+        } else {
+            None
+        };
+
+        Ok(Self { dist, sort })
+    }
+}
+
+impl TryFrom<DescribeTableResults> for RedshiftTableConfig {
+    type Error = String;
+
+    fn try_from(value: DescribeTableResults) -> Result<Self, Self::Error> {
+        let table = value
+            .table
+            .rows()
+            .into_iter()
+            .next()
+            .ok_or("table describe table is empty")?;
+
+        let dist = get_string_by_name_from_agate_row(&table, "diststyle")
+            .filter(|diststyle| !diststyle.trim().is_empty())
+            .map(|diststyle| parse_dist_style(diststyle.trim()));
+
+        // Compound sort keys have positive positions, interleaved ones alternate in sign
+        let mut sort_columns = Vec::new();
+        let mut interleaved = false;
+        for row in value.columns.rows() {
+            if let Ok(position) = row.get_attr("sort_key_position")
+                && let Some(position) = position.as_i64()
+                && position != 0
+                && let Some(column) = get_string_by_name_from_agate_row(&row, "column")
+            {
+                interleaved |= position < 0;
+                sort_columns.push((position.unsigned_abs(), normalize_identifier(&column)));
+            }
+        }
+        sort_columns.sort_by_key(|(position, _)| *position);
+        let sort_keys: Vec<String> = sort_columns.into_iter().map(|(_, name)| name).collect();
+
+        let sort = if !sort_keys.is_empty() && interleaved {
+            Some(RedshiftSortStyle::Interleaved(sort_keys))
+        } else if !sort_keys.is_empty() {
+            Some(RedshiftSortStyle::Compound(sort_keys))
+        } else if get_string_by_name_from_agate_row(&table, "sortkey1")
+            .is_some_and(|sortkey| sortkey.to_ascii_uppercase().starts_with("AUTO"))
+        {
+            Some(RedshiftSortStyle::Auto)
+        } else {
+            None
+        };
+
+        Ok(Self { dist, sort })
+    }
+}
+
+/// Parse the `diststyle` column of `svv_table_info`, e.g. `EVEN`, `KEY(id)` or `AUTO(KEY(id))`
+fn parse_dist_style(diststyle: &str) -> RedshiftDistStyle {
+    let upper = diststyle.to_ascii_uppercase();
+    if upper.starts_with("AUTO") {
+        // Redshift picks the style of AUTO tables, which is not drift
+        RedshiftDistStyle::Auto
+    } else if upper.starts_with("KEY(") && upper.ends_with(')') {
+        RedshiftDistStyle::Key(normalize_identifier(
+            &diststyle["KEY(".len()..diststyle.len() - 1],
+        ))
+    } else {
+        diststyle.into()
+    }
+}
+
+fn normalize_dist_style(diststyle: RedshiftDistStyle) -> RedshiftDistStyle {
+    match diststyle {
+        RedshiftDistStyle::Key(key) => RedshiftDistStyle::Key(normalize_identifier(&key)),
+        other => other,
+    }
+}
+
+fn normalize_sort_style(sortstyle: RedshiftSortStyle) -> RedshiftSortStyle {
+    let normalize = |keys: Vec<String>| keys.iter().map(|k| normalize_identifier(k)).collect();
+    match sortstyle {
+        RedshiftSortStyle::Compound(keys) => RedshiftSortStyle::Compound(normalize(keys)),
+        RedshiftSortStyle::Interleaved(keys) => RedshiftSortStyle::Interleaved(normalize(keys)),
+        RedshiftSortStyle::Auto => RedshiftSortStyle::Auto,
+    }
+}
+
+/// Redshift folds unquoted identifiers to lowercase
+fn normalize_identifier(identifier: &str) -> String {
+    let identifier = identifier.trim();
+    match identifier
+        .strip_prefix('"')
+        .and_then(|quoted| quoted.strip_suffix('"'))
+    {
+        Some(quoted) => quoted.to_string(),
+        None => identifier.to_lowercase(),
+    }
+}
+
+fn describe_dist(dist: Option<&RedshiftDistStyle>) -> String {
+    match dist {
+        Some(RedshiftDistStyle::Key(key)) => format!("key({key})"),
+        Some(diststyle) => diststyle.to_string(),
+        None => "none".to_string(),
+    }
+}
+
+fn describe_sort(sort: Option<&RedshiftSortStyle>) -> String {
+    match sort {
+        Some(RedshiftSortStyle::Auto) => "auto".to_string(),
+        Some(RedshiftSortStyle::Compound(keys)) => format!("compound({})", keys.join(", ")),
+        Some(RedshiftSortStyle::Interleaved(keys)) => format!("interleaved({})", keys.join(", ")),
+        None => "none".to_string(),
+    }
+}
+
+/// Differences between the dist and sort keys of an existing table and its model.
+///
+/// Redshift can alter the dist style, dist key and compound sort keys of a
+/// table in place. Interleaved sort keys can't be altered, so changing from or
+/// to an interleaved sort key requires a full refresh.
+#[derive(Debug)]
+pub struct RedshiftTableConfigChangeset {
+    dist: Option<RedshiftDistConfig>,
+    sort: Option<RedshiftSortConfig>,
+    sort_requires_full_refresh: bool,
+    /// Human readable `old -> new` descriptions of the changes, for logging
+    summary: Vec<String>,
+}
+
+impl RedshiftTableConfigChangeset {
+    pub fn new(old: RedshiftTableConfig, new: RedshiftTableConfig) -> Self {
+        let mut summary = Vec::new();
+
+        let dist = match new.dist {
+            Some(new_dist) if old.dist.as_ref() != Some(&new_dist) => {
+                summary.push(format!(
+                    "dist: {} -> {}",
+                    describe_dist(old.dist.as_ref()),
+                    describe_dist(Some(&new_dist))
+                ));
+                Some(RedshiftDistConfig {
+                    diststyle: new_dist,
+                })
+            }
+            _ => None,
+        };
+
+        let mut sort_requires_full_refresh = false;
+        let sort = match new.sort {
+            Some(new_sort) if old.sort.as_ref() != Some(&new_sort) => {
+                summary.push(format!(
+                    "sort: {} -> {}",
+                    describe_sort(old.sort.as_ref()),
+                    describe_sort(Some(&new_sort))
+                ));
+                sort_requires_full_refresh =
+                    matches!(old.sort, Some(RedshiftSortStyle::Interleaved(_)))
+                        || matches!(new_sort, RedshiftSortStyle::Interleaved(_));
+                Some(RedshiftSortConfig {
+                    sortstyle: new_sort,
+                })
+            }
+            _ => None,
+        };
+
+        Self {
+            dist,
+            sort,
+            sort_requires_full_refresh,
+            summary,
+        }
+    }
+}
+
+impl RelationChangeSet for RedshiftTableConfigChangeset {
+    // todo: revisit this abstraction
+    fn changes(&self) -> &BTreeMap<String, Arc<dyn ComponentConfig>> {
+        unimplemented!("Not available for Redshift")
+    }
+
+    fn requires_full_refresh(&self) -> bool {
+        self.sort.is_some() && self.sort_requires_full_refresh
+    }
+
+    // todo: revisit this abstraction
+    fn get_change(&self, _component_name: &str) -> Option<&dyn ComponentConfig> {
+        unimplemented!("Not available for Redshift")
+    }
+
+    fn has_changes(&self) -> bool {
+        self.dist.is_some() || self.sort.is_some()
+    }
+}
+
+impl Object for RedshiftTableConfigChangeset {
+    fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+        match key.as_str() {
+            Some("dist") => {
+                // Return None if no change, otherwise return the config as an object
+                self.dist
+                    .as_ref()
+                    .map(|dist_config| Value::from_object(dist_config.clone()))
+            }
+            Some("sort") => {
+                // Return None if no change, otherwise return the config as an object
+                self.sort
+                    .as_ref()
+                    .map(|sort_config| Value::from_object(sort_config.clone()))
+            }
+            Some("requires_full_refresh") => Some(Value::from(self.requires_full_refresh())),
+            Some("summary") => Some(Value::from(self.summary.join(", "))),
+            _ => None,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_parse_dist_style() {
+        assert_eq!(parse_dist_style("EVEN"), RedshiftDistStyle::Even);
+        assert_eq!(parse_dist_style("ALL"), RedshiftDistStyle::All);
+        assert_eq!(
+            parse_dist_style("KEY(Customer_ID)"),
+            RedshiftDistStyle::Key("customer_id".to_string())
+        );
+        assert_eq!(parse_dist_style("AUTO(KEY(id))"), RedshiftDistStyle::Auto);
+        assert_eq!(parse_dist_style("AUTO(EVEN)"), RedshiftDistStyle::Auto);
+    }
+
+    #[test]
+    fn test_unconfigured_settings_are_not_compared() {
+        let old = RedshiftTableConfig {
+            dist: Some(RedshiftDistStyle::Even),
+            sort: Some(RedshiftSortStyle::Compound(vec!["id".to_string()])),
+        };
+        let changeset = RedshiftTableConfigChangeset::new(old, RedshiftTableConfig::default());
+        assert!(!changeset.has_changes());
+    }
+
+    #[test]
+    fn test_dist_and_compound_sort_changes_are_altered() {
+        let old = RedshiftTableConfig {
+            dist: Some(RedshiftDistStyle::Even),
+            sort: None,
+        };
+        let new = RedshiftTableConfig {
+            dist: Some(RedshiftDistStyle::Key("id".to_string())),
+            sort: Some(RedshiftSortStyle::Compound(vec![
+                "created_at".to_string(),
+                "id".to_string(),
+            ])),
+        };
+        let changeset = Arc::new(RedshiftTableConfigChangeset::new(old, new));
+        assert!(changeset.has_changes());
+        assert!(!changeset.requires_full_refresh());
+        let dist = changeset.get_value(&Value::from("dist")).unwrap();
+        assert_eq!(dist.get_attr("distkey").unwrap().as_str(), Some("id"));
+        assert_eq!(
+            changeset
+                .get_value(&Value::from("summary"))
+                .unwrap()
+                .as_str(),
+            Some("dist: even -> key(id), sort: none -> compound(created_at, id)")
+        );
+    }
+
+    #[test]
+    fn test_interleaved_sort_changes_require_full_refresh() {
+        let old = RedshiftTableConfig {
+            dist: None,
+            sort: Some(RedshiftSortStyle::Interleaved(vec![
+                "a".to_string(),
+                "b".to_string(),
+            ])),
+        };
+        let new = RedshiftTableConfig {
+            dist: None,
+            sort: Some(RedshiftSortStyle::Compound(vec!["a".to_string()])),
+        };
+        let changeset = RedshiftTableConfigChangeset::new(old.clone(), new);
+        assert!(changeset.requires_full_refresh());
+
+        let changeset = RedshiftTableConfigChangeset::new(old.clone(), old);
+        assert!(!changeset.has_changes());
+    }
+
+    #[test]
+    fn test_normalize_identifier() {
+        assert_eq!(normalize_identifier(" ID "), "id");
+        assert_eq!(normalize_identifier("\"Mixed\""), "Mixed");
+    }
+}
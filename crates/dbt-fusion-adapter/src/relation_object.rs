@@ -98,19 +98,27 @@ impl Object for RelationObject {
             "incorporate" => self.incorporate(args),
             "information_schema" => self.information_schema(args),
             "relation_max_name_length" => self.relation_max_name_length(args),
+            "truncated_identifier" => self.truncated_identifier(args),
             // Below are available for Snowflake
             "get_ddl_prefix_for_create" => self.get_ddl_prefix_for_create(args),
             "get_ddl_prefix_for_alter" => self.get_ddl_prefix_for_alter(),
             "needs_to_drop" => self.needs_to_drop(args),
             "get_iceberg_ddl_options" => self.get_iceberg_ddl_options(args),
             "dynamic_table_config_changeset" => self.dynamic_table_config_changeset(args),
+            "iceberg_table_config_changeset" => self.iceberg_table_config_changeset(args),
             "from_config" => self.from_config(args),
             // Below are available for Snowflake
             "is_iceberg_format" => Ok(self.is_iceberg_format()),
             // Below are available for Databricks
             "is_hive_metastore" => Ok(self.is_hive_metastore()),
-            // Below are available for BigQuery and Redshift
+            // Below are available for Redshift
+            "table_config_changeset" => self.table_config_changeset(args),
+            // Below are available for BigQuery, Redshift and Snowflake
             "materialized_view_config_changeset" => self.materialized_view_config_changeset(args),
+            // Below are available for Postgres
+            "get_materialized_view_config_change_collection" => {
+                self.get_materialized_view_config_change_collection(args)
+            }
             _ => Err(minijinja::Error::new(
                 minijinja::ErrorKind::InvalidOperation,
                 format!("Unknown method on BaseRelationObject: '{name}'"),
@@ -1,12 +1,16 @@
 use crate::AdapterType;
+use crate::errors::{AdapterError, AdapterErrorKind};
 
-use arrow::array::RecordBatch;
+use arrow::array::{Array as _, AsArray as _, RecordBatch};
+use arrow::compute::cast;
+use arrow::datatypes::{DataType, UInt64Type};
 use dbt_agate::AgateTable;
 use minijinja::Value;
 use minijinja::listener::RenderingEventListener;
 use minijinja::value::{Enumerator, Object};
 use minijinja::{Error as MinijinjaError, ErrorKind as MinijinjaErrorKind, State};
 use serde::{Deserialize, Serialize};
+use std::collections::BTreeMap;
 use std::rc::Rc;
 use std::sync::Arc;
 
@@ -25,20 +29,98 @@ pub struct AdapterResponse {
     /// Query ID of executed statement, if available
     #[serde(default)]
     pub query_id: Option<String>,
+    /// Bytes scanned by the statement (BigQuery)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes_processed: Option<u64>,
+    /// Bytes billed for the statement (BigQuery)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub bytes_billed: Option<u64>,
+    /// Slot milliseconds consumed by the statement (BigQuery)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub slot_ms: Option<u64>,
+    /// Rows inserted by a DML statement
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rows_inserted: Option<u64>,
+    /// Rows updated by a DML statement
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rows_updated: Option<u64>,
+    /// Rows deleted by a DML statement
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub rows_deleted: Option<u64>,
+    /// Location the statement ran in (BigQuery)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub location: Option<String>,
+}
+
+/// Names of the columns in which DML statements report affected row counts
+/// as (inserted, updated, deleted)
+fn dml_count_columns(adapter_type: AdapterType) -> Option<[&'static str; 3]> {
+    match adapter_type {
+        AdapterType::Snowflake => Some([
+            "number of rows inserted",
+            "number of rows updated",
+            "number of rows deleted",
+        ]),
+        AdapterType::Databricks => {
+            Some(["num_inserted_rows", "num_updated_rows", "num_deleted_rows"])
+        }
+        _ => None,
+    }
 }
 
 impl AdapterResponse {
     //! TODO: find references via searching for keywords `def get_response` in the dbt-adapters repository.
     //! These components all differ across adapters, implement as they're when necessary
     pub fn new(batch: &RecordBatch, adapter_type: AdapterType) -> Self {
-        Self {
+        let mut response = Self {
             message: Self::message(batch, adapter_type),
             code: Self::code(batch, adapter_type),
             rows_affected: batch.num_rows() as i64,
             query_id: Self::query_id(batch, adapter_type),
+            ..Default::default()
+        };
+        response.collect_statistics(batch, adapter_type);
+        response
+    }
+
+    /// Fill the DML row counts from the result of the statement, for the
+    /// backends that report them as columns of a single row.
+    fn collect_statistics(&mut self, batch: &RecordBatch, adapter_type: AdapterType) {
+        if let Some([inserted, updated, deleted]) = dml_count_columns(adapter_type)
+            && batch.num_rows() == 1
+        {
+            self.rows_inserted = dml_count(batch, inserted);
+            self.rows_updated = dml_count(batch, updated);
+            self.rows_deleted = dml_count(batch, deleted);
         }
     }
 
+    /// Response for a statement that was stopped before completing.
+    ///
+    /// The code is `TIMED_OUT` when the statement exceeded its
+    /// `query_timeout` and `CANCELLED` when the user cancelled it. Returns
+    /// `None` if `err` is neither, so callers can tell the two apart from
+    /// regular failures.
+    pub fn from_interruption(err: &AdapterError) -> Option<Self> {
+        let code = match err.kind() {
+            AdapterErrorKind::TimedOut => "TIMED_OUT",
+            AdapterErrorKind::Cancelled => "CANCELLED",
+            _ => return None,
+        };
+        Some(Self {
+            message: err.to_string(),
+            code: code.to_string(),
+            ..Default::default()
+        })
+    }
+
+    /// The response as recorded under `adapter_response` in run results.
+    pub fn to_run_result(&self) -> BTreeMap<String, dbt_serde_yaml::Value> {
+        dbt_serde_yaml::to_value(self)
+            .and_then(dbt_serde_yaml::from_value)
+            .unwrap_or_default()
+    }
+
     /// Get the message for the response from the batch.
     fn message(batch: &RecordBatch, _adapter_type: AdapterType) -> String {
         format!("{} {}", "SUCCESS", batch.num_rows())
@@ -79,12 +161,31 @@ impl Object for AdapterResponse {
             "code" => Some(Value::from(self.code.clone())),
             "rows_affected" => Some(Value::from(self.rows_affected)),
             "query_id" => Some(Value::from(self.query_id.clone())),
+            "bytes_processed" => Some(Value::from(self.bytes_processed)),
+            "bytes_billed" => Some(Value::from(self.bytes_billed)),
+            "slot_ms" => Some(Value::from(self.slot_ms)),
+            "rows_inserted" => Some(Value::from(self.rows_inserted)),
+            "rows_updated" => Some(Value::from(self.rows_updated)),
+            "rows_deleted" => Some(Value::from(self.rows_deleted)),
+            "location" => Some(Value::from(self.location.clone())),
             _ => None,
         }
     }
 
     fn enumerate(self: &Arc<Self>) -> Enumerator {
-        Enumerator::Str(&["message", "code", "rows_affected", "query_id"])
+        Enumerator::Str(&[
+            "message",
+            "code",
+            "rows_affected",
+            "query_id",
+            "bytes_processed",
+            "bytes_billed",
+            "slot_ms",
+            "rows_inserted",
+            "rows_updated",
+            "rows_deleted",
+            "location",
+        ])
     }
 }
 
@@ -97,9 +198,7 @@ impl TryFrom<Value> for AdapterResponse {
         } else if let Some(message_str) = value.as_str() {
             Ok(AdapterResponse {
                 message: message_str.to_string(),
-                code: "".to_string(),
-                rows_affected: 0,
-                query_id: None,
+                ..Default::default()
             })
         } else {
             Err(MinijinjaError::new(
@@ -110,6 +209,14 @@ impl TryFrom<Value> for AdapterResponse {
     }
 }
 
+/// Row count reported in `column` of a DML statement's result
+fn dml_count(batch: &RecordBatch, column: &str) -> Option<u64> {
+    let counts = batch.column_by_name(column)?;
+    let counts = cast(counts, &DataType::UInt64).ok()?;
+    let counts = counts.as_primitive::<UInt64Type>();
+    counts.is_valid(0).then(|| counts.value(0))
+}
+
 /// load_result response object
 #[derive(Debug)]
 pub struct ResultObject {
@@ -170,3 +277,53 @@ impl Object for ResultObject {
         Enumerator::Str(&["table", "data", "response"])
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use arrow::array::Int64Array;
+    use arrow_schema::{Field, Schema};
+
+    #[test]
+    fn test_dml_row_counts() {
+        let schema = Schema::new(vec![
+            Field::new("number of rows inserted", DataType::Int64, false),
+            Field::new("number of rows updated", DataType::Int64, false),
+        ]);
+        let batch = RecordBatch::try_new(
+            Arc::new(schema),
+            vec![
+                Arc::new(Int64Array::from(vec![3])),
+                Arc::new(Int64Array::from(vec![4])),
+            ],
+        )
+        .unwrap();
+
+        let response = AdapterResponse::new(&batch, AdapterType::Snowflake);
+        assert_eq!(response.rows_inserted, Some(3));
+        assert_eq!(response.rows_updated, Some(4));
+        assert_eq!(response.rows_deleted, None);
+        // The counts don't replace the rows the driver reported
+        assert_eq!(response.rows_affected, 1);
+
+        // Other backends don't report DML counts in the result
+        let response = AdapterResponse::new(&batch, AdapterType::Postgres);
+        assert_eq!(response.rows_inserted, None);
+        assert_eq!(response.rows_affected, 1);
+    }
+
+    #[test]
+    fn test_interruption_codes_reach_run_results() {
+        let timed_out = AdapterError::new(AdapterErrorKind::TimedOut, "exceeded the query_timeout");
+        let response = AdapterResponse::from_interruption(&timed_out).unwrap();
+        assert_eq!(response.to_run_result()["code"].as_str(), Some("TIMED_OUT"));
+
+        let cancelled = AdapterError::new(AdapterErrorKind::Cancelled, "cancelled");
+        let response = AdapterResponse::from_interruption(&cancelled).unwrap();
+        assert_eq!(response.to_run_result()["code"].as_str(), Some("CANCELLED"));
+
+        // Regular failures are not interruptions
+        let failed = AdapterError::new(AdapterErrorKind::Driver, "syntax error");
+        assert!(AdapterResponse::from_interruption(&failed).is_none());
+    }
+}
@@ -0,0 +1,768 @@
+//! Error-classified retries for statement execution.
+//!
+//! Errors returned by the warehouse are classified per backend into
+//! [ErrorClass]es. Only the classes listed in the profile's `retry_on` are
+//! retried, with exponential backoff and jitter between attempts, and only
+//! up to `statement_retries` times (none by default). Cancellation and
+//! `query_timeout` interruptions are never retried, and neither are
+//! statements that may modify data once they were submitted, as they may have
+//! been applied before the error was reported. Errors raised before
+//! submission (e.g. when connecting) are retried for every statement.
+
+use crate::AdapterType;
+use crate::errors::{AdapterError, AdapterErrorKind, AdapterResult};
+use crate::sql_engine::{Options, SqlEngine, StatementError};
+
+use arrow::array::RecordBatch;
+use dbt_common::cancellation::CancellationToken;
+use dbt_serde_yaml::Value as YmlValue;
+use dbt_xdbc::{Connection, QueryCtx};
+use minijinja::State;
+use rand::Rng as _;
+
+use std::collections::BTreeSet;
+use std::fmt;
+use std::str::FromStr;
+use std::thread;
+use std::time::{Duration, Instant};
+
+/// Profile key with the error classes that are retried.
+const RETRY_ON: &str = "retry_on";
+/// Profile key with the upper bound (in seconds) for the wait between attempts.
+const MAX_BACKOFF: &str = "max_backoff";
+/// Profile key with the number of retries after the first attempt.
+///
+/// Distinct from `retries`, which dbt-postgres and dbt-redshift use for
+/// connection attempts.
+const STATEMENT_RETRIES: &str = "statement_retries";
+/// Profile key that makes every error retryable (dbt-redshift and dbt-snowflake compatible).
+const RETRY_ALL: &str = "retry_all";
+
+const DEFAULT_RETRIES: u32 = 0;
+const DEFAULT_BASE_BACKOFF: Duration = Duration::from_secs(1);
+const DEFAULT_MAX_BACKOFF: Duration = Duration::from_secs(60);
+/// How often a backoff wait checks for cancellation.
+const CANCELLATION_POLL_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Retry-relevant classification of an [AdapterError].
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord)]
+pub enum ErrorClass {
+    /// Network failures and temporary unavailability of the service.
+    Transient,
+    /// Rate limits, quotas and HTTP 429 responses.
+    Throttled,
+    /// The warehouse or cluster is resuming or starting up.
+    Resuming,
+    /// Deadlocks, serialization failures and concurrent update conflicts.
+    Deadlock,
+    /// Errors that fail the same way on every attempt, e.g. syntax errors.
+    Permanent,
+}
+
+impl ErrorClass {
+    /// Classes retried when a profile doesn't configure `retry_on`.
+    pub const RETRYABLE: [ErrorClass; 4] = [
+        ErrorClass::Transient,
+        ErrorClass::Throttled,
+        ErrorClass::Resuming,
+        ErrorClass::Deadlock,
+    ];
+
+    pub fn as_str(&self) -> &'static str {
+        match self {
+            ErrorClass::Transient => "transient",
+            ErrorClass::Throttled => "throttled",
+            ErrorClass::Resuming => "resuming",
+            ErrorClass::Deadlock => "deadlock",
+            ErrorClass::Permanent => "permanent",
+        }
+    }
+
+    /// Classify an error returned by the given backend.
+    pub fn classify(adapter_type: AdapterType, err: &AdapterError) -> Self {
+        match err.kind() {
+            AdapterErrorKind::SqlExecution | AdapterErrorKind::Driver | AdapterErrorKind::Io => {}
+            _ => return ErrorClass::Permanent,
+        }
+
+        let sqlstate = err.sqlstate();
+        if sqlstate.starts_with("08") {
+            // connection_exception
+            return ErrorClass::Transient;
+        }
+        match sqlstate {
+            // serialization_failure, deadlock_detected
+            "40001" | "40P01" => return ErrorClass::Deadlock,
+            // too_many_connections
+            "53300" => return ErrorClass::Throttled,
+            // cannot_connect_now (the server is starting up)
+            "57P03" => return ErrorClass::Resuming,
+            // admin_shutdown, crash_shutdown
+            "57P01" | "57P02" => return ErrorClass::Transient,
+            _ => {}
+        }
+
+        let message = err.message().to_ascii_lowercase();
+        let contains_any = |patterns: &[&str]| patterns.iter().any(|p| message.contains(p));
+        let backend_class = match adapter_type {
+            AdapterType::Snowflake => {
+                if contains_any(&["is resuming", "warehouse is starting"]) {
+                    Some(ErrorClass::Resuming)
+                } else if contains_any(&[
+                    "deadlock",
+                    "number of waiters for this lock exceeds",
+                    "transaction was aborted",
+                ]) {
+                    Some(ErrorClass::Deadlock)
+                } else {
+                    None
+                }
+            }
+            AdapterType::Bigquery => {
+                if contains_any(&["ratelimitexceeded", "jobratelimitexceeded"]) {
+                    Some(ErrorClass::Throttled)
+                } else if contains_any(&["backenderror", "internalerror"]) {
+                    Some(ErrorClass::Transient)
+                } else if contains_any(&["due to concurrent update", "could not serialize access"])
+                {
+                    Some(ErrorClass::Deadlock)
+                } else {
+                    None
+                }
+            }
+            AdapterType::Databricks => {
+                if contains_any(&["resource_exhausted", "request_limit_exceeded"]) {
+                    Some(ErrorClass::Throttled)
+                } else if contains_any(&[
+                    "cluster is starting",
+                    "warehouse is starting",
+                    "is not running",
+                ]) {
+                    Some(ErrorClass::Resuming)
+                } else if contains_any(&["concurrentappendexception", "concurrent_append"]) {
+                    Some(ErrorClass::Deadlock)
+                } else if contains_any(&["temporarily_unavailable"]) {
+                    Some(ErrorClass::Transient)
+                } else {
+                    None
+                }
+            }
+            AdapterType::Redshift => {
+                if contains_any(&["serializable isolation violation"]) {
+                    Some(ErrorClass::Deadlock)
+                } else if contains_any(&["is resuming", "workgroup is not available"]) {
+                    Some(ErrorClass::Resuming)
+                } else {
+                    None
+                }
+            }
+            _ => None,
+        };
+        if let Some(class) = backend_class {
+            return class;
+        }
+
+        if contains_any(&["http 429", "too many requests", "rate limit", "throttl"]) {
+            ErrorClass::Throttled
+        } else if contains_any(&["deadlock"]) {
+            ErrorClass::Deadlock
+        } else if contains_any(&[
+            "connection reset",
+            "connection refused",
+            "connection closed",
+            "broken pipe",
+            "unexpected eof",
+            "temporarily unavailable",
+            "service unavailable",
+            "bad gateway",
+            "gateway timeout",
+        ]) {
+            ErrorClass::Transient
+        } else {
+            ErrorClass::Permanent
+        }
+    }
+}
+
+impl fmt::Display for ErrorClass {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{}", self.as_str())
+    }
+}
+
+impl FromStr for ErrorClass {
+    type Err = AdapterError;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        match s.trim().to_ascii_lowercase().as_str() {
+            "transient" => Ok(ErrorClass::Transient),
+            "throttled" => Ok(ErrorClass::Throttled),
+            "resuming" => Ok(ErrorClass::Resuming),
+            "deadlock" => Ok(ErrorClass::Deadlock),
+            "permanent" => Ok(ErrorClass::Permanent),
+            other => Err(AdapterError::new(
+                AdapterErrorKind::Configuration,
+                format!(
+                    "Unknown error class '{other}' in {RETRY_ON}, expected one of: transient, throttled, resuming, deadlock, permanent"
+                ),
+            )),
+        }
+    }
+}
+
+/// When and how often to retry a failed statement.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct RetryPolicy {
+    /// Number of retries after the first attempt.
+    pub retries: u32,
+    /// Error classes that are retried.
+    pub retry_on: BTreeSet<ErrorClass>,
+    /// Wait before the first retry. Doubles on every retry.
+    pub base_backoff: Duration,
+    /// Upper bound for the wait between two attempts.
+    pub max_backoff: Duration,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            retries: DEFAULT_RETRIES,
+            retry_on: ErrorClass::RETRYABLE.into_iter().collect(),
+            base_backoff: DEFAULT_BASE_BACKOFF,
+            max_backoff: DEFAULT_MAX_BACKOFF,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// A policy that never retries.
+    pub fn never() -> Self {
+        Self {
+            retries: 0,
+            ..Self::default()
+        }
+    }
+
+    /// Build the policy from the `statement_retries`, `retry_on`, `retry_all`
+    /// and `max_backoff` settings of the engine's profile.
+    pub fn from_engine(engine: &SqlEngine) -> AdapterResult<Self> {
+        let mut policy = Self::default();
+        if let SqlEngine::Mock(_) = engine {
+            return Ok(policy);
+        }
+        if let SqlEngine::Replay(_) = engine {
+            // Replayed errors are deterministic: don't wait between attempts.
+            policy.base_backoff = Duration::ZERO;
+        }
+        let config = engine.get_config();
+
+        if let Some(retries) = config.get_string(STATEMENT_RETRIES) {
+            policy.retries = parse_non_negative(STATEMENT_RETRIES, &retries)? as u32;
+        }
+        if let Some(max_backoff) = config.get_string(MAX_BACKOFF) {
+            policy.max_backoff =
+                Duration::from_secs(parse_non_negative(MAX_BACKOFF, &max_backoff)?);
+        }
+        if let Some(retry_on) = config.get(RETRY_ON) {
+            policy.retry_on = parse_retry_on(retry_on)?;
+        }
+        if config
+            .get_string(RETRY_ALL)
+            .is_some_and(|v| v.eq_ignore_ascii_case("true"))
+        {
+            policy.retry_on.insert(ErrorClass::Permanent);
+        }
+        Ok(policy)
+    }
+
+    /// Whether an error of the given class is retried after `attempt` attempts.
+    pub fn should_retry(&self, class: ErrorClass, attempt: u32) -> bool {
+        attempt <= self.retries && self.retry_on.contains(&class)
+    }
+
+    /// Wait before retrying after the given (1-based) failed attempt.
+    ///
+    /// The wait grows exponentially and is jittered between half and all of
+    /// the capped value so that concurrent retries don't synchronize.
+    pub fn backoff(&self, attempt: u32) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(16);
+        let capped = self
+            .base_backoff
+            .saturating_mul(1 << exponent)
+            .min(self.max_backoff);
+        if capped.is_zero() {
+            return capped;
+        }
+        let half = capped / 2;
+        half + rand::rng().random_range(Duration::ZERO..=capped - half)
+    }
+}
+
+fn parse_non_negative(key: &str, value: &str) -> AdapterResult<u64> {
+    value.trim().parse::<u64>().map_err(|e| {
+        AdapterError::new(
+            AdapterErrorKind::Configuration,
+            format!("{key} must be a non-negative integer: {e}"),
+        )
+    })
+}
+
+/// `retry_on` is a list of error classes.
+fn parse_retry_on(value: &YmlValue) -> AdapterResult<BTreeSet<ErrorClass>> {
+    let invalid = || {
+        AdapterError::new(
+            AdapterErrorKind::Configuration,
+            format!("{RETRY_ON} must be a list of error classes"),
+        )
+    };
+    let YmlValue::Sequence(items, _) = value else {
+        return Err(invalid());
+    };
+    items
+        .iter()
+        .map(|item| item.as_str().ok_or_else(invalid)?.parse())
+        .collect()
+}
+
+/// Whether running `sql` again after a failure cannot change the outcome,
+/// i.e. whether it only reads.
+///
+/// A failed write may have been applied before the error reached the client
+/// (e.g. when the connection is reset while committing), so retrying it could
+/// apply it twice.
+pub fn is_idempotent(sql: &str) -> bool {
+    let words = sql_words(sql);
+    let reads = matches!(
+        words.first().map(String::as_str),
+        Some("select" | "with" | "show" | "describe" | "desc" | "explain" | "values")
+    );
+    // Postgres allows data-modifying statements in WITH and `select .. into`
+    reads
+        && !words.iter().any(|word| {
+            matches!(
+                word.as_str(),
+                "insert" | "update" | "delete" | "merge" | "into" | "copy" | "analyze"
+            )
+        })
+}
+
+/// Whether the warehouse reported that the connection could not be
+/// established, so no statement reached it.
+fn rejected_before_submission(err: &AdapterError) -> bool {
+    // sqlclient_unable_to_establish_sqlconnection,
+    // sqlserver_rejected_establishment_of_sqlconnection
+    matches!(err.sqlstate(), "08001" | "08004")
+}
+
+/// The lowercased words of `sql`, ignoring comments.
+fn sql_words(sql: &str) -> Vec<String> {
+    let mut words = Vec::new();
+    let mut rest = sql;
+    while !rest.is_empty() {
+        if let Some(comment) = rest.strip_prefix("--") {
+            rest = comment.split_once('\n').map_or("", |(_, rest)| rest);
+        } else if let Some(comment) = rest.strip_prefix("/*") {
+            rest = comment.split_once("*/").map_or("", |(_, rest)| rest);
+        } else {
+            let end = rest
+                .find(|c: char| !(c.is_alphanumeric() || c == '_'))
+                .unwrap_or(rest.len());
+            if end > 0 {
+                words.push(rest[..end].to_ascii_lowercase());
+                rest = &rest[end..];
+            } else {
+                let next = rest.chars().next().map_or(0, char::len_utf8);
+                rest = &rest[next..];
+            }
+        }
+    }
+    words
+}
+
+/// Sleep for `duration` unless the token is cancelled first.
+///
+/// Returns `false` if the wait was cut short by cancellation.
+fn sleep_unless_cancelled(token: &CancellationToken, duration: Duration) -> bool {
+    let deadline = Instant::now() + duration;
+    loop {
+        if token.is_cancelled() {
+            return false;
+        }
+        let now = Instant::now();
+        if now >= deadline {
+            return true;
+        }
+        thread::sleep((deadline - now).min(CANCELLATION_POLL_INTERVAL));
+    }
+}
+
+/// Execute a statement, retrying it according to `policy`.
+///
+/// https://github.com/dbt-labs/dbt-adapters/blob/996a302fa9107369eb30d733dadfaf307023f33d/dbt-adapters/src/dbt/adapters/sql/connections.py#L84
+pub fn execute_with_retry(
+    engine: &SqlEngine,
+    state: Option<&State>,
+    conn: &'_ mut dyn Connection,
+    query_ctx: &QueryCtx,
+    policy: &RetryPolicy,
+    options: &Options,
+    fetch: bool,
+) -> AdapterResult<RecordBatch> {
+    let token = engine.cancellation_token();
+    let mut attempt = 1;
+    loop {
+        // Only number the attempts in telemetry when retries are possible.
+        let attempt_label = (policy.retries > 0).then_some(attempt);
+        let StatementError {
+            error: err,
+            submitted,
+        } = match engine.try_execute_statement(
+            state,
+            query_ctx,
+            conn,
+            None,
+            options.clone(),
+            fetch,
+            attempt_label,
+        ) {
+            Ok(batch) => return Ok(batch),
+            Err(err) => err,
+        };
+        if matches!(
+            err.kind(),
+            AdapterErrorKind::Cancelled | AdapterErrorKind::TimedOut
+        ) {
+            return Err(err);
+        }
+
+        let class = ErrorClass::classify(engine.adapter_type(), &err);
+        let sql = query_ctx.sql().unwrap_or_default();
+        if !policy.should_retry(class, attempt)
+            || (submitted && !rejected_before_submission(&err) && !is_idempotent(&sql))
+        {
+            return Err(err);
+        }
+        let backoff = policy.backoff(attempt);
+        log::debug!(
+            "Retrying query after {class} error (attempt {} of {}) in {:.1}s: {}",
+            attempt + 1,
+            policy.retries + 1,
+            backoff.as_secs_f64(),
+            err.message()
+        );
+        if !sleep_unless_cancelled(&token, backoff) {
+            return Err(AdapterError::new(
+                AdapterErrorKind::Cancelled,
+                "SQL statement execution was cancelled",
+            ));
+        }
+        attempt += 1;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::config::AdapterConfig;
+    use crate::query_comment::QueryCommentConfig;
+    use crate::sql_types::NaiveTypeOpsImpl;
+    use crate::stmt_splitter::DialectStmtSplitter;
+    use adbc_core::PartitionedResult;
+    use adbc_core::error::{Error as AdbcError, Result as AdbcResult, Status};
+    use arrow::array::{RecordBatchIterator, RecordBatchReader};
+    use arrow_schema::Schema;
+    use dbt_auth::auth_for_backend;
+    use dbt_common::cancellation::never_cancels;
+    use dbt_schemas::schemas::relations::DEFAULT_RESOLVED_QUOTING;
+    use dbt_xdbc::{Backend, Statement};
+    use std::sync::{Arc, Mutex};
+
+    /// How often a [FlakyConnection] fails before and after submission
+    #[derive(Default)]
+    struct Failures {
+        new_statement: u32,
+        execute: u32,
+        executed: u32,
+    }
+
+    /// Connection that fails with a connection error until its failures
+    /// are used up
+    struct FlakyConnection(Arc<Mutex<Failures>>);
+
+    struct FlakyStatement(Arc<Mutex<Failures>>);
+
+    fn connection_reset() -> AdbcError {
+        AdbcError::with_message_and_status("connection reset by peer", Status::IO)
+    }
+
+    impl Connection for FlakyConnection {
+        fn new_statement(&mut self) -> AdbcResult<Box<dyn Statement>> {
+            let mut failures = self.0.lock().unwrap();
+            if failures.new_statement > 0 {
+                failures.new_statement -= 1;
+                return Err(connection_reset());
+            }
+            Ok(Box::new(FlakyStatement(self.0.clone())))
+        }
+
+        fn cancel(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+
+        fn commit(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+
+        fn rollback(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+
+        fn get_table_schema(
+            &self,
+            _catalog: Option<&str>,
+            _db_schema: Option<&str>,
+            _table_name: &str,
+        ) -> AdbcResult<Schema> {
+            unimplemented!()
+        }
+
+        fn update_node_id(&mut self, _node_id: Option<String>) {}
+    }
+
+    impl Statement for FlakyStatement {
+        fn bind(&mut self, _batch: RecordBatch) -> AdbcResult<()> {
+            unimplemented!()
+        }
+
+        fn bind_stream(&mut self, _reader: Box<dyn RecordBatchReader + Send>) -> AdbcResult<()> {
+            unimplemented!()
+        }
+
+        fn execute<'a>(&'a mut self) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'a>> {
+            let mut failures = self.0.lock().unwrap();
+            failures.executed += 1;
+            if failures.execute > 0 {
+                failures.execute -= 1;
+                return Err(connection_reset());
+            }
+            Ok(Box::new(RecordBatchIterator::new(
+                vec![],
+                Arc::new(Schema::empty()),
+            )))
+        }
+
+        fn execute_update(&mut self) -> AdbcResult<Option<i64>> {
+            unimplemented!()
+        }
+
+        fn execute_schema(&mut self) -> AdbcResult<Schema> {
+            unimplemented!()
+        }
+
+        fn execute_partitions(&mut self) -> AdbcResult<PartitionedResult> {
+            unimplemented!()
+        }
+
+        fn get_parameter_schema(&self) -> AdbcResult<Schema> {
+            unimplemented!()
+        }
+
+        fn prepare(&mut self) -> AdbcResult<()> {
+            unimplemented!()
+        }
+
+        fn set_sql_query(&mut self, _query: &QueryCtx) -> AdbcResult<()> {
+            Ok(())
+        }
+
+        fn set_substrait_plan(&mut self, _plan: &[u8]) -> AdbcResult<()> {
+            unimplemented!()
+        }
+
+        fn cancel(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+    }
+
+    fn driver_error(message: &str, sqlstate: &[u8; 5]) -> AdapterError {
+        AdapterError::new_with_sqlstate_and_vendor_code(
+            AdapterErrorKind::Driver,
+            message.to_string(),
+            *sqlstate,
+            None,
+        )
+    }
+
+    #[test]
+    fn test_classify() {
+        let cases = [
+            (
+                AdapterType::Postgres,
+                driver_error("deadlock detected", b"40P01"),
+                ErrorClass::Deadlock,
+            ),
+            (
+                AdapterType::Postgres,
+                driver_error("server closed the connection", b"08006"),
+                ErrorClass::Transient,
+            ),
+            (
+                AdapterType::Postgres,
+                driver_error("syntax error at or near", b"42601"),
+                ErrorClass::Permanent,
+            ),
+            (
+                AdapterType::Snowflake,
+                driver_error("Warehouse 'WH' is resuming", b"00000"),
+                ErrorClass::Resuming,
+            ),
+            (
+                AdapterType::Bigquery,
+                driver_error("rateLimitExceeded: too many table updates", b"00000"),
+                ErrorClass::Throttled,
+            ),
+            (
+                AdapterType::Databricks,
+                driver_error(
+                    "[DELTA_CONCURRENT_APPEND] ConcurrentAppendException",
+                    b"00000",
+                ),
+                ErrorClass::Deadlock,
+            ),
+            (
+                AdapterType::Redshift,
+                driver_error("1023: Serializable isolation violation on table", b"00000"),
+                ErrorClass::Deadlock,
+            ),
+            (
+                AdapterType::Snowflake,
+                driver_error("HTTP 429 Too Many Requests", b"00000"),
+                ErrorClass::Throttled,
+            ),
+            (
+                AdapterType::Snowflake,
+                AdapterError::new(AdapterErrorKind::Authentication, "connection reset"),
+                ErrorClass::Permanent,
+            ),
+        ];
+        for (adapter_type, err, expected) in cases {
+            assert_eq!(
+                ErrorClass::classify(adapter_type, &err),
+                expected,
+                "{adapter_type}: {}",
+                err.message()
+            );
+        }
+    }
+
+    #[test]
+    fn test_parse_retry_on() {
+        let list = dbt_serde_yaml::from_str::<YmlValue>("[Throttled, deadlock]").unwrap();
+        assert_eq!(
+            parse_retry_on(&list).unwrap(),
+            BTreeSet::from([ErrorClass::Throttled, ErrorClass::Deadlock])
+        );
+        // Profiles type retry_on as a list, a string is rejected
+        assert!(parse_retry_on(&YmlValue::string("transient, resuming".to_string())).is_err());
+        let empty = dbt_serde_yaml::from_str::<YmlValue>("[]").unwrap();
+        assert!(parse_retry_on(&empty).unwrap().is_empty());
+        let unknown = dbt_serde_yaml::from_str::<YmlValue>("[sometimes]").unwrap();
+        assert!(parse_retry_on(&unknown).is_err());
+    }
+
+    #[test]
+    fn test_is_idempotent() {
+        for sql in [
+            "select 1",
+            "  /* dbt */\n-- introspection\nSELECT * from t",
+            "with recent as (select * from orders) select count(*) from recent",
+            "show terse objects in schema analytics",
+            "(select 1) union all (select 2)",
+        ] {
+            assert!(is_idempotent(sql), "{sql}");
+        }
+        for sql in [
+            "insert into t select 1",
+            "-- select\ndelete from t",
+            "with stale as (delete from t returning *) select count(*) from stale",
+            "select * into backup from t",
+            "create table t as select 1",
+            "merge into t using s on t.id = s.id when matched then delete",
+            "",
+        ] {
+            assert!(!is_idempotent(sql), "{sql}");
+        }
+    }
+
+    #[test]
+    fn test_writes_are_retried_only_before_submission() {
+        let engine = SqlEngine::new(
+            AdapterType::Postgres,
+            auth_for_backend(Backend::Postgres).into(),
+            AdapterConfig::new(Default::default()),
+            DEFAULT_RESOLVED_QUOTING,
+            Arc::new(DialectStmtSplitter),
+            None,
+            QueryCommentConfig::from_query_comment(None, AdapterType::Postgres, false),
+            Box::new(NaiveTypeOpsImpl::new(AdapterType::Postgres)),
+            never_cancels(),
+        );
+        let policy = RetryPolicy {
+            retries: 2,
+            base_backoff: Duration::ZERO,
+            ..RetryPolicy::default()
+        };
+        let query_ctx = QueryCtx::new("postgres").with_sql("insert into t values (1)");
+        let execute = |failures: Failures| {
+            let failures = Arc::new(Mutex::new(failures));
+            let mut conn = FlakyConnection(failures.clone());
+            let result = execute_with_retry(
+                &engine,
+                None,
+                &mut conn,
+                &query_ctx,
+                &policy,
+                &vec![],
+                false,
+            );
+            let executed = failures.lock().unwrap().executed;
+            (result, executed)
+        };
+
+        // The insert never reached the warehouse: retrying it is safe
+        let (result, executed) = execute(Failures {
+            new_statement: 2,
+            ..Failures::default()
+        });
+        assert!(result.is_ok());
+        assert_eq!(executed, 1);
+
+        // The insert may have been applied before the connection was reset
+        let (result, executed) = execute(Failures {
+            execute: 1,
+            ..Failures::default()
+        });
+        assert!(result.is_err());
+        assert_eq!(executed, 1);
+    }
+
+    #[test]
+    fn test_backoff() {
+        let policy = RetryPolicy {
+            retries: 5,
+            max_backoff: Duration::from_secs(5),
+            ..RetryPolicy::default()
+        };
+        for attempt in 1..=5 {
+            let capped = Duration::from_secs(1 << (attempt - 1)).min(policy.max_backoff);
+            let backoff = policy.backoff(attempt);
+            assert!(backoff >= capped / 2 && backoff <= capped, "{backoff:?}");
+        }
+
+        assert!(policy.should_retry(ErrorClass::Transient, 5));
+        assert!(!policy.should_retry(ErrorClass::Transient, 6));
+        assert!(!policy.should_retry(ErrorClass::Permanent, 1));
+        assert!(!RetryPolicy::never().should_retry(ErrorClass::Transient, 1));
+        assert!(!RetryPolicy::default().should_retry(ErrorClass::Transient, 1));
+    }
+}
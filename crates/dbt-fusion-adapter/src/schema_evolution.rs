@@ -0,0 +1,587 @@
+//! Planning of `on_schema_change` for incremental models.
+//!
+//! Compares the columns of the relation produced by the model (the source) with
+//! the columns of the existing incremental relation (the target) and produces an
+//! ordered [SchemaChangePlan]: the columns to add, the columns to drop and the
+//! columns whose type changes. Type changes are classified as safe widenings
+//! (no value can be lost) or unsafe, and every change can be rendered as DDL in
+//! the dialect of the adapter.
+
+use std::mem::discriminant;
+use std::sync::Arc;
+
+use dbt_common::adapter::AdapterType;
+use dbt_schemas::schemas::common::OnSchemaChange;
+use dbt_xdbc::sql::types::SqlType;
+use minijinja::Value;
+use minijinja::listener::RenderingEventListener;
+use minijinja::value::Object;
+use serde::Serialize;
+
+use crate::columns::StdColumn;
+use crate::sql_types::{TypeOps, parse_nullable_sql_type};
+
+/// Suffix of the temporary column used to rewrite a column with a new type
+const TMP_COLUMN_SUFFIX: &str = "__dbt_alter";
+
+/// Whether a type change can lose or reject existing values
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
+pub enum TypeChangeSafety {
+    /// Every value of the old type is representable in the new type
+    SafeWidening,
+    /// Values may be truncated, rounded or fail to cast
+    Unsafe,
+}
+
+/// A single change of a [SchemaChangePlan]
+#[derive(Debug, Clone, PartialEq, Eq, Serialize)]
+#[serde(tag = "action", rename_all = "snake_case")]
+pub enum SchemaChange {
+    AddColumn {
+        name: String,
+        data_type: String,
+    },
+    DropColumn {
+        name: String,
+        data_type: String,
+    },
+    AlterColumnType {
+        name: String,
+        from: String,
+        to: String,
+        safety: TypeChangeSafety,
+    },
+}
+
+impl SchemaChange {
+    pub fn name(&self) -> &str {
+        match self {
+            SchemaChange::AddColumn { name, .. }
+            | SchemaChange::DropColumn { name, .. }
+            | SchemaChange::AlterColumnType { name, .. } => name,
+        }
+    }
+
+    pub fn is_safe(&self) -> bool {
+        match self {
+            SchemaChange::AddColumn { .. } => true,
+            SchemaChange::DropColumn { .. } => false,
+            SchemaChange::AlterColumnType { safety, .. } => {
+                *safety == TypeChangeSafety::SafeWidening
+            }
+        }
+    }
+}
+
+impl std::fmt::Display for SchemaChange {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        match self {
+            SchemaChange::AddColumn { name, data_type } => write!(f, "add {name} {data_type}"),
+            SchemaChange::DropColumn { name, data_type } => write!(f, "drop {name} {data_type}"),
+            SchemaChange::AlterColumnType {
+                name,
+                from,
+                to,
+                safety,
+            } => {
+                let safety = match safety {
+                    TypeChangeSafety::SafeWidening => "safe widening",
+                    TypeChangeSafety::Unsafe => "unsafe",
+                };
+                write!(f, "alter {name} {from} -> {to} ({safety})")
+            }
+        }
+    }
+}
+
+/// The ordered changes bringing the columns of an incremental relation in line
+/// with the columns its model now produces
+///
+/// Columns are added first (in the order of the source), then dropped (in the
+/// order of the target), then retyped (in the order of the source).
+#[derive(Debug, Clone)]
+pub struct SchemaChangePlan {
+    adapter_type: AdapterType,
+    on_schema_change: OnSchemaChange,
+    changes: Vec<SchemaChange>,
+    /// Source columns missing from the target, including those the
+    /// `on_schema_change` mode does not act on
+    source_not_in_target: Vec<StdColumn>,
+    /// Target columns missing from the source
+    target_not_in_source: Vec<StdColumn>,
+}
+
+impl SchemaChangePlan {
+    pub fn new(
+        type_ops: &dyn TypeOps,
+        on_schema_change: OnSchemaChange,
+        source: &[StdColumn],
+        target: &[StdColumn],
+    ) -> Self {
+        let adapter_type = type_ops.adapter_type();
+        let find = |columns: &[StdColumn], name: &str| {
+            columns
+                .iter()
+                .find(|column| column.name().eq_ignore_ascii_case(name))
+                .cloned()
+        };
+
+        let source_not_in_target: Vec<_> = source
+            .iter()
+            .filter(|column| find(target, column.name()).is_none())
+            .cloned()
+            .collect();
+        let target_not_in_source: Vec<_> = target
+            .iter()
+            .filter(|column| find(source, column.name()).is_none())
+            .cloned()
+            .collect();
+
+        let mut changes: Vec<_> = source_not_in_target
+            .iter()
+            .map(|column| SchemaChange::AddColumn {
+                name: column.name().to_string(),
+                data_type: column.data_type(),
+            })
+            .collect();
+        changes.extend(
+            target_not_in_source
+                .iter()
+                .map(|column| SchemaChange::DropColumn {
+                    name: column.name().to_string(),
+                    data_type: column.data_type(),
+                }),
+        );
+        for column in source {
+            let Some(existing) = find(target, column.name()) else {
+                continue;
+            };
+            let (from, to) = (existing.data_type(), column.data_type());
+            if let Some(safety) = compare_types(type_ops, &from, &to) {
+                changes.push(SchemaChange::AlterColumnType {
+                    name: existing.name().to_string(),
+                    from,
+                    to,
+                    safety,
+                });
+            }
+        }
+
+        // Only `sync_all_columns` acts on anything but new columns; `fail`
+        // keeps every change so that it can be reported
+        changes.retain(|change| match on_schema_change {
+            OnSchemaChange::SyncAllColumns | OnSchemaChange::Fail => true,
+            OnSchemaChange::AppendNewColumns => matches!(change, SchemaChange::AddColumn { .. }),
+            OnSchemaChange::Ignore | OnSchemaChange::Unknown => false,
+        });
+
+        Self {
+            adapter_type,
+            on_schema_change,
+            changes,
+            source_not_in_target,
+            target_not_in_source,
+        }
+    }
+
+    pub fn changes(&self) -> &[SchemaChange] {
+        &self.changes
+    }
+
+    pub fn has_changes(&self) -> bool {
+        !self.changes.is_empty()
+    }
+
+    /// Whether applying the plan cannot lose data
+    pub fn is_safe(&self) -> bool {
+        self.changes.iter().all(SchemaChange::is_safe)
+    }
+
+    /// Render the DDL applying the plan to `relation`, one statement per entry
+    pub fn render(&self, relation: &str) -> Vec<String> {
+        self.changes
+            .iter()
+            .flat_map(|change| render_change(self.adapter_type, relation, change))
+            .collect()
+    }
+
+    /// Human readable summary of the plan, one change per line
+    pub fn summary(&self) -> String {
+        if self.changes.is_empty() {
+            return "no schema changes".to_string();
+        }
+        self.changes
+            .iter()
+            .map(|change| format!("  - {change}"))
+            .collect::<Vec<_>>()
+            .join("\n")
+    }
+}
+
+/// Classify the change from type `from` to type `to`, `None` if they are the same
+fn compare_types(type_ops: &dyn TypeOps, from: &str, to: &str) -> Option<TypeChangeSafety> {
+    let adapter_type = type_ops.adapter_type();
+    let parse = |s: &str| {
+        parse_nullable_sql_type(s, adapter_type)
+            .ok()
+            .map(|(t, _)| t)
+    };
+    let (Some(from_type), Some(to_type)) = (parse(from), parse(to)) else {
+        // Types we cannot parse are compared verbatim and assumed unsafe to change
+        return (!from.trim().eq_ignore_ascii_case(to.trim())).then_some(TypeChangeSafety::Unsafe);
+    };
+
+    let render = |t: &SqlType| {
+        let mut out = String::new();
+        type_ops
+            .format_sql_type(t.clone(), &mut out)
+            .ok()
+            .map(|_| out.to_ascii_lowercase())
+    };
+    match (render(&from_type), render(&to_type)) {
+        (Some(a), Some(b)) if a == b => return None,
+        (None, None) if from.trim().eq_ignore_ascii_case(to.trim()) => return None,
+        _ => {}
+    }
+
+    Some(if is_widening(adapter_type, &from_type, &to_type) {
+        TypeChangeSafety::SafeWidening
+    } else {
+        TypeChangeSafety::Unsafe
+    })
+}
+
+/// Digits before the decimal point held by an integer type
+fn integer_digits(t: &SqlType) -> Option<i16> {
+    match t {
+        SqlType::TinyInt => Some(3),
+        SqlType::SmallInt => Some(5),
+        SqlType::Integer => Some(10),
+        SqlType::BigInt => Some(19),
+        _ => None,
+    }
+}
+
+fn is_widening(adapter_type: AdapterType, from: &SqlType, to: &SqlType) -> bool {
+    use SqlType::*;
+    match (from, to) {
+        // integers to larger integers or to decimals with enough integer digits
+        (_, _) if integer_digits(from).is_some() && integer_digits(to).is_some() => {
+            integer_digits(from) < integer_digits(to)
+        }
+        (_, Numeric(Some((precision, scale))) | BigNumeric(Some((precision, scale))))
+            if integer_digits(from).is_some() =>
+        {
+            *precision as i16 - scale.unwrap_or(0) as i16 >= integer_digits(from).unwrap_or(0)
+        }
+        (_, Numeric(None) | BigNumeric(None)) if integer_digits(from).is_some() => true,
+
+        // decimals to decimals keeping every integer and fractional digit
+        (
+            Numeric(Some((p1, s1))) | BigNumeric(Some((p1, s1))),
+            Numeric(Some((p2, s2))) | BigNumeric(Some((p2, s2))),
+        ) => {
+            let (s1, s2) = (s1.unwrap_or(0) as i16, s2.unwrap_or(0) as i16);
+            s2 >= s1 && *p2 as i16 - s2 >= *p1 as i16 - s1
+        }
+        // an unconstrained Postgres numeric holds any decimal
+        (Numeric(_), Numeric(None)) => {
+            matches!(
+                adapter_type,
+                AdapterType::Postgres | AdapterType::Salesforce
+            )
+        }
+        (Numeric(_), BigNumeric(None)) => true,
+
+        // floating point to double precision
+        (Real | Float(_), Double) => true,
+
+        // strings to longer or unbounded strings
+        (Char(a) | Varchar(a, _), Varchar(b, _)) => match (a, b) {
+            (_, None) => true,
+            (Some(a), Some(b)) => a <= b,
+            (None, Some(_)) => false,
+        },
+        (Char(_) | Varchar(..) | Text, Text) => true,
+        (Binary(a), Binary(b)) => match (a, b) {
+            (_, None) => true,
+            (Some(a), Some(b)) => a <= b,
+            (None, Some(_)) => false,
+        },
+
+        // more fractional seconds in the same time zone handling
+        (
+            Timestamp {
+                precision: p1,
+                time_zone_spec: tz1,
+            },
+            Timestamp {
+                precision: p2,
+                time_zone_spec: tz2,
+            },
+        ) => discriminant(tz1) == discriminant(tz2) && p1.unwrap_or(0) <= p2.unwrap_or(u8::MAX),
+
+        _ => false,
+    }
+}
+
+fn quote(adapter_type: AdapterType, name: &str) -> String {
+    crate::columns::StdColumnType::new(adapter_type).quote(name)
+}
+
+fn render_change(adapter_type: AdapterType, relation: &str, change: &SchemaChange) -> Vec<String> {
+    match change {
+        SchemaChange::AddColumn { name, data_type } => {
+            let column = quote(adapter_type, name);
+            vec![match adapter_type {
+                AdapterType::Databricks => {
+                    format!("alter table {relation} add columns ({column} {data_type})")
+                }
+                _ => format!("alter table {relation} add column {column} {data_type}"),
+            }]
+        }
+        SchemaChange::DropColumn { name, .. } => {
+            let column = quote(adapter_type, name);
+            vec![format!("alter table {relation} drop column {column}")]
+        }
+        SchemaChange::AlterColumnType {
+            name, to, safety, ..
+        } => {
+            let column = quote(adapter_type, name);
+            if *safety == TypeChangeSafety::SafeWidening
+                && let Some(sql) = render_native_alter(adapter_type, relation, &column, to)
+            {
+                return vec![sql];
+            }
+            render_column_rewrite(adapter_type, relation, name, to)
+        }
+    }
+}
+
+/// In-place `ALTER COLUMN ... TYPE`, for the widenings the warehouse supports natively
+fn render_native_alter(
+    adapter_type: AdapterType,
+    relation: &str,
+    column: &str,
+    to: &str,
+) -> Option<String> {
+    match adapter_type {
+        AdapterType::Postgres | AdapterType::Databricks => Some(format!(
+            "alter table {relation} alter column {column} type {to}"
+        )),
+        // Redshift only widens varchar columns in place
+        AdapterType::Redshift => {
+            let to_lower = to.to_ascii_lowercase();
+            (to_lower.starts_with("character varying") || to_lower.starts_with("varchar"))
+                .then(|| format!("alter table {relation} alter column {column} type {to}"))
+        }
+        AdapterType::Snowflake | AdapterType::Bigquery => Some(format!(
+            "alter table {relation} alter column {column} set data type {to}"
+        )),
+        AdapterType::Salesforce => None,
+    }
+}
+
+/// Rewrite of a column through a temporary column, as dbt's `alter_column_type`
+fn render_column_rewrite(
+    adapter_type: AdapterType,
+    relation: &str,
+    name: &str,
+    to: &str,
+) -> Vec<String> {
+    let column = quote(adapter_type, name);
+    let tmp_column = quote(adapter_type, &format!("{name}{TMP_COLUMN_SUFFIX}"));
+    let add = match adapter_type {
+        AdapterType::Databricks => {
+            format!("alter table {relation} add columns ({tmp_column} {to})")
+        }
+        _ => format!("alter table {relation} add column {tmp_column} {to}"),
+    };
+    let update = match adapter_type {
+        // BigQuery requires a WHERE clause on UPDATE
+        AdapterType::Bigquery => {
+            format!("update {relation} set {tmp_column} = cast({column} as {to}) where true")
+        }
+        _ => format!("update {relation} set {tmp_column} = cast({column} as {to})"),
+    };
+    let drop = match adapter_type {
+        AdapterType::Postgres | AdapterType::Redshift => {
+            format!("alter table {relation} drop column {column} cascade")
+        }
+        _ => format!("alter table {relation} drop column {column}"),
+    };
+    let rename = format!("alter table {relation} rename column {tmp_column} to {column}");
+    vec![add, update, drop, rename]
+}
+
+impl Object for SchemaChangePlan {
+    fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+        let columns = |columns: &[StdColumn]| {
+            Value::from(
+                columns
+                    .iter()
+                    .map(|column| Value::from_object(column.clone()))
+                    .collect::<Vec<_>>(),
+            )
+        };
+        match key.as_str()? {
+            "changes" => Some(Value::from_serialize(&self.changes)),
+            "has_changes" | "schema_changed" => Some(Value::from(self.has_changes())),
+            "is_safe" => Some(Value::from(self.is_safe())),
+            "on_schema_change" => Some(Value::from_serialize(&self.on_schema_change)),
+            "source_not_in_target" => Some(columns(&self.source_not_in_target)),
+            "target_not_in_source" => Some(columns(&self.target_not_in_source)),
+            // same shape as dbt's `check_for_schema_changes`
+            "new_target_types" => Some(Value::from(
+                self.changes
+                    .iter()
+                    .filter_map(|change| match change {
+                        SchemaChange::AlterColumnType { name, to, .. } => {
+                            Some(Value::from_serialize(serde_json::json!({
+                                "column_name": name,
+                                "new_type": to,
+                            })))
+                        }
+                        _ => None,
+                    })
+                    .collect::<Vec<_>>(),
+            )),
+            "summary" => Some(Value::from(self.summary())),
+            _ => None,
+        }
+    }
+
+    fn call_method(
+        self: &Arc<Self>,
+        _state: &minijinja::State,
+        name: &str,
+        args: &[Value],
+        _listeners: &[std::rc::Rc<dyn RenderingEventListener>],
+    ) -> Result<Value, minijinja::Error> {
+        match name {
+            "render" => {
+                let [relation] = args else {
+                    return Err(minijinja::Error::new(
+                        minijinja::ErrorKind::InvalidOperation,
+                        "render() takes exactly one argument: the relation to alter",
+                    ));
+                };
+                Ok(Value::from(self.render(&relation.to_string())))
+            }
+            _ => Err(minijinja::Error::new(
+                minijinja::ErrorKind::InvalidOperation,
+                format!("Unknown method on SchemaChangePlan: '{name}'"),
+            )),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::sql_types::NaiveTypeOpsImpl;
+
+    fn column(name: &str, dtype: &str, char_size: Option<u32>) -> StdColumn {
+        StdColumn::new(
+            AdapterType::Postgres,
+            name.to_string(),
+            dtype.to_string(),
+            char_size,
+            None,
+            None,
+        )
+    }
+
+    fn numeric(name: &str, precision: u64, scale: u64) -> StdColumn {
+        StdColumn::new(
+            AdapterType::Postgres,
+            name.to_string(),
+            "numeric".to_string(),
+            None,
+            Some(precision),
+            Some(scale),
+        )
+    }
+
+    fn plan(on_schema_change: OnSchemaChange) -> SchemaChangePlan {
+        let type_ops = NaiveTypeOpsImpl::new(AdapterType::Postgres);
+        let source = vec![
+            column("id", "bigint", None),
+            column("name", "character varying", Some(512)),
+            numeric("amount", 12, 2),
+            column("status", "integer", None),
+            column("created_at", "timestamp without time zone", None),
+        ];
+        let target = vec![
+            column("ID", "integer", None),
+            column("name", "character varying", Some(256)),
+            numeric("amount", 10, 4),
+            column("status", "character varying", Some(16)),
+            column("legacy", "text", None),
+        ];
+        SchemaChangePlan::new(&type_ops, on_schema_change, &source, &target)
+    }
+
+    #[test]
+    fn test_plan_sync_all_columns() {
+        let plan = plan(OnSchemaChange::SyncAllColumns);
+        let summary: Vec<_> = plan.changes().iter().map(ToString::to_string).collect();
+        assert_eq!(
+            summary,
+            vec![
+                "add created_at timestamp without time zone",
+                "drop legacy character varying(256)",
+                "alter ID integer -> bigint (safe widening)",
+                "alter name character varying(256) -> character varying(512) (safe widening)",
+                "alter amount numeric(10,4) -> numeric(12,2) (unsafe)",
+                "alter status character varying(16) -> integer (unsafe)",
+            ]
+        );
+        assert!(!plan.is_safe());
+    }
+
+    #[test]
+    fn test_plan_respects_on_schema_change() {
+        let append = plan(OnSchemaChange::AppendNewColumns);
+        assert_eq!(append.changes().len(), 1);
+        assert!(append.is_safe());
+        assert!(!plan(OnSchemaChange::Ignore).has_changes());
+        assert_eq!(plan(OnSchemaChange::Fail).changes().len(), 6);
+    }
+
+    #[test]
+    fn test_render() {
+        let change = SchemaChange::AlterColumnType {
+            name: "status".to_string(),
+            from: "character varying(16)".to_string(),
+            to: "integer".to_string(),
+            safety: TypeChangeSafety::Unsafe,
+        };
+        assert_eq!(
+            render_change(AdapterType::Postgres, "db.s.t", &change),
+            vec![
+                "alter table db.s.t add column \"status__dbt_alter\" integer",
+                "update db.s.t set \"status__dbt_alter\" = cast(\"status\" as integer)",
+                "alter table db.s.t drop column \"status\" cascade",
+                "alter table db.s.t rename column \"status__dbt_alter\" to \"status\"",
+            ]
+        );
+
+        let widen = SchemaChange::AlterColumnType {
+            name: "ID".to_string(),
+            from: "NUMBER(10,0)".to_string(),
+            to: "NUMBER(20,0)".to_string(),
+            safety: TypeChangeSafety::SafeWidening,
+        };
+        assert_eq!(
+            render_change(AdapterType::Snowflake, "DB.S.T", &widen),
+            vec!["alter table DB.S.T alter column \"ID\" set data type NUMBER(20,0)"]
+        );
+        assert_eq!(
+            render_change(AdapterType::Redshift, "db.s.t", &widen).len(),
+            4,
+            "Redshift only widens varchar in place"
+        );
+    }
+}
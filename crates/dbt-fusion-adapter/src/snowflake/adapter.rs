@@ -16,7 +16,7 @@ use dbt_common::adapter::AdapterType;
 use dbt_common::behavior_flags::BehaviorFlag;
 use dbt_common::unexpected_fs_err;
 use dbt_schemas::dbt_types::RelationType;
-use dbt_schemas::schemas::common::{ConstraintSupport, ConstraintType, DbtIncrementalStrategy};
+use dbt_schemas::schemas::common::DbtIncrementalStrategy;
 use dbt_schemas::schemas::relations::base::{BaseRelation, TableFormat};
 use dbt_xdbc::{Connection, QueryCtx};
 use minijinja::{State, Value};
@@ -221,6 +221,8 @@ prevent unnecessary latency for other users."#,
             Some(snowflake::relation_type_from_table_flags(is_dynamic)?)
         } else if relation_type_name.eq_ignore_ascii_case("view") {
             Some(RelationType::View)
+        } else if snowflake::is_materialized_view_kind(relation_type_name) {
+            Some(RelationType::MaterializedView)
         } else {
             None
         };
@@ -338,16 +340,6 @@ prevent unnecessary latency for other users."#,
             DbtIncrementalStrategy::InsertOverwrite,
         ]
     }
-
-    /// https://github.com/dbt-labs/dbt-adapters/blob/aa1de3d16267a456326a36045701fb48a61a6b6c/dbt-snowflake/src/dbt/adapters/snowflake/impl.py#L74
-    fn get_constraint_support(&self, ct: ConstraintType) -> ConstraintSupport {
-        match ct {
-            ConstraintType::Check => ConstraintSupport::NotSupported,
-            ConstraintType::NotNull | ConstraintType::ForeignKey => ConstraintSupport::Enforced,
-            ConstraintType::Unique | ConstraintType::PrimaryKey => ConstraintSupport::NotEnforced,
-            _ => ConstraintSupport::NotSupported,
-        }
-    }
 }
 
 impl fmt::Display for SnowflakeAdapter {
@@ -360,7 +352,7 @@ impl fmt::Display for SnowflakeAdapter {
 mod tests {
     use super::*;
     use crate::sql_types::NaiveTypeOpsImpl;
-    use crate::stmt_splitter::NaiveStmtSplitter;
+    use crate::stmt_splitter::DialectStmtSplitter;
 
     use crate::config::AdapterConfig;
     use crate::query_comment::QueryCommentConfig;
@@ -389,7 +381,7 @@ mod tests {
             auth.into(),
             AdapterConfig::new(config),
             SNOWFLAKE_RESOLVED_QUOTING,
-            Arc::new(NaiveStmtSplitter),
+            Arc::new(DialectStmtSplitter),
             None,
             QueryCommentConfig::from_query_comment(None, AdapterType::Snowflake, false),
             Box::new(NaiveTypeOpsImpl::new(AdapterType::Snowflake)), // XXX: NaiveTypeOpsImpl
@@ -440,7 +432,7 @@ mod tests {
             auth.into(),
             config,
             SNOWFLAKE_RESOLVED_QUOTING,
-            Arc::new(NaiveStmtSplitter),
+            Arc::new(DialectStmtSplitter),
             None,
             QueryCommentConfig::from_query_comment(None, AdapterType::Snowflake, false),
             Box::new(NaiveTypeOpsImpl::new(AdapterType::Snowflake)),
@@ -1,8 +1,16 @@
+use crate::catalog_relation::CatalogRelation;
 use crate::information_schema::InformationSchema;
 use crate::relation_object::{RelationObject, StaticBaseRelation};
 use crate::snowflake::relation_configs::dynamic_table::{
     DescribeDynamicTableResults, SnowflakeDynamicTableConfig, SnowflakeDynamicTableConfigChangeset,
 };
+use crate::snowflake::relation_configs::iceberg_table::{
+    DescribeIcebergTableResults, SnowflakeIcebergTableConfig, SnowflakeIcebergTableConfigChangeset,
+};
+use crate::snowflake::relation_configs::materialized_view::{
+    DescribeMaterializedViewResults, SnowflakeMaterializedViewConfig,
+    SnowflakeMaterializedViewConfigChangeset,
+};
 
 use dbt_common::{ErrorCode, FsResult, current_function_name, fs_err};
 use dbt_schemas::dbt_types::RelationType;
@@ -10,7 +18,7 @@ use dbt_schemas::schemas::common::{DbtMaterialization, DbtQuoting, ResolvedQuoti
 use dbt_schemas::schemas::relations::base::{
     BaseRelation, BaseRelationProperties, Policy, RelationPath, TableFormat,
 };
-use dbt_schemas::schemas::{InternalDbtNodeWrapper, RelationChangeSet};
+use dbt_schemas::schemas::{DbtModel, InternalDbtNodeWrapper, RelationChangeSet};
 use minijinja::arg_utils::ArgsIter;
 use minijinja::{Error as MinijinjaError, ErrorKind as MinijinjaErrorKind, State, Value};
 use serde::Deserialize;
@@ -198,7 +206,10 @@ impl BaseRelation for SnowflakeRelation {
     fn can_be_replaced(&self) -> bool {
         matches!(
             self.relation_type(),
-            Some(RelationType::Table) | Some(RelationType::View) | Some(RelationType::DynamicTable)
+            Some(RelationType::Table)
+                | Some(RelationType::View)
+                | Some(RelationType::DynamicTable)
+                | Some(RelationType::MaterializedView)
         )
     }
 
@@ -251,6 +262,110 @@ impl BaseRelation for SnowflakeRelation {
         }
     }
 
+    fn iceberg_table_config_changeset(&self, args: &[Value]) -> Result<Value, MinijinjaError> {
+        let iter = ArgsIter::new(
+            current_function_name!(),
+            &["relation_results", "relation_config", "catalog_relation"],
+            args,
+        );
+
+        let relation_results_value = iter.next_arg::<&Value>()?;
+        let relation_config_value = iter.next_arg::<&Value>()?;
+        let catalog_relation_value = iter.next_arg::<&Value>()?;
+        iter.finish()?;
+
+        let catalog_relation = catalog_relation_value
+            .downcast_object_ref::<CatalogRelation>()
+            .ok_or_else(|| {
+                MinijinjaError::new(
+                    MinijinjaErrorKind::InvalidArgument,
+                    "iceberg_table_config_changeset: catalog_relation must be built with adapter.build_catalog_relation",
+                )
+            })?;
+
+        let relation_results = DescribeIcebergTableResults::try_from(relation_results_value)
+            .map_err(|e| {
+                MinijinjaError::new(
+                    MinijinjaErrorKind::SerdeDeserializeError,
+                    format!("iceberg_table_config_changeset: Failed to deserialize DescribeIcebergTableResults: {e}"),
+                )
+            })?;
+
+        let existing_config = SnowflakeIcebergTableConfig::try_from(relation_results)
+            .map_err(|e| {
+                MinijinjaError::new(
+                    MinijinjaErrorKind::SerdeDeserializeError,
+                    format!("iceberg_table_config_changeset: Failed to deserialize SnowflakeIcebergTableConfig: {e}"),
+                )
+            })?;
+
+        let model = node_value_to_model(
+            relation_config_value,
+            &[DbtMaterialization::Table, DbtMaterialization::Incremental],
+        )?;
+        let new_config = SnowflakeIcebergTableConfig::from_model(&model, catalog_relation)
+            .map_err(|e| MinijinjaError::new(MinijinjaErrorKind::InvalidOperation, e))?;
+
+        let changeset = SnowflakeIcebergTableConfigChangeset::new(existing_config, new_config);
+
+        if changeset.has_changes() {
+            Ok(Value::from_object(changeset))
+        } else {
+            Ok(Value::from(()))
+        }
+    }
+
+    fn materialized_view_config_changeset(&self, args: &[Value]) -> Result<Value, MinijinjaError> {
+        let iter = ArgsIter::new(
+            current_function_name!(),
+            &["relation_results", "relation_config"],
+            args,
+        );
+
+        let relation_results_value = iter.next_arg::<&Value>()?;
+        let relation_config_value = iter.next_arg::<&Value>()?;
+        // The compiled query, to detect query changes, which require a full refresh
+        let sql = iter.next_kwarg::<Option<String>>("sql")?;
+        iter.finish()?;
+
+        let relation_results = DescribeMaterializedViewResults::try_from(relation_results_value)
+            .map_err(|e| {
+                MinijinjaError::new(
+                    MinijinjaErrorKind::SerdeDeserializeError,
+                    format!("materialized_view_config_changeset: Failed to deserialize DescribeMaterializedViewResults: {e}"),
+                )
+            })?;
+
+        let existing_config = SnowflakeMaterializedViewConfig::try_from(relation_results)
+            .map_err(|e| {
+                MinijinjaError::new(
+                    MinijinjaErrorKind::SerdeDeserializeError,
+                    format!("materialized_view_config_changeset: Failed to deserialize SnowflakeMaterializedViewConfig: {e}"),
+                )
+            })?;
+
+        let model = node_value_to_model(
+            relation_config_value,
+            &[DbtMaterialization::MaterializedView],
+        )?;
+        let new_config = SnowflakeMaterializedViewConfig::try_from(&*model)
+            .map_err(|e| {
+                MinijinjaError::new(
+                    MinijinjaErrorKind::SerdeDeserializeError,
+                    format!("Failed to deserialize SnowflakeMaterializedViewConfig: {e}"),
+                )
+            })?
+            .with_query(sql);
+
+        let changeset = SnowflakeMaterializedViewConfigChangeset::new(existing_config, new_config);
+
+        if changeset.has_changes() {
+            Ok(Value::from_object(changeset))
+        } else {
+            Ok(Value::from(()))
+        }
+    }
+
     fn quoted(&self, s: &str) -> String {
         format!("\"{s}\"")
     }
@@ -465,6 +580,21 @@ impl BaseRelation for SnowflakeRelation {
 fn node_value_to_snowflake_dynamic_table(
     node_value: &Value,
 ) -> Result<SnowflakeDynamicTableConfig, MinijinjaError> {
+    let model = node_value_to_model(node_value, &[DbtMaterialization::DynamicTable])?;
+
+    SnowflakeDynamicTableConfig::try_from(&*model).map_err(|e| {
+        MinijinjaError::new(
+            MinijinjaErrorKind::SerdeDeserializeError,
+            format!("Failed to deserialize SnowflakeDynamicTableConfig: {e}"),
+        )
+    })
+}
+
+/// Deserialize a model node with one of the given materializations
+fn node_value_to_model(
+    node_value: &Value,
+    materializations: &[DbtMaterialization],
+) -> Result<Box<DbtModel>, MinijinjaError> {
     let config_wrapper = InternalDbtNodeWrapper::deserialize(node_value).map_err(|e| {
         MinijinjaError::new(
             MinijinjaErrorKind::SerdeDeserializeError,
@@ -482,7 +612,7 @@ fn node_value_to_snowflake_dynamic_table(
         }
     };
 
-    if model.__base_attr__.materialized != DbtMaterialization::DynamicTable {
+    if !materializations.contains(&model.__base_attr__.materialized) {
         return Err(MinijinjaError::new(
             MinijinjaErrorKind::InvalidOperation,
             format!(
@@ -492,12 +622,7 @@ fn node_value_to_snowflake_dynamic_table(
         ));
     }
 
-    SnowflakeDynamicTableConfig::try_from(&*model).map_err(|e| {
-        MinijinjaError::new(
-            MinijinjaErrorKind::SerdeDeserializeError,
-            format!("Failed to deserialize SnowflakeDynamicTableConfig: {e}"),
-        )
-    })
+    Ok(model)
 }
 
 #[cfg(test)]
@@ -0,0 +1,368 @@
+//! Change detection for Snowflake-managed Iceberg tables
+//!
+//! reference: https://docs.snowflake.com/en/sql-reference/sql/create-iceberg-table-snowflake
+
+use std::convert::TryFrom;
+use std::result::Result;
+use std::sync::Arc;
+
+use arrow::array::RecordBatch;
+use dbt_agate::AgateTable;
+use dbt_schemas::schemas::dbt_catalogs::CatalogType;
+use dbt_schemas::schemas::{DbtModel, RelationChangeSet};
+use minijinja::{Value, value::Object};
+
+use super::{
+    SnowflakeRelationConfigChange, get_show_value, normalize_cluster_by, parse_cluster_by,
+};
+use crate::catalog_relation::CatalogRelation;
+
+/// The catalog of Snowflake-managed Iceberg tables
+const SNOWFLAKE_CATALOG: &str = "SNOWFLAKE";
+
+/// Deserialization target for macro snowflake__describe_iceberg_table
+#[derive(Debug)]
+pub struct DescribeIcebergTableResults {
+    /// Output of `show iceberg tables`
+    pub iceberg_table: Arc<RecordBatch>,
+    /// Output of `show tables`, which has the clustering key
+    pub table: Option<Arc<RecordBatch>>,
+}
+
+impl TryFrom<&Value> for DescribeIcebergTableResults {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let iceberg_table = value
+            .get_item(&Value::from_safe_string("iceberg_table".into()))
+            .map_err(|e| format!("Expected key `iceberg_table`: {e}"))?
+            .downcast_object::<AgateTable>()
+            .ok_or("Failed to convert iceberg_table to AgateTable")?
+            .original_record_batch();
+
+        let table_value = value
+            .get_item(&Value::from_safe_string("table".into()))
+            .map_err(|e| format!("Expected key `table`: {e}"))?;
+        let table = if table_value == Value::UNDEFINED || table_value.is_none() {
+            None
+        } else {
+            Some(
+                table_value
+                    .downcast_object::<AgateTable>()
+                    .ok_or("Failed to convert table to AgateTable")?
+                    .original_record_batch(),
+            )
+        };
+
+        Ok(Self {
+            iceberg_table,
+            table,
+        })
+    }
+}
+
+/// The configuration of a Snowflake-managed Iceberg table that can drift from its model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnowflakeIcebergTableConfig {
+    /// Name of the table.
+    pub table_name: String,
+    /// Name of the schema containing the table.
+    pub schema_name: String,
+    /// Name of the database containing the table.
+    pub database_name: String,
+    /// The external volume the table's data and metadata are written to.
+    /// `None` when neither the model nor its catalog sets one, leaving it to the database default.
+    pub external_volume: Option<String>,
+    /// The path of the table within the external volume.
+    pub base_location: Option<String>,
+    /// The catalog that manages the table.
+    pub catalog: Option<String>,
+    /// The clustering expressions of the table, empty if it isn't clustered.
+    pub cluster_by: Vec<String>,
+}
+
+impl SnowflakeIcebergTableConfig {
+    /// The configuration a model's Iceberg table should have.
+    ///
+    /// The external volume and base location are resolved by
+    /// [CatalogRelation], from the model config or its catalogs.yml write integration.
+    pub fn from_model(
+        model: &DbtModel,
+        catalog_relation: &CatalogRelation,
+    ) -> Result<Self, String> {
+        if catalog_relation.catalog_type != CatalogType::SnowflakeBuiltIn.as_str() {
+            return Err(format!(
+                "Only Snowflake-managed Iceberg tables are supported, found catalog_type {}",
+                catalog_relation.catalog_type
+            ));
+        }
+
+        let cluster_by = model
+            .__adapter_attr__
+            .snowflake_attr
+            .as_ref()
+            .and_then(|snowflake_config| snowflake_config.cluster_by.as_ref())
+            .map(|cluster_by| normalize_cluster_by(cluster_by.fields()))
+            .unwrap_or_default();
+
+        Ok(Self {
+            table_name: model.__base_attr__.alias.clone(),
+            schema_name: model.__base_attr__.schema.clone(),
+            database_name: model.__base_attr__.database.clone(),
+            external_volume: catalog_relation.external_volume.clone(),
+            base_location: catalog_relation.base_location.clone(),
+            catalog: Some(SNOWFLAKE_CATALOG.to_string()),
+            cluster_by,
+        })
+    }
+}
+
+impl TryFrom<DescribeIcebergTableResults> for SnowflakeIcebergTableConfig {
+    type Error = String;
+
+    fn try_from(value: DescribeIcebergTableResults) -> Result<Self, Self::Error> {
+        let batch = value.iceberg_table;
+        if batch.num_rows() == 0 {
+            return Err("iceberg_table describe table is empty".to_string());
+        }
+
+        let table_name = get_show_value(&batch, "iceberg_table", "name")?;
+        let schema_name = get_show_value(&batch, "iceberg_table", "schema_name")?;
+        let database_name = get_show_value(&batch, "iceberg_table", "database_name")?;
+        let external_volume = get_show_value(&batch, "iceberg_table", "external_volume_name")?;
+        let base_location = get_show_value(&batch, "iceberg_table", "base_location")?;
+        let catalog = get_show_value(&batch, "iceberg_table", "catalog_name")?;
+
+        let cluster_by = match value.table {
+            Some(table) if table.num_rows() > 0 => {
+                parse_cluster_by(&get_show_value(&table, "table", "cluster_by")?)
+            }
+            _ => Vec::new(),
+        };
+
+        Ok(Self {
+            table_name,
+            schema_name,
+            database_name,
+            external_volume: Some(external_volume),
+            base_location: Some(base_location),
+            catalog: Some(catalog),
+            cluster_by,
+        })
+    }
+}
+
+/// Differences between an existing Iceberg table and its model.
+///
+/// Moving a table to another external volume, base location or catalog
+/// rewrites its data, so those require a full refresh. The clustering key
+/// can be altered in place.
+#[derive(Debug)]
+pub struct SnowflakeIcebergTableConfigChangeset {
+    external_volume: Option<SnowflakeRelationConfigChange>,
+    base_location: Option<SnowflakeRelationConfigChange>,
+    catalog: Option<SnowflakeRelationConfigChange>,
+    cluster_by: Option<SnowflakeRelationConfigChange>,
+}
+
+impl SnowflakeIcebergTableConfigChangeset {
+    pub fn new(old: SnowflakeIcebergTableConfig, new: SnowflakeIcebergTableConfig) -> Self {
+        let full_refresh =
+            |context: String| SnowflakeRelationConfigChange::full_refresh(Value::from(context));
+
+        // Settings the model doesn't set are left as they are
+        let external_volume = match (old.external_volume, new.external_volume) {
+            (Some(old), Some(new)) if !old.eq_ignore_ascii_case(&new) => Some(full_refresh(new)),
+            _ => None,
+        };
+
+        let base_location = match (old.base_location, new.base_location) {
+            (Some(old), Some(new)) if old.trim_end_matches('/') != new.trim_end_matches('/') => {
+                Some(full_refresh(new))
+            }
+            _ => None,
+        };
+
+        let catalog = match (old.catalog, new.catalog) {
+            (Some(old), Some(new)) if !old.eq_ignore_ascii_case(&new) => Some(full_refresh(new)),
+            _ => None,
+        };
+
+        let cluster_by = if old.cluster_by != new.cluster_by {
+            Some(SnowflakeRelationConfigChange::alter(Value::from(
+                new.cluster_by,
+            )))
+        } else {
+            None
+        };
+
+        Self {
+            external_volume,
+            base_location,
+            catalog,
+            cluster_by,
+        }
+    }
+
+    fn iter_changes(&self) -> impl Iterator<Item = &SnowflakeRelationConfigChange> {
+        [
+            &self.external_volume,
+            &self.base_location,
+            &self.catalog,
+            &self.cluster_by,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl RelationChangeSet for SnowflakeIcebergTableConfigChangeset {
+    fn changes(
+        &self,
+    ) -> &std::collections::BTreeMap<String, Arc<dyn dbt_schemas::schemas::ComponentConfig>> {
+        unimplemented!("Iceberg table changes are exposed as Jinja objects")
+    }
+
+    fn requires_full_refresh(&self) -> bool {
+        self.iter_changes()
+            .any(|change| change.requires_full_refresh())
+    }
+
+    fn get_change(
+        &self,
+        _component_name: &str,
+    ) -> Option<&dyn dbt_schemas::schemas::ComponentConfig> {
+        unimplemented!("Iceberg table changes are exposed as Jinja objects")
+    }
+
+    fn has_changes(&self) -> bool {
+        self.iter_changes().next().is_some()
+    }
+}
+
+impl Object for SnowflakeIcebergTableConfigChangeset {
+    fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+        let change = match key.as_str() {
+            Some("external_volume") => &self.external_volume,
+            Some("base_location") => &self.base_location,
+            Some("catalog") => &self.catalog,
+            Some("cluster_by") => &self.cluster_by,
+            Some("requires_full_refresh") => {
+                return Some(Value::from(self.requires_full_refresh()));
+            }
+            _ => return None,
+        };
+        // Return None if no change, otherwise return the change as an object
+        change
+            .as_ref()
+            .map(|change| Value::from_object(change.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snowflake::relation_configs::show_batch;
+
+    fn config() -> SnowflakeIcebergTableConfig {
+        SnowflakeIcebergTableConfig {
+            table_name: "MY_TABLE".into(),
+            schema_name: "MY_SCHEMA".into(),
+            database_name: "MY_DB".into(),
+            external_volume: Some("S3_VOLUME".into()),
+            base_location: Some("_dbt/MY_SCHEMA/MY_TABLE".into()),
+            catalog: Some("SNOWFLAKE".into()),
+            cluster_by: vec![],
+        }
+    }
+
+    #[test]
+    fn test_no_changes() {
+        let mut old = config();
+        old.base_location = Some("_dbt/MY_SCHEMA/MY_TABLE/".into());
+        old.external_volume = Some("s3_volume".into());
+        let changeset = SnowflakeIcebergTableConfigChangeset::new(old, config());
+        assert!(!changeset.has_changes());
+        assert!(!changeset.requires_full_refresh());
+    }
+
+    #[test]
+    fn test_unset_settings_are_not_compared() {
+        let new = SnowflakeIcebergTableConfig {
+            external_volume: None,
+            base_location: None,
+            ..config()
+        };
+        let changeset = SnowflakeIcebergTableConfigChangeset::new(config(), new);
+        assert!(!changeset.has_changes());
+    }
+
+    #[test]
+    fn test_location_changes_require_full_refresh() {
+        let new = SnowflakeIcebergTableConfig {
+            external_volume: Some("GCS_VOLUME".into()),
+            base_location: Some("custom/MY_SCHEMA/MY_TABLE".into()),
+            ..config()
+        };
+        let changeset = Arc::new(SnowflakeIcebergTableConfigChangeset::new(config(), new));
+        assert!(changeset.has_changes());
+        assert!(changeset.requires_full_refresh());
+        assert_eq!(
+            changeset
+                .get_value(&Value::from("external_volume"))
+                .unwrap()
+                .get_attr("context")
+                .unwrap()
+                .to_string(),
+            "GCS_VOLUME"
+        );
+        assert!(changeset.get_value(&Value::from("cluster_by")).is_none());
+    }
+
+    #[test]
+    fn test_cluster_by_change_is_altered() {
+        let new = SnowflakeIcebergTableConfig {
+            cluster_by: vec!["ID".into()],
+            ..config()
+        };
+        let changeset = Arc::new(SnowflakeIcebergTableConfigChangeset::new(config(), new));
+        assert!(changeset.has_changes());
+        assert!(!changeset.requires_full_refresh());
+        let cluster_by = changeset.get_value(&Value::from("cluster_by")).unwrap();
+        assert_eq!(cluster_by.get_attr("context").unwrap().len(), Some(1));
+        assert!(
+            !cluster_by
+                .get_attr("requires_full_refresh")
+                .unwrap()
+                .is_true()
+        );
+    }
+
+    #[test]
+    fn test_config_from_describe_results() {
+        let results = DescribeIcebergTableResults {
+            iceberg_table: show_batch(&[
+                ("name", Some("MY_TABLE")),
+                ("schema_name", Some("MY_SCHEMA")),
+                ("database_name", Some("MY_DB")),
+                ("external_volume_name", Some("S3_VOLUME")),
+                ("base_location", Some("_dbt/MY_SCHEMA/MY_TABLE/")),
+                ("catalog_name", Some("SNOWFLAKE")),
+            ]),
+            table: Some(show_batch(&[("cluster_by", Some("LINEAR(id, ts)"))])),
+        };
+        let config = SnowflakeIcebergTableConfig::try_from(results).unwrap();
+        assert_eq!(config.external_volume.as_deref(), Some("S3_VOLUME"));
+        assert_eq!(
+            config.base_location.as_deref(),
+            Some("_dbt/MY_SCHEMA/MY_TABLE/")
+        );
+        assert_eq!(config.cluster_by, vec!["ID".to_string(), "TS".to_string()]);
+
+        let results = DescribeIcebergTableResults {
+            iceberg_table: show_batch(&[("name", Some("MY_TABLE"))]),
+            table: None,
+        };
+        assert!(SnowflakeIcebergTableConfig::try_from(results).is_err());
+    }
+}
@@ -0,0 +1,362 @@
+//! Change detection for Snowflake materialized views
+//!
+//! reference: https://docs.snowflake.com/en/sql-reference/sql/create-materialized-view
+
+use std::convert::TryFrom;
+use std::result::Result;
+use std::sync::Arc;
+
+use arrow::array::RecordBatch;
+use dbt_agate::AgateTable;
+use dbt_schemas::schemas::{DbtModel, RelationChangeSet};
+use minijinja::{Value, value::Object};
+
+use super::{
+    SnowflakeRelationConfigChange, get_show_value, normalize_cluster_by, parse_cluster_by,
+};
+
+/// Deserialization target for macro snowflake__describe_materialized_view
+#[derive(Debug)]
+pub struct DescribeMaterializedViewResults {
+    /// Output of `show materialized views`
+    pub materialized_view: Arc<RecordBatch>,
+}
+
+impl TryFrom<&Value> for DescribeMaterializedViewResults {
+    type Error = String;
+
+    fn try_from(value: &Value) -> Result<Self, Self::Error> {
+        let materialized_view = value
+            .get_item(&Value::from_safe_string("materialized_view".into()))
+            .map_err(|e| format!("Expected key `materialized_view`: {e}"))?
+            .downcast_object::<AgateTable>()
+            .ok_or("Failed to convert materialized_view to AgateTable")?
+            .original_record_batch();
+
+        Ok(Self { materialized_view })
+    }
+}
+
+/// The configuration of a Snowflake materialized view that can drift from its model
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SnowflakeMaterializedViewConfig {
+    /// Name of the materialized view.
+    pub table_name: String,
+    /// Name of the schema containing the materialized view.
+    pub schema_name: String,
+    /// Name of the database containing the materialized view.
+    pub database_name: String,
+    /// The query of the materialized view; `None` when it isn't known.
+    pub query: Option<String>,
+    /// Whether the materialized view is secure.
+    pub secure: bool,
+    /// The clustering expressions of the materialized view, empty if it isn't clustered.
+    pub cluster_by: Vec<String>,
+    /// Whether the materialized view is reclustered automatically; `None` when the model doesn't set it.
+    pub automatic_clustering: Option<bool>,
+}
+
+impl SnowflakeMaterializedViewConfig {
+    /// Set the compiled query of the model
+    pub fn with_query(mut self, query: Option<String>) -> Self {
+        self.query = query;
+        self
+    }
+}
+
+impl TryFrom<&DbtModel> for SnowflakeMaterializedViewConfig {
+    type Error = String;
+
+    fn try_from(model: &DbtModel) -> Result<Self, Self::Error> {
+        let snowflake_config = model
+            .__adapter_attr__
+            .snowflake_attr
+            .as_ref()
+            .ok_or("Snowflake attributes not found.")?;
+
+        let cluster_by = snowflake_config
+            .cluster_by
+            .as_ref()
+            .map(|cluster_by| normalize_cluster_by(cluster_by.fields()))
+            .unwrap_or_default();
+
+        Ok(Self {
+            table_name: model.__base_attr__.alias.clone(),
+            schema_name: model.__base_attr__.schema.clone(),
+            database_name: model.__base_attr__.database.clone(),
+            query: None,
+            secure: snowflake_config.secure.unwrap_or(false),
+            cluster_by,
+            automatic_clustering: snowflake_config.automatic_clustering,
+        })
+    }
+}
+
+impl TryFrom<DescribeMaterializedViewResults> for SnowflakeMaterializedViewConfig {
+    type Error = String;
+
+    fn try_from(value: DescribeMaterializedViewResults) -> Result<Self, Self::Error> {
+        let batch = value.materialized_view;
+        if batch.num_rows() == 0 {
+            return Err("materialized_view describe table is empty".to_string());
+        }
+
+        let kind = "materialized_view";
+        let table_name = get_show_value(&batch, kind, "name")?;
+        let schema_name = get_show_value(&batch, kind, "schema_name")?;
+        let database_name = get_show_value(&batch, kind, "database_name")?;
+        let query = get_show_value(&batch, kind, "text")?;
+        let secure = get_show_value(&batch, kind, "is_secure")?.eq_ignore_ascii_case("true");
+        let cluster_by = parse_cluster_by(&get_show_value(&batch, kind, "cluster_by")?);
+        let automatic_clustering =
+            get_show_value(&batch, kind, "automatic_clustering")?.eq_ignore_ascii_case("on");
+
+        Ok(Self {
+            table_name,
+            schema_name,
+            database_name,
+            query: Some(query),
+            secure,
+            cluster_by,
+            automatic_clustering: Some(automatic_clustering),
+        })
+    }
+}
+
+/// Collapse whitespace and drop a trailing semicolon so queries compare equal
+fn normalize_query(query: &str) -> String {
+    query
+        .trim()
+        .trim_end_matches(';')
+        .split_whitespace()
+        .collect::<Vec<_>>()
+        .join(" ")
+}
+
+/// Whether the `text` of an existing materialized view, which is its full
+/// `create` statement, was created from the given query.
+fn text_matches_query(text: &str, query: &str) -> bool {
+    let text = normalize_query(text);
+    let query = normalize_query(query);
+    // Materialized views created by older versions wrap the query in parentheses
+    text.ends_with(&query)
+        || text.ends_with(&format!("{query})"))
+        || text.ends_with(&format!("{query} )"))
+}
+
+/// Differences between an existing materialized view and its model.
+///
+/// Snowflake can't alter the query of a materialized view, so a new query
+/// requires a full refresh. Its secure flag and clustering are altered in place.
+#[derive(Debug)]
+pub struct SnowflakeMaterializedViewConfigChangeset {
+    query: Option<SnowflakeRelationConfigChange>,
+    secure: Option<SnowflakeRelationConfigChange>,
+    cluster_by: Option<SnowflakeRelationConfigChange>,
+    automatic_clustering: Option<SnowflakeRelationConfigChange>,
+}
+
+impl SnowflakeMaterializedViewConfigChangeset {
+    pub fn new(old: SnowflakeMaterializedViewConfig, new: SnowflakeMaterializedViewConfig) -> Self {
+        let query = match (old.query, new.query) {
+            (Some(old), Some(new)) if !text_matches_query(&old, &new) => Some(
+                SnowflakeRelationConfigChange::full_refresh(Value::from(new)),
+            ),
+            _ => None,
+        };
+
+        let secure = if old.secure != new.secure {
+            Some(SnowflakeRelationConfigChange::alter(Value::from(
+                new.secure,
+            )))
+        } else {
+            None
+        };
+
+        let cluster_by = if old.cluster_by != new.cluster_by {
+            Some(SnowflakeRelationConfigChange::alter(Value::from(
+                new.cluster_by,
+            )))
+        } else {
+            None
+        };
+
+        // Settings the model doesn't set are left as they are
+        let automatic_clustering = match (old.automatic_clustering, new.automatic_clustering) {
+            (Some(old), Some(new)) if old != new => {
+                Some(SnowflakeRelationConfigChange::alter(Value::from(new)))
+            }
+            _ => None,
+        };
+
+        Self {
+            query,
+            secure,
+            cluster_by,
+            automatic_clustering,
+        }
+    }
+
+    fn iter_changes(&self) -> impl Iterator<Item = &SnowflakeRelationConfigChange> {
+        [
+            &self.query,
+            &self.secure,
+            &self.cluster_by,
+            &self.automatic_clustering,
+        ]
+        .into_iter()
+        .flatten()
+    }
+}
+
+impl RelationChangeSet for SnowflakeMaterializedViewConfigChangeset {
+    fn changes(
+        &self,
+    ) -> &std::collections::BTreeMap<String, Arc<dyn dbt_schemas::schemas::ComponentConfig>> {
+        unimplemented!("Materialized view changes are exposed as Jinja objects")
+    }
+
+    fn requires_full_refresh(&self) -> bool {
+        self.iter_changes()
+            .any(|change| change.requires_full_refresh())
+    }
+
+    fn get_change(
+        &self,
+        _component_name: &str,
+    ) -> Option<&dyn dbt_schemas::schemas::ComponentConfig> {
+        unimplemented!("Materialized view changes are exposed as Jinja objects")
+    }
+
+    fn has_changes(&self) -> bool {
+        self.iter_changes().next().is_some()
+    }
+}
+
+impl Object for SnowflakeMaterializedViewConfigChangeset {
+    fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+        let change = match key.as_str() {
+            Some("query") => &self.query,
+            Some("secure") => &self.secure,
+            Some("cluster_by") => &self.cluster_by,
+            Some("automatic_clustering") => &self.automatic_clustering,
+            Some("requires_full_refresh") => {
+                return Some(Value::from(self.requires_full_refresh()));
+            }
+            _ => return None,
+        };
+        // Return None if no change, otherwise return the change as an object
+        change
+            .as_ref()
+            .map(|change| Value::from_object(change.clone()))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::snowflake::relation_configs::show_batch;
+
+    const QUERY: &str = "select id, name\nfrom db.schema.source";
+
+    fn existing() -> SnowflakeMaterializedViewConfig {
+        SnowflakeMaterializedViewConfig::try_from(DescribeMaterializedViewResults {
+            materialized_view: show_batch(&[
+                ("name", Some("MY_VIEW")),
+                ("schema_name", Some("MY_SCHEMA")),
+                ("database_name", Some("MY_DB")),
+                (
+                    "text",
+                    Some(
+                        "create or replace materialized view MY_DB.MY_SCHEMA.MY_VIEW\n  cluster by (id)\nas\nselect id, name\n  from db.schema.source;",
+                    ),
+                ),
+                ("is_secure", Some("false")),
+                ("cluster_by", Some("LINEAR(ID)")),
+                ("automatic_clustering", Some("ON")),
+            ]),
+        })
+        .unwrap()
+    }
+
+    fn model() -> SnowflakeMaterializedViewConfig {
+        SnowflakeMaterializedViewConfig {
+            table_name: "MY_VIEW".into(),
+            schema_name: "MY_SCHEMA".into(),
+            database_name: "MY_DB".into(),
+            query: Some(QUERY.into()),
+            secure: false,
+            cluster_by: vec!["ID".into()],
+            automatic_clustering: None,
+        }
+    }
+
+    #[test]
+    fn test_config_from_describe_results() {
+        let config = existing();
+        assert!(!config.secure);
+        assert_eq!(config.cluster_by, vec!["ID".to_string()]);
+        assert_eq!(config.automatic_clustering, Some(true));
+    }
+
+    #[test]
+    fn test_no_changes() {
+        let changeset = SnowflakeMaterializedViewConfigChangeset::new(existing(), model());
+        assert!(!changeset.has_changes());
+        assert!(!changeset.requires_full_refresh());
+    }
+
+    #[test]
+    fn test_query_change_requires_full_refresh() {
+        let new = model().with_query(Some("select id from db.schema.source".into()));
+        let changeset = Arc::new(SnowflakeMaterializedViewConfigChangeset::new(
+            existing(),
+            new,
+        ));
+        assert!(changeset.has_changes());
+        assert!(changeset.requires_full_refresh());
+        assert!(changeset.get_value(&Value::from("query")).is_some());
+
+        // An unknown query is never a change
+        let changeset =
+            SnowflakeMaterializedViewConfigChangeset::new(existing(), model().with_query(None));
+        assert!(!changeset.has_changes());
+    }
+
+    #[test]
+    fn test_alterable_changes() {
+        let new = SnowflakeMaterializedViewConfig {
+            secure: true,
+            cluster_by: vec![],
+            automatic_clustering: Some(false),
+            ..model()
+        };
+        let changeset = Arc::new(SnowflakeMaterializedViewConfigChangeset::new(
+            existing(),
+            new,
+        ));
+        assert!(changeset.has_changes());
+        assert!(!changeset.requires_full_refresh());
+        let secure = changeset.get_value(&Value::from("secure")).unwrap();
+        assert!(secure.get_attr("context").unwrap().is_true());
+        let cluster_by = changeset.get_value(&Value::from("cluster_by")).unwrap();
+        assert_eq!(cluster_by.get_attr("context").unwrap().len(), Some(0));
+        assert!(
+            changeset
+                .get_value(&Value::from("automatic_clustering"))
+                .is_some()
+        );
+    }
+
+    #[test]
+    fn test_text_matches_query() {
+        assert!(text_matches_query(
+            "create materialized view V as (\n  select 1\n)",
+            "select 1"
+        ));
+        assert!(!text_matches_query(
+            "create materialized view V as select 1",
+            "select 2"
+        ));
+    }
+}
@@ -1 +1,201 @@
 pub mod dynamic_table;
+pub mod iceberg_table;
+pub mod materialized_view;
+
+use std::fmt::{Display, Formatter};
+use std::sync::Arc;
+
+use arrow::array::{Array, RecordBatch, StringArray};
+use dbt_schemas::schemas::RelationChangeSet;
+use minijinja::{Value, value::Object};
+
+use crate::record_batch_utils::get_column_values;
+
+/// A change to one setting of an Iceberg table or materialized view
+#[derive(Debug, Clone)]
+pub struct SnowflakeRelationConfigChange {
+    // The value to change to, since the presence of this object enforces a config change for this field.
+    context: Value,
+    requires_full_refresh: bool,
+}
+
+impl SnowflakeRelationConfigChange {
+    /// A change that can be applied with `alter`
+    pub fn alter(context: Value) -> Self {
+        Self {
+            context,
+            requires_full_refresh: false,
+        }
+    }
+
+    /// A change that requires the relation to be rebuilt
+    pub fn full_refresh(context: Value) -> Self {
+        Self {
+            context,
+            requires_full_refresh: true,
+        }
+    }
+}
+
+impl RelationChangeSet for SnowflakeRelationConfigChange {
+    fn requires_full_refresh(&self) -> bool {
+        self.requires_full_refresh
+    }
+
+    fn changes(
+        &self,
+    ) -> &std::collections::BTreeMap<String, Arc<dyn dbt_schemas::schemas::ComponentConfig>> {
+        unimplemented!("Snowflake relation config changes are exposed as Jinja objects")
+    }
+
+    fn get_change(
+        &self,
+        _component_name: &str,
+    ) -> Option<&dyn dbt_schemas::schemas::ComponentConfig> {
+        unimplemented!("Snowflake relation config changes are exposed as Jinja objects")
+    }
+}
+
+impl Object for SnowflakeRelationConfigChange {
+    fn enumerate(self: &Arc<Self>) -> minijinja::value::Enumerator {
+        minijinja::value::Enumerator::Str(&["context", "requires_full_refresh"])
+    }
+
+    fn get_value(self: &Arc<Self>, key: &Value) -> Option<Value> {
+        match key.as_str() {
+            Some("context") => Some(self.context.clone()),
+            Some("requires_full_refresh") => Some(Value::from(self.requires_full_refresh)),
+            _ => None,
+        }
+    }
+
+    fn render(self: &Arc<Self>, f: &mut Formatter<'_>) -> std::fmt::Result
+    where
+        Self: Sized + 'static,
+    {
+        write!(f, "{}", self.context)
+    }
+}
+
+impl Display for SnowflakeRelationConfigChange {
+    fn fmt(&self, f: &mut Formatter<'_>) -> std::fmt::Result {
+        self.context.fmt(f)
+    }
+}
+
+/// Get the single string value of a column of `SHOW` output; NULL is read as an empty string
+pub(crate) fn get_show_value(
+    batch: &Arc<RecordBatch>,
+    relation_kind: &str,
+    col_name: &str,
+) -> Result<String, String> {
+    let column_values = get_column_values::<StringArray>(batch, col_name)
+        .map_err(|_| format!("Show {relation_kind} is missing {col_name}."))?;
+    if column_values.len() != 1 {
+        return Err(format!(
+            "Show {relation_kind} returned an unexpected number of values for {col_name}."
+        ));
+    }
+    if column_values.is_null(0) {
+        return Ok(String::new());
+    }
+    Ok(column_values.value(0).to_string())
+}
+
+/// Parse the `cluster_by` column of `SHOW` output, e.g. `LINEAR(ID, "Name")`
+pub(crate) fn parse_cluster_by(value: &str) -> Vec<String> {
+    let value = value.trim();
+    let inner = value
+        .strip_prefix("LINEAR(")
+        .or_else(|| value.strip_prefix("linear("))
+        .and_then(|v| v.strip_suffix(')'))
+        .unwrap_or(value);
+    normalize_cluster_by(split_top_level(inner))
+}
+
+/// Split on commas that are not nested in parentheses or quotes
+fn split_top_level(value: &str) -> Vec<&str> {
+    let mut parts = Vec::new();
+    let mut depth = 0usize;
+    let mut quoted = false;
+    let mut start = 0;
+    for (i, c) in value.char_indices() {
+        match c {
+            '"' => quoted = !quoted,
+            '(' if !quoted => depth += 1,
+            ')' if !quoted => depth = depth.saturating_sub(1),
+            ',' if !quoted && depth == 0 => {
+                parts.push(&value[start..i]);
+                start = i + 1;
+            }
+            _ => {}
+        }
+    }
+    parts.push(&value[start..]);
+    parts
+}
+
+/// Normalize clustering expressions so they compare equal to what Snowflake reports
+pub(crate) fn normalize_cluster_by<'a>(fields: impl IntoIterator<Item = &'a str>) -> Vec<String> {
+    fields
+        .into_iter()
+        .map(str::trim)
+        .filter(|field| !field.is_empty())
+        .map(|field| {
+            if field.starts_with('"') && field.ends_with('"') {
+                field.to_string()
+            } else {
+                field.to_uppercase()
+            }
+        })
+        .collect()
+}
+
+/// A single row of `SHOW` output with the given string columns
+#[cfg(test)]
+pub(crate) fn show_batch(columns: &[(&str, Option<&str>)]) -> Arc<RecordBatch> {
+    use arrow_schema::{DataType, Field, Schema};
+
+    let schema = Schema::new(
+        columns
+            .iter()
+            .map(|(name, _)| Field::new(*name, DataType::Utf8, true))
+            .collect::<Vec<_>>(),
+    );
+    let arrays = columns
+        .iter()
+        .map(|(_, value)| Arc::new(StringArray::from(vec![*value])) as _)
+        .collect();
+    Arc::new(RecordBatch::try_new(Arc::new(schema), arrays).unwrap())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_get_show_value() {
+        let batch = show_batch(&[("name", Some("MY_VIEW")), ("cluster_by", None)]);
+        assert_eq!(get_show_value(&batch, "view", "name").unwrap(), "MY_VIEW");
+        assert_eq!(get_show_value(&batch, "view", "cluster_by").unwrap(), "");
+        assert!(get_show_value(&batch, "view", "text").is_err());
+    }
+
+    #[test]
+    fn test_parse_cluster_by() {
+        assert_eq!(
+            parse_cluster_by(r#"LINEAR(id, "Name")"#),
+            vec!["ID".to_string(), "\"Name\"".to_string()]
+        );
+        assert_eq!(parse_cluster_by("linear(a)"), vec!["A".to_string()]);
+        assert_eq!(
+            parse_cluster_by("LINEAR(SUBSTR(NAME, 1, 2), ID)"),
+            vec!["SUBSTR(NAME, 1, 2)".to_string(), "ID".to_string()]
+        );
+        assert!(parse_cluster_by("").is_empty());
+        assert_eq!(
+            normalize_cluster_by(["id", " to_date(ts) "]),
+            vec!["ID".to_string(), "TO_DATE(TS)".to_string()]
+        );
+    }
+}
@@ -1,18 +1,20 @@
 use crate::auth::Auth;
 use crate::base_adapter::backend_of;
 use crate::bigquery::adapter::ADBC_EXECUTE_INVOCATION_OPTION;
+use crate::concurrency::ConcurrencyLimits;
 use crate::config::AdapterConfig;
 use crate::databricks::databricks_compute_from_state;
 use crate::errors::{
     AdapterError, AdapterErrorKind, AdapterResult, adbc_error_to_adapter_error,
     arrow_error_to_adapter_error,
 };
-use crate::query_cache::QueryCache;
+use crate::query_attribution::QueryAttribution;
+use crate::query_cache::{QueryCache, QueryCacheConfig, QueryCacheImpl};
 use crate::query_comment::{EMPTY_CONFIG, QueryCommentConfig};
 use crate::record_and_replay::{RecordEngine, ReplayEngine};
 use crate::sql_types::{NaiveTypeOpsImpl, TypeOps};
 use crate::stmt_splitter::StmtSplitter;
-use crate::{AdapterResponse, TrackedStatement};
+use crate::{AdapterResponse, StatementWatchdog, StmtInterruption, TrackedStatement};
 
 use adbc_core::options::{OptionStatement, OptionValue};
 use arrow::array::RecordBatch;
@@ -24,12 +26,13 @@ use dbt_common::cancellation::{Cancellable, CancellationToken, never_cancels};
 use dbt_common::constants::EXECUTING;
 use dbt_common::create_debug_span;
 use dbt_common::hashing::code_hash;
+use dbt_common::redaction::{redact_credentials, redact_credentials_any_dialect};
 use dbt_common::tracing::span_info::record_current_span_status_from_attrs;
 use dbt_frontend_common::dialect::Dialect;
 use dbt_schemas::schemas::common::ResolvedQuoting;
 use dbt_schemas::schemas::telemetry::{QueryExecuted, QueryOutcome};
 use dbt_xdbc::bigquery::QUERY_LABELS;
-use dbt_xdbc::semaphore::Semaphore;
+use dbt_xdbc::snowflake::QUERY_TAG as SNOWFLAKE_QUERY_TAG;
 use dbt_xdbc::{Backend, Connection, Database, QueryCtx, Statement, connection, database, driver};
 use log;
 use minijinja::State;
@@ -37,22 +40,23 @@ use serde_json::json;
 use std::borrow::Cow;
 use tracy_client::span;
 
+use std::cell::Cell;
 use std::collections::{BTreeMap, HashMap};
 use std::fmt::Write;
 use std::hash::{BuildHasher, Hasher};
 use std::path::PathBuf;
 use std::sync::RwLock;
 use std::sync::{Arc, LazyLock};
-use std::{thread, time::Duration};
+use std::time::Duration;
 
 pub type Options = Vec<(String, OptionValue)>;
 
-/// Naive statement splitter used in the MockAdapter
-///
-/// IMPORTANT: not suitable for production use.
-/// TODO: remove when the full stmt splitter is available to this crate.
-static NAIVE_STMT_SPLITTER: LazyLock<Arc<dyn StmtSplitter>> =
-    LazyLock::new(|| Arc::new(crate::stmt_splitter::NaiveStmtSplitter));
+/// Name of the profile and node config that bounds statement execution time (in seconds).
+const QUERY_TIMEOUT: &str = "query_timeout";
+
+/// Statement splitter used in the MockAdapter
+static MOCK_STMT_SPLITTER: LazyLock<Arc<dyn StmtSplitter>> =
+    LazyLock::new(|| Arc::new(crate::stmt_splitter::DialectStmtSplitter));
 
 /// Naive type parser/formatter used in the MockAdapter
 ///
@@ -139,8 +143,8 @@ pub struct ActualEngine {
     config: AdapterConfig,
     /// Lazily initialized databases
     configured_databases: RwLock<DatabaseMap>,
-    /// Semaphore for limiting the number of concurrent connections
-    semaphore: Arc<Semaphore>,
+    /// Limits on concurrently executing statements, per backend
+    concurrency: AdapterResult<ConcurrencyLimits>,
     /// Resolved quoting policy
     quoting: ResolvedQuoting,
     /// Statement splitter
@@ -165,31 +169,22 @@ impl ActualEngine {
         splitter: Arc<dyn StmtSplitter>,
         query_comment: QueryCommentConfig,
         type_ops: Box<dyn TypeOps>,
-        query_cache: Option<Arc<dyn QueryCache>>,
+        query_cache: Option<QueryCacheConfig>,
         token: CancellationToken,
     ) -> Self {
-        let threads = config
-            .get("threads")
-            .and_then(|t| {
-                let u = t.as_u64();
-                debug_assert!(u.is_some(), "threads must be an integer if specified");
-                u
-            })
-            .map(|t| t as u32)
-            .unwrap_or(0u32);
-
-        let permits = if matches!(adapter_type, AdapterType::Redshift) && threads > 0 {
-            threads
-        } else {
-            u32::MAX
-        };
+        // Invalid limits are reported when a statement is executed.
+        let concurrency = ConcurrencyLimits::from_config(adapter_type, &config);
+        let query_cache = query_cache.map(|cache_config| {
+            let cache_config = cache_config.for_target(adapter_type, &config);
+            Arc::new(QueryCacheImpl::new(cache_config)) as Arc<dyn QueryCache>
+        });
         Self {
             adapter_type,
             auth,
             config,
             quoting,
             configured_databases: RwLock::new(DatabaseMap::default()),
-            semaphore: Arc::new(Semaphore::new(permits)),
+            concurrency,
             splitter,
             type_ops,
             query_comment,
@@ -214,7 +209,6 @@ impl ActualEngine {
 
         // The driver is loaded only once even if this runs multiple times.
         let mut driver = driver::Builder::new(self.auth.backend())
-            .with_semaphore(self.semaphore.clone())
             .try_load()
             .map_err(adbc_error_to_adapter_error)?;
 
@@ -291,6 +285,10 @@ impl ActualEngine {
     fn cancellation_token(&self) -> CancellationToken {
         self.cancellation_token.clone()
     }
+
+    fn concurrency(&self) -> AdapterResult<&ConcurrencyLimits> {
+        self.concurrency.as_ref().map_err(Clone::clone)
+    }
 }
 
 /// A simple bridge between adapters and the drivers.
@@ -316,7 +314,7 @@ impl SqlEngine {
         config: AdapterConfig,
         quoting: ResolvedQuoting,
         stmt_splitter: Arc<dyn StmtSplitter>,
-        query_cache: Option<Arc<dyn QueryCache>>,
+        query_cache: Option<QueryCacheConfig>,
         query_comment: QueryCommentConfig,
         type_ops: Box<dyn TypeOps>,
         token: CancellationToken,
@@ -385,7 +383,7 @@ impl SqlEngine {
             SqlEngine::Warehouse(engine) => engine.splitter.as_ref(),
             SqlEngine::Record(engine) => engine.splitter(),
             SqlEngine::Replay(engine) => engine.splitter(),
-            SqlEngine::Mock(_) => NAIVE_STMT_SPLITTER.as_ref(),
+            SqlEngine::Mock(_) => MOCK_STMT_SPLITTER.as_ref(),
         }
     }
 
@@ -491,10 +489,68 @@ impl SqlEngine {
         options: Options,
         fetch: bool,
     ) -> AdapterResult<RecordBatch> {
+        self.execute_statement(state, query_ctx, conn, None, options, fetch, None)
+    }
+
+    /// Execute the given parameterized SQL statement.
+    ///
+    /// Every row of `params` is bound to the statement's placeholders, one
+    /// column per placeholder, so a multi-row batch executes the statement
+    /// once per row. Placeholders use the driver's native syntax (`$1` on
+    /// Postgres and Redshift, `?` elsewhere). Parameterized statements
+    /// bypass the query cache.
+    pub fn execute_with_params(
+        &self,
+        state: Option<&State>,
+        query_ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        params: RecordBatch,
+        options: Options,
+        fetch: bool,
+    ) -> AdapterResult<RecordBatch> {
+        assert!(query_ctx.sql().is_some());
+        self.execute_statement(state, query_ctx, conn, Some(params), options, fetch, None)
+    }
+
+    /// Execute a statement, optionally binding `params` to it.
+    ///
+    /// `attempt` numbers the execution in telemetry when it is retried.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn execute_statement(
+        &self,
+        state: Option<&State>,
+        query_ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        params: Option<RecordBatch>,
+        options: Options,
+        fetch: bool,
+        attempt: Option<u32>,
+    ) -> AdapterResult<RecordBatch> {
+        self.try_execute_statement(state, query_ctx, conn, params, options, fetch, attempt)
+            .map_err(|e| e.error)
+    }
+
+    /// Like [SqlEngine::execute_statement], but tells whether the statement
+    /// was submitted to the warehouse when it failed.
+    #[allow(clippy::too_many_arguments)]
+    pub(crate) fn try_execute_statement(
+        &self,
+        state: Option<&State>,
+        query_ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        params: Option<RecordBatch>,
+        options: Options,
+        fetch: bool,
+        attempt: Option<u32>,
+    ) -> Result<RecordBatch, StatementError> {
         assert!(query_ctx.sql().is_some() || !options.is_empty());
 
         let maybe_query_comment = if let Some(state) = state {
-            Some(self.query_comment().resolve_comment(state)?)
+            Some(
+                self.query_comment()
+                    .resolve_comment(state)
+                    .map_err(StatementError::before_submission)?,
+            )
         } else {
             None
         };
@@ -506,9 +562,54 @@ impl SqlEngine {
                 query_ctx
             };
 
+        let adapter_type = self.adapter_type();
+        let attribution = state.and_then(QueryAttribution::from_state);
+        let query_ctx = match (attribution.as_ref(), query_ctx.sql()) {
+            (Some(attribution), Some(sql)) if QueryAttribution::uses_comment(adapter_type) => {
+                &query_ctx.with_sql(
+                    self.query_comment()
+                        .add_comment(&sql, &attribution.comment()),
+                )
+            }
+            _ => query_ctx,
+        };
+        if QueryAttribution::uses_application_name(adapter_type) {
+            // Only the first statement of a node on a session sets the name.
+            // Connections without a session get the name set every time.
+            let session = conn.session().cloned().unwrap_or_default();
+            if let Some(change_application_name) =
+                QueryAttribution::application_name_change_sql(&session, attribution.as_ref())
+            {
+                // Executed directly so it is neither attributed nor commented itself
+                let change_ctx = query_ctx.with_sql(change_application_name);
+                Self::log_query_ctx_for_execution(&change_ctx);
+                conn.new_statement()
+                    .and_then(|mut stmt| {
+                        stmt.set_sql_query(&change_ctx)?;
+                        stmt.execute_update()
+                    })
+                    .map_err(|e| {
+                        StatementError::before_submission(adbc_error_to_adapter_error(e))
+                    })?;
+                if let Some(session) = conn.session() {
+                    QueryAttribution::record_application_name(session, attribution.as_ref());
+                }
+            }
+        }
+
         let mut options = options;
+        if let Some(query_tag) = attribution
+            .as_ref()
+            .filter(|_| adapter_type == AdapterType::Snowflake)
+            .and_then(QueryAttribution::query_tag)
+        {
+            options.push((
+                SNOWFLAKE_QUERY_TAG.to_owned(),
+                OptionValue::String(query_tag),
+            ));
+        }
         if let Some(state) = state
-            && self.adapter_type() == AdapterType::Bigquery
+            && adapter_type == AdapterType::Bigquery
         {
             let mut job_labels =
                 maybe_query_comment
@@ -526,6 +627,9 @@ impl SqlEngine {
                     invocation_id_label,
                 );
             }
+            if let Some(attribution) = attribution.as_ref() {
+                job_labels.extend(attribution.job_labels());
+            }
 
             let job_label_option =
                 serde_json::to_string(&job_labels).expect("Should be able to serialize job labels");
@@ -537,7 +641,12 @@ impl SqlEngine {
 
         Self::log_query_ctx_for_execution(query_ctx);
 
+        let timeout = self
+            .query_timeout(state)
+            .map_err(StatementError::before_submission)?;
         let token = self.cancellation_token();
+        let interruption = Cell::new(None);
+        let submitted = Cell::new(false);
         let do_execute = |conn: &'_ mut dyn Connection| -> Result<
             (Arc<Schema>, Vec<RecordBatch>),
             Cancellable<adbc_core::error::Error>,
@@ -545,10 +654,15 @@ impl SqlEngine {
             use dbt_xdbc::statement::Statement as _;
 
             let mut stmt = conn.new_statement()?;
-            let mut stmt = match self.query_cache() {
-                Some(query_cache) => query_cache.new_statement(stmt, query_ctx.clone()),
-                None => {
+            let mut stmt = match (self.query_cache(), params) {
+                // The cache keys on the SQL text alone, so results of
+                // parameterized statements must never be served from it.
+                (Some(query_cache), None) => query_cache.new_statement(stmt, query_ctx.clone()),
+                (_, params) => {
                     stmt.set_sql_query(query_ctx)?;
+                    if let Some(params) = params {
+                        stmt.bind(params)?;
+                    }
                     stmt
                 }
             };
@@ -563,42 +677,101 @@ impl SqlEngine {
             // Track the statement so execution can be cancelled
             // when the user Ctrl-C's the process.
             let mut stmt = TrackedStatement::new(stmt);
-
-            let reader = stmt.execute()?;
-            let schema = reader.schema();
-            let mut batches = Vec::with_capacity(1);
-            if !fetch {
-                return Ok((schema, batches));
-            }
-            for res in reader {
-                let batch = res.map_err(adbc_core::error::Error::from)?;
-                batches.push(batch);
-                // Check for cancellation before processing the next batch
-                // or concatenating the batches produced so far.
-                token.check_cancellation()?;
-            }
-            Ok((schema, batches))
+            // The watchdog cancels the statement on the server when the
+            // timeout elapses while the driver blocks.
+            let watchdog = StatementWatchdog::spawn(stmt.id(), timeout);
+
+            let res = (|| -> Result<
+                (Arc<Schema>, Vec<RecordBatch>),
+                Cancellable<adbc_core::error::Error>,
+            > {
+                submitted.set(true);
+                let reader = stmt.execute()?;
+                let schema = reader.schema();
+                let mut batches = Vec::with_capacity(1);
+                if !fetch {
+                    return Ok((schema, batches));
+                }
+                for res in reader {
+                    let batch = res.map_err(adbc_core::error::Error::from)?;
+                    batches.push(batch);
+                    // Check for cancellation before processing the next batch
+                    // or concatenating the batches produced so far.
+                    token.check_cancellation()?;
+                }
+                Ok((schema, batches))
+            })();
+            interruption.set(watchdog.and_then(StatementWatchdog::finish));
+            res
         };
         let _span = span!("SqlEngine::execute");
 
+        // The permit is held until the statement has executed and its
+        // results are fetched.
+        let concurrency = self
+            .concurrency()
+            .map_err(StatementError::before_submission)?;
+        let semaphore = concurrency.and_then(|concurrency| {
+            concurrency.semaphore_for(&concurrency.backend_key(self.get_config(), state))
+        });
+        let permit = semaphore.as_ref().map(|semaphore| {
+            let priority = concurrency.map_or(0, |concurrency| {
+                concurrency.priority_of(query_ctx.node_id().as_deref())
+            });
+            semaphore.acquire(priority)
+        });
+        let permit_wait_ms = permit
+            .as_ref()
+            .map(|permit| permit.wait().as_millis() as u64);
+
         let sql = query_ctx.sql().unwrap_or_default();
         let sql_hash = code_hash(sql.as_ref());
-        let adapter_type = self.adapter_type();
         let _query_span_guard = create_debug_span!(
-            QueryExecuted::start(
-                sql,
-                sql_hash,
-                adapter_type.as_ref().to_owned(),
-                query_ctx.node_id(),
-                query_ctx.desc()
-            )
+            QueryExecuted {
+                attempt,
+                permit_wait_ms,
+                ..QueryExecuted::start(
+                    sql,
+                    sql_hash,
+                    adapter_type.as_ref().to_owned(),
+                    query_ctx.node_id(),
+                    query_ctx.desc(),
+                )
+            }
             .into()
         )
         .entered();
 
-        let (schema, batches) = match do_execute(conn) {
-            Ok(res) => res,
-            Err(Cancellable::Cancelled) => {
+        let res = do_execute(conn);
+        // Ctrl-C cancels tracked statements directly, the token tells why they stopped
+        let interruption = interruption
+            .get()
+            .or_else(|| token.is_cancelled().then_some(StmtInterruption::Cancelled));
+        let (schema, batches) = match (res, interruption) {
+            (Ok(res), _) => res,
+            // Drivers report a server-side cancellation as an arbitrary error,
+            // so the watchdog is the source of truth for why the statement stopped.
+            (Err(_), Some(StmtInterruption::TimedOut)) => {
+                let e = AdapterError::new(
+                    AdapterErrorKind::TimedOut,
+                    format!(
+                        "SQL statement execution exceeded the query_timeout of {}s and was cancelled",
+                        timeout.map_or(0, |t| t.as_secs())
+                    ),
+                );
+
+                record_current_span_status_from_attrs(|attrs| {
+                    if let Some(attrs) = attrs.downcast_mut::<QueryExecuted>() {
+                        // dbt core had different event codes for start and end of a query
+                        attrs.dbt_core_event_code = "E017".to_string();
+                        attrs.set_query_outcome(QueryOutcome::TimedOut);
+                        attrs.query_error_adapter_message = Some(e.message().to_string());
+                    }
+                });
+
+                return Err(e.into());
+            }
+            (Err(Cancellable::Cancelled), _) | (Err(_), Some(StmtInterruption::Cancelled)) => {
                 let e = AdapterError::new(
                     AdapterErrorKind::Cancelled,
                     "SQL statement execution was cancelled",
@@ -613,9 +786,11 @@ impl SqlEngine {
                     }
                 });
 
-                return Err(e);
+                return Err(e.into());
             }
-            Err(Cancellable::Error(e)) => {
+            (Err(Cancellable::Error(mut e)), None) => {
+                // Drivers may echo the statement, credentials included
+                e.message = redact_credentials(adapter_type, &e.message).into_owned();
                 // TODO: wouldn't it be possible to salvage query_id if at least one batch was produced?
                 record_current_span_status_from_attrs(|attrs| {
                     if let Some(attrs) = attrs.downcast_mut::<QueryExecuted>() {
@@ -628,24 +803,129 @@ impl SqlEngine {
                     }
                 });
 
-                return Err(adbc_error_to_adapter_error(e));
+                let error = adbc_error_to_adapter_error(e);
+                return Err(if submitted.get() {
+                    StatementError::from(error)
+                } else {
+                    StatementError::before_submission(error)
+                });
             }
         };
+        drop(permit);
         let total_batch =
             concat_batches(&schema, &batches).map_err(arrow_error_to_adapter_error)?;
 
+        let response = AdapterResponse::new(&total_batch, adapter_type);
         record_current_span_status_from_attrs(|attrs| {
             if let Some(attrs) = attrs.downcast_mut::<QueryExecuted>() {
                 // dbt core had different event codes for start and end of a query
                 attrs.dbt_core_event_code = "E017".to_string();
                 attrs.set_query_outcome(QueryOutcome::Success);
-                attrs.query_id = AdapterResponse::query_id(&total_batch, adapter_type)
+                attrs.query_id = response.query_id.clone();
+                attrs.bytes_processed = response.bytes_processed;
+                attrs.bytes_billed = response.bytes_billed;
+                attrs.slot_ms = response.slot_ms;
+                attrs.rows_inserted = response.rows_inserted;
+                attrs.rows_updated = response.rows_updated;
+                attrs.rows_deleted = response.rows_deleted;
             }
         });
 
         Ok(total_batch)
     }
 
+    /// Bulk ingest `batch` through the driver's ADBC ingestion.
+    ///
+    /// `options` name the target table and the ingest mode. Drivers read them
+    /// when the data is bound, so they are set before binding. Ingestion has
+    /// no SQL text, so neither the query comment nor query attribution apply.
+    /// Returns the number of rows ingested, if the driver reports it.
+    pub fn ingest(
+        &self,
+        state: Option<&State>,
+        query_ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        batch: RecordBatch,
+        options: Options,
+    ) -> AdapterResult<Option<i64>> {
+        let adapter_type = self.adapter_type();
+        Self::log_query_ctx_for_execution(query_ctx);
+
+        let timeout = self.query_timeout(state)?;
+        let token = self.cancellation_token();
+        let interruption = Cell::new(None);
+        let do_ingest = |conn: &'_ mut dyn Connection| -> Result<
+            Option<i64>,
+            Cancellable<adbc_core::error::Error>,
+        > {
+            use dbt_xdbc::statement::Statement as _;
+
+            let mut stmt = conn.new_statement()?;
+            options
+                .into_iter()
+                .try_for_each(|(key, value)| stmt.set_option(OptionStatement::Other(key), value))?;
+            stmt.bind(batch)?;
+            token.check_cancellation()?;
+
+            let mut stmt = TrackedStatement::new(stmt);
+            let watchdog = StatementWatchdog::spawn(stmt.id(), timeout);
+            let res = stmt.execute_update().map_err(Cancellable::from);
+            interruption.set(watchdog.and_then(StatementWatchdog::finish));
+            res
+        };
+        let _span = span!("SqlEngine::ingest");
+
+        let concurrency = self.concurrency()?;
+        let semaphore = concurrency.and_then(|concurrency| {
+            concurrency.semaphore_for(&concurrency.backend_key(self.get_config(), state))
+        });
+        let _permit = semaphore.as_ref().map(|semaphore| {
+            let priority = concurrency.map_or(0, |concurrency| {
+                concurrency.priority_of(query_ctx.node_id().as_deref())
+            });
+            semaphore.acquire(priority)
+        });
+
+        let res = do_ingest(conn);
+        let interruption = interruption
+            .get()
+            .or_else(|| token.is_cancelled().then_some(StmtInterruption::Cancelled));
+        match (res, interruption) {
+            (Ok(rows), _) => Ok(rows),
+            (Err(_), Some(StmtInterruption::TimedOut)) => Err(AdapterError::new(
+                AdapterErrorKind::TimedOut,
+                format!(
+                    "Bulk ingestion exceeded the query_timeout of {}s and was cancelled",
+                    timeout.map_or(0, |t| t.as_secs())
+                ),
+            )),
+            (Err(Cancellable::Cancelled), _) | (Err(_), Some(StmtInterruption::Cancelled)) => Err(
+                AdapterError::new(AdapterErrorKind::Cancelled, "Bulk ingestion was cancelled"),
+            ),
+            (Err(Cancellable::Error(mut e)), None) => {
+                e.message = redact_credentials(adapter_type, &e.message).into_owned();
+                Err(adbc_error_to_adapter_error(e))
+            }
+        }
+    }
+
+    /// Resolve the timeout for statements executed on behalf of the current node.
+    ///
+    /// The node's `query_timeout` config takes precedence over the profile's.
+    /// A timeout of `0` disables the timeout.
+    fn query_timeout(&self, state: Option<&State>) -> AdapterResult<Option<Duration>> {
+        let node_timeout = state
+            .and_then(|state| state.lookup("model"))
+            .and_then(|model| model.get_attr("config").ok())
+            .and_then(|config| config.get_attr(QUERY_TIMEOUT).ok())
+            .filter(|value| !value.is_undefined() && !value.is_none())
+            .map(|value| value.to_string());
+        match node_timeout.or_else(|| self.config(QUERY_TIMEOUT).map(Cow::into_owned)) {
+            Some(seconds) => parse_query_timeout(&seconds),
+            None => Ok(None),
+        }
+    }
+
     // TODO: kill this when telemtry starts writing dbt.log
     /// Format query context as we want to see it in a log file and log it in query_log
     pub fn log_query_ctx_for_execution(ctx: &QueryCtx) {
@@ -666,6 +946,10 @@ impl SqlEngine {
         }
 
         if let Some(sql) = ctx.sql() {
+            let sql = match ctx.adapter_type().parse::<AdapterType>() {
+                Ok(adapter_type) => redact_credentials(adapter_type, &sql),
+                Err(_) => redact_credentials_any_dialect(&sql),
+            };
             write!(&mut buf, "{sql}").unwrap();
             if !sql.ends_with(";") {
                 write!(&mut buf, ";").unwrap();
@@ -726,47 +1010,185 @@ impl SqlEngine {
             Self::Mock(_) => never_cancels(),
         }
     }
+
+    /// Get the per-backend concurrency limits
+    ///
+    /// ## Returns
+    /// always is Ok(None) for non Warehouse/Record variance
+    pub fn concurrency(&self) -> AdapterResult<Option<&ConcurrencyLimits>> {
+        match self {
+            Self::Warehouse(actual_engine) => actual_engine.concurrency().map(Some),
+            Self::Record(record_engine) => record_engine.concurrency(),
+            Self::Replay(_) | Self::Mock(_) => Ok(None),
+        }
+    }
+
+    /// Set the priorities (by node unique_id) with which statements acquire
+    /// contended permits, e.g. the critical path priorities of the DAG.
+    pub fn set_node_priorities(
+        &self,
+        priorities: impl IntoIterator<Item = (String, u32)>,
+    ) -> AdapterResult<()> {
+        if let Some(concurrency) = self.concurrency()? {
+            concurrency.set_node_priorities(priorities);
+        }
+        Ok(())
+    }
 }
 
-/// Execute query and retry in case of an error. Retry is done (up to
-/// the given limit) regardless of the error encountered.
-///
-/// https://github.com/dbt-labs/dbt-adapters/blob/996a302fa9107369eb30d733dadfaf307023f33d/dbt-adapters/src/dbt/adapters/sql/connections.py#L84
-pub fn execute_query_with_retry(
-    engine: Arc<SqlEngine>,
-    state: Option<&State>,
-    conn: &'_ mut dyn Connection,
-    query_ctx: &QueryCtx,
-    retry_limit: u32,
-    options: &Options,
-    fetch: bool,
-) -> AdapterResult<RecordBatch> {
-    let mut attempt = 0;
-    let mut last_error = None;
-
-    while attempt < retry_limit {
-        match engine.execute_with_options(state, query_ctx, conn, options.clone(), fetch) {
-            Ok(result) => return Ok(result),
-            Err(err) => {
-                last_error = Some(err.clone());
-                thread::sleep(Duration::from_secs(1));
-                attempt += 1;
-            }
+/// Error of a statement executed by [SqlEngine::try_execute_statement]
+#[derive(Debug)]
+pub(crate) struct StatementError {
+    pub(crate) error: AdapterError,
+    /// Whether the statement may have reached the warehouse before failing
+    pub(crate) submitted: bool,
+}
+
+impl StatementError {
+    /// Error raised before the statement was submitted, e.g. while preparing it
+    fn before_submission(error: AdapterError) -> Self {
+        Self {
+            error,
+            submitted: false,
         }
     }
+}
 
-    if let Some(err) = last_error {
-        Err(err)
-    } else {
-        unreachable!("last_error should not be None if we exit the loop")
+impl From<AdapterError> for StatementError {
+    /// Unless known otherwise, the statement may have been submitted
+    fn from(error: AdapterError) -> Self {
+        Self {
+            error,
+            submitted: true,
+        }
     }
 }
 
+/// Parse a `query_timeout` value given in seconds.
+fn parse_query_timeout(seconds: &str) -> AdapterResult<Option<Duration>> {
+    let seconds = seconds.trim().parse::<u64>().map_err(|e| {
+        AdapterError::new(
+            AdapterErrorKind::Configuration,
+            format!("{QUERY_TIMEOUT} must be a non-negative number of seconds: {e}"),
+        )
+    })?;
+    Ok((seconds > 0).then(|| Duration::from_secs(seconds)))
+}
+
 #[cfg(test)]
 mod tests {
     use dbt_xdbc::QueryCtx;
 
-    use super::SqlEngine;
+    use super::{SqlEngine, parse_query_timeout};
+    use std::time::Duration;
+
+    use crate::config::AdapterConfig;
+    use crate::query_comment::QueryCommentConfig;
+    use crate::sql_types::NaiveTypeOpsImpl;
+    use crate::stmt_splitter::DialectStmtSplitter;
+    use adbc_core::PartitionedResult;
+    use adbc_core::error::Result as AdbcResult;
+    use arrow_array::{RecordBatchIterator, RecordBatchReader};
+    use arrow_schema::Schema;
+    use dbt_auth::auth_for_backend;
+    use dbt_common::adapter::AdapterType;
+    use dbt_common::cancellation::never_cancels;
+    use dbt_schemas::schemas::relations::DEFAULT_RESOLVED_QUOTING;
+    use dbt_xdbc::{Backend, Connection, Statement};
+    use std::sync::{Arc, Mutex};
+    use std::thread;
+
+    /// Connection whose statements record the node they execute for
+    struct RecordingConnection(Arc<Mutex<Vec<String>>>);
+
+    struct RecordingStatement {
+        executed: Arc<Mutex<Vec<String>>>,
+        node_id: Option<String>,
+    }
+
+    impl Connection for RecordingConnection {
+        fn new_statement(&mut self) -> AdbcResult<Box<dyn Statement>> {
+            Ok(Box::new(RecordingStatement {
+                executed: self.0.clone(),
+                node_id: None,
+            }))
+        }
+
+        fn cancel(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+
+        fn commit(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+
+        fn rollback(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+
+        fn get_table_schema(
+            &self,
+            _catalog: Option<&str>,
+            _db_schema: Option<&str>,
+            _table_name: &str,
+        ) -> AdbcResult<Schema> {
+            unimplemented!()
+        }
+
+        fn update_node_id(&mut self, _node_id: Option<String>) {}
+    }
+
+    impl Statement for RecordingStatement {
+        fn bind(&mut self, _batch: arrow::array::RecordBatch) -> AdbcResult<()> {
+            unimplemented!()
+        }
+
+        fn bind_stream(&mut self, _reader: Box<dyn RecordBatchReader + Send>) -> AdbcResult<()> {
+            unimplemented!()
+        }
+
+        fn execute<'a>(&'a mut self) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'a>> {
+            let node_id = self.node_id.clone().unwrap_or_default();
+            self.executed.lock().unwrap().push(node_id);
+            Ok(Box::new(RecordBatchIterator::new(
+                vec![],
+                Arc::new(Schema::empty()),
+            )))
+        }
+
+        fn execute_update(&mut self) -> AdbcResult<Option<i64>> {
+            unimplemented!()
+        }
+
+        fn execute_schema(&mut self) -> AdbcResult<Schema> {
+            unimplemented!()
+        }
+
+        fn execute_partitions(&mut self) -> AdbcResult<PartitionedResult> {
+            unimplemented!()
+        }
+
+        fn get_parameter_schema(&self) -> AdbcResult<Schema> {
+            unimplemented!()
+        }
+
+        fn prepare(&mut self) -> AdbcResult<()> {
+            unimplemented!()
+        }
+
+        fn set_sql_query(&mut self, query: &QueryCtx) -> AdbcResult<()> {
+            self.node_id = query.node_id();
+            Ok(())
+        }
+
+        fn set_substrait_plan(&mut self, _plan: &[u8]) -> AdbcResult<()> {
+            unimplemented!()
+        }
+
+        fn cancel(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+    }
 
     #[test]
     fn test_log_for_execution() {
@@ -778,4 +1200,76 @@ mod tests {
         // Should not panic
         SqlEngine::log_query_ctx_for_execution(&query_ctx);
     }
+
+    #[test]
+    fn test_parse_query_timeout() {
+        assert_eq!(
+            parse_query_timeout("30").unwrap(),
+            Some(Duration::from_secs(30))
+        );
+        assert_eq!(
+            parse_query_timeout(" 5 ").unwrap(),
+            Some(Duration::from_secs(5))
+        );
+        // 0 disables the timeout
+        assert_eq!(parse_query_timeout("0").unwrap(), None);
+        assert!(parse_query_timeout("-1").is_err());
+        assert!(parse_query_timeout("1m").is_err());
+    }
+
+    #[test]
+    fn test_higher_priority_node_executes_first() {
+        let config = dbt_serde_yaml::from_str("max_concurrent_queries: 1").unwrap();
+        let engine = SqlEngine::new(
+            AdapterType::Postgres,
+            auth_for_backend(Backend::Postgres).into(),
+            AdapterConfig::new(config),
+            DEFAULT_RESOLVED_QUOTING,
+            Arc::new(DialectStmtSplitter),
+            None,
+            QueryCommentConfig::from_query_comment(None, AdapterType::Postgres, false),
+            Box::new(NaiveTypeOpsImpl::new(AdapterType::Postgres)),
+            never_cancels(),
+        );
+        engine
+            .set_node_priorities([("model.p.critical".to_string(), 5)])
+            .unwrap();
+
+        // Hold the only permit until both nodes are waiting for it
+        let semaphore = engine
+            .concurrency()
+            .unwrap()
+            .unwrap()
+            .semaphore_for("")
+            .unwrap();
+        let permit = semaphore.acquire(0);
+
+        let executed = Arc::new(Mutex::new(Vec::new()));
+        let mut handles = vec![];
+        // The low priority node queues up first
+        for (queued, node_id) in ["model.p.leaf", "model.p.critical"].into_iter().enumerate() {
+            let engine = engine.clone();
+            let mut conn = RecordingConnection(executed.clone());
+            handles.push(thread::spawn(move || {
+                let query_ctx = QueryCtx::new("postgres")
+                    .with_node_id(node_id)
+                    .with_sql("select 1");
+                engine
+                    .execute_statement(None, &query_ctx, &mut conn, None, vec![], false, None)
+                    .unwrap();
+            }));
+            // Wait until the node is blocked on the permit
+            while semaphore.waiting() <= queued {
+                thread::yield_now();
+            }
+        }
+        drop(permit);
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(
+            *executed.lock().unwrap(),
+            vec!["model.p.critical".to_string(), "model.p.leaf".to_string()]
+        );
+    }
 }
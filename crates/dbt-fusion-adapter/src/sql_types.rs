@@ -116,8 +116,10 @@ impl TypeOps for NaiveTypeOpsImpl {
         })
     }
 
-    fn parse_into_nullable_arrow_type(&self, _s: &str) -> AdapterResult<(DataType, bool)> {
-        todo!("NaiveTypeOpsImpl::parse_into_nullable_arrow_type")
+    fn parse_into_nullable_arrow_type(&self, s: &str) -> AdapterResult<(DataType, bool)> {
+        let (sql_type, nullable) = parse_nullable_sql_type(s, self.0)?;
+        let field = sql_type.to_field(self.1, String::new(), nullable);
+        Ok((field.data_type().clone(), nullable))
     }
 }
 
@@ -1,6 +1,9 @@
 use core::fmt;
-use std::sync::LazyLock;
-use std::sync::atomic::{AtomicU64, Ordering};
+use std::collections::BTreeMap;
+use std::sync::atomic::{AtomicBool, AtomicU64, Ordering};
+use std::sync::{Arc, Condvar, LazyLock, Mutex};
+use std::thread;
+use std::time::{Duration, Instant};
 
 use adbc_core::PartitionedResult;
 use adbc_core::error::Result;
@@ -128,6 +131,22 @@ pub fn cancel_all_tracked_statements(from_stmt_id: u64) -> StmtCancellationRepor
     }
 }
 
+/// Cancel a single tracked statement.
+///
+/// Returns `None` if the statement is not tracked anymore (i.e. it finished
+/// and was dropped before the cancellation request could be delivered).
+pub fn cancel_tracked_statement(stmt_id: u64) -> Option<Result<()>> {
+    let _all_permits = TRACKED_STMTS_SEMAPHORE.acquire_all();
+    let entry = TRACKED_STMTS.get(&stmt_id)?;
+    let mut erased_ptr = *entry.value();
+    // SAFETY: the Drop handler of the [TrackedStatement] is blocked by the
+    // semaphore, so the pointer is still valid. The same RISK described in
+    // `cancel_all_tracked_statements` applies here: `cancel()` is called
+    // concurrently with the thread executing the statement.
+    let stmt = unsafe { erased_ptr.as_raw_ptr() };
+    Some(stmt.cancel())
+}
+
 /// De-registers a statement from the global `TRACKED_STMTS` map and drops it.
 ///
 /// IMPORTANT: must be called from the destructor of [TrackedStatement] which,
@@ -174,6 +193,11 @@ impl TrackedStatement {
         }
     }
 
+    /// The ID under which this statement is tracked.
+    pub fn id(&self) -> u64 {
+        self.stmt_id
+    }
+
     #[inline]
     fn inner(&self) -> &dyn Statement {
         self.inner_ptr
@@ -242,3 +266,109 @@ impl Statement for TrackedStatement {
         self.inner().debug_fmt(f)
     }
 }
+
+/// Why a statement was interrupted before it completed.
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub enum StmtInterruption {
+    /// The statement ran for longer than its `query_timeout`.
+    TimedOut,
+    /// The cancellation token fired (e.g. the user pressed Ctrl-C).
+    Cancelled,
+}
+
+/// The deadlines of all statements watched by a [StatementWatchdog].
+///
+/// A single timer thread sleeps until the earliest deadline and cancels the
+/// statements whose deadline passed. Each entry holds the flag that tells
+/// the watchdog its statement was timed out.
+struct WatchdogTimer {
+    deadlines: Mutex<BTreeMap<(Instant, u64), Arc<AtomicBool>>>,
+    cvar: Condvar,
+}
+
+static WATCHDOG_TIMER: LazyLock<Arc<WatchdogTimer>> = LazyLock::new(|| {
+    let timer = Arc::new(WatchdogTimer {
+        deadlines: Mutex::new(BTreeMap::new()),
+        cvar: Condvar::new(),
+    });
+    let _ = {
+        let timer = Arc::clone(&timer);
+        thread::Builder::new()
+            .name("stmt-watchdog".to_string())
+            // Failing to spawn the thread only means we lose the ability
+            // to time out statements, the queries themselves can proceed.
+            .spawn(move || timer.run())
+    };
+    timer
+});
+
+impl WatchdogTimer {
+    fn run(&self) {
+        let mut deadlines = self.deadlines.lock().unwrap();
+        loop {
+            let now = Instant::now();
+            let Some(&(deadline, stmt_id)) = deadlines.keys().next() else {
+                deadlines = self.cvar.wait(deadlines).unwrap();
+                continue;
+            };
+            if deadline > now {
+                deadlines = self.cvar.wait_timeout(deadlines, deadline - now).unwrap().0;
+                continue;
+            }
+            if let Some(timed_out) = deadlines.remove(&(deadline, stmt_id)) {
+                timed_out.store(true, Ordering::Release);
+            }
+            // Release the lock before calling into the driver so watchdogs
+            // are never blocked by a slow cancellation.
+            drop(deadlines);
+            let _ = cancel_tracked_statement(stmt_id);
+            deadlines = self.deadlines.lock().unwrap();
+        }
+    }
+}
+
+/// Asks the server to cancel a [TrackedStatement] once its `query_timeout`
+/// elapses.
+///
+/// Driver calls like `execute()` block until the warehouse answers, so the
+/// statement is cancelled from the shared timer thread instead. Cancellation
+/// through the token (e.g. Ctrl-C) goes through [cancel_all_tracked_statements].
+pub struct StatementWatchdog {
+    key: (Instant, u64),
+    timed_out: Arc<AtomicBool>,
+}
+
+impl StatementWatchdog {
+    /// Start watching the statement tracked under `stmt_id`.
+    ///
+    /// Returns `None` when there is no timeout, nothing needs watching then.
+    pub fn spawn(stmt_id: u64, timeout: Option<Duration>) -> Option<Self> {
+        let key = (Instant::now() + timeout?, stmt_id);
+        let timed_out = Arc::new(AtomicBool::new(false));
+        let timer = &*WATCHDOG_TIMER;
+        let mut deadlines = timer.deadlines.lock().unwrap();
+        let is_earliest = deadlines
+            .keys()
+            .next()
+            .is_none_or(|earliest| key < *earliest);
+        deadlines.insert(key, Arc::clone(&timed_out));
+        if is_earliest {
+            timer.cvar.notify_one();
+        }
+        Some(Self { key, timed_out })
+    }
+
+    /// Stop watching and return the reason the statement was interrupted, if any.
+    pub fn finish(self) -> Option<StmtInterruption> {
+        // Dropping `self` stops watching
+        self.timed_out
+            .load(Ordering::Acquire)
+            .then_some(StmtInterruption::TimedOut)
+    }
+}
+
+impl Drop for StatementWatchdog {
+    fn drop(&mut self) {
+        WATCHDOG_TIMER.deadlines.lock().unwrap().remove(&self.key);
+    }
+}
@@ -1,4 +1,5 @@
 use dbt_frontend_common::dialect::Dialect;
+use dbt_xdbc::sql::tokenizer::{DollarQuoting, LexerOptions, Token, Tokenizer};
 use std::fmt::Debug;
 
 /// Trait for SQL statement splitting functionality
@@ -14,19 +15,289 @@ pub trait StmtSplitter: Send + Sync + Debug {
     fn is_empty(&self, sql: &str, dialect: Dialect) -> bool;
 }
 
-/// Naive implementation of StmtSplitter
+/// Statement splitter built on the [Tokenizer] from `dbt_xdbc`.
 ///
-/// Used as a placeholder until a more robust solution is made available
-/// to this crate.
-#[derive(Debug)]
-pub struct NaiveStmtSplitter;
+/// Semicolons only terminate a statement when they appear outside of
+/// string literals, quoted identifiers, comments, dollar-quoted bodies and
+/// procedural blocks (`BEGIN ... END`, `IF ... END IF`, etc.).
+#[derive(Debug, Default)]
+pub struct DialectStmtSplitter;
 
-impl StmtSplitter for NaiveStmtSplitter {
-    fn split(&self, sql: &str, _dialect: Dialect) -> Vec<String> {
-        sql.split(';').map(|s| s.trim().to_string()).collect()
+impl StmtSplitter for DialectStmtSplitter {
+    fn split(&self, sql: &str, dialect: Dialect) -> Vec<String> {
+        let mut tokenizer = Tokenizer::with_options(sql, lexer_options(dialect));
+        let scripting = has_scripting_blocks(dialect);
+
+        let mut statements = Vec::new();
+        let mut blocks = Vec::<Block>::new();
+        let mut start = 0;
+        let mut at_stmt_start = true;
+        loop {
+            let before = tokenizer.position();
+            let word = match tokenizer.next() {
+                None => {
+                    push_statement(&mut statements, &sql[start..]);
+                    break;
+                }
+                Some(Token::Comment(_)) => continue,
+                Some(Token::Semicolon) => {
+                    if blocks.is_empty() {
+                        push_statement(&mut statements, &sql[start..before]);
+                        start = tokenizer.position();
+                    }
+                    at_stmt_start = true;
+                    continue;
+                }
+                Some(Token::Word(word)) => word,
+                Some(_) => {
+                    at_stmt_start = false;
+                    continue;
+                }
+            };
+            let stmt_start = std::mem::replace(&mut at_stmt_start, false);
+            if stmt_start && scripting && tokenizer.match_(|tok| tok == Token::Colon) {
+                // `label: BEGIN` and `label: LOOP` label the statement that follows.
+                at_stmt_start = true;
+                continue;
+            }
+
+            if word.eq_ignore_ascii_case("BEGIN") {
+                if blocks.last() == Some(&Block::Declare) {
+                    // Snowflake's DECLARE section ends where its block begins.
+                    blocks.pop();
+                    blocks.push(Block::Begin);
+                    at_stmt_start = true;
+                } else if is_block_begin(&tokenizer, scripting) {
+                    blocks.push(Block::Begin);
+                    at_stmt_start = true;
+                }
+            } else if word.eq_ignore_ascii_case("END") {
+                // Consume the keyword of `END IF`, `END LOOP`, etc.
+                tokenizer.match_(|tok| matches!(tok, Token::Word(w) if is_control_flow(w)));
+                blocks.pop();
+            } else if word.eq_ignore_ascii_case("CASE") {
+                if scripting && stmt_start {
+                    blocks.push(Block::Statement);
+                } else if !blocks.is_empty() {
+                    // The END of a CASE expression must not close the enclosing block.
+                    blocks.push(Block::Expression);
+                }
+            } else if scripting && stmt_start && is_control_flow(word) {
+                blocks.push(Block::Statement);
+                // LOOP and REPEAT bodies start right away, the others after DO or THEN.
+                at_stmt_start =
+                    word.eq_ignore_ascii_case("LOOP") || word.eq_ignore_ascii_case("REPEAT");
+            } else if dialect == Dialect::Snowflake
+                && stmt_start
+                && word.eq_ignore_ascii_case("DECLARE")
+            {
+                blocks.push(Block::Declare);
+                at_stmt_start = true;
+            } else if blocks.last() == Some(&Block::Statement)
+                && ["THEN", "ELSE", "DO"]
+                    .iter()
+                    .any(|k| word.eq_ignore_ascii_case(k))
+            {
+                at_stmt_start = true;
+            }
+        }
+        statements
+    }
+
+    fn is_empty(&self, sql: &str, dialect: Dialect) -> bool {
+        let mut tokenizer = Tokenizer::with_options(sql, lexer_options(dialect));
+        while let Some(tok) = tokenizer.next() {
+            if !matches!(tok, Token::Comment(_) | Token::Semicolon) {
+                return false;
+            }
+        }
+        true
+    }
+}
+
+/// Constructs whose body may contain semicolons.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Block {
+    /// `BEGIN ... END`
+    Begin,
+    /// Snowflake `DECLARE ... BEGIN ... END`, before the `BEGIN`
+    Declare,
+    /// Procedural `IF`, `CASE`, `LOOP`, `WHILE`, `FOR` and `REPEAT`
+    Statement,
+    /// `CASE ... END` expression nested in a block
+    Expression,
+}
+
+/// Keywords of procedural statements that are closed by `END <keyword>`.
+fn is_control_flow(word: &str) -> bool {
+    ["IF", "CASE", "LOOP", "WHILE", "FOR", "REPEAT"]
+        .iter()
+        .any(|k| word.eq_ignore_ascii_case(k))
+}
+
+/// Dialects with procedural scripting outside of dollar-quoted bodies.
+fn has_scripting_blocks(dialect: Dialect) -> bool {
+    matches!(
+        dialect,
+        Dialect::Snowflake
+            | Dialect::Bigquery
+            | Dialect::Databricks
+            | Dialect::SparkSql
+            | Dialect::SparkLp
+    )
+}
+
+/// Decide whether the `BEGIN` just consumed opens a block or a transaction.
+fn is_block_begin(tokenizer: &Tokenizer<'_>, scripting: bool) -> bool {
+    let mut lookahead = tokenizer.clone();
+    let next = loop {
+        match lookahead.next() {
+            Some(Token::Comment(_)) => continue,
+            tok => break tok,
+        }
+    };
+    match next {
+        // PostgreSQL SQL-standard function bodies: BEGIN ATOMIC ... END
+        Some(Token::Word(w)) if w.eq_ignore_ascii_case("ATOMIC") => true,
+        // BEGIN [TRANSACTION | WORK | NAME <name>]
+        None | Some(Token::Semicolon) => false,
+        Some(Token::Word(w))
+            if ["TRANSACTION", "WORK", "NAME"]
+                .iter()
+                .any(|k| w.eq_ignore_ascii_case(k)) =>
+        {
+            false
+        }
+        Some(_) => scripting,
+    }
+}
+
+fn push_statement(statements: &mut Vec<String>, stmt: &str) {
+    let stmt = stmt.trim();
+    if !stmt.is_empty() {
+        statements.push(stmt.to_string());
+    }
+}
+
+/// Dialect-specific lexical rules for string literals, identifiers and comments.
+fn lexer_options(dialect: Dialect) -> LexerOptions {
+    let statements = LexerOptions {
+        statements: true,
+        ..LexerOptions::default()
+    };
+    match dialect {
+        Dialect::Postgresql => LexerOptions {
+            dollar_quoting: DollarQuoting::Tagged,
+            nested_block_comments: true,
+            ..statements
+        },
+        Dialect::Redshift => LexerOptions {
+            dollar_quoting: DollarQuoting::Tagged,
+            backslash_escapes: true,
+            ..statements
+        },
+        Dialect::Snowflake => LexerOptions {
+            dollar_quoting: DollarQuoting::Anonymous,
+            backslash_escapes: true,
+            ..statements
+        },
+        Dialect::Bigquery => LexerOptions {
+            backslash_escapes: true,
+            hash_comments: true,
+            triple_quotes: true,
+            ..statements
+        },
+        Dialect::Databricks | Dialect::SparkSql | Dialect::SparkLp => LexerOptions {
+            backslash_escapes: true,
+            nested_block_comments: true,
+            ..statements
+        },
+        Dialect::Sdf | Dialect::Trino | Dialect::DataFusion => statements,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn split(sql: &str, dialect: Dialect) -> Vec<String> {
+        DialectStmtSplitter.split(sql, dialect)
+    }
+
+    #[test]
+    fn test_split_respects_quotes_and_comments() {
+        assert_eq!(
+            split(
+                "select ';' as a; select \"b;c\" -- x;\nfrom t; /* ; */",
+                Dialect::Postgresql
+            ),
+            vec!["select ';' as a", "select \"b;c\" -- x;\nfrom t", "/* ; */"]
+        );
+        assert_eq!(
+            split("select 'it\\'s;'; select `a;b`; # c;", Dialect::Bigquery),
+            vec!["select 'it\\'s;'", "select `a;b`", "# c;"]
+        );
+        assert_eq!(
+            split("select '''a;b'''; select 1", Dialect::Bigquery),
+            vec!["select '''a;b'''", "select 1"]
+        );
+    }
+
+    #[test]
+    fn test_split_dollar_quoted_bodies() {
+        let sql = "create function f() returns int as $body$ begin return 1; end; $body$ language plpgsql; select f()";
+        assert_eq!(
+            split(sql, Dialect::Postgresql),
+            vec![
+                "create function f() returns int as $body$ begin return 1; end; $body$ language plpgsql",
+                "select f()"
+            ]
+        );
+        let sql =
+            "create procedure p() returns int language sql as $$ begin return 1; end; $$; call p()";
+        assert_eq!(
+            split(sql, Dialect::Snowflake),
+            vec![
+                "create procedure p() returns int language sql as $$ begin return 1; end; $$",
+                "call p()"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_split_scripting_blocks() {
+        let sql = "begin transaction; declare x int64 default 1; begin select case when x = 1 then 2 end; if x > 0 then select 1; end if; end; commit transaction;";
+        assert_eq!(
+            split(sql, Dialect::Bigquery),
+            vec![
+                "begin transaction",
+                "declare x int64 default 1",
+                "begin select case when x = 1 then 2 end; if x > 0 then select 1; end if; end",
+                "commit transaction"
+            ]
+        );
+        let sql = "declare c int; begin c := 1; loop c := c + 1; end loop; return c; end; select 1";
+        assert_eq!(
+            split(sql, Dialect::Snowflake),
+            vec![
+                "declare c int; begin c := 1; loop c := c + 1; end loop; return c; end",
+                "select 1"
+            ]
+        );
+        assert_eq!(
+            split("begin; insert into t values (1); end;", Dialect::Redshift),
+            vec!["begin", "insert into t values (1)", "end"]
+        );
     }
 
-    fn is_empty(&self, sql: &str, _dialect: Dialect) -> bool {
-        sql.trim().is_empty()
+    #[test]
+    fn test_is_empty() {
+        let splitter = DialectStmtSplitter;
+        assert!(splitter.is_empty("", Dialect::Snowflake));
+        assert!(splitter.is_empty("  \n ", Dialect::Snowflake));
+        assert!(splitter.is_empty("-- comment\n/* another */;", Dialect::Snowflake));
+        assert!(splitter.is_empty("# comment", Dialect::Bigquery));
+        assert!(!splitter.is_empty("# comment", Dialect::Snowflake));
+        assert!(!splitter.is_empty("-- comment\nselect 1", Dialect::Postgresql));
     }
 }
@@ -0,0 +1,280 @@
+//! Explicit transactions on adapter connections.
+//!
+//! Like dbt-core's connection managers, statements executed with
+//! `auto_begin` begin a transaction on connections that are not in one yet,
+//! `adapter.commit()` commits it, and an error rolls it back. Only the
+//! adapters whose dbt-core connection managers issue `BEGIN` do so; the
+//! others run every statement in autocommit mode. Whether a transaction is
+//! open is tracked in the connection's [Session](dbt_xdbc::connection::Session).
+
+use crate::AdapterType;
+use crate::errors::AdapterResult;
+use crate::sql_engine::SqlEngine;
+
+use dbt_xdbc::{Connection, QueryCtx};
+use minijinja::State;
+
+/// Whether dbt begins explicit transactions on connections of `adapter_type`
+pub fn uses_transactions(adapter_type: AdapterType) -> bool {
+    matches!(adapter_type, AdapterType::Postgres | AdapterType::Redshift)
+}
+
+/// Begin a transaction on `conn`, unless one is already open
+pub fn begin(
+    engine: &SqlEngine,
+    state: Option<&State>,
+    conn: &'_ mut dyn Connection,
+    query_ctx: &QueryCtx,
+) -> AdapterResult<()> {
+    if !uses_transactions(engine.adapter_type()) {
+        return Ok(());
+    }
+    match conn.session() {
+        Some(session) if !session.transaction_open => {}
+        _ => return Ok(()),
+    }
+    let query_ctx = query_ctx.with_sql("BEGIN").with_desc("Begin transaction");
+    engine.execute(state, conn, &query_ctx)?;
+    set_transaction_open(conn, true);
+    Ok(())
+}
+
+/// Commit the transaction open on `conn`, if any
+pub fn commit(
+    engine: &SqlEngine,
+    state: Option<&State>,
+    conn: &'_ mut dyn Connection,
+    query_ctx: &QueryCtx,
+) -> AdapterResult<()> {
+    end(engine, state, conn, query_ctx, "COMMIT")
+}
+
+/// Roll back the transaction open on `conn`, if any
+pub fn rollback(
+    engine: &SqlEngine,
+    state: Option<&State>,
+    conn: &'_ mut dyn Connection,
+    query_ctx: &QueryCtx,
+) -> AdapterResult<()> {
+    end(engine, state, conn, query_ctx, "ROLLBACK")
+}
+
+fn end(
+    engine: &SqlEngine,
+    state: Option<&State>,
+    conn: &'_ mut dyn Connection,
+    query_ctx: &QueryCtx,
+    sql: &str,
+) -> AdapterResult<()> {
+    if !conn
+        .session()
+        .is_some_and(|session| session.transaction_open)
+    {
+        return Ok(());
+    }
+    // The transaction is over even if ending it fails
+    set_transaction_open(conn, false);
+    let query_ctx = query_ctx.with_sql(sql).with_desc("End transaction");
+    engine.execute(state, conn, &query_ctx)?;
+    Ok(())
+}
+
+fn set_transaction_open(conn: &'_ mut dyn Connection, open: bool) {
+    if let Some(session) = conn.session() {
+        session.transaction_open = open;
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::base_adapter::AdapterTyping;
+    use crate::config::AdapterConfig;
+    use crate::postgres::adapter::PostgresAdapter;
+    use crate::query_comment::QueryCommentConfig;
+    use crate::sql_types::NaiveTypeOpsImpl;
+    use crate::stmt_splitter::DialectStmtSplitter;
+    use crate::typed_adapter::TypedBaseAdapter;
+    use adbc_core::PartitionedResult;
+    use adbc_core::error::Result as AdbcResult;
+    use arrow::array::{Int64Array, RecordBatch, RecordBatchIterator, RecordBatchReader};
+    use arrow_schema::{DataType, Field, Schema};
+    use dbt_auth::auth_for_backend;
+    use dbt_common::cancellation::never_cancels;
+    use dbt_schemas::schemas::relations::DEFAULT_RESOLVED_QUOTING;
+    use dbt_xdbc::connection::Session;
+    use dbt_xdbc::{Backend, Statement};
+    use std::sync::{Arc, Mutex};
+
+    /// Connection that logs the SQL of the statements it executes
+    struct SqlLogConnection(Arc<Mutex<Vec<String>>>, Session);
+
+    struct SqlLogStatement(Arc<Mutex<Vec<String>>>, Option<String>);
+
+    impl Connection for SqlLogConnection {
+        fn new_statement(&mut self) -> AdbcResult<Box<dyn Statement>> {
+            Ok(Box::new(SqlLogStatement(self.0.clone(), None)))
+        }
+
+        fn cancel(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+
+        fn commit(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+
+        fn rollback(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+
+        fn get_table_schema(
+            &self,
+            _catalog: Option<&str>,
+            _db_schema: Option<&str>,
+            _table_name: &str,
+        ) -> AdbcResult<Schema> {
+            unimplemented!()
+        }
+
+        fn update_node_id(&mut self, _node_id: Option<String>) {}
+
+        fn session(&mut self) -> Option<&mut Session> {
+            Some(&mut self.1)
+        }
+    }
+
+    impl Statement for SqlLogStatement {
+        fn bind(&mut self, _batch: RecordBatch) -> AdbcResult<()> {
+            Ok(())
+        }
+
+        fn bind_stream(&mut self, _reader: Box<dyn RecordBatchReader + Send>) -> AdbcResult<()> {
+            unimplemented!()
+        }
+
+        fn execute<'a>(&'a mut self) -> AdbcResult<Box<dyn RecordBatchReader + Send + 'a>> {
+            self.0
+                .lock()
+                .unwrap()
+                .push(self.1.clone().unwrap_or_default());
+            Ok(Box::new(RecordBatchIterator::new(
+                vec![],
+                Arc::new(Schema::empty()),
+            )))
+        }
+
+        fn execute_update(&mut self) -> AdbcResult<Option<i64>> {
+            unimplemented!()
+        }
+
+        fn execute_schema(&mut self) -> AdbcResult<Schema> {
+            unimplemented!()
+        }
+
+        fn execute_partitions(&mut self) -> AdbcResult<PartitionedResult> {
+            unimplemented!()
+        }
+
+        fn get_parameter_schema(&self) -> AdbcResult<Schema> {
+            unimplemented!()
+        }
+
+        fn prepare(&mut self) -> AdbcResult<()> {
+            unimplemented!()
+        }
+
+        fn set_sql_query(&mut self, query: &QueryCtx) -> AdbcResult<()> {
+            self.1 = query.sql();
+            Ok(())
+        }
+
+        fn set_substrait_plan(&mut self, _plan: &[u8]) -> AdbcResult<()> {
+            unimplemented!()
+        }
+
+        fn cancel(&mut self) -> AdbcResult<()> {
+            Ok(())
+        }
+    }
+
+    fn adapter(adapter_type: AdapterType, backend: Backend) -> PostgresAdapter {
+        PostgresAdapter::new(SqlEngine::new(
+            adapter_type,
+            auth_for_backend(backend).into(),
+            AdapterConfig::new(Default::default()),
+            DEFAULT_RESOLVED_QUOTING,
+            Arc::new(DialectStmtSplitter),
+            None,
+            QueryCommentConfig::from_query_comment(None, adapter_type, false),
+            Box::new(NaiveTypeOpsImpl::new(adapter_type)),
+            never_cancels(),
+        ))
+    }
+
+    fn params() -> RecordBatch {
+        let schema = Arc::new(Schema::new(vec![Field::new("id", DataType::Int64, false)]));
+        RecordBatch::try_new(schema, vec![Arc::new(Int64Array::from(vec![1]))]).unwrap()
+    }
+
+    #[test]
+    fn test_auto_begin_begins_a_transaction() {
+        let adapter = adapter(AdapterType::Postgres, Backend::Postgres);
+        let executed = Arc::new(Mutex::new(Vec::new()));
+        let mut conn = SqlLogConnection(executed.clone(), Session::default());
+        let query_ctx = QueryCtx::new("postgres").with_sql("insert into t values ($1)");
+
+        adapter
+            .execute_with_params(
+                None,
+                &mut conn,
+                &query_ctx,
+                params(),
+                true,
+                false,
+                None,
+                None,
+            )
+            .unwrap();
+        assert!(conn.1.transaction_open);
+        // The open transaction is reused rather than begun again
+        adapter
+            .execute(None, &mut conn, &query_ctx, true, false, None, None)
+            .unwrap();
+        commit(adapter.engine(), None, &mut conn, &query_ctx).unwrap();
+        assert!(!conn.1.transaction_open);
+        assert_eq!(
+            *executed.lock().unwrap(),
+            [
+                "BEGIN",
+                "insert into t values ($1)",
+                "insert into t values ($1)",
+                "COMMIT"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_no_transaction_without_auto_begin() {
+        let adapter = adapter(AdapterType::Postgres, Backend::Postgres);
+        let executed = Arc::new(Mutex::new(Vec::new()));
+        let mut conn = SqlLogConnection(executed.clone(), Session::default());
+        let query_ctx = QueryCtx::new("postgres").with_sql("insert into t values ($1)");
+
+        adapter
+            .execute_with_params(
+                None,
+                &mut conn,
+                &query_ctx,
+                params(),
+                false,
+                false,
+                None,
+                None,
+            )
+            .unwrap();
+        commit(adapter.engine(), None, &mut conn, &query_ctx).unwrap();
+        assert!(!conn.1.transaction_open);
+        assert_eq!(*executed.lock().unwrap(), ["insert into t values ($1)"]);
+    }
+}
@@ -1,13 +1,19 @@
+use crate::cache::SchemaLastAltered;
 use crate::column::ColumnBuilder;
 use crate::columns::StdColumn;
 use crate::errors::{AdapterError, AdapterErrorKind};
 use crate::funcs::{execute_macro, none_value};
+use crate::grants::{GrantsDict, GrantsPlan};
+use crate::ingest::{IngestMode, ingest_record_batch};
 use crate::metadata::CatalogAndSchema;
-use crate::record_batch_utils::get_column_values;
-use crate::relation_object::RelationObject;
+use crate::record_batch_utils::{get_column_values, limit_rows};
+use crate::relation_object::{RelationObject, create_relation};
 use crate::response::{AdapterResponse, ResultObject};
+use crate::retry::{RetryPolicy, execute_with_retry};
+use crate::schema_evolution::SchemaChangePlan;
 use crate::snapshots::SnapshotStrategy;
-use crate::sql_engine::{Options as ExecuteOptions, SqlEngine, execute_query_with_retry};
+use crate::sql_engine::{Options as ExecuteOptions, SqlEngine};
+use crate::transactions;
 use crate::{AdapterResult, AdapterType, AdapterTyping};
 
 use adbc_core::options::OptionValue;
@@ -16,11 +22,14 @@ use arrow_schema::{DataType, Schema};
 use dbt_agate::AgateTable;
 use dbt_common::FsResult;
 use dbt_common::behavior_flags::BehaviorFlag;
+use dbt_common::redaction::redact_credentials;
 use dbt_frontend_common::dialect::Dialect;
+use dbt_schemas::dbt_types::RelationType;
 use dbt_schemas::schemas::common::Constraint;
 use dbt_schemas::schemas::common::ConstraintSupport;
 use dbt_schemas::schemas::common::ConstraintType;
 use dbt_schemas::schemas::common::DbtIncrementalStrategy;
+use dbt_schemas::schemas::common::OnSchemaChange;
 use dbt_schemas::schemas::dbt_column::{DbtColumn, DbtColumnRef};
 use dbt_schemas::schemas::manifest::{BigqueryClusterConfig, BigqueryPartitionConfig};
 use dbt_schemas::schemas::project::ModelConfig;
@@ -74,8 +83,8 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
     }
 
     /// Redact credentials expressions from DDL statements
-    fn redact_credentials(&self, _sql: &str) -> AdapterResult<String> {
-        unimplemented!("Only available with Databricks adapter")
+    fn redact_credentials(&self, sql: &str) -> AdapterResult<String> {
+        Ok(redact_credentials(self.adapter_type(), sql).into_owned())
     }
 
     /// Create a new connection
@@ -101,9 +110,9 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
         state: Option<&State>,
         conn: &'_ mut dyn Connection,
         query_ctx: &QueryCtx,
-        _auto_begin: bool,
+        auto_begin: bool,
         fetch: bool,
-        _limit: Option<i64>,
+        limit: Option<i64>,
         options: Option<HashMap<String, String>>,
     ) -> AdapterResult<(AdapterResponse, AgateTable)> {
         let sql = query_ctx.sql().ok_or_else(|| {
@@ -154,23 +163,37 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
             _ => {}
         }
 
+        if auto_begin {
+            transactions::begin(&engine, state, conn, query_ctx)?;
+        }
+
+        let retry_policy = RetryPolicy::from_engine(&engine)?;
         let mut last_batch = None;
         for statement in statements {
-            last_batch = Some(execute_query_with_retry(
-                engine.clone(),
+            let result = execute_with_retry(
+                &engine,
                 state,
                 conn,
                 &query_ctx.with_sql(statement),
-                1,
+                &retry_policy,
                 &options,
                 fetch,
-            )?);
+            );
+            match result {
+                Ok(batch) => last_batch = Some(batch),
+                Err(e) => {
+                    // A failed statement aborts the transaction it ran in;
+                    // the statement's error is the one worth reporting
+                    let _ = transactions::rollback(&engine, state, conn, query_ctx);
+                    return Err(e);
+                }
+            }
         }
 
         let last_batch = last_batch.expect("last_batch should never be None");
 
         let response = AdapterResponse::new(&last_batch, self.adapter_type());
-        let table = AgateTable::from_record_batch(Arc::new(last_batch));
+        let table = AgateTable::from_record_batch(Arc::new(limit_rows(last_batch, limit)));
 
         Ok((response, table))
     }
@@ -204,6 +227,58 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
         )
     }
 
+    /// Execute a single parameterized statement.
+    ///
+    /// Each row of `params` binds one set of values to the placeholders in
+    /// the statement, which is never split into multiple statements.
+    /// `auto_begin` and `limit` are handled as in [TypedBaseAdapter::execute].
+    #[allow(clippy::too_many_arguments)]
+    fn execute_with_params(
+        &self,
+        state: Option<&State>,
+        conn: &'_ mut dyn Connection,
+        query_ctx: &QueryCtx,
+        params: RecordBatch,
+        auto_begin: bool,
+        fetch: bool,
+        limit: Option<i64>,
+        options: Option<HashMap<String, String>>,
+    ) -> AdapterResult<(AdapterResponse, AgateTable)> {
+        if self.as_replay().is_some() {
+            return Err(AdapterError::new(
+                AdapterErrorKind::NotSupported,
+                "Parameterized execution is not supported in replay mode",
+            ));
+        }
+
+        let mut options = options
+            .unwrap_or_default()
+            .into_iter()
+            .map(|(key, value)| (key, OptionValue::String(value)))
+            .collect::<Vec<_>>();
+        if let Some(state) = state {
+            options.extend(self.get_adbc_execute_options(state));
+        }
+
+        let engine = self.engine();
+        if auto_begin {
+            transactions::begin(engine, state, conn, query_ctx)?;
+        }
+        let batch = match engine.execute_with_params(state, query_ctx, conn, params, options, fetch)
+        {
+            Ok(batch) => batch,
+            Err(e) => {
+                let _ = transactions::rollback(engine, state, conn, query_ctx);
+                return Err(e);
+            }
+        };
+
+        let response = AdapterResponse::new(&batch, self.adapter_type());
+        let table = AgateTable::from_record_batch(Arc::new(limit_rows(batch, limit)));
+
+        Ok((response, table))
+    }
+
     /// Execute a statement, expect no results.
     fn exec_stmt(
         &self,
@@ -311,7 +386,7 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
     /// In Fusion, we treat it as a Jinja accessible flat container of values
     /// needed for Iceberg ddl generation.
     fn build_catalog_relation(&self, _model_config: &Value) -> AdapterResult<Value> {
-        unimplemented!("only available with Databricks and Snowflake adapters")
+        unimplemented!("only available with BigQuery, Databricks and Snowflake adapters")
     }
 
     /// Drop relation
@@ -384,6 +459,22 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
             .collect())
     }
 
+    /// Plans the `on_schema_change` of an incremental model: the changes
+    /// bringing the target columns in line with the source columns
+    fn plan_schema_changes(
+        &self,
+        source_cols: &[StdColumn],
+        target_cols: &[StdColumn],
+        on_schema_change: OnSchemaChange,
+    ) -> SchemaChangePlan {
+        SchemaChangePlan::new(
+            self.engine().type_ops(),
+            on_schema_change,
+            source_cols,
+            target_cols,
+        )
+    }
+
     /// Get columns in relation
     fn get_columns_in_relation(
         &self,
@@ -602,12 +693,7 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
     /// Given a constraint, return the support status of the constraint on this adapter.
     /// https://github.com/dbt-labs/dbt-adapters/blob/5379513bad9c75661b990a5ed5f32ac9c62a0758/dbt-adapters/src/dbt/adapters/base/impl.py#L293
     fn get_constraint_support(&self, ct: ConstraintType) -> ConstraintSupport {
-        match ct {
-            ConstraintType::Check => ConstraintSupport::NotSupported,
-            ConstraintType::NotNull | ConstraintType::ForeignKey => ConstraintSupport::Enforced,
-            ConstraintType::Unique | ConstraintType::PrimaryKey => ConstraintSupport::NotEnforced,
-            _ => ConstraintSupport::NotSupported,
-        }
+        ConstraintSupport::of(self.adapter_type(), ct)
     }
 
     /// Given existing columns and columns from our model
@@ -648,6 +734,20 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
         Ok(result)
     }
 
+    /// Plans the revokes and grants bringing current_grants, as returned by
+    /// [TypedBaseAdapter::standardize_grants_dict], in line with the `grants` config
+    fn diff_grants(
+        &self,
+        current_grants: &GrantsDict,
+        grant_config: &GrantsDict,
+    ) -> AdapterResult<GrantsPlan> {
+        Ok(GrantsPlan::new(
+            self.adapter_type(),
+            current_grants,
+            grant_config,
+        ))
+    }
+
     /// Docs see the impl of this method from bigquery/adapter.rs
     fn nest_column_data_types(
         &self,
@@ -695,20 +795,42 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
         unimplemented!("only available with BigQuery adapter")
     }
 
-    /// load dataframe only used by bigquery adapter
+    /// Append the rows of `agate_table` to an existing table.
+    ///
+    /// The table's [RecordBatch] is cast to the table's column types and
+    /// loaded with ADBC bulk ingestion, or batched parameterized inserts when
+    /// ingestion fails. `file_path` and `field_delimiter` are only used by
+    /// adapters that load from the seed file.
     #[allow(clippy::too_many_arguments)]
     fn load_dataframe(
         &self,
-        _query_ctx: &QueryCtx,
-        _conn: &'_ mut dyn Connection,
-        _database: &str,
-        _schema: &str,
-        _table_name: &str,
-        _agate_table: Arc<AgateTable>,
+        query_ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        database: &str,
+        schema: &str,
+        table_name: &str,
+        agate_table: Arc<AgateTable>,
         _file_path: &str,
         _field_delimiter: &str,
     ) -> AdapterResult<Value> {
-        unimplemented!("only available with BigQuery or Salesforce adapter")
+        let relation = create_relation(
+            self.adapter_type(),
+            database.to_string(),
+            schema.to_string(),
+            Some(table_name.to_string()),
+            Some(RelationType::Table),
+            self.quoting(),
+        )?;
+        ingest_record_batch(
+            self.engine(),
+            None,
+            query_ctx,
+            conn,
+            relation.as_ref(),
+            agate_table.original_record_batch().as_ref().clone(),
+            IngestMode::Append,
+        )?;
+        Ok(none_value())
     }
 
     /// alter_table_add_columns
@@ -875,7 +997,8 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
             Bigquery => bigquery::list_relations(adapter, query_ctx, conn, db_schema),
             Databricks => databricks::list_relations(adapter, query_ctx, conn, db_schema),
             Redshift => redshift::list_relations(adapter, query_ctx, conn, db_schema),
-            Postgres | Salesforce => {
+            Postgres => postgres::list_relations(adapter, query_ctx, conn, db_schema),
+            Salesforce => {
                 let err = AdapterError::new(
                     AdapterErrorKind::Internal,
                     format!(
@@ -888,6 +1011,31 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
         }
     }
 
+    /// Reports when the relations of each of `db_schemas` were last altered
+    ///
+    /// Only the schemas the warehouse keeps track of are reported, none for
+    /// adapters whose warehouse has no such metadata.
+    fn list_schemas_last_altered(
+        &self,
+        query_ctx: &QueryCtx,
+        conn: &'_ mut dyn Connection,
+        db_schemas: &[CatalogAndSchema],
+    ) -> AdapterResult<BTreeMap<CatalogAndSchema, SchemaLastAltered>> {
+        use crate::metadata::*;
+
+        if self.as_replay().is_some() {
+            return Ok(BTreeMap::new());
+        }
+
+        let adapter = self.as_typed_base_adapter();
+        match self.adapter_type() {
+            AdapterType::Snowflake => {
+                snowflake::list_schemas_last_altered(adapter, query_ctx, conn, db_schemas)
+            }
+            _ => Ok(BTreeMap::new()),
+        }
+    }
+
     /// Behavior (flags)
     ///
     /// By default no adapter has extra flags, but each adapter can
@@ -953,7 +1101,7 @@ pub trait TypedBaseAdapter: fmt::Debug + Send + Sync + AdapterTyping {
 
     /// relation_max_name_length
     fn relation_max_name_length(&self) -> AdapterResult<u32> {
-        unimplemented!("only available with Postgres and Redshift adapters")
+        Ok(self.adapter_type().identifier_limits().relation as u32)
     }
 
     /// copy_table
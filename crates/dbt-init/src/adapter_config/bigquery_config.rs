@@ -135,6 +135,13 @@ pub fn setup_bigquery_profile(
         job_retries: None,
         job_retry_deadline_seconds: None,
         target_name: None,
+        query_timeout: None,
+        statement_retries: None,
+        query_cache_max_size: None,
+        retry_on: None,
+        max_backoff: None,
+        max_concurrent_queries: None,
+        concurrency_limits: None,
     };
     let mut config = ConfigProcessor::process_config(existing_config.or(Some(&default_config)))?;
 
@@ -185,6 +185,13 @@ pub fn setup_redshift_profile(
         cluster_id: None,
         region: None,
         threads: None,
+        query_timeout: None,
+        statement_retries: None,
+        query_cache_max_size: None,
+        retry_on: None,
+        max_backoff: None,
+        max_concurrent_queries: None,
+        concurrency_limits: None,
     };
     let mut config = ConfigProcessor::process_config(existing_config.or(Some(&default_config)))?;
 
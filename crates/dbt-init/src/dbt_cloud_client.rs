@@ -196,6 +196,13 @@ fn create_merged_db_config(
                 job_retries: None,
                 job_retry_deadline_seconds: None,
                 target_name: None,
+                query_timeout: None,
+                statement_retries: None,
+                query_cache_max_size: None,
+                retry_on: None,
+                max_backoff: None,
+                max_concurrent_queries: None,
+                concurrency_limits: None,
             }))
         }
         models::UserCredentialsResponseCredentials::RedshiftCredentials(redshift) => {
@@ -311,6 +318,13 @@ fn create_merged_db_config(
                     job_retries: None,
                     job_retry_deadline_seconds: None,
                     target_name: None,
+                    query_timeout: None,
+                    statement_retries: None,
+                    query_cache_max_size: None,
+                    retry_on: None,
+                    max_backoff: None,
+                    max_concurrent_queries: None,
+                    concurrency_limits: None,
                 };
                 bigquery_config.merge(connection_details);
             }
@@ -363,6 +377,13 @@ fn create_merged_db_config(
                     timeout_seconds: None,
                     job_retries: None,
                     target_name: None,
+                    query_timeout: None,
+                    statement_retries: None,
+                    query_cache_max_size: None,
+                    retry_on: None,
+                    max_backoff: None,
+                    max_concurrent_queries: None,
+                    concurrency_limits: None,
                 };
                 bigquery_config.merge(connection_details);
             }
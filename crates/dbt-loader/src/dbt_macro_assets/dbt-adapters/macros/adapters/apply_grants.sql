@@ -157,8 +157,10 @@
             {#-- Show current grants and calculate diffs --#}
             {% set current_grants_table = run_query(get_show_grant_sql(relation)) %}
             {% set current_grants_dict = adapter.standardize_grants_dict(current_grants_table) %}
-            {% set needs_granting = diff_of_two_dicts(grant_config, current_grants_dict) %}
-            {% set needs_revoking = diff_of_two_dicts(current_grants_dict, grant_config) %}
+            {#-- Privilege aliases, grantee casing and `+` keys are handled by the adapter --#}
+            {% set grants_plan = adapter.diff_grants(current_grants_dict, grant_config) %}
+            {% set needs_granting = grants_plan.needs_granting %}
+            {% set needs_revoking = grants_plan.needs_revoking %}
             {% if not (needs_granting or needs_revoking) %}
                 {{ log('On ' ~ relation.render() ~': All grants are in place, no revocation or granting needed.')}}
             {% endif %}
@@ -21,7 +21,11 @@
 
 -- funcsign: (relation, string) -> relation
 {% macro default__make_temp_relation(base_relation, suffix) %}
-    {%- set temp_identifier = base_relation.identifier ~ suffix -%}
+    {%- if flags.get('truncate_long_relation_names', false) -%}
+        {%- set temp_identifier = base_relation.truncated_identifier(suffix) -%}
+    {%- else -%}
+        {%- set temp_identifier = base_relation.identifier ~ suffix -%}
+    {%- endif -%}
     {%- set temp_relation = base_relation.incorporate(
                                 path={"identifier": temp_identifier}) -%}
 
@@ -35,7 +39,11 @@
 
 -- funcsign: (relation, string, string) -> relation
 {% macro default__make_backup_relation(base_relation, backup_relation_type, suffix) %}
-    {%- set backup_identifier = base_relation.identifier ~ suffix -%}
+    {%- if flags.get('truncate_long_relation_names', false) -%}
+        {%- set backup_identifier = base_relation.truncated_identifier(suffix) -%}
+    {%- else -%}
+        {%- set backup_identifier = base_relation.identifier ~ suffix -%}
+    {%- endif -%}
     {%- set backup_relation = base_relation.incorporate(
                                   path={"identifier": backup_identifier},
                                   type=backup_relation_type
@@ -27,6 +27,31 @@
 
   {{ run_hooks(pre_hooks, inside_transaction=False) }}
 
+  {#-- Detect drift of the physical layout of the table, e.g. Redshift dist and sort keys. Altering it can't happen inside a transaction --#}
+  {% if existing_relation is not none and not full_refresh_mode %}
+    {% set table_changes = get_table_configuration_changes(existing_relation, config) %}
+    {% set on_configuration_change = config.get('on_configuration_change', 'apply') %}
+    {% if table_changes is none %}
+      {#-- the table matches its configuration --#}
+    {% elif on_configuration_change == 'continue' %}
+      {{ exceptions.warn("Configuration changes (" ~ table_changes.summary ~ ") were identified and `on_configuration_change` was set to `continue` for `" ~ target_relation ~ "`") }}
+    {% elif on_configuration_change == 'fail' %}
+      {{ exceptions.raise_fail_fast_error("Configuration changes (" ~ table_changes.summary ~ ") were identified and `on_configuration_change` was set to `fail` for `" ~ target_relation ~ "`") }}
+    {% elif table_changes.requires_full_refresh %}
+      {% do exceptions.raise_compiler_error(
+          "Unable to apply configuration changes (" ~ table_changes.summary ~ ") to the incremental model `" ~ target_relation.identifier ~ "` in place. Please execute with --full-refresh to rebuild the table."
+        )
+      %}
+    {% else %}
+      {{ log("Applying configuration changes (" ~ table_changes.summary ~ ") to: `" ~ target_relation ~ "`", info=True) }}
+      {% for alter_sql in get_alter_table_statements(existing_relation, table_changes) %}
+        {%- call statement('alter_table_' ~ loop.index, auto_begin=False) -%}
+          {{ alter_sql }}
+        {%- endcall -%}
+      {% endfor %}
+    {% endif %}
+  {% endif %}
+
   -- `BEGIN` happens here:
   {{ run_hooks(pre_hooks, inside_transaction=True) }}
 
@@ -112,6 +112,9 @@
 
       {% if schema_changes_dict['schema_changed'] %}
 
+        {#-- typed plan of the changes, logged for review before anything is altered --#}
+        {% set schema_change_plan = adapter.plan_schema_changes(target_relation, schema_changes_dict['source_columns'], schema_changes_dict['target_columns'], on_schema_change) %}
+
         {% if on_schema_change == 'fail' %}
 
           {% set fail_msg %}
@@ -125,6 +128,10 @@
                  Source columns not in target: {{ schema_changes_dict['source_not_in_target'] }}
                  Target columns not in source: {{ schema_changes_dict['target_not_in_source'] }}
                  New column types: {{ schema_changes_dict['new_target_types'] }}
+              {%- if schema_change_plan %}
+                 Planned changes:
+{{ schema_change_plan.summary }}
+              {%- endif %}
           {% endset %}
 
           {% do exceptions.raise_compiler_error(fail_msg) %}
@@ -97,38 +97,28 @@
 -- funcsign: (model, agate_table) -> string
 {% macro default__load_csv_rows(model, agate_table) %}
 
-  {% set batch_size = get_batch_size() %}
+  {#-- Rows are bulk loaded through the driver, the insert is only rendered into the compiled file --#}
+  {% do adapter.load_dataframe(
+      model['database'],
+      model['schema'],
+      model['alias'],
+      model['project_root'] | string ~ model['original_file_path'] | string,
+      agate_table,
+      model['config'].get('delimiter', ','),
+  ) %}
+
+  {{ return(get_csv_insert_sql(model, agate_table)) }}
+{% endmacro %}
 
+-- funcsign: (model, agate_table) -> string
+{% macro get_csv_insert_sql(model, agate_table) %}
   {% set cols_sql = get_seed_column_quoted_csv(model, agate_table.column_names) %}
-  {% set bindings = [] %}
-
-  {% set statements = [] %}
-
-  {% for chunk in agate_table.rows | batch(batch_size) %}
-      {% set bindings = [] %}
-
-      {% for row in chunk %}
-          {% do bindings.extend(row) %}
-      {% endfor %}
-
-      {% set sql %}
-          insert into {{ this.render() }} ({{ cols_sql }}) values
-          {% for row in chunk -%}
-              ({%- for column in agate_table.column_names -%}
-                  {{ get_binding_char() }}
-                  {%- if not loop.last%},{%- endif %}
-              {%- endfor -%})
-              {%- if not loop.last%},{%- endif %}
-          {%- endfor %}
-      {% endset %}
-
-      {% do adapter.add_query(sql, bindings=bindings, abridge_sql_log=True) %}
-
-      {% if loop.index0 == 0 %}
-          {% do statements.append(sql) %}
-      {% endif %}
-  {% endfor %}
-
-  {# Return SQL so we can render it out into the compiled files #}
-  {{ return(statements[0]) }}
+  {% set sql %}
+      insert into {{ this.render() }} ({{ cols_sql }}) values
+      ({%- for column in agate_table.column_names -%}
+          {{ get_binding_char() }}
+          {%- if not loop.last%},{%- endif %}
+      {%- endfor -%})
+  {% endset %}
+  {{ return(sql) }}
 {% endmacro %}
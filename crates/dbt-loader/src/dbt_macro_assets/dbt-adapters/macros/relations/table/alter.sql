@@ -0,0 +1,28 @@
+-- funcsign: (relation, config) -> optional[any]
+{% macro get_table_configuration_changes(existing_relation, new_config) %}
+    {#-
+        Adapters that can detect drift of the physical layout of a table, such as its
+        distribution or sort keys, return a changeset with `requires_full_refresh` and
+        `summary` attributes. The changeset needs to align with `get_alter_table_statements`.
+    -#}
+    {{- log('Determining table configuration changes on: ' ~ existing_relation) -}}
+    {%- do return(adapter.dispatch('get_table_configuration_changes', 'dbt')(existing_relation, new_config)) -%}
+{% endmacro %}
+
+
+-- funcsign: (relation, config) -> optional[any]
+{% macro default__get_table_configuration_changes(existing_relation, new_config) %}
+    {%- do return(none) -%}
+{% endmacro %}
+
+
+-- funcsign: (relation, any) -> list[string]
+{% macro get_alter_table_statements(relation, configuration_changes) %}
+    {%- do return(adapter.dispatch('get_alter_table_statements', 'dbt')(relation, configuration_changes)) -%}
+{% endmacro %}
+
+
+-- funcsign: (relation, any) -> list[string]
+{% macro default__get_alter_table_statements(relation, configuration_changes) %}
+    {%- do return([]) -%}
+{% endmacro %}
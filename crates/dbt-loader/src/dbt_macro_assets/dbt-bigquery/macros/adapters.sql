@@ -26,6 +26,13 @@
     {{ partition_by(partition_config) }}
     {{ cluster_by(raw_cluster_by) }}
 
+    {%- if not temporary -%}
+      {%- set catalog_relation = adapter.build_catalog_relation(config.model) -%}
+      {%- if catalog_relation.catalog_type == 'biglake_metastore' %}
+    with connection {{ catalog_relation.connection or 'DEFAULT' }}
+      {%- endif -%}
+    {%- endif %}
+
     {{ bigquery_table_options(config, model, temporary) }}
 
     {#-- PARTITION BY cannot be used with the AS query_statement clause.
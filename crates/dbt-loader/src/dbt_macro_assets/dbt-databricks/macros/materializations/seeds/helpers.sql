@@ -7,6 +7,10 @@
   {% set batch_size = get_batch_size() %}
   {% set column_override = model['config'].get('column_types', {}) %}
   {% set must_cast = model['config'].get('file_format', 'delta') == 'parquet' %}
+  {#-- Bulk loaded rows keep their Arrow types, parquet tables need them cast to the column types --#}
+  {% if not must_cast %}
+    {{ return(default__load_csv_rows(model, agate_table)) }}
+  {% endif %}
 
   {% set statements = [] %}
 
@@ -14,7 +14,45 @@
   {% if arg_dict["unique_key"] %}
     {% do return(adapter.dispatch('get_incremental_merge_sql', 'dbt')(arg_dict)) %}
   {% else %}
-    {{ exceptions.raise_compiler_error("dbt-postgres 'microbatch' requires a `unique_key` config") }}
+    {% do return(postgres__get_incremental_microbatch_delete_insert_sql(arg_dict)) %}
   {% endif %}
 
 {% endmacro %}
+
+
+{#-- Replace the rows of the running batch, as no unique_key identifies them --#}
+{% macro postgres__get_incremental_microbatch_delete_insert_sql(arg_dict) %}
+
+  {%- set target = arg_dict["target_relation"] -%}
+  {%- set source = arg_dict["temp_relation"] -%}
+  {%- set dest_columns = arg_dict["dest_columns"] -%}
+  {%- set predicates = [] -%}
+
+  {%- set incremental_predicates = [] if arg_dict.get('incremental_predicates') is none else arg_dict.get('incremental_predicates') -%}
+  {%- for pred in incremental_predicates -%}
+    {%- set pred = pred | replace("DBT_INTERNAL_DEST.", target ~ ".") | replace("dbt_internal_dest.", target ~ ".") -%}
+    {%- do predicates.append(pred) -%}
+  {%- endfor -%}
+
+  {% if not model.batch or (not model.batch.event_time_start or not model.batch.event_time_end) -%}
+    {% do exceptions.raise_compiler_error('dbt could not compute the start and end timestamps for the running batch') %}
+  {% endif %}
+
+  {% do predicates.append(model.config.event_time ~ " >= TIMESTAMP '" ~ model.batch.event_time_start ~ "'") %}
+  {% do predicates.append(model.config.event_time ~ " < TIMESTAMP '" ~ model.batch.event_time_end ~ "'") %}
+
+  delete from {{ target }}
+  where (
+  {% for predicate in predicates %}
+    {%- if not loop.first %}and {% endif -%} {{ predicate }}
+  {% endfor %}
+  );
+
+  {%- set dest_cols_csv = get_quoted_csv(dest_columns | map(attribute="name")) -%}
+  insert into {{ target }} ({{ dest_cols_csv }})
+  (
+    select {{ dest_cols_csv }}
+    from {{ source }}
+  )
+
+{% endmacro %}
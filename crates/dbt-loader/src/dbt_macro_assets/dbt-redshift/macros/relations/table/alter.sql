@@ -0,0 +1,31 @@
+{% macro redshift__get_alter_table_statements(relation, configuration_changes) %}
+    {#-
+        Redshift alters one of the dist style, dist key or sort key per statement, and
+        none of them can run inside a transaction block.
+    -#}
+    {%- set _statements = [] -%}
+
+    {%- set dist = configuration_changes.dist -%}
+    {%- if dist -%}
+        {%- if dist.diststyle == 'key' -%}
+            {%- do _statements.append('alter table ' ~ relation ~ ' alter distkey ' ~ adapter.quote(dist.distkey)) -%}
+        {%- else -%}
+            {%- do _statements.append('alter table ' ~ relation ~ ' alter diststyle ' ~ dist.diststyle) -%}
+        {%- endif -%}
+    {%- endif -%}
+
+    {%- set sort = configuration_changes.sort -%}
+    {%- if sort -%}
+        {%- if sort.sortstyle == 'auto' -%}
+            {%- do _statements.append('alter table ' ~ relation ~ ' alter sortkey auto') -%}
+        {%- else -%}
+            {%- set _sortkey = [] -%}
+            {%- for column in sort.sortkey -%}
+                {%- do _sortkey.append(adapter.quote(column)) -%}
+            {%- endfor -%}
+            {%- do _statements.append('alter table ' ~ relation ~ ' alter compound sortkey (' ~ _sortkey | join(', ') ~ ')') -%}
+        {%- endif -%}
+    {%- endif -%}
+
+    {%- do return(_statements) -%}
+{% endmacro %}
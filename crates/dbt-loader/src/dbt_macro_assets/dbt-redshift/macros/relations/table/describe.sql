@@ -0,0 +1,52 @@
+{% macro redshift__describe_table(relation) %}
+    {#-
+        These need to be separate queries because redshift will not let you run queries
+        against svv_table_info and pg_attribute in the same query.
+    -#}
+
+    {%- set _table_sql -%}
+        select
+            tb.database,
+            tb.schema,
+            tb.table,
+            tb.diststyle,
+            tb.sortkey1
+        from svv_table_info tb
+        where tb.table ilike '{{ relation.identifier }}'
+        and tb.schema ilike '{{ relation.schema }}'
+        and tb.database ilike '{{ relation.database }}'
+    {%- endset %}
+    {% set _table = run_query(_table_sql) %}
+
+    {%- set _column_descriptor_sql -%}
+        select
+            a.attname as column,
+            a.attisdistkey as is_dist_key,
+            a.attsortkeyord::int as sort_key_position
+        from pg_class c
+        join pg_namespace n on n.oid = c.relnamespace
+        join pg_attribute a on a.attrelid = c.oid
+        where n.nspname ilike '{{ relation.schema }}'
+        and c.relname ilike '{{ relation.identifier }}'
+        and a.attnum > 0
+        and (a.attisdistkey or a.attsortkeyord != 0)
+    {%- endset %}
+    {% set _column_descriptor = run_query(_column_descriptor_sql) %}
+
+    {% do return({
+       'table': _table,
+       'columns': _column_descriptor,
+    })%}
+
+{% endmacro %}
+
+
+{% macro redshift__get_table_configuration_changes(existing_relation, new_config) -%}
+    {#-- without a dist or sort config there is no layout to compare, so skip describing the table --#}
+    {% if new_config.get('dist') is none and new_config.get('sort') is none %}
+        {% do return(none) %}
+    {% endif %}
+    {% set _existing_table = redshift__describe_table(existing_relation) %}
+    {% set _configuration_changes = existing_relation.table_config_changeset(_existing_table, new_config.model) %}
+    {% do return(_configuration_changes) %}
+{%- endmacro %}
@@ -105,6 +105,21 @@
 
   {{ run_hooks(pre_hooks) }}
 
+  {#-- Detect drift of the external volume, base location, catalog and clustering of Snowflake-managed Iceberg tables --#}
+  {% set iceberg_changes = none %}
+  {% if existing_relation is not none and not full_refresh_mode
+        and existing_relation.is_iceberg_format and target_relation.is_iceberg_format
+        and catalog_relation.catalog_type == 'BUILT_IN' %}
+    {% set iceberg_changes = snowflake__get_iceberg_table_configuration_changes(existing_relation, config, catalog_relation) %}
+    {% set on_configuration_change = config.get('on_configuration_change', 'apply') %}
+    {% if iceberg_changes is not none and on_configuration_change == 'continue' %}
+      {{ exceptions.warn("Configuration changes were identified and `on_configuration_change` was set to `continue` for `" ~ target_relation ~ "`") }}
+      {% set iceberg_changes = none %}
+    {% elif iceberg_changes is not none and on_configuration_change == 'fail' %}
+      {{ exceptions.raise_fail_fast_error("Configuration changes were identified and `on_configuration_change` was set to `fail` for `" ~ target_relation ~ "`") }}
+    {% endif %}
+  {% endif %}
+
   {% if existing_relation is none %}
     {%- call statement('main', language=language) -%}
       {{ create_table_as(False, target_relation, compiled_code, language) }}
@@ -132,7 +147,19 @@
       )
     %}
 
+  {% elif iceberg_changes is not none and iceberg_changes.requires_full_refresh %}
+    {% do exceptions.raise_compiler_error(
+        "Unable to update the incremental model `" ~ target_relation.identifier ~ "` because its external volume, base location or catalog changed. Please execute with --full-refresh to recreate the Iceberg table."
+      )
+    %}
+
   {% else %}
+    {% if iceberg_changes is not none %}
+      {%- call statement('alter_iceberg_table') -%}
+        {{ snowflake__get_alter_iceberg_table_as_sql(existing_relation, iceberg_changes) }}
+      {%- endcall -%}
+    {% endif %}
+
     {#-- Create the temp relation, either as a view or as a temp table --#}
     {% if is_catalog_linked_db %}
         {%- call statement('create_tmp_relation', language=language) -%}
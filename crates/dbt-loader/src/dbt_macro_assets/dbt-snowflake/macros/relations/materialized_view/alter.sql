@@ -0,0 +1,50 @@
+-- funcsign: (relation, config) -> string
+{% macro snowflake__get_materialized_view_configuration_changes(existing_relation, new_config) -%}
+    {% set _existing_materialized_view = snowflake__describe_materialized_view(existing_relation) %}
+    {% set _configuration_changes = existing_relation.materialized_view_config_changeset(_existing_materialized_view, new_config.model, sql=sql) %}
+    {% do return(_configuration_changes) %}
+{%- endmacro %}
+
+
+-- funcsign: (relation, string, string, relation, relation, relation) -> string
+{% macro snowflake__get_alter_materialized_view_as_sql(
+    relation,
+    configuration_changes,
+    sql,
+    existing_relation,
+    backup_relation,
+    intermediate_relation
+) -%}
+{#-
+    Produce DDL that applies the configuration changes to an existing materialized view
+
+    Snowflake can't alter the query of a materialized view, so query changes replace it.
+    The remaining changes are applied as individual alter statements.
+-#}
+
+    {%- if configuration_changes.requires_full_refresh -%}
+        {{ get_replace_sql(existing_relation, relation, sql) }}
+
+    {%- else -%}
+
+        {%- set secure = configuration_changes.secure -%}
+        {%- set cluster_by = configuration_changes.cluster_by -%}
+        {%- set automatic_clustering = configuration_changes.automatic_clustering -%}
+
+        {%- if secure -%}
+        alter materialized view {{ existing_relation }} {% if secure.context %}set{% else %}unset{% endif %} secure;
+        {%- endif %}
+        {%- if cluster_by -%}
+            {%- if cluster_by.context -%}
+        alter materialized view {{ existing_relation }} cluster by ({{ cluster_by.context | join(', ') }});
+            {%- else -%}
+        alter materialized view {{ existing_relation }} drop clustering key;
+            {%- endif -%}
+        {%- endif %}
+        {%- if automatic_clustering -%}
+        alter materialized view {{ existing_relation }} {% if automatic_clustering.context %}resume{% else %}suspend{% endif %} recluster;
+        {%- endif %}
+
+    {%- endif -%}
+
+{%- endmacro %}
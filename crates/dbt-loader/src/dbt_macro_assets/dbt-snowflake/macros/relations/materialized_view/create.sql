@@ -0,0 +1,37 @@
+-- funcsign: (optional[relation], string) -> string
+{% macro snowflake__get_create_materialized_view_as_sql(relation, sql) -%}
+{#-
+    Produce DDL that creates a materialized view
+
+    This follows the syntax outlined here:
+    https://docs.snowflake.com/en/sql-reference/sql/create-materialized-view#syntax
+
+    Args:
+    - relation: Union[SnowflakeRelation, str]
+        - SnowflakeRelation - required for relation.render()
+        - str - is already the rendered relation name
+    - sql: str - the code defining the model
+    Returns:
+        A valid DDL statement which will result in a new materialized view.
+-#}
+    {{ _snowflake__materialized_view_ddl(relation, sql, replace=false) }}
+{%- endmacro %}
+
+
+{% macro _snowflake__materialized_view_ddl(relation, sql, replace) -%}
+    {%- set secure = config.get('secure', default=false) -%}
+    {%- set copy_grants = config.get('copy_grants', default=false) -%}
+    {%- set cluster_by_keys = config.get('cluster_by', default=none) -%}
+    {%- if cluster_by_keys is not none and cluster_by_keys is string -%}
+        {%- set cluster_by_keys = [cluster_by_keys] -%}
+    {%- endif -%}
+    {%- set sql_header = config.get('sql_header', none) -%}
+
+    {{ sql_header if sql_header is not none }}
+create {% if replace %}or replace {% endif %}{% if secure %}secure {% endif %}materialized view {{ relation }}
+    {% if copy_grants and replace -%} copy grants {%- endif %}
+    {% if cluster_by_keys -%} cluster by ({{ cluster_by_keys | join(', ') }}) {%- endif %}
+    as (
+        {{ sql }}
+    )
+{%- endmacro %}
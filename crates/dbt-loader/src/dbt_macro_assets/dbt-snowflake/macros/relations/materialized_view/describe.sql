@@ -0,0 +1,29 @@
+{% macro snowflake__describe_materialized_view(relation) %}
+{#-
+--  Get all relevant metadata about a materialized view
+--
+--  Args:
+--  - relation: SnowflakeRelation - the relation to describe
+--  Returns:
+--      A dictionary with one entry:
+--      - materialized_view: the metadata associated with the materialized view
+-#}
+    {%- set _materialized_view_sql -%}
+        show materialized views
+            like '{{ relation.identifier }}'
+            in schema {{ relation.database }}.{{ relation.schema }}
+        ;
+        select
+            "name",
+            "schema_name",
+            "database_name",
+            "text",
+            "is_secure"::varchar as "is_secure",
+            "cluster_by",
+            "automatic_clustering"
+        from table(result_scan(last_query_id()))
+    {%- endset %}
+    {% set results = {'materialized_view': run_query(_materialized_view_sql)} %}
+
+    {% do return(results) %}
+{% endmacro %}
@@ -0,0 +1,5 @@
+-- funcsign: (relation) -> string
+{% macro snowflake__drop_materialized_view(relation) -%}
+    {#- Snowflake doesn't support `cascade` for materialized views -#}
+    drop materialized view if exists {{ relation }}
+{%- endmacro %}
@@ -0,0 +1,5 @@
+-- funcsign: (relation) -> string
+{% macro snowflake__refresh_materialized_view(relation) -%}
+    {#- Snowflake keeps materialized views up to date in the background, so there is nothing to run -#}
+    {%- do return('') -%}
+{%- endmacro %}
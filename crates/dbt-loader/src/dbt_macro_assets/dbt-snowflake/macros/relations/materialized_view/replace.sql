@@ -0,0 +1,15 @@
+-- funcsign: (relation, string) -> string
+{% macro snowflake__get_replace_materialized_view_sql(relation, sql) -%}
+{#-
+    Produce DDL that replaces a materialized view with a new materialized view
+
+    Args:
+    - relation: Union[SnowflakeRelation, str]
+        - SnowflakeRelation - required for relation.render()
+        - str - is already the rendered relation name
+    - sql: str - the code defining the model
+    Returns:
+        A valid DDL statement which will result in a new materialized view.
+-#}
+    {{ _snowflake__materialized_view_ddl(relation, sql, replace=true) }}
+{%- endmacro %}
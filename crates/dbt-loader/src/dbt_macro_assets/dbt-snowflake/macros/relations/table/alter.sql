@@ -0,0 +1,17 @@
+{% macro snowflake__get_alter_iceberg_table_as_sql(relation, configuration_changes) -%}
+{#-
+    Produce DDL that applies the configuration changes to an existing Iceberg table
+
+    Only changes that don't require a full refresh can be applied; moving the table
+    to another external volume, base location or catalog requires --full-refresh.
+-#}
+    {%- set cluster_by = configuration_changes.cluster_by -%}
+    {%- if cluster_by -%}
+        {{- log('Applying UPDATE CLUSTER BY to: ' ~ relation) -}}
+        {%- if cluster_by.context -%}
+    alter iceberg table {{ relation }} cluster by ({{ cluster_by.context | join(', ') }})
+        {%- else -%}
+    alter iceberg table {{ relation }} drop clustering key
+        {%- endif -%}
+    {%- endif -%}
+{%- endmacro %}
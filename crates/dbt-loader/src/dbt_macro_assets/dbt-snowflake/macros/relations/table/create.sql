@@ -9,7 +9,7 @@
             {{ snowflake__create_table_info_schema_sql(relation, compiled_code) }}
         {%- elif catalog_relation.catalog_type == 'BUILT_IN' -%}
             {{ snowflake__create_table_built_in_sql(relation, compiled_code) }}
-        {%- elif catalog_relation.catalog_type == 'ICEBERG_REST' -%}
+        {%- elif catalog_relation.catalog_type in ['ICEBERG_REST', 'GLUE', 'POLARIS'] -%}
             {{ snowflake__create_table_iceberg_rest_sql(relation, compiled_code) }}
         {%- else -%}
             {% do exceptions.raise_compiler_error('Unexpected model config for: ' ~ relation) %}
@@ -0,0 +1,48 @@
+{% macro snowflake__describe_iceberg_table(relation) %}
+{#-
+--  Get all relevant metadata about a Snowflake-managed Iceberg table
+--
+--  Args:
+--  - relation: SnowflakeRelation - the relation to describe
+--  Returns:
+--      A dictionary with two entries:
+--      - iceberg_table: the metadata associated with the iceberg catalog
+--      - table: the clustering key of the table
+-#}
+    {%- set _iceberg_table_sql -%}
+        show iceberg tables
+            like '{{ relation.identifier }}'
+            in schema {{ relation.database }}.{{ relation.schema }}
+        ;
+        select
+            "name",
+            "schema_name",
+            "database_name",
+            "catalog_name",
+            "external_volume_name",
+            "base_location"
+        from table(result_scan(last_query_id()))
+    {%- endset %}
+
+    {%- set _table_sql -%}
+        show tables
+            like '{{ relation.identifier }}'
+            in schema {{ relation.database }}.{{ relation.schema }}
+        ;
+        select
+            "cluster_by"
+        from table(result_scan(last_query_id()))
+    {%- endset %}
+
+    {% do return({
+        'iceberg_table': run_query(_iceberg_table_sql),
+        'table': run_query(_table_sql),
+    }) %}
+{% endmacro %}
+
+
+{% macro snowflake__get_iceberg_table_configuration_changes(existing_relation, new_config, catalog_relation) -%}
+    {% set _existing_iceberg_table = snowflake__describe_iceberg_table(existing_relation) %}
+    {% set _configuration_changes = existing_relation.iceberg_table_config_changeset(_existing_iceberg_table, new_config.model, catalog_relation) %}
+    {% do return(_configuration_changes) %}
+{%- endmacro %}
@@ -429,27 +429,6 @@ pub async fn load_inner(
         files.sort_by(|a, b| a.0.cmp(&b.0));
     }
 
-    let python_files = find_files_by_kind_and_extension(
-        package_path,
-        &dbt_project.name,
-        &ResourcePathKind::ModelPaths,
-        &["py"],
-        &all_files,
-    );
-
-    if !python_files.is_empty() {
-        for file in python_files {
-            show_warning!(
-                &arg.io,
-                *fs_err!(
-                    code => ErrorCode::UnsupportedFileExtension,
-                    loc => file.path.clone(),
-                    "Python models are not currently supported"
-                )
-            );
-        }
-    }
-
     // todo: we could optimize here, but for now just take everything,...
     let mut dbt_properties = find_files_by_kind_and_extension(
         package_path,
@@ -524,6 +503,24 @@ pub async fn load_inner(
         &["sql"],
         &all_files,
     );
+    let model_python_files = find_files_by_kind_and_extension(
+        package_path,
+        &dbt_project.name,
+        &ResourcePathKind::ModelPaths,
+        &["py"],
+        &all_files,
+    );
+    // Python models are parsed, but they can't be executed yet
+    for file in &model_python_files {
+        show_warning!(
+            &arg.io,
+            *fs_err!(
+                code => ErrorCode::UnsupportedFileExtension,
+                loc => file.path.clone(),
+                "Python models are not currently supported"
+            )
+        );
+    }
     let function_sql_files = find_files_by_kind_and_extension(
         package_path,
         &dbt_project.name,
@@ -593,6 +590,7 @@ pub async fn load_inner(
         dbt_properties,
         analysis_files,
         model_sql_files,
+        model_python_files,
         function_sql_files,
         test_files,
         fixture_files,
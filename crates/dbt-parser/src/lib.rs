@@ -13,6 +13,7 @@ pub mod args;
 /// DbtNamespace for intercepting dbt macro calls during parse phase
 pub mod dbt_namespace;
 pub mod dbt_project_config;
+pub mod python_model;
 pub mod renderer;
 #[cfg(test)]
 mod renderer_test;
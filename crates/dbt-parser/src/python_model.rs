@@ -0,0 +1,1216 @@
+//! Static parsing of Python models.
+//!
+//! Python models are never executed during parsing. Instead, the `model(dbt, session)`
+//! function and the top level helper functions it may pass `dbt` to are scanned with
+//! a lightweight tokenizer and the `dbt.ref(...)`, `dbt.source(...)`, `dbt.config(...)`
+//! and `dbt.config.get(...)` calls are extracted from them, the same way dbt-core
+//! walks the Python AST. All arguments to these calls
+//! must be literals so that the node's dependencies and configuration are known
+//! without running any Python.
+
+use crate::args::ResolveArgs;
+use crate::renderer::{RenderCtx, SqlFileRenderResult, extract_model_and_version_config};
+use crate::resolve::resolve_properties::MinimalPropertiesEntry;
+use crate::sql_file_info::SqlFileInfo;
+use crate::utils::{get_node_fqn, trigger_duplicate_errors};
+use dbt_common::cancellation::CancellationToken;
+use dbt_common::constants::PARSING;
+use dbt_common::tokiofs::read_to_string;
+use dbt_common::{ErrorCode, FsError, FsResult, fs_err, fsinfo, show_error, show_progress};
+use dbt_frontend_common::error::CodeLocation;
+use dbt_jinja_utils::phases::parse::sql_resource::SqlResource;
+use dbt_jinja_utils::serde::into_typed_with_error;
+use dbt_schemas::schemas::common::DbtChecksum;
+use dbt_schemas::schemas::project::{DefaultTo, ModelConfig};
+use dbt_schemas::schemas::properties::{GetConfig, ModelProperties};
+use dbt_schemas::state::{DbtAsset, ModelStatus};
+use dbt_serde_yaml::{Mapping, Marker, Span, Value as YmlValue};
+use minijinja::MacroSpans;
+use std::collections::BTreeMap;
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// The dbt calls extracted from the top level functions of a Python model
+#[derive(Debug, Clone, Default)]
+pub struct PythonModelInfo {
+    /// e.g. dbt.ref('package', 'model', v=2)
+    pub refs: Vec<(String, Option<String>, Option<String>, CodeLocation)>,
+    /// e.g. dbt.source('source', 'table')
+    pub sources: Vec<(String, String, CodeLocation)>,
+    /// e.g. dbt.config(materialized='table'), in call order
+    pub configs: Vec<(YmlValue, CodeLocation)>,
+    /// e.g. dbt.config.get('key', 'default')
+    pub config_keys_used: Vec<(String, Option<YmlValue>, CodeLocation)>,
+}
+
+/// An error found while statically parsing a Python model
+#[derive(Debug, Clone, PartialEq)]
+pub struct PythonModelError {
+    /// A description of the problem
+    pub message: String,
+    /// Where in the Python file the problem was found
+    pub location: CodeLocation,
+}
+
+impl PythonModelError {
+    fn new(message: impl Into<String>, location: CodeLocation) -> Self {
+        Self {
+            message: message.into(),
+            location,
+        }
+    }
+
+    /// Convert into an [FsError] located in the given file
+    pub fn into_fs_error(self, path: &Path) -> Box<FsError> {
+        fs_err!(
+            code => ErrorCode::InvalidPythonModel,
+            loc => dbt_common::CodeLocation::new(
+                self.location.line,
+                self.location.col,
+                self.location.index,
+                path,
+            ),
+            "{}",
+            self.message
+        )
+    }
+}
+
+type PyResult<T> = Result<T, PythonModelError>;
+
+#[derive(Debug, Clone, PartialEq)]
+enum TokenKind {
+    Name(String),
+    /// A string literal; `literal` is false for f-strings and byte strings
+    Str {
+        value: String,
+        literal: bool,
+    },
+    Number(String),
+    Op(&'static str),
+    /// End of a logical line
+    Newline,
+}
+
+#[derive(Debug, Clone)]
+struct Token {
+    kind: TokenKind,
+    location: CodeLocation,
+    /// The indentation of the logical line, set on the first token of the line
+    line_indent: Option<usize>,
+}
+
+const TWO_CHAR_OPS: &[&str] = &[
+    "**", "//", "==", "!=", "<=", ">=", "->", ":=", "<<", ">>", "+=", "-=", "*=", "/=", "%=", "&=",
+    "|=", "^=", "@=",
+];
+const ONE_CHAR_OPS: &[&str] = &[
+    "(", ")", "[", "]", "{", "}", ",", ":", ".", ";", "=", "+", "-", "*", "/", "%", "@", "&", "|",
+    "^", "~", "<", ">",
+];
+
+/// A minimal Python tokenizer: it understands names, numbers, (triple-quoted and
+/// prefixed) strings, comments, line continuations and bracket nesting, which is all
+/// that is needed to find the dbt calls and their literal arguments.
+struct Tokenizer<'a> {
+    source: &'a str,
+    chars: Vec<(usize, char)>,
+    pos: usize,
+    line: usize,
+    col: usize,
+    depth: usize,
+    tokens: Vec<Token>,
+    at_line_start: bool,
+    indent: usize,
+}
+
+impl<'a> Tokenizer<'a> {
+    fn new(source: &'a str) -> Self {
+        Self {
+            source,
+            chars: source.char_indices().collect(),
+            pos: 0,
+            line: 1,
+            col: 1,
+            depth: 0,
+            tokens: Vec::new(),
+            at_line_start: true,
+            indent: 0,
+        }
+    }
+
+    fn peek(&self, offset: usize) -> Option<char> {
+        self.chars.get(self.pos + offset).map(|(_, c)| *c)
+    }
+
+    fn location(&self) -> CodeLocation {
+        let index = self
+            .chars
+            .get(self.pos)
+            .map(|(i, _)| *i)
+            .unwrap_or(self.source.len());
+        CodeLocation::new(self.line, self.col, index)
+    }
+
+    fn bump(&mut self) -> Option<char> {
+        let c = self.peek(0)?;
+        self.pos += 1;
+        if c == '\n' {
+            self.line += 1;
+            self.col = 1;
+        } else {
+            self.col += 1;
+        }
+        Some(c)
+    }
+
+    fn push(&mut self, kind: TokenKind, location: CodeLocation) {
+        let line_indent = if self.at_line_start {
+            self.at_line_start = false;
+            Some(self.indent)
+        } else {
+            None
+        };
+        self.tokens.push(Token {
+            kind,
+            location,
+            line_indent,
+        });
+    }
+
+    fn tokenize(mut self) -> PyResult<Vec<Token>> {
+        while let Some(c) = self.peek(0) {
+            match c {
+                '\n' => {
+                    self.bump();
+                    if self.depth == 0 && !self.at_line_start {
+                        let location = self.location();
+                        self.tokens.push(Token {
+                            kind: TokenKind::Newline,
+                            location,
+                            line_indent: None,
+                        });
+                        self.at_line_start = true;
+                    }
+                    if self.at_line_start {
+                        self.indent = 0;
+                    }
+                }
+                ' ' | '\t' | '\x0c' | '\r' => {
+                    self.bump();
+                    if self.at_line_start && c != '\r' {
+                        self.indent += 1;
+                    }
+                }
+                '#' => {
+                    while let Some(c) = self.peek(0) {
+                        if c == '\n' {
+                            break;
+                        }
+                        self.bump();
+                    }
+                }
+                '\\' if self.peek(1) == Some('\n') => {
+                    self.bump();
+                    self.bump();
+                }
+                '\\' if self.peek(1) == Some('\r') && self.peek(2) == Some('\n') => {
+                    self.bump();
+                    self.bump();
+                    self.bump();
+                }
+                c if c.is_ascii_digit()
+                    || (c == '.' && self.peek(1).is_some_and(|n| n.is_ascii_digit())) =>
+                {
+                    self.number();
+                }
+                c if c == '_' || c.is_alphabetic() => {
+                    if let Some(prefix_len) = self.string_prefix_len() {
+                        self.string(prefix_len)?;
+                    } else {
+                        self.name();
+                    }
+                }
+                '\'' | '"' => self.string(0)?,
+                _ => self.op()?,
+            }
+        }
+        if !self.at_line_start {
+            let location = self.location();
+            self.tokens.push(Token {
+                kind: TokenKind::Newline,
+                location,
+                line_indent: None,
+            });
+        }
+        Ok(self.tokens)
+    }
+
+    fn name(&mut self) {
+        let location = self.location();
+        let mut name = String::new();
+        while let Some(c) = self.peek(0) {
+            if c == '_' || c.is_alphanumeric() {
+                name.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        self.push(TokenKind::Name(name), location);
+    }
+
+    fn number(&mut self) {
+        let location = self.location();
+        let mut number = String::new();
+        while let Some(c) = self.peek(0) {
+            let is_exponent_sign = (c == '+' || c == '-')
+                && number.ends_with(['e', 'E'])
+                && !number.starts_with("0x")
+                && !number.starts_with("0X");
+            if c.is_ascii_alphanumeric() || c == '_' || c == '.' || is_exponent_sign {
+                number.push(c);
+                self.bump();
+            } else {
+                break;
+            }
+        }
+        self.push(TokenKind::Number(number), location);
+    }
+
+    /// Returns the length of a string prefix (e.g. `r`, `f`, `rb`) if one starts here
+    fn string_prefix_len(&self) -> Option<usize> {
+        let is_prefix = |c: char| matches!(c.to_ascii_lowercase(), 'r' | 'b' | 'u' | 'f');
+        let is_quote = |c: Option<char>| matches!(c, Some('\'') | Some('"'));
+        if !self.peek(0).is_some_and(is_prefix) {
+            return None;
+        }
+        if is_quote(self.peek(1)) {
+            Some(1)
+        } else if self.peek(1).is_some_and(is_prefix) && is_quote(self.peek(2)) {
+            Some(2)
+        } else {
+            None
+        }
+    }
+
+    fn string(&mut self, prefix_len: usize) -> PyResult<()> {
+        let location = self.location();
+        let prefix: String = (0..prefix_len)
+            .filter_map(|_| self.bump())
+            .map(|c| c.to_ascii_lowercase())
+            .collect();
+        let raw = prefix.contains('r');
+        let literal = !prefix.contains('f') && !prefix.contains('b');
+
+        let quote = self.bump().expect("string must start with a quote");
+        let triple = self.peek(0) == Some(quote) && self.peek(1) == Some(quote);
+        if triple {
+            self.bump();
+            self.bump();
+        }
+
+        let mut value = String::new();
+        loop {
+            let Some(c) = self.bump() else {
+                return Err(PythonModelError::new(
+                    "Unterminated string literal",
+                    location,
+                ));
+            };
+            if c == quote {
+                if !triple {
+                    break;
+                }
+                if self.peek(0) == Some(quote) && self.peek(1) == Some(quote) {
+                    self.bump();
+                    self.bump();
+                    break;
+                }
+                value.push(c);
+            } else if c == '\n' && !triple {
+                return Err(PythonModelError::new(
+                    "Unterminated string literal",
+                    location,
+                ));
+            } else if c == '\\' {
+                let Some(escaped) = self.bump() else {
+                    continue;
+                };
+                if raw {
+                    value.push('\\');
+                    value.push(escaped);
+                    continue;
+                }
+                match escaped {
+                    '\n' => {}
+                    'n' => value.push('\n'),
+                    't' => value.push('\t'),
+                    'r' => value.push('\r'),
+                    '0' => value.push('\0'),
+                    '\\' | '\'' | '"' => value.push(escaped),
+                    other => {
+                        value.push('\\');
+                        value.push(other);
+                    }
+                }
+            } else {
+                value.push(c);
+            }
+        }
+        self.push(TokenKind::Str { value, literal }, location);
+        Ok(())
+    }
+
+    fn op(&mut self) -> PyResult<()> {
+        let location = self.location();
+        let two: String = [self.peek(0), self.peek(1)].into_iter().flatten().collect();
+        if let Some(op) = TWO_CHAR_OPS.iter().find(|op| **op == two) {
+            self.bump();
+            self.bump();
+            self.push(TokenKind::Op(*op), location);
+            return Ok(());
+        }
+        let c = self.bump().expect("op called at end of input");
+        let Some(op) = ONE_CHAR_OPS.iter().find(|op| op.starts_with(c)) else {
+            return Err(PythonModelError::new(
+                format!("Unexpected character '{c}'"),
+                location,
+            ));
+        };
+        match *op {
+            "(" | "[" | "{" => self.depth += 1,
+            ")" | "]" | "}" => {
+                if self.depth == 0 {
+                    return Err(PythonModelError::new(format!("Unmatched '{op}'"), location));
+                }
+                self.depth -= 1;
+            }
+            _ => {}
+        }
+        self.push(TokenKind::Op(*op), location);
+        Ok(())
+    }
+}
+
+/// Statically parse a Python model and extract the dbt calls made by its
+/// `model(dbt, session)` function and by the top level functions that take the
+/// dbt object under the same parameter name
+pub fn parse_python_model(source: &str) -> PyResult<PythonModelInfo> {
+    let tokens = Tokenizer::new(source).tokenize()?;
+    let parser = Parser { tokens: &tokens };
+
+    let function_defs: Vec<usize> = (0..tokens.len())
+        .filter(|&i| tokens[i].line_indent == Some(0) && parser.is_name(i, "def"))
+        .collect();
+
+    // Exactly one top level `def model(...)` is allowed per file
+    let model_defs: Vec<usize> = function_defs
+        .iter()
+        .copied()
+        .filter(|&i| parser.is_name(i + 1, "model"))
+        .collect();
+    let def_idx = match model_defs.as_slice() {
+        [idx] => *idx,
+        [] => {
+            return Err(PythonModelError::new(
+                "Python models must define a top level function `def model(dbt, session)`",
+                CodeLocation::start_of_file(),
+            ));
+        }
+        [_, second, ..] => {
+            return Err(PythonModelError::new(
+                format!(
+                    "dbt allows exactly one model defined per python file, found {}",
+                    model_defs.len()
+                ),
+                tokens[*second].location,
+            ));
+        }
+    };
+
+    let (params, _) = parser.function_params(def_idx)?;
+    if params.len() != 2 {
+        return Err(PythonModelError::new(
+            "The model function should have two arguments, `dbt` and a session to the current warehouse",
+            tokens[def_idx + 1].location,
+        ));
+    }
+    let dbt = params[0].as_str();
+
+    let mut info = PythonModelInfo::default();
+    for def_idx in function_defs {
+        // Helpers are only scanned when they receive the dbt object, and
+        // signatures the model parser rejects are left to Python
+        let Ok((params, body_start)) = parser.function_params(def_idx) else {
+            continue;
+        };
+        if !params.iter().any(|param| param == dbt) {
+            continue;
+        }
+        // The body ends at the next logical line that is not indented
+        let body_end = (body_start..tokens.len())
+            .find(|&i| tokens[i].line_indent == Some(0))
+            .unwrap_or(tokens.len());
+        parser.dbt_calls(dbt, body_start, body_end, &mut info)?;
+    }
+    Ok(info)
+}
+
+/// A positional or keyword argument of a dbt call
+struct CallArg {
+    keyword: Option<String>,
+    value: YmlValue,
+    location: CodeLocation,
+}
+
+fn as_str(arg: &CallArg, call: &str) -> PyResult<String> {
+    match &arg.value {
+        YmlValue::String(s, _) => Ok(s.clone()),
+        _ => Err(PythonModelError::new(
+            format!("The arguments to {call} must be string literals"),
+            arg.location,
+        )),
+    }
+}
+
+fn ref_from_args(
+    args: Vec<CallArg>,
+    location: CodeLocation,
+) -> PyResult<(String, Option<String>, Option<String>, CodeLocation)> {
+    let mut positional = Vec::new();
+    let mut version = None;
+    for arg in args {
+        match arg.keyword.as_deref() {
+            None => positional.push(as_str(&arg, "dbt.ref")?),
+            Some("v") | Some("version") => {
+                version = Some(match &arg.value {
+                    YmlValue::String(s, _) => s.clone(),
+                    YmlValue::Number(n, _) => n.to_string(),
+                    _ => {
+                        return Err(PythonModelError::new(
+                            "The version passed to dbt.ref must be a string or number literal",
+                            arg.location,
+                        ));
+                    }
+                });
+            }
+            Some(other) => {
+                return Err(PythonModelError::new(
+                    format!("dbt.ref got an unexpected keyword argument '{other}'"),
+                    arg.location,
+                ));
+            }
+        }
+    }
+    let mut positional = positional.into_iter();
+    match (positional.next(), positional.next(), positional.next()) {
+        (Some(name), None, None) => Ok((name, None, version, location)),
+        (Some(package), Some(name), None) => Ok((name, Some(package), version, location)),
+        _ => Err(PythonModelError::new(
+            "dbt.ref takes a model name and an optional package name",
+            location,
+        )),
+    }
+}
+
+fn source_from_args(
+    args: Vec<CallArg>,
+    location: CodeLocation,
+) -> PyResult<(String, String, CodeLocation)> {
+    let mut names = Vec::new();
+    for arg in args {
+        if let Some(keyword) = &arg.keyword {
+            return Err(PythonModelError::new(
+                format!("dbt.source got an unexpected keyword argument '{keyword}'"),
+                arg.location,
+            ));
+        }
+        names.push(as_str(&arg, "dbt.source")?);
+    }
+    match <[String; 2]>::try_from(names) {
+        Ok([source, table]) => Ok((source, table, location)),
+        Err(_) => Err(PythonModelError::new(
+            "dbt.source takes exactly two arguments, a source name and a table name",
+            location,
+        )),
+    }
+}
+
+fn config_from_args(args: Vec<CallArg>, location: &CodeLocation) -> PyResult<YmlValue> {
+    let mut mapping = Mapping::with_capacity(args.len());
+    for arg in args {
+        match arg.keyword {
+            Some(keyword) => {
+                let key = YmlValue::String(keyword, arg.value.span().clone());
+                mapping.insert(key, arg.value);
+            }
+            // a single positional dict is accepted, as with config() in sql models
+            None => match arg.value {
+                YmlValue::Mapping(entries, _) => mapping.extend(entries),
+                _ => {
+                    return Err(PythonModelError::new(
+                        "dbt.config only accepts keyword arguments or a dictionary literal",
+                        arg.location,
+                    ));
+                }
+            },
+        }
+    }
+    Ok(YmlValue::Mapping(mapping, span_at(location)))
+}
+
+fn config_get_from_args(
+    args: Vec<CallArg>,
+    location: CodeLocation,
+) -> PyResult<(String, Option<YmlValue>, CodeLocation)> {
+    let mut args = args.into_iter();
+    let key = match args.next() {
+        Some(arg) if arg.keyword.is_none() => as_str(&arg, "dbt.config.get")?,
+        _ => {
+            return Err(PythonModelError::new(
+                "dbt.config.get requires a config key as its first argument",
+                location,
+            ));
+        }
+    };
+    let default = match args.next() {
+        Some(arg) if arg.keyword.is_none() || arg.keyword.as_deref() == Some("default") => {
+            Some(arg.value)
+        }
+        Some(arg) => {
+            return Err(PythonModelError::new(
+                "dbt.config.get takes a config key and an optional default value",
+                arg.location,
+            ));
+        }
+        None => None,
+    };
+    if let Some(extra) = args.next() {
+        return Err(PythonModelError::new(
+            "dbt.config.get takes a config key and an optional default value",
+            extra.location,
+        ));
+    }
+    Ok((key, default, location))
+}
+
+fn span_at(location: &CodeLocation) -> Span {
+    Span {
+        start: Marker::new(location.index, location.line, location.col),
+        end: Marker::new(location.index, location.line, location.col),
+        filename: None,
+    }
+}
+
+struct Parser<'a> {
+    tokens: &'a [Token],
+}
+
+impl Parser<'_> {
+    fn kind(&self, i: usize) -> Option<&TokenKind> {
+        self.tokens.get(i).map(|t| &t.kind)
+    }
+
+    fn is_name(&self, i: usize, name: &str) -> bool {
+        matches!(self.kind(i), Some(TokenKind::Name(n)) if n == name)
+    }
+
+    fn is_op(&self, i: usize, op: &str) -> bool {
+        matches!(self.kind(i), Some(TokenKind::Op(o)) if *o == op)
+    }
+
+    /// Returns the attribute name of `.name` starting at `i`
+    fn attr(&self, i: usize) -> Option<&str> {
+        if !self.is_op(i, ".") {
+            return None;
+        }
+        match self.kind(i + 1) {
+            Some(TokenKind::Name(name)) => Some(name.as_str()),
+            _ => None,
+        }
+    }
+
+    fn location(&self, i: usize) -> CodeLocation {
+        self.tokens
+            .get(i)
+            .or(self.tokens.last())
+            .map(|t| t.location)
+            .unwrap_or_else(CodeLocation::start_of_file)
+    }
+
+    /// Extracts the calls made on the `dbt` name between `start` and `end`
+    fn dbt_calls(
+        &self,
+        dbt: &str,
+        start: usize,
+        end: usize,
+        info: &mut PythonModelInfo,
+    ) -> PyResult<()> {
+        let tokens = self.tokens;
+        let mut i = start;
+        while i < end {
+            if !self.is_name(i, dbt) || (i > 0 && self.is_op(i - 1, ".")) {
+                i += 1;
+                continue;
+            }
+            let Some(method) = self.attr(i + 1) else {
+                i += 1;
+                continue;
+            };
+            let location = tokens[i].location;
+            match method {
+                "ref" if self.is_op(i + 3, "(") => {
+                    let (args, next) = self.call_args(i + 3, "dbt.ref")?;
+                    info.refs.push(ref_from_args(args, location)?);
+                    i = next;
+                }
+                "source" if self.is_op(i + 3, "(") => {
+                    let (args, next) = self.call_args(i + 3, "dbt.source")?;
+                    info.sources.push(source_from_args(args, location)?);
+                    i = next;
+                }
+                "config" if self.is_op(i + 3, "(") => {
+                    let (args, next) = self.call_args(i + 3, "dbt.config")?;
+                    info.configs
+                        .push((config_from_args(args, &location)?, location));
+                    i = next;
+                }
+                "config" if self.attr(i + 3) == Some("get") && self.is_op(i + 5, "(") => {
+                    let (args, next) = self.call_args(i + 5, "dbt.config.get")?;
+                    info.config_keys_used
+                        .push(config_get_from_args(args, location)?);
+                    i = next;
+                }
+                _ => i += 1,
+            }
+        }
+        Ok(())
+    }
+
+    /// Parses the parameter names of `def name(...)` starting at the `def` token,
+    /// returning them along with the index of the first token of the body
+    fn function_params(&self, def_idx: usize) -> PyResult<(Vec<String>, usize)> {
+        let mut i = def_idx + 2;
+        if !self.is_op(i, "(") {
+            return Err(PythonModelError::new(
+                "Expected '(' after `def model`",
+                self.location(i),
+            ));
+        }
+        i += 1;
+        let mut params = Vec::new();
+        let mut depth = 0usize;
+        let mut expect_param = true;
+        loop {
+            match self.kind(i) {
+                None | Some(TokenKind::Newline) => {
+                    return Err(PythonModelError::new(
+                        "Unterminated parameter list for `def model`",
+                        self.location(def_idx),
+                    ));
+                }
+                Some(TokenKind::Op(op)) if matches!(*op, "(" | "[" | "{") => depth += 1,
+                Some(TokenKind::Op(op)) if matches!(*op, ")" | "]" | "}") => {
+                    if depth == 0 {
+                        break;
+                    }
+                    depth -= 1;
+                }
+                Some(TokenKind::Op(",")) if depth == 0 => expect_param = true,
+                Some(TokenKind::Name(name)) if depth == 0 && expect_param => {
+                    params.push(name.clone());
+                    expect_param = false;
+                }
+                Some(TokenKind::Op(op)) if depth == 0 && expect_param && *op != "/" => {
+                    // `*args` and `**kwargs` are not valid model parameters
+                    return Err(PythonModelError::new(
+                        "The model function should have two arguments, `dbt` and a session to the current warehouse",
+                        self.location(i),
+                    ));
+                }
+                _ => {}
+            }
+            i += 1;
+        }
+        // skip an optional return annotation up to the ':' that starts the body
+        while !self.is_op(i, ":") {
+            if matches!(self.kind(i), None | Some(TokenKind::Newline)) {
+                return Err(PythonModelError::new(
+                    "Expected ':' after the parameters of `def model`",
+                    self.location(i),
+                ));
+            }
+            i += 1;
+        }
+        Ok((params, i + 1))
+    }
+
+    /// Parses the arguments of a call whose '(' is at `open`, returning them along
+    /// with the index after the closing ')'
+    fn call_args(&self, open: usize, call: &str) -> PyResult<(Vec<CallArg>, usize)> {
+        let mut i = open + 1;
+        let mut args = Vec::new();
+        while !self.is_op(i, ")") {
+            let location = self.location(i);
+            let keyword = match (self.kind(i), self.kind(i + 1)) {
+                (Some(TokenKind::Name(name)), Some(TokenKind::Op("="))) => {
+                    i += 2;
+                    Some(name.clone())
+                }
+                _ => None,
+            };
+            let (value, next) = self.literal(i).map_err(|location| {
+                PythonModelError::new(
+                    format!("The arguments to {call} must be literals"),
+                    location,
+                )
+            })?;
+            args.push(CallArg {
+                keyword,
+                value,
+                location,
+            });
+            i = next;
+            if self.is_op(i, ",") {
+                i += 1;
+            } else if !self.is_op(i, ")") {
+                return Err(PythonModelError::new(
+                    format!("The arguments to {call} must be literals"),
+                    self.location(i),
+                ));
+            }
+        }
+        Ok((args, i + 1))
+    }
+
+    /// Parses a literal (string, number, bool, None, list, tuple or dict of literals)
+    /// starting at `i`, returning the value and the index after it. On failure, the
+    /// location of the offending token is returned.
+    fn literal(&self, i: usize) -> Result<(YmlValue, usize), CodeLocation> {
+        let location = self.location(i);
+        let span = span_at(&location);
+        match self.kind(i) {
+            Some(TokenKind::Str { .. }) => {
+                // adjacent string literals are concatenated
+                let mut value = String::new();
+                let mut i = i;
+                while let Some(TokenKind::Str { value: s, literal }) = self.kind(i) {
+                    if !literal {
+                        return Err(self.location(i));
+                    }
+                    value.push_str(s);
+                    i += 1;
+                }
+                Ok((YmlValue::String(value, span), i))
+            }
+            Some(TokenKind::Number(n)) => Ok((parse_number(n, false, span, &location)?, i + 1)),
+            Some(TokenKind::Op(sign @ ("-" | "+"))) => match self.kind(i + 1) {
+                Some(TokenKind::Number(n)) => {
+                    Ok((parse_number(n, *sign == "-", span, &location)?, i + 2))
+                }
+                _ => Err(location),
+            },
+            Some(TokenKind::Name(name)) => {
+                let value = match name.as_str() {
+                    "True" => YmlValue::Bool(true, span),
+                    "False" => YmlValue::Bool(false, span),
+                    "None" => YmlValue::Null(span),
+                    _ => return Err(location),
+                };
+                Ok((value, i + 1))
+            }
+            Some(TokenKind::Op(open @ ("[" | "("))) => {
+                let close = if *open == "[" { "]" } else { ")" };
+                let mut items = Vec::new();
+                let mut i = i + 1;
+                while !self.is_op(i, close) {
+                    let (item, next) = self.literal(i)?;
+                    items.push(item);
+                    i = next;
+                    if self.is_op(i, ",") {
+                        i += 1;
+                    } else if !self.is_op(i, close) {
+                        return Err(self.location(i));
+                    }
+                }
+                Ok((YmlValue::Sequence(items, span), i + 1))
+            }
+            Some(TokenKind::Op("{")) => {
+                let mut mapping = Mapping::new();
+                let mut i = i + 1;
+                while !self.is_op(i, "}") {
+                    let (key, next) = self.literal(i)?;
+                    if !self.is_op(next, ":") {
+                        return Err(self.location(next));
+                    }
+                    let (value, next) = self.literal(next + 1)?;
+                    mapping.insert(key, value);
+                    i = next;
+                    if self.is_op(i, ",") {
+                        i += 1;
+                    } else if !self.is_op(i, "}") {
+                        return Err(self.location(i));
+                    }
+                }
+                Ok((YmlValue::Mapping(mapping, span), i + 1))
+            }
+            _ => Err(location),
+        }
+    }
+}
+
+fn parse_number(
+    n: &str,
+    negative: bool,
+    span: Span,
+    location: &CodeLocation,
+) -> Result<YmlValue, CodeLocation> {
+    let digits = n.replace('_', "");
+    let sign = if negative { -1 } else { 1 };
+    let number = if let Ok(int) = digits.parse::<i64>() {
+        dbt_serde_yaml::Number::from(sign * int)
+    } else if let Ok(float) = digits.parse::<f64>() {
+        dbt_serde_yaml::Number::from(sign as f64 * float)
+    } else {
+        return Err(*location);
+    };
+    Ok(YmlValue::Number(number, span))
+}
+
+/// Parse the Python model files of a package, returning the same render results
+/// as SQL models so that they can be resolved alongside them
+pub async fn render_unresolved_python_files(
+    render_ctx: &RenderCtx<ModelConfig>,
+    model_python_files: &[DbtAsset],
+    node_properties: &mut BTreeMap<String, MinimalPropertiesEntry>,
+    token: &CancellationToken,
+) -> FsResult<Vec<SqlFileRenderResult<ModelConfig, ModelProperties>>> {
+    let inner = &render_ctx.inner;
+    let args: &ResolveArgs = &inner.args;
+    let dependency_package_name = if inner.package_name != inner.root_project_name {
+        Some(inner.package_name.as_str())
+    } else {
+        None
+    };
+
+    let mut results = Vec::new();
+    let mut duplicate_errors = Vec::new();
+    for dbt_asset in model_python_files {
+        token.check_cancellation()?;
+
+        let ref_name = dbt_asset.path.file_stem().unwrap().to_str().unwrap();
+        let (maybe_model, maybe_version_config) =
+            if let Some(mpe) = node_properties.get_mut(ref_name) {
+                extract_model_and_version_config::<ModelConfig, ModelProperties>(
+                    ref_name,
+                    mpe,
+                    &mut duplicate_errors,
+                    args,
+                    &render_ctx.jinja_env,
+                    &inner.base_ctx,
+                    dependency_package_name,
+                )?
+            } else {
+                (None, None)
+            };
+        if maybe_model.is_none() && maybe_version_config.is_none() && !duplicate_errors.is_empty() {
+            continue;
+        }
+
+        let fqn = get_node_fqn(
+            &inner.package_name,
+            dbt_asset.path.clone(),
+            vec![ref_name.to_string()],
+            &inner.resource_paths,
+        );
+        let project_config = inner.local_project_config.get_config_for_fqn(&fqn);
+        let mut properties_config = match maybe_model.as_ref().and_then(|m| m.get_config()) {
+            Some(properties_config) => {
+                let mut properties_config = properties_config.clone();
+                properties_config.default_to(project_config);
+                properties_config
+            }
+            None => project_config.clone(),
+        };
+        if let Some(mut version_config) = maybe_version_config {
+            version_config.default_to(&properties_config);
+            properties_config = version_config;
+        }
+
+        let absolute_path = dbt_asset.base_path.join(&dbt_asset.path);
+        let source = read_to_string(&absolute_path).await?;
+        let display_path = dbt_asset.to_display_path(&args.io.in_dir);
+        show_progress!(
+            args.io,
+            fsinfo!(PARSING.into(), display_path.display().to_string())
+        );
+
+        let patch_path = node_properties
+            .get(ref_name)
+            .map(|mpe| mpe.relative_path.clone());
+        let checksum = DbtChecksum::hash(source.trim().as_bytes());
+
+        let sql_resources = parse_python_model(&source)
+            .map_err(|err| err.into_fs_error(&dbt_asset.path))
+            .and_then(|info| {
+                python_model_resources(render_ctx, &properties_config, &fqn, info, &display_path)
+            });
+        let (sql_file_info, status) = match sql_resources {
+            Ok(sql_resources) => {
+                let sql_file_info = SqlFileInfo::from_sql_resources(sql_resources, checksum, false);
+                let status = if sql_file_info.config.get_enabled().unwrap_or(true) {
+                    ModelStatus::Enabled
+                } else {
+                    ModelStatus::Disabled
+                };
+                (sql_file_info, status)
+            }
+            Err(err) => {
+                // A disabled model is not reported, mirroring sql models that fail to render
+                let status = if properties_config.get_enabled().unwrap_or(true) {
+                    show_error!(args.io, err);
+                    ModelStatus::ParsingFailed
+                } else {
+                    ModelStatus::Disabled
+                };
+                let sql_file_info = SqlFileInfo::from_sql_resources(
+                    vec![SqlResource::Config(Box::new(properties_config))],
+                    checksum,
+                    false,
+                );
+                (sql_file_info, status)
+            }
+        };
+
+        results.push(SqlFileRenderResult {
+            asset: dbt_asset.clone(),
+            status,
+            sql_file_info,
+            rendered_sql: source,
+            macro_spans: MacroSpans::default(),
+            properties: maybe_model,
+            patch_path,
+        });
+    }
+    trigger_duplicate_errors(&args.io, &mut duplicate_errors)?;
+    Ok(results)
+}
+
+/// Turn the calls extracted from a Python model into the sql resources of the node,
+/// deserializing each `dbt.config(...)` call into a [ModelConfig]
+fn python_model_resources(
+    render_ctx: &RenderCtx<ModelConfig>,
+    properties_config: &ModelConfig,
+    fqn: &[String],
+    info: PythonModelInfo,
+    display_path: &Path,
+) -> FsResult<Vec<SqlResource<ModelConfig>>> {
+    let inner = &render_ctx.inner;
+    let dependency_package_name = if inner.package_name != inner.root_project_name {
+        Some(inner.package_name.as_str())
+    } else {
+        None
+    };
+
+    let mut sql_resources = vec![SqlResource::Config(Box::new(properties_config.clone()))];
+    for (config, _) in info.configs {
+        let config = with_filename(config, display_path);
+        let config: ModelConfig = into_typed_with_error(
+            &inner.args.io,
+            config,
+            true,
+            dependency_package_name,
+            Some(display_path.to_path_buf()),
+        )?;
+        sql_resources.push(SqlResource::Config(Box::new(config)));
+    }
+    // Recorded so that the compiled model can resolve `dbt.config.get` at run time
+    let (config_keys_used, config_keys_defaults) = info
+        .config_keys_used
+        .into_iter()
+        .map(|(key, default, _)| (key, default.unwrap_or(YmlValue::null())))
+        .unzip();
+    sql_resources.push(SqlResource::Config(Box::new(ModelConfig {
+        config_keys_used: Some(config_keys_used),
+        config_keys_defaults: Some(config_keys_defaults),
+        ..Default::default()
+    })));
+    if dependency_package_name.is_some() {
+        let root_config = inner.root_project_config.get_config_for_fqn(fqn).clone();
+        sql_resources.push(SqlResource::Config(Box::new(root_config)));
+    }
+    sql_resources.extend(info.refs.into_iter().map(SqlResource::Ref));
+    sql_resources.extend(info.sources.into_iter().map(SqlResource::Source));
+    Ok(sql_resources)
+}
+
+/// Attach the file name to the spans of a config value so that deserialization
+/// errors point at the Python file
+fn with_filename(value: YmlValue, path: &Path) -> YmlValue {
+    let filename = Some(Arc::new(PathBuf::from(path)));
+    let span = Span {
+        filename: filename.clone(),
+        ..value.span().clone()
+    };
+    match value {
+        YmlValue::Mapping(mapping, _) => YmlValue::Mapping(
+            mapping
+                .into_iter()
+                .map(|(k, v)| (with_filename(k, path), with_filename(v, path)))
+                .collect(),
+            span,
+        ),
+        YmlValue::Sequence(items, _) => YmlValue::Sequence(
+            items.into_iter().map(|v| with_filename(v, path)).collect(),
+            span,
+        ),
+        other => other.with_span(span),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(source: &str) -> PythonModelInfo {
+        parse_python_model(source).expect("python model should parse")
+    }
+
+    fn parse_err(source: &str) -> PythonModelError {
+        parse_python_model(source).expect_err("python model should not parse")
+    }
+
+    #[test]
+    fn test_extracts_refs_and_sources() {
+        let info = parse(
+            r#"
+import pandas as pd
+
+def helper(dbt):
+    # helpers that receive dbt can ref models too
+    return dbt.ref("from_helper")
+
+def model(dbt, session):
+    orders = dbt.ref("orders")
+    customers = dbt.ref('crm', "customers", v=2)
+    payments = dbt.ref("payments", version="3")
+    raw = dbt.source("stripe", 'charges')
+    # dbt.ref("commented_out")
+    text = "dbt.ref('in_a_string')"
+    return orders
+
+def unrelated(df):
+    # does not receive dbt, so this is not a dbt call
+    return df.ref(column)
+"#,
+        );
+        let refs: Vec<_> = info
+            .refs
+            .iter()
+            .map(|(name, package, version, _)| (name.as_str(), package.clone(), version.clone()))
+            .collect();
+        assert_eq!(
+            refs,
+            vec![
+                ("from_helper", None, None),
+                ("orders", None, None),
+                ("customers", Some("crm".to_string()), Some("2".to_string())),
+                ("payments", None, Some("3".to_string())),
+            ]
+        );
+        let (source, table, location) = &info.sources[0];
+        assert_eq!((source.as_str(), table.as_str()), ("stripe", "charges"));
+        assert_eq!((location.line, location.col), (12, 11));
+    }
+
+    #[test]
+    fn test_extracts_config_and_config_get() {
+        let info = parse(
+            r#"
+def model(dbt, session):
+    dbt.config(
+        materialized="incremental",
+        packages=["numpy==1.26", 'pandas'],
+        meta={"owner": "data", "tier": 1},
+        enabled=True,
+    )
+    dbt.config({"tags": ("a", "b")})
+    target = dbt.config.get("target_name", None)
+    return session.sql(f"select 1 where x = {target}")
+"#,
+        );
+        assert_eq!(info.configs.len(), 2);
+        let first = info.configs[0].0.as_mapping().unwrap();
+        assert_eq!(
+            first.get("materialized").and_then(|v| v.as_str()),
+            Some("incremental")
+        );
+        assert_eq!(
+            first
+                .get("packages")
+                .and_then(|v| v.as_sequence())
+                .map(|s| s.len()),
+            Some(2)
+        );
+        assert_eq!(
+            first
+                .get("meta")
+                .and_then(|v| v.as_mapping())
+                .and_then(|m| m.get("tier"))
+                .and_then(|v| v.as_i64()),
+            Some(1)
+        );
+        assert_eq!(first.get("enabled").and_then(|v| v.as_bool()), Some(true));
+        let second = info.configs[1].0.as_mapping().unwrap();
+        assert!(second.get("tags").is_some_and(|v| v.is_sequence()));
+        assert_eq!(info.config_keys_used.len(), 1);
+        assert_eq!(info.config_keys_used[0].0, "target_name");
+        assert!(
+            info.config_keys_used[0]
+                .1
+                .as_ref()
+                .is_some_and(|v| v.is_null())
+        );
+    }
+
+    #[test]
+    fn test_supports_renamed_dbt_parameter_and_multiline_strings() {
+        let info = parse(
+            "def model(ctx: object, session) -> object:\n    \"\"\"Uses dbt.ref('doc')\n    \"\"\"\n    return ctx.ref(\"a\" \\\n        \"b\")\n",
+        );
+        assert_eq!(info.refs.len(), 1);
+        assert_eq!(info.refs[0].0, "ab");
+    }
+
+    #[test]
+    fn test_rejects_invalid_models() {
+        let err = parse_err("def transform(dbt, session):\n    return dbt.ref('a')\n");
+        assert!(err.message.contains("def model(dbt, session)"));
+
+        let err = parse_err("def model(dbt):\n    return dbt.ref('a')\n");
+        assert!(err.message.contains("two arguments"));
+
+        let err = parse_err(
+            "def model(dbt, session):\n    return 1\n\ndef model(dbt, session):\n    return 2\n",
+        );
+        assert!(err.message.contains("exactly one model"));
+        assert_eq!(err.location.line, 4);
+
+        let err = parse_err("def model(dbt, session):\n    name = 'a'\n    return dbt.ref(name)\n");
+        assert!(err.message.contains("dbt.ref must be literals"));
+        assert_eq!((err.location.line, err.location.col), (3, 20));
+
+        let err = parse_err("def model(dbt, session):\n    return dbt.source(f'{x}', 'b')\n");
+        assert!(err.message.contains("dbt.source must be literals"));
+
+        let err = parse_err("def model(dbt, session):\n    dbt.config(materialized=mat)\n");
+        assert!(err.message.contains("dbt.config must be literals"));
+
+        let err = parse_err("def model(dbt, session):\n    return dbt.source('only_one')\n");
+        assert!(err.message.contains("exactly two arguments"));
+
+        let err = parse_err("def model(dbt, session):\n    return dbt.ref('a\n");
+        assert!(err.message.contains("Unterminated string"));
+    }
+}
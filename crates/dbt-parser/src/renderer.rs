@@ -62,7 +62,7 @@ pub struct SqlFileRenderResult<T: DefaultTo<T>, S> {
 }
 
 /// Extracts model and version configuration from node properties
-fn extract_model_and_version_config<T: DefaultTo<T>, S: GetConfig<T> + Debug>(
+pub(crate) fn extract_model_and_version_config<T: DefaultTo<T>, S: GetConfig<T> + Debug>(
     ref_name: &str,
     mpe: &mut MinimalPropertiesEntry,
     duplicate_errors: &mut Vec<FsError>,
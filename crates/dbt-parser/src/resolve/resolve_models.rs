@@ -1,6 +1,7 @@
 use crate::args::ResolveArgs;
 use crate::dbt_project_config::RootProjectConfigs;
 use crate::dbt_project_config::init_project_config;
+use crate::python_model::render_unresolved_python_files;
 use crate::renderer::RenderCtx;
 use crate::renderer::RenderCtxInner;
 use crate::renderer::SqlFileRenderResult;
@@ -62,7 +63,10 @@ use std::sync::Arc;
 
 use super::resolve_properties::MinimalPropertiesEntry;
 use super::resolve_tests::persist_generic_data_tests::TestableNodeTrait;
-use super::validate_models::validate_model;
+use super::validate_models::{
+    parse_deprecation_date, validate_constraint_support, validate_model, validate_python_model,
+    validate_relation_name_lengths,
+};
 
 #[allow(
     clippy::cognitive_complexity,
@@ -168,6 +172,16 @@ pub async fn resolve_models(
             jinja_type_checking_event_listener_factory.clone(),
         )
         .await?;
+    // Python models are parsed statically, without rendering
+    model_sql_resources_map.extend(
+        render_unresolved_python_files(
+            &render_ctx,
+            &package.model_python_files,
+            &mut models_properties_sans_semantics,
+            token,
+        )
+        .await?,
+    );
     // make deterministic
     model_sql_resources_map.sort_by(|a, b| {
         a.asset
@@ -177,6 +191,15 @@ pub async fn resolve_models(
             .then(a.asset.path.cmp(&b.asset.path))
     });
 
+    // Unsupported or unenforced constraints are errors rather than warnings with this project flag
+    let require_supported_constraints = root_project
+        .flags
+        .as_ref()
+        .and_then(|flags| flags.as_mapping())
+        .and_then(|flags| flags.get("require_supported_constraints"))
+        .and_then(|value| value.as_bool())
+        .unwrap_or(false);
+
     // Initialize a counter struct to track the version of each model
     let mut duplicates = Vec::new();
 
@@ -191,11 +214,16 @@ pub async fn resolve_models(
     } in model_sql_resources_map.into_iter()
     {
         let ref_name = dbt_asset.path.file_stem().unwrap().to_str().unwrap();
+        let is_python_model = dbt_asset.path.extension().is_some_and(|ext| ext == "py");
         // Is there a better way to handle this if the model doesn't have a config?
         let mut model_config = *sql_file_info.config;
-        // Default to View if no materialized is set
+        // Default to View if no materialized is set (Table for Python models)
         if model_config.materialized.is_none() {
-            model_config.materialized = Some(DbtMaterialization::View);
+            model_config.materialized = Some(if is_python_model {
+                DbtMaterialization::Table
+            } else {
+                DbtMaterialization::View
+            });
         }
         // Set to Inline if this is the inline file
         let is_inline_file = package
@@ -294,6 +322,18 @@ pub async fn resolve_models(
 
         let model_constraints = properties.constraints.clone().unwrap_or_default();
 
+        let deprecation_date = match resolve_deprecation_date(
+            &properties,
+            maybe_version.as_ref(),
+            patch_path.as_ref().unwrap_or(&dbt_asset.path),
+        ) {
+            Ok(deprecation_date) => deprecation_date,
+            Err(err) => {
+                show_error!(&arg.io, err);
+                None
+            }
+        };
+
         // Iterate over metrics and construct the dependencies
         let mut metrics = Vec::new();
         for (metric, package) in sql_file_info.metrics.iter() {
@@ -325,9 +365,14 @@ pub async fn resolve_models(
             ModelFreshnessRules::validate(freshness.build_after.as_ref())?;
         }
 
-        let static_analysis = model_config
-            .static_analysis
-            .unwrap_or(StaticAnalysisKind::On);
+        // Python models have no SQL to analyze
+        let static_analysis = if is_python_model {
+            StaticAnalysisKind::Off
+        } else {
+            model_config
+                .static_analysis
+                .unwrap_or(StaticAnalysisKind::On)
+        };
 
         // Hydrate time_spine from model properties
         let mut time_spine: Option<TimeSpine> = None;
@@ -382,7 +427,7 @@ pub async fn resolve_models(
                 // NOTE: raw_code has to be this value for dbt-evaluator to return truthy
                 // hydrating it with get_original_file_contents would actually break dbt-evaluator
                 raw_code: Some("--placeholder--".to_string()),
-                language: Some("sql".to_string()), // TODO: are python models supported?
+                language: Some(if is_python_model { "python" } else { "sql" }.to_string()),
                 tags: model_config
                     .tags
                     .clone()
@@ -447,8 +492,12 @@ pub async fn resolve_models(
                     .unwrap_or_default()
                     .snowflake_ignore_case
                     .unwrap_or(false),
-                static_analysis_off_reason: matches!(static_analysis, StaticAnalysisKind::Off)
-                    .then(|| StaticAnalysisOffReason::ConfiguredOff),
+                static_analysis_off_reason: if is_python_model {
+                    Some(StaticAnalysisOffReason::PythonModel)
+                } else {
+                    matches!(static_analysis, StaticAnalysisKind::Off)
+                        .then(|| StaticAnalysisOffReason::ConfiguredOff)
+                },
                 static_analysis,
             },
             __model_attr__: DbtModelAttr {
@@ -460,7 +509,7 @@ pub async fn resolve_models(
                 version: maybe_version.map(|v| v.into()),
                 latest_version: maybe_latest_version.map(|v| v.into()),
                 constraints: model_constraints,
-                deprecation_date: None,
+                deprecation_date,
                 primary_key: model_config
                     .__warehouse_specific_config__
                     .primary_key
@@ -501,6 +550,29 @@ pub async fn resolve_models(
             &components,
             adapter_type,
         )?;
+        for err in validate_relation_name_lengths(
+            &dbt_model,
+            adapter_type,
+            &dbt_asset.path,
+            patch_path.as_ref(),
+        ) {
+            show_error!(&arg.io, Box::new(err));
+        }
+        if is_python_model && let Some(err) = validate_python_model(&dbt_model, &dbt_asset.path) {
+            show_error!(&arg.io, Box::new(err));
+        }
+        for err in validate_constraint_support(
+            &dbt_model,
+            adapter_type,
+            models_properties.get(ref_name).map(|mpe| &mpe.schema_value),
+            patch_path.as_ref().unwrap_or(&dbt_asset.path),
+        ) {
+            if require_supported_constraints {
+                show_error!(&arg.io, Box::new(err));
+            } else {
+                show_warning!(&arg.io, Box::new(err));
+            }
+        }
 
         // Update time_spine node_relation with the resolved relation components
         if dbt_model.__model_attr__.time_spine.is_some() {
@@ -618,6 +690,37 @@ pub async fn resolve_models(
     Ok((models, rendering_results, disabled_models))
 }
 
+/// The deprecation date of a model (or of its version, which takes precedence),
+/// normalized to RFC 3339 so that it carries its timezone into the manifest
+fn resolve_deprecation_date(
+    properties: &ModelProperties,
+    maybe_version: Option<&String>,
+    path: &Path,
+) -> FsResult<Option<String>> {
+    let version_deprecation_date = properties
+        .versions
+        .iter()
+        .flatten()
+        .find(|version| maybe_version.is_some_and(|v| Some(v) == version.get_version().as_ref()))
+        .and_then(|version| version.__additional_properties__.get("deprecation_date"))
+        .and_then(|date| date.as_str());
+    let Some(deprecation_date) =
+        version_deprecation_date.or(properties.deprecation_date.as_deref())
+    else {
+        return Ok(None);
+    };
+    match parse_deprecation_date(deprecation_date) {
+        Some(date) => Ok(Some(date.to_rfc3339())),
+        None => Err(fs_err!(
+            code => ErrorCode::InvalidConfig,
+            loc => path.to_path_buf(),
+            "Invalid deprecation_date '{}' for model '{}', expected an ISO 8601 date or datetime",
+            deprecation_date,
+            properties.name
+        )),
+    }
+}
+
 fn process_versioned_columns(
     model_config: &ModelConfig,
     maybe_version: Option<&String>,
@@ -1,6 +1,16 @@
-use dbt_common::{ErrorCode, FsError, FsResult, fs_err};
+use chrono::{DateTime, Duration, FixedOffset, Local, NaiveDate, NaiveDateTime, Utc};
+use dbt_common::adapter::AdapterType;
+use dbt_common::{CodeLocation, ErrorCode, FsError, FsResult, fs_err};
+use dbt_schemas::schemas::DbtModel;
+use dbt_schemas::schemas::common::{ConstraintSupport, ConstraintType, DbtMaterialization};
 use dbt_schemas::schemas::properties::ModelProperties;
-use std::collections::HashSet;
+use std::collections::{BTreeMap, HashSet};
+use std::path::{Path, PathBuf};
+use std::sync::Arc;
+
+/// How many days ahead of a model's deprecation date references to it are reported,
+/// unless the `deprecation_warning_window_days` project flag says otherwise
+pub const DEFAULT_DEPRECATION_WARNING_WINDOW_DAYS: i64 = 30;
 
 /// Validates time spine configuration for semantic models according to the rules ported from Python dbt.
 /// This checks:
@@ -160,15 +170,334 @@ pub fn validate_model(model_props: &ModelProperties) -> FsResult<Vec<FsError>> {
     Ok(errors)
 }
 
+/// Validates the schema, alias and column names of a model against the
+/// identifier limits of the warehouse, so that names the warehouse would reject
+/// (or silently truncate) are reported at parse time.
+///
+/// # Arguments
+/// * `model` - The model, with its relation components resolved
+/// * `adapter_type` - The adapter the model is materialized with
+/// * `path` - The file reported for the schema and alias
+/// * `properties_path` - The file reported for the columns, if any
+pub fn validate_relation_name_lengths(
+    model: &DbtModel,
+    adapter_type: AdapterType,
+    path: &Path,
+    properties_path: Option<&PathBuf>,
+) -> Vec<FsError> {
+    if matches!(
+        model.__base_attr__.materialized,
+        DbtMaterialization::Ephemeral
+    ) {
+        return vec![];
+    }
+    let limits = adapter_type.identifier_limits();
+    let too_long = |kind: &str, name: &str, max: usize, hint: &str, loc: &Path| {
+        *fs_err!(
+            code => ErrorCode::InvalidConfig,
+            loc => loc.to_path_buf(),
+            "The {} '{}' of model '{}' is {} long, {} allows at most {}{}",
+            kind,
+            name,
+            model.__common_attr__.name,
+            limits.length_of(name),
+            adapter_type,
+            max,
+            hint
+        )
+    };
+
+    let mut errors = Vec::new();
+    let schema = &model.__base_attr__.schema;
+    if limits.length_of(schema) > limits.schema {
+        errors.push(too_long("schema", schema, limits.schema, "", path));
+    }
+    let alias = &model.__base_attr__.alias;
+    if limits.length_of(alias) > limits.relation {
+        let hint = match &model.__model_attr__.version {
+            Some(version) if alias.ends_with(&format!("_v{version}")) => {
+                format!(" (including the version suffix '_v{version}'); set a shorter `alias`")
+            }
+            _ => String::new(),
+        };
+        errors.push(too_long("alias", alias, limits.relation, &hint, path));
+    }
+    for name in model.__base_attr__.columns.keys() {
+        if limits.length_of(name) > limits.column {
+            errors.push(too_long(
+                "column",
+                name,
+                limits.column,
+                "",
+                properties_path.map(PathBuf::as_path).unwrap_or(path),
+            ));
+        }
+    }
+    errors
+}
+
+/// Validates the constraints of a model with an enforced contract against the
+/// constraint support of the adapter, so that constraints which are silently
+/// skipped (not supported) or only informational (not enforced) are reported
+/// at parse time. Constraints with `warn_unsupported: false` or
+/// `warn_unenforced: false` are not reported.
+///
+/// # Arguments
+/// * `model` - The model, with its columns and constraints resolved
+/// * `adapter_type` - The adapter the model is materialized with
+/// * `schema_value` - The properties of the model, used to locate each constraint
+/// * `path` - The file reported for the constraints
+pub fn validate_constraint_support(
+    model: &DbtModel,
+    adapter_type: AdapterType,
+    schema_value: Option<&dbt_serde_yaml::Value>,
+    path: &Path,
+) -> Vec<FsError> {
+    model
+        .constraint_support(adapter_type)
+        .into_iter()
+        .filter(|constraint| constraint.support != ConstraintSupport::Enforced)
+        .filter(|constraint| !constraint.silenced)
+        .map(|constraint| {
+            let (consequence, opt_out) = match constraint.support {
+                ConstraintSupport::NotSupported => {
+                    ("is not supported by", "`warn_unsupported: false`")
+                }
+                _ => ("is not enforced by", "`warn_unenforced: false`"),
+            };
+            let subject = match &constraint.column {
+                Some(column) => format!("column '{column}' of model"),
+                None => "model".to_string(),
+            };
+            let loc = schema_value
+                .and_then(|value| {
+                    locate_constraint(value, constraint.column.as_deref(), constraint.index)
+                })
+                .map(|span| {
+                    CodeLocation::new(span.start.line, span.start.column, span.start.index, path)
+                })
+                .unwrap_or_else(|| path.to_path_buf().into());
+            *fs_err!(
+                code => ErrorCode::InvalidConfig,
+                loc => loc,
+                "The {}{} constraint on {} '{}' {} {}; set {} on the constraint to silence this",
+                constraint_type_name(constraint.type_),
+                constraint.name.map(|name| format!(" '{name}'")).unwrap_or_default(),
+                subject,
+                model.__common_attr__.name,
+                consequence,
+                adapter_type,
+                opt_out
+            )
+        })
+        .collect()
+}
+
+/// The span of the `index`th constraint of the model, or of one of its columns, in its properties
+fn locate_constraint(
+    schema_value: &dbt_serde_yaml::Value,
+    column: Option<&str>,
+    index: usize,
+) -> Option<dbt_serde_yaml::Span> {
+    let properties = schema_value.as_mapping()?;
+    let constraints = match column {
+        None => properties.get("constraints")?,
+        Some(column) => properties
+            .get("columns")?
+            .as_sequence()?
+            .iter()
+            .filter_map(|column_value| column_value.as_mapping())
+            .find(|column_props| {
+                column_props
+                    .get("name")
+                    .and_then(|name| name.as_str())
+                    .is_some_and(|name| name == column)
+            })?
+            .get("constraints")?,
+    };
+    Some(constraints.as_sequence()?.get(index)?.span().clone())
+}
+
+fn constraint_type_name(constraint_type: ConstraintType) -> &'static str {
+    match constraint_type {
+        ConstraintType::NotNull => "not_null",
+        ConstraintType::Unique => "unique",
+        ConstraintType::PrimaryKey => "primary_key",
+        ConstraintType::ForeignKey => "foreign_key",
+        ConstraintType::Check => "check",
+        ConstraintType::Custom => "custom",
+    }
+}
+
+/// Validates that a Python model is materialized in a way that can run Python.
+/// Only `table`, `incremental` and custom materializations (which may
+/// implement Python support themselves) are accepted.
+///
+/// # Arguments
+/// * `model` - The Python model, with its config resolved
+/// * `path` - The file reported for the error
+pub fn validate_python_model(model: &DbtModel, path: &Path) -> Option<FsError> {
+    match &model.__base_attr__.materialized {
+        DbtMaterialization::Table
+        | DbtMaterialization::Incremental
+        | DbtMaterialization::Unknown(_) => None,
+        materialized => Some(*fs_err!(
+            code => ErrorCode::InvalidPythonModel,
+            loc => path.to_path_buf(),
+            "Python model '{}' is materialized as '{}', but Python models only support the table and incremental materializations",
+            model.__common_attr__.name,
+            materialized
+        )),
+    }
+}
+
+/// Parses the `deprecation_date` of a model or model version. ISO 8601 dates and
+/// datetimes are accepted; values without a UTC offset are interpreted in the local
+/// timezone, as dbt-core does.
+pub fn parse_deprecation_date(value: &str) -> Option<DateTime<FixedOffset>> {
+    let value = value.trim();
+    let with_offset = match value.strip_suffix(['Z', 'z']) {
+        Some(utc) => format!("{utc}+00:00"),
+        None => value.to_string(),
+    };
+    let with_offset = [
+        "%Y-%m-%dT%H:%M:%S%.f%:z",
+        "%Y-%m-%d %H:%M:%S%.f%:z",
+        "%Y-%m-%dT%H:%M:%S%.f%z",
+        "%Y-%m-%d %H:%M:%S%.f%z",
+    ]
+    .iter()
+    .find_map(|format| DateTime::parse_from_str(&with_offset, format).ok());
+    if with_offset.is_some() {
+        return with_offset;
+    }
+
+    let naive = [
+        "%Y-%m-%dT%H:%M:%S%.f",
+        "%Y-%m-%d %H:%M:%S%.f",
+        "%Y-%m-%dT%H:%M",
+        "%Y-%m-%d %H:%M",
+    ]
+    .iter()
+    .find_map(|format| NaiveDateTime::parse_from_str(value, format).ok())
+    .or_else(|| {
+        NaiveDate::parse_from_str(value, "%Y-%m-%d")
+            .ok()
+            .and_then(|date| date.and_hms_opt(0, 0, 0))
+    })?;
+    naive
+        .and_local_timezone(Local)
+        .earliest()
+        .map(|date| date.fixed_offset())
+}
+
+/// Reports models that are past their deprecation date, and references from models
+/// to models that are deprecated or will be within `warning_window_days` days.
+///
+/// # Arguments
+/// * `models` - The enabled models, with their dependencies resolved
+/// * `now` - The time deprecation dates are compared against, i.e. the run start
+/// * `warning_window_days` - How many days ahead of a deprecation date references are reported
+pub fn validate_model_deprecations(
+    models: &BTreeMap<String, Arc<DbtModel>>,
+    now: DateTime<Utc>,
+    warning_window_days: i64,
+) -> Vec<FsError> {
+    let deprecation_date = |model: &DbtModel| {
+        model
+            .__model_attr__
+            .deprecation_date
+            .as_deref()
+            .and_then(|date| DateTime::parse_from_rfc3339(date).ok())
+            .map(|date| date.with_timezone(&Utc))
+    };
+    let versioned_name = |model: &DbtModel| match &model.__model_attr__.version {
+        Some(version) => format!("{}.v{}", model.__common_attr__.name, version),
+        None => model.__common_attr__.name.clone(),
+    };
+
+    let mut errors = Vec::new();
+    for model in models.values() {
+        if deprecation_date(model).is_some_and(|date| date <= now) {
+            errors.push(*fs_err!(
+                code => ErrorCode::DeprecatedModel,
+                loc => model
+                    .__common_attr__
+                    .patch_path
+                    .clone()
+                    .unwrap_or_else(|| model.__common_attr__.original_file_path.clone()),
+                "Model {} has passed its deprecation date of {}. This model should be disabled or removed.",
+                versioned_name(model),
+                model.__model_attr__.deprecation_date.as_deref().unwrap_or_default()
+            ));
+        }
+
+        for (dependency_id, location) in &model.__base_attr__.depends_on.nodes_with_ref_location {
+            let Some(dependency) = models.get(dependency_id) else {
+                continue;
+            };
+            let Some(date) = deprecation_date(dependency) else {
+                continue;
+            };
+            let raw_date = dependency
+                .__model_attr__
+                .deprecation_date
+                .as_deref()
+                .unwrap_or_default();
+            let (code, status) = if date <= now {
+                (
+                    ErrorCode::DeprecatedReference,
+                    format!("which was deprecated on '{raw_date}'"),
+                )
+            } else if date - now <= Duration::days(warning_window_days) {
+                (
+                    ErrorCode::UpcomingReferenceDeprecation,
+                    format!(
+                        "which is slated for deprecation on '{}' ({} days from now)",
+                        raw_date,
+                        (date - now).num_days()
+                    ),
+                )
+            } else {
+                continue;
+            };
+            let newer_version = match (
+                &dependency.__model_attr__.version,
+                &dependency.__model_attr__.latest_version,
+            ) {
+                (Some(version), Some(latest)) if version != latest => format!(
+                    " A new version of '{name}' is available. Try it out: {{{{ ref('{package}', '{name}', v='{latest}') }}}}.",
+                    name = dependency.__common_attr__.name,
+                    package = dependency.__common_attr__.package_name,
+                ),
+                _ => String::new(),
+            };
+            errors.push(*fs_err!(
+                code => code,
+                loc => location.clone(),
+                "While compiling '{}': Found a reference to {}, {}.{}",
+                model.__common_attr__.name,
+                versioned_name(dependency),
+                status,
+                newer_version
+            ));
+        }
+    }
+    errors
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
-    use dbt_schemas::schemas::common::Versions;
-    use dbt_schemas::schemas::dbt_column::{ColumnProperties, Granularity};
+    use dbt_schemas::schemas::common::{Constraint, DbtContract, Versions};
+    use dbt_schemas::schemas::dbt_column::{ColumnProperties, DbtColumn, Granularity};
+    use dbt_schemas::schemas::properties::ModelConstraint;
     use dbt_schemas::schemas::properties::model_properties::{
         ModelPropertiesTimeSpine, TimeSpineCustomGranularity,
     };
     use dbt_schemas::schemas::serde::FloatOrString;
+    use dbt_schemas::schemas::serde::StringOrInteger;
+    use std::sync::Arc;
 
     fn create_test_model_properties(name: &str) -> ModelProperties {
         ModelProperties {
@@ -422,4 +751,232 @@ mod tests {
         assert!(combined_errors.contains("missing_custom1"));
         assert!(combined_errors.contains("missing_custom2"));
     }
+
+    #[test]
+    fn test_validate_relation_name_lengths() {
+        let mut model = DbtModel::default();
+        model.__common_attr__.name = "orders".to_string();
+        model.__base_attr__.materialized = DbtMaterialization::Table;
+        model.__base_attr__.schema = "analytics".to_string();
+        model.__base_attr__.alias = format!("{}_v2", "o".repeat(62));
+        model.__model_attr__.version = Some(StringOrInteger::Integer(2));
+        let path = Path::new("models/orders.sql");
+
+        let errors = validate_relation_name_lengths(&model, AdapterType::Postgres, path, None);
+        assert_eq!(errors.len(), 1);
+        let message = errors[0].to_string();
+        assert!(message.contains("version suffix '_v2'"));
+        assert!(message.contains("at most 63"));
+
+        assert!(
+            validate_relation_name_lengths(&model, AdapterType::Snowflake, path, None).is_empty()
+        );
+
+        // ephemeral models are never materialized
+        model.__base_attr__.materialized = DbtMaterialization::Ephemeral;
+        assert!(
+            validate_relation_name_lengths(&model, AdapterType::Postgres, path, None).is_empty()
+        );
+    }
+
+    #[test]
+    fn test_validate_constraint_support() {
+        let mut model = DbtModel::default();
+        model.__common_attr__.name = "orders".to_string();
+        model.__model_attr__.constraints = vec![
+            ModelConstraint {
+                type_: ConstraintType::Check,
+                expression: Some("amount > 0".to_string()),
+                ..Default::default()
+            },
+            // custom constraints are rendered on every adapter
+            ModelConstraint {
+                type_: ConstraintType::Custom,
+                expression: Some("masking policy mask_pii".to_string()),
+                ..Default::default()
+            },
+        ];
+        model.__base_attr__.columns.insert(
+            "customer_id".to_string(),
+            Arc::new(DbtColumn {
+                name: "customer_id".to_string(),
+                constraints: vec![
+                    Constraint {
+                        type_: ConstraintType::NotNull,
+                        ..Default::default()
+                    },
+                    Constraint {
+                        type_: ConstraintType::ForeignKey,
+                        name: Some("fk_customer".to_string()),
+                        ..Default::default()
+                    },
+                ],
+                ..Default::default()
+            }),
+        );
+        let path = Path::new("models/schema.yml");
+
+        // constraints are only applied with an enforced contract
+        assert!(validate_constraint_support(&model, AdapterType::Redshift, None, path).is_empty());
+
+        model.__model_attr__.contract = Some(DbtContract {
+            alias_types: true,
+            enforced: true,
+            checksum: None,
+        });
+        let schema_value: dbt_serde_yaml::Value = dbt_serde_yaml::from_str(
+            "name: orders
+constraints:
+  - type: check
+    expression: amount > 0
+  - type: custom
+    expression: masking policy mask_pii
+columns:
+  - name: customer_id
+    constraints:
+      - type: not_null
+      - type: foreign_key
+        name: fk_customer
+",
+        )
+        .unwrap();
+        let errors =
+            validate_constraint_support(&model, AdapterType::Redshift, Some(&schema_value), path);
+        assert_eq!(errors.len(), 2);
+        let message = errors[0].to_string();
+        assert!(message.contains("check constraint on model 'orders' is not supported"));
+        assert!(message.contains("warn_unsupported: false"));
+        let message = errors[1].to_string();
+        assert!(message.contains(
+            "foreign_key 'fk_customer' constraint on column 'customer_id' of model 'orders' is not enforced"
+        ));
+        assert_eq!(
+            locate_constraint(&schema_value, Some("customer_id"), 1)
+                .unwrap()
+                .start
+                .line,
+            11
+        );
+
+        // Postgres enforces foreign keys, and the check constraint can opt out
+        model.__model_attr__.constraints[0].warn_unsupported = Some(false);
+        assert!(
+            validate_constraint_support(&model, AdapterType::Postgres, Some(&schema_value), path)
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_validate_python_model() {
+        let mut model = DbtModel::default();
+        model.__common_attr__.name = "forecast".to_string();
+        model.__base_attr__.materialized = DbtMaterialization::Incremental;
+        let path = Path::new("models/forecast.py");
+        assert!(validate_python_model(&model, path).is_none());
+
+        model.__base_attr__.materialized = DbtMaterialization::Unknown("custom".to_string());
+        assert!(validate_python_model(&model, path).is_none());
+
+        model.__base_attr__.materialized = DbtMaterialization::View;
+        let message = validate_python_model(&model, path).unwrap().to_string();
+        assert!(message.contains("Python model 'forecast' is materialized as 'view'"));
+    }
+
+    #[test]
+    fn test_parse_deprecation_date() {
+        let parsed = |value: &str| parse_deprecation_date(value).map(|date| date.to_rfc3339());
+        assert_eq!(
+            parsed("2024-03-01T12:30:00+02:00").as_deref(),
+            Some("2024-03-01T12:30:00+02:00")
+        );
+        assert_eq!(
+            parsed("2024-03-01 12:30:00Z").as_deref(),
+            Some("2024-03-01T12:30:00+00:00")
+        );
+        assert_eq!(
+            parsed("2024-03-01T12:30:00.5-0500").as_deref(),
+            Some("2024-03-01T12:30:00.500-05:00")
+        );
+
+        // dates and datetimes without an offset are local
+        let local_midnight = NaiveDate::from_ymd_opt(2024, 3, 1)
+            .unwrap()
+            .and_hms_opt(0, 0, 0)
+            .unwrap()
+            .and_local_timezone(Local)
+            .earliest()
+            .unwrap()
+            .fixed_offset();
+        assert_eq!(parse_deprecation_date("2024-03-01"), Some(local_midnight));
+        assert_eq!(
+            parse_deprecation_date("2024-03-01T00:00:00"),
+            Some(local_midnight)
+        );
+
+        assert_eq!(parse_deprecation_date("next tuesday"), None);
+        assert_eq!(parse_deprecation_date("2024-13-01"), None);
+    }
+
+    #[test]
+    fn test_validate_model_deprecations() {
+        let model = |name: &str, version: Option<i64>, deprecation_date: Option<&str>| {
+            let mut model = DbtModel::default();
+            model.__common_attr__.name = name.to_string();
+            model.__common_attr__.package_name = "shop".to_string();
+            model.__common_attr__.unique_id = match version {
+                Some(v) => format!("model.shop.{name}.v{v}"),
+                None => format!("model.shop.{name}"),
+            };
+            model.__model_attr__.version = version.map(StringOrInteger::Integer);
+            model.__model_attr__.latest_version = version.map(|_| StringOrInteger::Integer(3));
+            model.__model_attr__.deprecation_date = deprecation_date.map(str::to_string);
+            model
+        };
+        let now = DateTime::parse_from_rfc3339("2024-06-01T00:00:00+00:00")
+            .unwrap()
+            .with_timezone(&Utc);
+
+        let mut orders = model("orders", None, None);
+        orders.__base_attr__.depends_on.nodes_with_ref_location = vec![
+            (
+                "model.shop.customers.v1".to_string(),
+                CodeLocation::default(),
+            ),
+            (
+                "model.shop.customers.v2".to_string(),
+                CodeLocation::default(),
+            ),
+            ("model.shop.payments".to_string(), CodeLocation::default()),
+        ];
+        let models: BTreeMap<String, Arc<DbtModel>> = [
+            orders,
+            model("customers", Some(1), Some("2024-05-01T00:00:00+00:00")),
+            model("customers", Some(2), Some("2024-06-11T00:00:00+00:00")),
+            model("payments", None, Some("2024-09-01T00:00:00+00:00")),
+        ]
+        .into_iter()
+        .map(|m| (m.__common_attr__.unique_id.clone(), Arc::new(m)))
+        .collect();
+
+        let errors = validate_model_deprecations(&models, now, 30);
+        let codes: Vec<_> = errors.iter().map(|e| e.code).collect();
+        assert_eq!(
+            codes,
+            vec![
+                ErrorCode::DeprecatedModel,
+                ErrorCode::DeprecatedReference,
+                ErrorCode::UpcomingReferenceDeprecation,
+            ]
+        );
+        assert!(errors[0].to_string().contains(
+            "Model customers.v1 has passed its deprecation date of 2024-05-01T00:00:00+00:00"
+        ));
+        let message = errors[1].to_string();
+        assert!(message.contains("While compiling 'orders': Found a reference to customers.v1"));
+        assert!(message.contains("{{ ref('shop', 'customers', v='3') }}"));
+        assert!(errors[2].to_string().contains("(10 days from now)"));
+
+        // payments is only reported once it is inside the window
+        assert_eq!(validate_model_deprecations(&models, now, 100).len(), 4);
+    }
 }
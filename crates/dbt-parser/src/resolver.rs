@@ -1,4 +1,5 @@
 //! Module containing the entrypoint for the resolve phase.
+use chrono::Utc;
 #[allow(unused_imports)]
 use dbt_common::FsError;
 use dbt_common::adapter::AdapterType;
@@ -30,6 +31,9 @@ use crate::dbt_project_config::{RootProjectConfigs, build_root_project_configs};
 use crate::resolve::resolve_groups::resolve_groups;
 use crate::resolve::resolve_operations::resolve_operations;
 use crate::resolve::resolve_query_comment::resolve_query_comment;
+use crate::resolve::validate_models::{
+    DEFAULT_DEPRECATION_WARNING_WINDOW_DAYS, validate_model_deprecations,
+};
 use crate::utils::{self, clear_package_diagnostics};
 use dbt_schemas::schemas::common::DbtQuoting;
 use dbt_schemas::schemas::telemetry::{ExecutionPhase, NodeType, PhaseExecuted};
@@ -301,6 +305,23 @@ pub async fn resolve(
     // Check access
     check_access(arg, &nodes, &all_runtime_configs);
 
+    // Warn about deprecated models and references to them
+    let deprecation_warning_window_days = dbt_state
+        .root_project()
+        .flags
+        .as_ref()
+        .and_then(|flags| flags.as_mapping())
+        .and_then(|flags| flags.get("deprecation_warning_window_days"))
+        .and_then(|value| value.as_i64())
+        .unwrap_or(DEFAULT_DEPRECATION_WARNING_WINDOW_DAYS);
+    for err in validate_model_deprecations(
+        &nodes.models,
+        dbt_state.run_started_at.with_timezone(&Utc),
+        deprecation_warning_window_days,
+    ) {
+        show_warning!(arg.io, Box::new(err));
+    }
+
     Ok((
         ResolverState {
             root_project_name: root_project_name.to_string(),
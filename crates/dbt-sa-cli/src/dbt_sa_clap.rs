@@ -19,6 +19,7 @@ use dbt_common::io_args::{
     SystemArgs, check_selector, check_var,
 };
 use dbt_common::row_limit::RowLimit;
+use dbt_schemas::schemas::manifest::ManifestVersion;
 
 use clap::{Parser, Subcommand};
 use clap::{ValueEnum, arg};
@@ -87,6 +88,12 @@ pub enum Commands {
 
     /// Create reference documentation (json schema for artifacts)
     Man(ManArgs),
+
+    /// Inspect and maintain the local query cache
+    Cache(CacheArgs),
+
+    /// Work with manifest artifacts
+    Manifest(ManifestArgs),
 }
 
 #[derive(
@@ -155,7 +162,8 @@ pub struct ListArgs {
     #[arg(global = true, long, aliases = ["format"])]
     pub output: Option<DisplayFormat>,
 
-    /// Space-separated node properties to include as JSON keys (e.g. --output-keys name type desc)
+    /// Space-separated node properties to include as JSON keys (e.g. --output-keys name type desc).
+    /// `constraints_enforced` shows whether the adapter enforces the constraints of models
     #[arg(long, num_args(1..), value_delimiter = ' ')]
     pub output_keys: Vec<String>,
 
@@ -190,6 +198,72 @@ pub struct ManArgs {
     pub schema: Vec<JsonSchemaTypes>,
 }
 
+#[derive(Parser, Debug, Clone, Serialize, Deserialize)]
+pub struct CacheArgs {
+    #[command(subcommand)]
+    pub command: CacheCommand,
+
+    /// The query cache directory [default: <target-path>/query_cache]
+    #[arg(long)]
+    pub cache_path: Option<PathBuf>,
+
+    // Flattened Common args
+    #[clap(flatten)]
+    pub common_args: CommonArgs,
+}
+
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize)]
+pub enum CacheCommand {
+    /// Show the number of entries, size and hit rate of the query cache
+    Stats,
+
+    /// Remove expired, orphaned and least recently used entries
+    Prune(CachePruneArgs),
+
+    /// Remove all entries of the query cache
+    Clear,
+}
+
+#[derive(Parser, Debug, Default, Clone, Serialize, Deserialize)]
+pub struct CachePruneArgs {
+    /// Evict least recently used entries until the cache fits in this size (e.g. 500MB, 2GB)
+    #[arg(long, value_parser = check_size)]
+    pub max_size: Option<u64>,
+
+    /// Remove entries older than this duration (e.g. 12h, 7days)
+    #[arg(long, value_parser = check_duration)]
+    pub ttl: Option<std::time::Duration>,
+}
+
+#[derive(Parser, Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestArgs {
+    #[command(subcommand)]
+    pub command: ManifestCommand,
+
+    // Flattened Common args
+    #[clap(flatten)]
+    pub common_args: CommonArgs,
+}
+
+#[derive(Subcommand, Debug, Clone, Serialize, Deserialize)]
+pub enum ManifestCommand {
+    /// Convert a manifest to another schema version, reporting dropped and synthesized fields
+    Convert(ManifestConvertArgs),
+}
+
+#[derive(Parser, Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestConvertArgs {
+    /// The manifest schema version to write (v10, v11 or v12)
+    #[arg(long)]
+    pub to: ManifestVersion,
+
+    /// The manifest to convert
+    pub input: PathBuf,
+
+    /// Where to write the converted manifest
+    pub output: PathBuf,
+}
+
 // ----------------------------------------------------------------------------------------------
 // Commmon Command Args
 #[derive(Parser, Debug, Default, Clone, Serialize, Deserialize)]
@@ -308,6 +382,14 @@ pub struct CommonArgs {
     #[arg(global = true, long, short = 'd', default_value = "false", action = ArgAction::SetTrue,  env = "DBT_DEBUG", value_parser = BoolishValueParser::new(),hide = true)]
     pub debug: bool,
 
+    /// Persist the relation cache to the target path and reuse it in the next invocation
+    #[arg(global = true, long, default_value = "false", action = ArgAction::SetTrue, env = "DBT_PERSIST_RELATION_CACHE", value_parser = BoolishValueParser::new())]
+    pub persist_relation_cache: bool,
+
+    /// Ignore the persisted relation cache and list schemas from the warehouse again
+    #[arg(global = true, long, default_value = "false", action = ArgAction::SetTrue, env = "DBT_REFRESH_RELATION_CACHE", value_parser = BoolishValueParser::new())]
+    pub refresh_relation_cache: bool,
+
     /// Show produced artifacts [default: 'progress']
     #[clap(long, num_args(0..), help = "Show produced artifacts [default: 'progress']")]
     pub show: Vec<ShowOptions>,
@@ -321,7 +403,7 @@ impl Cli {
         // Some commands operate without project context, while others must be run in a project directory.
         let (in_dir, out_dir) = {
             match &self.command {
-                Commands::Man(_) | Commands::Init(_) => {
+                Commands::Man(_) | Commands::Init(_) | Commands::Manifest(_) => {
                     // These commands do not require a project directory
                     (PathBuf::from("."), PathBuf::from("."))
                 }
@@ -365,6 +447,8 @@ impl Cli {
             Commands::Ls(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
             Commands::Clean(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
             Commands::Man(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
+            Commands::Cache(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
+            Commands::Manifest(args) => args.to_eval_args(system_arg, &in_dir, &out_dir),
         };
         arg.from_main = from_main;
 
@@ -380,6 +464,8 @@ impl Cli {
             Commands::Parse(args) => args.common_args.clone(),
             Commands::Clean(args) => args.common_args.clone(),
             Commands::Man(args) => args.common_args.clone(),
+            Commands::Cache(args) => args.common_args.clone(),
+            Commands::Manifest(args) => args.common_args.clone(),
         }
     }
 
@@ -401,6 +487,8 @@ impl Cli {
             Commands::Ls(..) => "ls",
             Commands::Clean(..) => "clean",
             Commands::Man(..) => "man",
+            Commands::Cache(..) => "cache",
+            Commands::Manifest(..) => "manifest",
         }
     }
 }
@@ -458,6 +546,17 @@ impl ManArgs {
         eval_args.with_schema(self.schema.clone())
     }
 }
+impl CacheArgs {
+    pub fn to_eval_args(&self, arg: SystemArgs, in_dir: &Path, out_dir: &Path) -> EvalArgs {
+        self.common_args.to_eval_args(arg, in_dir, out_dir)
+    }
+}
+impl ManifestArgs {
+    pub fn to_eval_args(&self, arg: SystemArgs, in_dir: &Path, out_dir: &Path) -> EvalArgs {
+        self.common_args.to_eval_args(arg, in_dir, out_dir)
+    }
+}
+
 impl InitArgs {
     pub fn to_eval_args(&self, arg: SystemArgs, in_dir: &Path, out_dir: &Path) -> EvalArgs {
         let show = if arg.io.show.contains(&ShowOptions::All) {
@@ -520,6 +619,36 @@ pub fn check_target(filename: &str) -> Result<String, String> {
     }
 }
 
+pub fn check_size(size: &str) -> Result<u64, String> {
+    let size = size.trim();
+    let split = size
+        .find(|c: char| !c.is_ascii_digit())
+        .unwrap_or(size.len());
+    let (number, unit) = size.split_at(split);
+    let number = number
+        .parse::<u64>()
+        .map_err(|_| format!("Invalid size '{size}', expected e.g. 500MB or 2GB"))?;
+    let multiplier: u64 = match unit.trim().to_ascii_uppercase().as_str() {
+        "" | "B" => 1,
+        "KB" => 1 << 10,
+        "MB" => 1 << 20,
+        "GB" => 1 << 30,
+        "TB" => 1 << 40,
+        _ => {
+            return Err(format!(
+                "Invalid size unit '{unit}', expected B, KB, MB, GB or TB"
+            ));
+        }
+    };
+    number
+        .checked_mul(multiplier)
+        .ok_or_else(|| format!("Size '{size}' is too large"))
+}
+
+pub fn check_duration(duration: &str) -> Result<std::time::Duration, String> {
+    humantime::parse_duration(duration).map_err(|e| format!("Invalid duration '{duration}': {e}"))
+}
+
 impl CommonArgs {
     pub fn to_eval_args(&self, arg: SystemArgs, in_dir: &Path, out_dir: &Path) -> EvalArgs {
         let mut show = if self.show.contains(&ShowOptions::All) {
@@ -624,6 +753,8 @@ impl CommonArgs {
                 self.write_json
             },
             target_path: self.target_path.clone(),
+            persist_relation_cache: self.persist_relation_cache,
+            refresh_relation_cache: self.refresh_relation_cache,
             ..Default::default()
         }
     }
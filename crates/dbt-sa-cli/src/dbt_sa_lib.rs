@@ -1,7 +1,12 @@
-use crate::dbt_sa_clap::{Cli, Commands, ProjectTemplate};
+use crate::dbt_sa_clap::{
+    CacheArgs, CacheCommand, Cli, Commands, ManifestArgs, ManifestCommand, ProjectTemplate,
+};
 use dbt_common::cancellation::CancellationToken;
 use dbt_common::create_root_info_span;
 use dbt_common::tracing::invocation::create_invocation_attributes;
+use dbt_fusion_adapter::query_cache::{
+    QUERY_CACHE_DIR, clear_query_cache, prune_query_cache, query_cache_stats,
+};
 use dbt_init::init;
 use dbt_jinja_utils::invocation_args::InvocationArgs;
 use dbt_jinja_utils::listener::DefaultJinjaTypeCheckEventListenerFactory;
@@ -11,11 +16,11 @@ use dbt_schemas::man::execute_man_command;
 use dbt_common::io_args::EvalArgs;
 use dbt_common::{
     ErrorCode, FsResult, checkpoint_maybe_exit,
-    constants::{DBT_MANIFEST_JSON, INSTALLING, VALIDATING},
+    constants::{CACHING, CONVERTING, DBT_MANIFEST_JSON, INSTALLING, REMOVING, VALIDATING},
     fs_err, fsinfo,
     io_args::{Phases, SystemArgs},
     logging::init_logger,
-    pretty_string::GREEN,
+    pretty_string::{GREEN, YELLOW},
     show_error, show_progress, show_progress_exit, show_result_with_default_title, stdfs,
     tracing::span_info::record_span_status,
 };
@@ -25,7 +30,7 @@ use dbt_schemas::state::Macros;
 #[allow(unused_imports)]
 use git_version::git_version;
 
-use dbt_schemas::schemas::manifest::build_manifest;
+use dbt_schemas::schemas::manifest::{ConversionChangeKind, build_manifest, convert_manifest_file};
 use tracing::Instrument;
 
 use std::sync::Arc;
@@ -76,6 +81,22 @@ async fn do_execute_fs(eval_arg: &EvalArgs, cli: Cli, token: CancellationToken)
                 Ok(1)
             }
         };
+    } else if let Commands::Cache(cache_args) = &cli.command {
+        return match execute_cache_command(eval_arg, cache_args) {
+            Ok(code) => Ok(code),
+            Err(e) => {
+                show_error!(&eval_arg.io, e);
+                Ok(1)
+            }
+        };
+    } else if let Commands::Manifest(manifest_args) = &cli.command {
+        return match execute_manifest_command(eval_arg, manifest_args) {
+            Ok(code) => Ok(code),
+            Err(e) => {
+                show_error!(&eval_arg.io, e);
+                Ok(1)
+            }
+        };
     } else if let Commands::Init(init_args) = &cli.command {
         // Handle init command
         use dbt_init::init::run_init_workflow;
@@ -136,6 +157,104 @@ async fn do_execute_fs(eval_arg: &EvalArgs, cli: Cli, token: CancellationToken)
     }
 }
 
+fn execute_cache_command(eval_arg: &EvalArgs, cache_args: &CacheArgs) -> FsResult<i32> {
+    let cache_path = cache_args
+        .cache_path
+        .clone()
+        .unwrap_or_else(|| eval_arg.io.out_dir.join(QUERY_CACHE_DIR));
+    let io_err = |e: std::io::Error| {
+        fs_err!(
+            ErrorCode::IoError,
+            "Failed to update the query cache at {}: {}",
+            cache_path.display(),
+            e
+        )
+    };
+
+    let summary = match &cache_args.command {
+        CacheCommand::Stats => {
+            let stats = query_cache_stats(&cache_path);
+            let lookups = stats.hits + stats.misses;
+            let hit_rate = if lookups == 0 {
+                0.0
+            } else {
+                stats.hits as f64 * 100.0 / lookups as f64
+            };
+            log::info!(
+                "{} {} entries for {} nodes ({}) in {}",
+                GREEN.apply_to(CACHING),
+                stats.entries,
+                stats.nodes,
+                format_size(stats.total_bytes),
+                eval_arg.io.format_display_path(&cache_path)
+            );
+            log::info!(
+                "{} {} hits, {} misses ({hit_rate:.1}% hit rate)",
+                GREEN.apply_to(CACHING),
+                stats.hits,
+                stats.misses
+            );
+            return Ok(0);
+        }
+        CacheCommand::Prune(prune_args) => {
+            prune_query_cache(&cache_path, prune_args.ttl, prune_args.max_size).map_err(io_err)?
+        }
+        CacheCommand::Clear => clear_query_cache(&cache_path).map_err(io_err)?,
+    };
+    log::info!(
+        "{} {} entries ({}) from {}",
+        GREEN.apply_to(REMOVING),
+        summary.removed_entries,
+        format_size(summary.freed_bytes),
+        eval_arg.io.format_display_path(&cache_path)
+    );
+    Ok(0)
+}
+
+fn execute_manifest_command(eval_arg: &EvalArgs, manifest_args: &ManifestArgs) -> FsResult<i32> {
+    let ManifestCommand::Convert(convert_args) = &manifest_args.command;
+    let report = convert_manifest_file(&convert_args.input, &convert_args.output, convert_args.to)?;
+    for change in &report.changes {
+        let style = match change.kind {
+            ConversionChangeKind::Dropped => &YELLOW,
+            ConversionChangeKind::Synthesized => &GREEN,
+        };
+        log::info!(
+            "{} {}: {}",
+            style.apply_to(format!("{:>11}", change.kind)),
+            change.path,
+            change.reason
+        );
+    }
+    log::info!(
+        "{} manifest {} to {} in {} ({} dropped, {} synthesized)",
+        GREEN.apply_to(CONVERTING),
+        report.from,
+        report.to,
+        eval_arg.io.format_display_path(&convert_args.output),
+        report.dropped().count(),
+        report.synthesized().count()
+    );
+    Ok(0)
+}
+
+fn format_size(bytes: u64) -> String {
+    const UNITS: [&str; 4] = ["KB", "MB", "GB", "TB"];
+    if bytes < 1024 {
+        return format!("{bytes} B");
+    }
+    let mut size = bytes as f64;
+    let mut unit = "B";
+    for next in UNITS {
+        if size < 1024.0 {
+            break;
+        }
+        size /= 1024.0;
+        unit = next;
+    }
+    format!("{size:.1} {unit}")
+}
+
 #[allow(clippy::cognitive_complexity)]
 async fn execute_setup_and_all_phases(
     eval_arg: &EvalArgs,
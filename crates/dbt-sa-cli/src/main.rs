@@ -4,6 +4,7 @@ use clap::error::ErrorKind;
 use dbt_common::cancellation::CancellationTokenSource;
 use dbt_common::tracing::{FsTraceConfig, init_tracing};
 use dbt_common::{constants::PANIC, pretty_string::GREEN, pretty_string::RED};
+use dbt_fusion_adapter::cancel_all_tracked_statements;
 use dbt_sa_lib::dbt_sa_clap::Cli;
 use dbt_sa_lib::dbt_sa_clap::from_main;
 use dbt_sa_lib::dbt_sa_lib::execute_fs;
@@ -21,7 +22,6 @@ const FS_DEFAULT_MAX_BLOCKING_THREADS: usize = 512;
 
 fn main() -> ExitCode {
     let cst = CancellationTokenSource::new();
-    // TODO(felipecrv): cancel the token (through the cst) on Ctrl-C
     let token = cst.token();
 
     let cli = match Cli::try_parse() {
@@ -114,6 +114,21 @@ fn main() -> ExitCode {
         }));
     }
 
+    // The first Ctrl-C cancels the token and the in-flight statements on the
+    // warehouse. A second Ctrl-C exits immediately.
+    {
+        let cst = cst.clone();
+        tokio_rt.spawn(async move {
+            if tokio::signal::ctrl_c().await.is_ok() {
+                cst.cancel();
+                let _ = tokio::task::spawn_blocking(|| cancel_all_tracked_statements(0)).await;
+                if tokio::signal::ctrl_c().await.is_ok() {
+                    std::process::exit(130);
+                }
+            }
+        });
+    }
+
     // Run within the process span
     let future = Box::pin(execute_fs(arg, cli, token));
 
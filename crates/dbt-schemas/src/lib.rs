@@ -47,8 +47,8 @@ pub mod schemas {
         DbtFunctionAttr, DbtModel, DbtModelAttr, DbtSeed, DbtSeedAttr, DbtSnapshot,
         DbtSnapshotAttr, DbtSource, DbtSourceAttr, DbtTest, DbtTestAttr, DbtUnitTest,
         DbtUnitTestAttr, InternalDbtNode, InternalDbtNodeAttributes, InternalDbtNodeWrapper,
-        IntrospectionKind, NodeBaseAttributes, Nodes, TestMetadata, TimeSpine,
-        TimeSpinePrimaryColumn,
+        IntrospectionKind, ModelConstraintSupport, NodeBaseAttributes, Nodes, TestMetadata,
+        TimeSpine, TimeSpinePrimaryColumn,
     };
 
     pub use sources::{FreshnessResultsArtifact, FreshnessResultsMetadata, FreshnessResultsNode};
@@ -63,6 +63,7 @@ pub mod schemas {
     }
     pub mod manifest {
         mod bigquery_partition;
+        pub mod convert;
         mod group;
         #[allow(clippy::module_inception)]
         mod manifest;
@@ -84,6 +85,10 @@ pub mod schemas {
             BigqueryClusterConfig, BigqueryPartitionConfig, BigqueryPartitionConfigInner,
             GrantAccessToTarget, PartitionConfig, Range, RangeConfig, TimeConfig,
         };
+        pub use convert::{
+            ConversionChange, ConversionChangeKind, ConversionReport, ManifestVersion,
+            convert_manifest, convert_manifest_file,
+        };
         pub use group::ManifestGroup;
         pub use manifest::{
             BaseMetadata, DbtManifest, DbtNode, ManifestMetadata, build_manifest,
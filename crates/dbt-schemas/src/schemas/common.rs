@@ -21,7 +21,7 @@ use crate::schemas::dbt_column::{ColumnPropertiesDimensionType, Granularity};
 use crate::schemas::manifest::common::SourceFileMetadata;
 use crate::schemas::semantic_layer::semantic_manifest::SemanticLayerElementConfig;
 
-use super::serde::StringOrArrayOfStrings;
+use super::serde::{StringOrArrayOfStrings, bool_or_string_bool};
 #[derive(Default, Deserialize, Serialize, Debug, Clone, JsonSchema, PartialEq, Eq)]
 pub struct FreshnessRules {
     pub count: Option<i64>,
@@ -633,13 +633,49 @@ pub struct Constraint {
     pub warn_unenforced: Option<bool>,
 }
 
-#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize)]
+#[serde(rename_all = "snake_case")]
 pub enum ConstraintSupport {
     Enforced,
     NotEnforced,
     NotSupported,
 }
 
+impl ConstraintSupport {
+    /// The support of a constraint type on an adapter, i.e. the `CONSTRAINT_SUPPORT` of the adapter
+    /// https://github.com/dbt-labs/dbt-adapters/blob/5379513bad9c75661b990a5ed5f32ac9c62a0758/dbt-adapters/src/dbt/adapters/base/impl.py#L293
+    pub fn of(adapter_type: AdapterType, ct: ConstraintType) -> Self {
+        match (adapter_type, ct) {
+            // https://github.com/dbt-labs/dbt-adapters/blob/4a00354a497214d9043bf4122810fe2d04de17bb/dbt-bigquery/src/dbt/adapters/bigquery/impl.py#L132
+            (AdapterType::Bigquery, ct) => match ct {
+                ConstraintType::NotNull => Self::Enforced,
+                ConstraintType::PrimaryKey | ConstraintType::ForeignKey => Self::NotEnforced,
+                _ => Self::NotSupported,
+            },
+            // https://github.com/databricks/dbt-databricks/blob/822b105b15e644676d9e1f47cbfd765cd4c1541f/dbt/adapters/databricks/constraints.py#L17
+            (AdapterType::Databricks, ct) => match ct {
+                ConstraintType::Check | ConstraintType::NotNull => Self::Enforced,
+                ConstraintType::PrimaryKey | ConstraintType::ForeignKey => Self::NotEnforced,
+                _ => Self::NotSupported,
+            },
+            // https://github.com/dbt-labs/dbt-adapters/blob/2a94cc75dba1f98fa5caff1f396f5af7ee444598/dbt-redshift/src/dbt/adapters/redshift/impl.py#L53
+            (AdapterType::Redshift, ct) => match ct {
+                ConstraintType::NotNull => Self::Enforced,
+                ConstraintType::Unique
+                | ConstraintType::PrimaryKey
+                | ConstraintType::ForeignKey => Self::NotEnforced,
+                _ => Self::NotSupported,
+            },
+            // https://github.com/dbt-labs/dbt-adapters/blob/aa1de3d16267a456326a36045701fb48a61a6b6c/dbt-snowflake/src/dbt/adapters/snowflake/impl.py#L74
+            (_, ct) => match ct {
+                ConstraintType::NotNull | ConstraintType::ForeignKey => Self::Enforced,
+                ConstraintType::Unique | ConstraintType::PrimaryKey => Self::NotEnforced,
+                _ => Self::NotSupported,
+            },
+        }
+    }
+}
+
 #[derive(Debug, Clone, Copy, Serialize, Deserialize, PartialEq, Eq, Default, JsonSchema)]
 #[serde(rename_all = "snake_case")]
 pub enum ConstraintType {
@@ -780,6 +816,26 @@ pub struct PersistDocsConfig {
     pub relation: Option<bool>,
 }
 
+/// Attribution of warehouse queries to the dbt node that issued them
+///
+/// Set once for the project (`models: +query_attribution: ...`) and
+/// overridden per model. Custom `tags` of a model are merged over the ones
+/// it inherits.
+#[skip_serializing_none]
+#[derive(Deserialize, Serialize, Debug, Clone, Default, PartialEq, Eq, JsonSchema)]
+pub struct QueryAttributionConfig {
+    #[serde(default, deserialize_with = "bool_or_string_bool")]
+    pub enabled: Option<bool>,
+    /// Custom key/value tags attached next to the node attribution
+    pub tags: Option<BTreeMap<String, String>>,
+}
+
+impl QueryAttributionConfig {
+    pub fn is_enabled(&self) -> bool {
+        self.enabled.unwrap_or(true)
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Deserialize, Serialize, Debug, Clone, PartialEq, Eq, JsonSchema)]
 pub struct ScheduleConfig {
@@ -40,6 +40,26 @@
 //!           file_format: delta                                        // required with table_format=iceberg
 //!           adapter_properties:
 //!             location: <string>                                      // optional; if present, non-empty -- will be external_volume under the hood
+//!
+//!       ## ==== AWS Glue and Apache Polaris (written through a Snowflake catalog-linked database)
+//!       write_integrations:
+//!         - name: <non-empty>
+//!           catalog_type: glue | polaris
+//!           table_format: iceberg
+//!           adapter_properties:                                       // REST properties above
+//!             catalog_linked_database: <string, non-empty>            // required
+//!
+//!       ## ==== BigQuery BigLake metastore
+//!       write_integrations:
+//!         - name: <non-empty>
+//!           catalog_type: biglake_metastore
+//!           table_format: iceberg
+//!           file_format: parquet
+//!           external_volume: gs://<bucket>[/<path>]                   // required
+//!           base_location_root: <string>                              // optional, non-empty
+//!           base_location_subpath: <string>                           // optional, non-empty
+//!           adapter_properties:
+//!             connection: <project.region.connection_id>              // optional; defaults to the DEFAULT connection
 
 use std::collections::HashSet;
 use std::path::Path;
@@ -88,6 +108,9 @@ pub enum CatalogType {
     SnowflakeIcebergRest,
     DatabricksHiveMetastore,
     DatabricksUnity,
+    Glue,
+    Polaris,
+    BiglakeMetastore,
 }
 
 impl CatalogType {
@@ -111,20 +134,37 @@ impl CatalogType {
             CatalogType::SnowflakeIcebergRest => "ICEBERG_REST",
             CatalogType::DatabricksHiveMetastore => "hive_metastore",
             CatalogType::DatabricksUnity => "unity",
+            CatalogType::Glue => "GLUE",
+            CatalogType::Polaris => "POLARIS",
+            CatalogType::BiglakeMetastore => "biglake_metastore",
         }
     }
+
+    /// Whether tables of this catalog are written through a Snowflake
+    /// catalog-linked database, with `CREATE ICEBERG TABLE` REST DDL
+    pub fn is_snowflake_catalog_linked(&self) -> bool {
+        matches!(
+            self,
+            CatalogType::SnowflakeIcebergRest | CatalogType::Glue | CatalogType::Polaris
+        )
+    }
 }
 
-const CATALOG_TYPES: [(&str, CatalogType); 6] = [
+const CATALOG_TYPES: [(&str, CatalogType); 10] = [
     ("built_in", CatalogType::SnowflakeBuiltIn),
     ("snowflake", CatalogType::SnowflakeBuiltIn),
     ("rest", CatalogType::SnowflakeIcebergRest),
     ("iceberg_rest", CatalogType::SnowflakeIcebergRest),
     ("hive_metastore", CatalogType::DatabricksHiveMetastore),
     ("unity", CatalogType::DatabricksUnity),
+    ("glue", CatalogType::Glue),
+    ("polaris", CatalogType::Polaris),
+    ("biglake_metastore", CatalogType::BiglakeMetastore),
+    ("biglake", CatalogType::BiglakeMetastore),
 ];
 
-const CATALOG_TYPE_OPTS: &str = "built_in|snowflake|rest|iceberg_rest|unity|hive_metastore";
+const CATALOG_TYPE_OPTS: &str =
+    "built_in|snowflake|rest|iceberg_rest|unity|hive_metastore|glue|polaris|biglake_metastore";
 
 #[derive(Debug, Clone, Copy, PartialEq, Eq)]
 pub enum TableFormat {
@@ -213,14 +253,54 @@ pub struct DatabricksUnityPropsView<'a> {
     pub location_root: Option<&'a str>,
 }
 
+// AWS Glue Properties
+#[derive(Debug, Default)]
+pub struct GluePropsView<'a> {
+    pub auto_refresh: Option<bool>,
+    pub catalog_linked_database: Option<&'a str>,
+    pub max_data_extension_time_in_days: Option<u32>,
+    pub target_file_size: Option<TargetFileSize>,
+}
+
+// Apache Polaris Properties
+#[derive(Debug, Default)]
+pub struct PolarisPropsView<'a> {
+    pub auto_refresh: Option<bool>,
+    pub catalog_linked_database: Option<&'a str>,
+    pub max_data_extension_time_in_days: Option<u32>,
+    pub target_file_size: Option<TargetFileSize>,
+}
+
+// BigQuery BigLake metastore Properties
+#[derive(Debug, Default)]
+pub struct BiglakePropsView<'a> {
+    /// BigQuery connection used to reach the storage bucket
+    pub connection: Option<&'a str>,
+}
+
 #[derive(Debug)]
 pub enum AdapterPropsView<'a> {
     SnowflakeBuiltIn(SnowflakeBuiltInPropsView),
     SnowflakeRest(SnowflakeRestPropsView<'a>),
     DatabricksUnity(DatabricksUnityPropsView<'a>),
+    Glue(GluePropsView<'a>),
+    Polaris(PolarisPropsView<'a>),
+    Biglake(BiglakePropsView<'a>),
     Empty,
 }
 
+impl AdapterPropsView<'_> {
+    /// `catalog_linked_database` of the catalogs written through one
+    fn catalog_linked_database(&self) -> Option<&str> {
+        match self {
+            AdapterPropsView::SnowflakeRest(props) => props.catalog_linked_database,
+            AdapterPropsView::Glue(props) => props.catalog_linked_database,
+            AdapterPropsView::Polaris(props) => props.catalog_linked_database,
+            _ => None,
+        }
+    }
+}
+
 #[inline]
 fn get_str<'a>(m: &'a yml::Mapping, k: &str) -> FsResult<Option<(&'a str, yml::Span)>> {
     match m.get(yml::Value::from(k)) {
@@ -494,13 +574,14 @@ impl<'a> WriteIntegrationView<'a> {
 
         match catalog_type {
             CatalogType::SnowflakeBuiltIn => Self::from_snowflake_built_in(map, integration_name.0),
-            CatalogType::SnowflakeIcebergRest => {
-                Self::from_snowflake_iceberg_rest(map, integration_name.0)
+            CatalogType::SnowflakeIcebergRest | CatalogType::Glue | CatalogType::Polaris => {
+                Self::from_catalog_linked(map, integration_name.0, catalog_type)
             }
             CatalogType::DatabricksUnity => Self::from_databricks_unity(map, integration_name.0),
             CatalogType::DatabricksHiveMetastore => {
                 Self::from_databricks_hms(map, integration_name.0)
             }
+            CatalogType::BiglakeMetastore => Self::from_biglake(map, integration_name.0),
         }
     }
 
@@ -585,10 +666,13 @@ impl<'a> WriteIntegrationView<'a> {
         })
     }
 
-    fn from_snowflake_iceberg_rest(
+    // Snowflake ICEBERG_REST and the REST catalogs it links to (Glue, Polaris)
+    fn from_catalog_linked(
         map: &'a yml::Mapping,
         integration_name: &'a str,
+        catalog_type: CatalogType,
     ) -> FsResult<Self> {
+        let label = catalog_type_label(catalog_type);
         check_unknown_keys(
             map,
             &[
@@ -598,7 +682,7 @@ impl<'a> WriteIntegrationView<'a> {
                 "table_format",
                 "adapter_properties",
             ],
-            "write_integration(Snowflake iceberg_rest)",
+            &format!("write_integration({label})"),
         )?;
 
         let (table_format, table_format_span) = match get_str(map, "table_format")? {
@@ -612,17 +696,15 @@ impl<'a> WriteIntegrationView<'a> {
             return err!(
                 code => ErrorCode::InvalidConfig,
                 hacky_yml_loc => Some(table_format_span),
-                "integration '{}': Snowflake iceberg_rest requires table_format=iceberg",
-                integration_name
+                "integration '{}': {} requires table_format=iceberg",
+                integration_name,
+                label
             );
         }
 
         let adapter_properties = {
             if let Some((props, _span)) = get_map(map, "adapter_properties")? {
-                Some(parse_adapter_properties(
-                    props,
-                    CatalogType::SnowflakeIcebergRest,
-                )?)
+                Some(parse_adapter_properties(props, catalog_type)?)
             } else {
                 None
             }
@@ -630,7 +712,7 @@ impl<'a> WriteIntegrationView<'a> {
 
         Ok(Self {
             integration_name,
-            catalog_type: CatalogType::SnowflakeIcebergRest,
+            catalog_type,
             table_format,
             external_volume: None,
             base_location_root: None,
@@ -789,6 +871,121 @@ impl<'a> WriteIntegrationView<'a> {
             adapter_properties,
         })
     }
+
+    // BigQuery BigLake metastore
+    fn from_biglake(map: &'a yml::Mapping, integration_name: &'a str) -> FsResult<Self> {
+        check_unknown_keys(
+            map,
+            &[
+                "integration_name",
+                "name",
+                "catalog_type",
+                "table_format",
+                "file_format",
+                "external_volume",
+                "base_location_root",
+                "base_location_subpath",
+                "adapter_properties",
+            ],
+            "write_integration(BigQuery biglake_metastore)",
+        )?;
+
+        let (table_format, table_format_span) = match get_str(map, "table_format")? {
+            Some((s, span)) => (
+                TableFormat::parse(s).map_err(|e| e.with_hacky_yml_location(Some(span.clone())))?,
+                span,
+            ),
+            None => return Err(key_err("table_format", None)),
+        };
+        if table_format != TableFormat::Iceberg {
+            return err!(
+                code => ErrorCode::InvalidConfig,
+                hacky_yml_loc => Some(table_format_span),
+                "integration '{}': BigQuery biglake_metastore requires table_format=iceberg",
+                integration_name
+            );
+        }
+
+        // BigLake Iceberg tables are always written as Parquet
+        match get_str(map, "file_format")? {
+            Some((s, _)) if s.eq_ignore_ascii_case("parquet") => {}
+            Some((s, span)) => {
+                return err!(
+                    code => ErrorCode::InvalidConfig,
+                    hacky_yml_loc => Some(span),
+                    "integration '{}': file_format '{}' invalid for BigQuery biglake_metastore (must be 'parquet')",
+                    integration_name,
+                    s
+                );
+            }
+            None => {
+                return err!(
+                    code => ErrorCode::InvalidConfig,
+                    hacky_yml_loc => None::<yml::Span>,
+                    "integration '{}': BigQuery biglake_metastore requires file_format (must be 'parquet')",
+                    integration_name
+                );
+            }
+        }
+
+        let external_volume = match get_str(map, "external_volume")? {
+            Some((s, _)) if s.starts_with("gs://") && s.len() > "gs://".len() => s,
+            Some((_, span)) => {
+                return err!(
+                    code => ErrorCode::InvalidConfig,
+                    hacky_yml_loc => Some(span),
+                    "integration '{}': 'external_volume' must be a Cloud Storage URI (gs://<bucket>) for BigLake catalogs",
+                    integration_name
+                );
+            }
+            None => {
+                return err!(
+                    code => ErrorCode::InvalidConfig,
+                    hacky_yml_loc => None::<yml::Span>,
+                    "integration '{}': 'external_volume' is required for BigLake catalogs",
+                    integration_name
+                );
+            }
+        };
+
+        let base_location_root = get_str(map, "base_location_root")?.map(|(s, _)| s);
+        let base_location_subpath = get_str(map, "base_location_subpath")?.map(|(s, _)| s);
+
+        let adapter_properties = {
+            if let Some((props, _span)) = get_map(map, "adapter_properties")? {
+                Some(parse_adapter_properties(
+                    props,
+                    CatalogType::BiglakeMetastore,
+                )?)
+            } else {
+                None
+            }
+        };
+
+        Ok(Self {
+            integration_name,
+            catalog_type: CatalogType::BiglakeMetastore,
+            table_format,
+            external_volume: Some(external_volume),
+            base_location_root,
+            base_location_subpath,
+            file_format: None,
+            adapter_properties,
+        })
+    }
+}
+
+/// How a catalog type is named in errors
+fn catalog_type_label(catalog_type: CatalogType) -> &'static str {
+    match catalog_type {
+        CatalogType::SnowflakeBuiltIn => "Snowflake built_in",
+        CatalogType::SnowflakeIcebergRest => "Snowflake iceberg_rest",
+        CatalogType::DatabricksHiveMetastore => "Databricks hive_metastore",
+        CatalogType::DatabricksUnity => "Databricks unity",
+        CatalogType::Glue => "AWS Glue",
+        CatalogType::Polaris => "Apache Polaris",
+        CatalogType::BiglakeMetastore => "BigQuery biglake_metastore",
+    }
 }
 
 fn parse_adapter_properties<'a>(
@@ -885,6 +1082,71 @@ fn parse_adapter_properties<'a>(
                 },
             ))
         }
+        CatalogType::Glue => {
+            check_unknown_keys(
+                properties,
+                &[
+                    "auto_refresh",
+                    "catalog_linked_database",
+                    "max_data_extension_time_in_days",
+                    "target_file_size",
+                ],
+                "adapter_properties(glue)",
+            )?;
+            Ok(AdapterPropsView::Glue(GluePropsView {
+                target_file_size: target_file_size(get_str(properties, "target_file_size")?)?,
+                auto_refresh: get_bool(properties, "auto_refresh")?.map(|(v, _)| v),
+                max_data_extension_time_in_days: get_u32(
+                    properties,
+                    "max_data_extension_time_in_days",
+                )?
+                .map(|(v, _)| v),
+                catalog_linked_database: get_str(properties, "catalog_linked_database")?
+                    .map(|(s, _)| s),
+            }))
+        }
+        CatalogType::Polaris => {
+            check_unknown_keys(
+                properties,
+                &[
+                    "auto_refresh",
+                    "catalog_linked_database",
+                    "max_data_extension_time_in_days",
+                    "target_file_size",
+                ],
+                "adapter_properties(polaris)",
+            )?;
+            Ok(AdapterPropsView::Polaris(PolarisPropsView {
+                target_file_size: target_file_size(get_str(properties, "target_file_size")?)?,
+                auto_refresh: get_bool(properties, "auto_refresh")?.map(|(v, _)| v),
+                max_data_extension_time_in_days: get_u32(
+                    properties,
+                    "max_data_extension_time_in_days",
+                )?
+                .map(|(v, _)| v),
+                catalog_linked_database: get_str(properties, "catalog_linked_database")?
+                    .map(|(s, _)| s),
+            }))
+        }
+        CatalogType::BiglakeMetastore => {
+            check_unknown_keys(
+                properties,
+                &["connection"],
+                "adapter_properties(biglake_metastore)",
+            )?;
+            if let Some((connection, span)) = get_str(properties, "connection")?
+                && connection.is_empty()
+            {
+                return err!(
+                    code => ErrorCode::InvalidConfig,
+                    hacky_yml_loc => Some(span),
+                    "adapter_properties.connection cannot be blank"
+                );
+            }
+            Ok(AdapterPropsView::Biglake(BiglakePropsView {
+                connection: get_str(properties, "connection")?.map(|(s, _)| s),
+            }))
+        }
         CatalogType::DatabricksHiveMetastore => {
             if !properties.is_empty() {
                 let first_key_span = properties.keys().next().map(|k| k.span());
@@ -1023,8 +1285,14 @@ pub fn validate_catalogs(spec: &DbtCatalogsView<'_>, _path: &Path) -> FsResult<(
                 // === 6a. built in catalog type
                 CatalogType::SnowflakeBuiltIn => { /* no non-structural constraints */ }
 
-                // === 6b. iceberg rest catalog type
-                CatalogType::SnowflakeIcebergRest => {
+                // === 6b. iceberg rest catalog types (Snowflake REST, Glue, Polaris)
+                CatalogType::SnowflakeIcebergRest | CatalogType::Glue | CatalogType::Polaris => {
+                    let label = match write_integration.catalog_type {
+                        CatalogType::Glue => "AWS Glue",
+                        CatalogType::Polaris => "Apache Polaris",
+                        _ => "Iceberg REST",
+                    };
+
                     // REST forbids base_location_* fields
                     if write_integration.base_location_root.is_some()
                         || write_integration.base_location_subpath.is_some()
@@ -1032,31 +1300,30 @@ pub fn validate_catalogs(spec: &DbtCatalogsView<'_>, _path: &Path) -> FsResult<(
                         return err!(
                             code => ErrorCode::InvalidConfig,
                             hacky_yml_loc => Some(catalog.write_integrations.1.clone()),
-                            "Catalog '{}' integration '{}': base_location_* not valid for iceberg_rest",
-                            catalog.catalog_name.0, write_integration.integration_name
+                            "Catalog '{}' integration '{}': base_location_* not valid for {}",
+                            catalog.catalog_name.0, write_integration.integration_name,
+                            write_integration.catalog_type.as_str().to_ascii_lowercase()
                         );
                     }
 
                     match &write_integration.adapter_properties {
-                        Some(AdapterPropsView::SnowflakeRest(rest)) => {
-                            match rest.catalog_linked_database {
-                                Some(name) if !name.is_empty() => {}
-                                _ => {
-                                    return err!(
-                                        code => ErrorCode::InvalidConfig,
-                                        hacky_yml_loc => Some(catalog.write_integrations.1.clone()),
-                                        "integration '{}': 'catalog_linked_database' is required and cannot be blank for Iceberg REST",
-                                        write_integration.integration_name
-                                    );
-                                }
+                        Some(properties) => match properties.catalog_linked_database() {
+                            Some(name) if !name.is_empty() => {}
+                            _ => {
+                                return err!(
+                                    code => ErrorCode::InvalidConfig,
+                                    hacky_yml_loc => Some(catalog.write_integrations.1.clone()),
+                                    "integration '{}': 'catalog_linked_database' is required and cannot be blank for {}",
+                                    write_integration.integration_name, label
+                                );
                             }
-                        }
-                        _ => {
+                        },
+                        None => {
                             return err!(
                                 code => ErrorCode::InvalidConfig,
                                 hacky_yml_loc => Some(catalog.write_integrations.1.clone()),
-                                "integration '{}': only REST adapter_properties are valid for Iceberg REST",
-                                write_integration.integration_name
+                                "integration '{}': only REST adapter_properties are valid for {}",
+                                write_integration.integration_name, label
                             );
                         }
                     }
@@ -1110,6 +1377,22 @@ pub fn validate_catalogs(spec: &DbtCatalogsView<'_>, _path: &Path) -> FsResult<(
                             write_integration.integration_name);
                     }
                 }
+
+                CatalogType::BiglakeMetastore => {
+                    for (field, value) in [
+                        ("base_location_root", write_integration.base_location_root),
+                        (
+                            "base_location_subpath",
+                            write_integration.base_location_subpath,
+                        ),
+                    ] {
+                        if value.is_some_and(is_blank) {
+                            return err!(code => ErrorCode::InvalidConfig, hacky_yml_loc => Some(catalog.write_integrations.1.clone()),
+                                "integration '{}': '{}' cannot be blank",
+                                write_integration.integration_name, field);
+                        }
+                    }
+                }
             }
         }
     }
@@ -2179,4 +2462,124 @@ catalogs:
             "unexpected error: {msg}"
         );
     }
+
+    #[test]
+    fn happy_glue_and_polaris() {
+        let yaml = r#"
+catalogs:
+  - name: glue
+    active_write_integration: glue_int
+    write_integrations:
+      - name: glue_int
+        catalog_type: glue
+        table_format: iceberg
+        adapter_properties:
+          catalog_linked_database: glue_cld
+          auto_refresh: true
+  - name: polaris
+    active_write_integration: polaris_int
+    write_integrations:
+      - name: polaris_int
+        catalog_type: polaris
+        table_format: iceberg
+        adapter_properties:
+          catalog_linked_database: polaris_cld
+          target_file_size: 64MB
+"#;
+        let res = parse_view_and_validate(yaml);
+        assert!(
+            res.is_ok(),
+            "glue and polaris should validate: {:?}",
+            res.err()
+        );
+    }
+
+    #[test]
+    fn glue_requires_catalog_linked_database() {
+        let yaml = r#"
+catalogs:
+  - name: glue
+    active_write_integration: i
+    write_integrations:
+      - name: i
+        catalog_type: glue
+        table_format: iceberg
+        adapter_properties:
+          auto_refresh: true
+"#;
+        assert_err_contains(
+            yaml,
+            "'catalog_linked_database' is required and cannot be blank for AWS Glue",
+        );
+    }
+
+    #[test]
+    fn glue_rejects_catalog_id() {
+        // the Glue catalog is configured on the Snowflake catalog integration
+        let yaml = r#"
+catalogs:
+  - name: glue
+    active_write_integration: i
+    write_integrations:
+      - name: i
+        catalog_type: glue
+        table_format: iceberg
+        adapter_properties:
+          catalog_linked_database: glue_cld
+          catalog_id: "123456789012"
+"#;
+        assert_err_contains(yaml, "Unknown key 'catalog_id' in adapter_properties(glue)");
+    }
+
+    #[test]
+    fn happy_biglake_metastore() {
+        let yaml = r#"
+catalogs:
+  - name: biglake
+    active_write_integration: i
+    write_integrations:
+      - name: i
+        catalog_type: biglake_metastore
+        table_format: iceberg
+        file_format: parquet
+        external_volume: gs://dbt-iceberg
+        base_location_root: analytics
+        adapter_properties:
+          connection: my-project.us.biglake
+"#;
+        let res = parse_view_and_validate(yaml);
+        assert!(res.is_ok(), "biglake should validate: {:?}", res.err());
+    }
+
+    #[test]
+    fn biglake_requires_gcs_external_volume() {
+        let yaml = r#"
+catalogs:
+  - name: biglake
+    active_write_integration: i
+    write_integrations:
+      - name: i
+        catalog_type: biglake_metastore
+        table_format: iceberg
+        file_format: parquet
+        external_volume: s3://not-gcs
+"#;
+        assert_err_contains(yaml, "must be a Cloud Storage URI");
+    }
+
+    #[test]
+    fn biglake_requires_parquet() {
+        let yaml = r#"
+catalogs:
+  - name: biglake
+    active_write_integration: i
+    write_integrations:
+      - name: i
+        catalog_type: biglake_metastore
+        table_format: iceberg
+        file_format: delta
+        external_volume: gs://dbt-iceberg
+"#;
+        assert_err_contains(yaml, "invalid for BigQuery biglake_metastore");
+    }
 }
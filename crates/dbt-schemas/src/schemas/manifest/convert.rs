@@ -0,0 +1,821 @@
+//! Conversions between the manifest schema versions read by this crate.
+//!
+//! The versioned manifest types are read-only and already normalized into
+//! today's node shapes, so conversions operate on the raw JSON document.
+//! Every step moves one schema version up or down and records each field it
+//! has to drop or synthesize in a [`ConversionReport`].
+
+use std::{fmt, path::Path, str::FromStr};
+
+use dbt_common::{ErrorCode, FsResult, fs_err, stdfs};
+use serde::{Deserialize, Serialize};
+use serde_json::{Map, Value};
+
+use crate::dbt_utils::get_dbt_schema_version;
+
+/// A manifest schema version that can be read and written
+#[derive(Debug, Clone, Copy, PartialEq, Eq, PartialOrd, Ord, Hash, Serialize, Deserialize)]
+#[serde(rename_all = "lowercase")]
+pub enum ManifestVersion {
+    V10,
+    V11,
+    V12,
+}
+
+impl ManifestVersion {
+    pub const ALL: [ManifestVersion; 3] = [Self::V10, Self::V11, Self::V12];
+
+    pub fn number(self) -> i16 {
+        match self {
+            Self::V10 => 10,
+            Self::V11 => 11,
+            Self::V12 => 12,
+        }
+    }
+
+    /// The `metadata.dbt_schema_version` written for this version
+    pub fn schema_url(self) -> String {
+        get_dbt_schema_version("manifest", self.number())
+    }
+
+    /// Reads the version from a `metadata.dbt_schema_version` url.
+    ///
+    /// Fusion's own v20 manifests are not read: their nodes differ from the
+    /// v12 schema, so treating them as v12 would only restamp the url.
+    pub fn from_schema_url(url: &str) -> Option<Self> {
+        let version = url.strip_suffix(".json")?.rsplit('/').next()?;
+        match version {
+            "v10" => Some(Self::V10),
+            "v11" => Some(Self::V11),
+            "v12" => Some(Self::V12),
+            _ => None,
+        }
+    }
+}
+
+impl FromStr for ManifestVersion {
+    type Err = String;
+
+    fn from_str(s: &str) -> Result<Self, Self::Err> {
+        let number = s.trim().trim_start_matches(['v', 'V']);
+        Self::ALL
+            .into_iter()
+            .find(|version| version.number().to_string() == number)
+            .ok_or_else(|| {
+                format!("Unsupported manifest version '{s}', expected one of v10, v11 or v12")
+            })
+    }
+}
+
+impl fmt::Display for ManifestVersion {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "v{}", self.number())
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ConversionChangeKind {
+    /// The field or resource has no counterpart in the target version
+    Dropped,
+    /// The field is required by the target version and was derived or defaulted
+    Synthesized,
+}
+
+impl fmt::Display for ConversionChangeKind {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.pad(match self {
+            Self::Dropped => "dropped",
+            Self::Synthesized => "synthesized",
+        })
+    }
+}
+
+/// A single field dropped or synthesized during a conversion
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionChange {
+    pub kind: ConversionChangeKind,
+    /// The location of the field, e.g. `unit_tests["unit_test.pkg.orders.t"]`
+    pub path: String,
+    pub reason: String,
+}
+
+/// What a conversion had to change beyond reshaping fields
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ConversionReport {
+    pub from: ManifestVersion,
+    pub to: ManifestVersion,
+    pub changes: Vec<ConversionChange>,
+}
+
+impl ConversionReport {
+    fn new(from: ManifestVersion, to: ManifestVersion) -> Self {
+        Self {
+            from,
+            to,
+            changes: vec![],
+        }
+    }
+
+    pub fn dropped(&self) -> impl Iterator<Item = &ConversionChange> {
+        self.changes
+            .iter()
+            .filter(|change| change.kind == ConversionChangeKind::Dropped)
+    }
+
+    pub fn synthesized(&self) -> impl Iterator<Item = &ConversionChange> {
+        self.changes
+            .iter()
+            .filter(|change| change.kind == ConversionChangeKind::Synthesized)
+    }
+
+    pub fn is_lossless(&self) -> bool {
+        self.dropped().next().is_none()
+    }
+
+    fn record_dropped(&mut self, path: String, reason: impl Into<String>) {
+        self.changes.push(ConversionChange {
+            kind: ConversionChangeKind::Dropped,
+            path,
+            reason: reason.into(),
+        });
+    }
+
+    fn record_synthesized(&mut self, path: String, reason: impl Into<String>) {
+        self.changes.push(ConversionChange {
+            kind: ConversionChangeKind::Synthesized,
+            path,
+            reason: reason.into(),
+        });
+    }
+}
+
+/// Reads the schema version of a manifest document
+pub fn manifest_version(manifest: &Value) -> FsResult<ManifestVersion> {
+    let url = manifest
+        .pointer("/metadata/dbt_schema_version")
+        .and_then(Value::as_str)
+        .ok_or_else(|| {
+            fs_err!(
+                ErrorCode::UnsupportedManifestVersion,
+                "Manifest has no metadata.dbt_schema_version"
+            )
+        })?;
+    ManifestVersion::from_schema_url(url).ok_or_else(|| {
+        fs_err!(
+            ErrorCode::UnsupportedManifestVersion,
+            "Unsupported manifest schema version '{}', expected one of v10, v11 or v12",
+            url
+        )
+    })
+}
+
+/// Converts a manifest document to the given schema version
+pub fn convert_manifest(
+    mut manifest: Value,
+    to: ManifestVersion,
+) -> FsResult<(Value, ConversionReport)> {
+    let from = manifest_version(&manifest)?;
+    let mut report = ConversionReport::new(from, to);
+    let doc = manifest.as_object_mut().ok_or_else(|| {
+        fs_err!(
+            ErrorCode::SerializationError,
+            "Manifest must be a JSON object"
+        )
+    })?;
+
+    let mut version = from;
+    while version != to {
+        version = match (version, version < to) {
+            (ManifestVersion::V10, true) => {
+                upgrade_v10_to_v11(doc, &mut report);
+                ManifestVersion::V11
+            }
+            (ManifestVersion::V11, true) => {
+                upgrade_v11_to_v12(doc, &mut report);
+                ManifestVersion::V12
+            }
+            (ManifestVersion::V12, false) => {
+                downgrade_v12_to_v11(doc, &mut report);
+                ManifestVersion::V11
+            }
+            (ManifestVersion::V11, false) => {
+                downgrade_v11_to_v10(doc, &mut report);
+                ManifestVersion::V10
+            }
+            (ManifestVersion::V10, false) | (ManifestVersion::V12, true) => {
+                unreachable!("no manifest version beyond v10 and v12")
+            }
+        };
+    }
+
+    if let Some(Value::Object(metadata)) = doc.get_mut("metadata") {
+        metadata.insert(
+            "dbt_schema_version".to_string(),
+            Value::String(to.schema_url()),
+        );
+    }
+    Ok((manifest, report))
+}
+
+/// Converts the manifest at `input` and writes it to `output`
+pub fn convert_manifest_file(
+    input: &Path,
+    output: &Path,
+    to: ManifestVersion,
+) -> FsResult<ConversionReport> {
+    let manifest: Value = serde_json::from_str(&stdfs::read_to_string(input)?).map_err(|e| {
+        fs_err!(
+            code => ErrorCode::SerializationError,
+            loc => input.to_path_buf(),
+            "Failed to parse manifest: {}",
+            e
+        )
+    })?;
+    let (manifest, report) = convert_manifest(manifest, to)
+        .map_err(|e| Box::new((*e).with_location(input.to_path_buf())))?;
+    if let Some(parent) = output.parent()
+        && !parent.as_os_str().is_empty()
+    {
+        stdfs::create_dir_all(parent)?;
+    }
+    stdfs::write(output, serde_json::to_string(&manifest)?)?;
+    Ok(report)
+}
+
+// ------------------------------------------------------------------------------------------------
+// Upgrades
+
+fn upgrade_v10_to_v11(doc: &mut Map<String, Value>, report: &mut ConversionReport) {
+    // v11 wraps every metric filter in a `where_filters` intersection
+    for metric in resources_mut(doc, "metrics") {
+        for filter in metric_filters_mut(metric) {
+            if filter.get("where_sql_template").is_some() {
+                let where_filter = filter.take();
+                *filter = Value::Object(Map::from_iter([(
+                    "where_filters".to_string(),
+                    Value::Array(vec![where_filter]),
+                )]));
+            }
+        }
+    }
+    synthesize_relation_names(doc, report);
+    synthesize_section(doc, "saved_queries", ManifestVersion::V11, report);
+}
+
+fn upgrade_v11_to_v12(doc: &mut Map<String, Value>, report: &mut ConversionReport) {
+    synthesize_section(doc, "unit_tests", ManifestVersion::V12, report);
+    synthesize_section(doc, "functions", ManifestVersion::V12, report);
+}
+
+// ------------------------------------------------------------------------------------------------
+// Downgrades
+
+fn downgrade_v12_to_v11(doc: &mut Map<String, Value>, report: &mut ConversionReport) {
+    drop_section(doc, "unit_tests", "unit_test", ManifestVersion::V11, report);
+    drop_section(doc, "functions", "function", ManifestVersion::V11, report);
+}
+
+fn downgrade_v11_to_v10(doc: &mut Map<String, Value>, report: &mut ConversionReport) {
+    drop_section(
+        doc,
+        "saved_queries",
+        "saved_query",
+        ManifestVersion::V10,
+        report,
+    );
+
+    // v10 metric filters hold a single `where_sql_template`
+    for (id, metric) in resources_with_ids_mut(doc, "metrics") {
+        for filter in metric_filters_mut(metric) {
+            let Some(Value::Array(where_filters)) = filter.get_mut("where_filters") else {
+                continue;
+            };
+            let mut where_filters = std::mem::take(where_filters);
+            *filter = match where_filters.len() {
+                0 => Value::Null,
+                1 => where_filters.remove(0),
+                n => {
+                    report.record_synthesized(
+                        format!("metrics[{id:?}].filter"),
+                        format!("combined {n} where filters into a single where_sql_template"),
+                    );
+                    let template = where_filters
+                        .iter()
+                        .filter_map(|f| f.get("where_sql_template").and_then(Value::as_str))
+                        .map(|template| format!("({template})"))
+                        .collect::<Vec<_>>()
+                        .join(" AND ");
+                    Value::Object(Map::from_iter([(
+                        "where_sql_template".to_string(),
+                        Value::String(template),
+                    )]))
+                }
+            };
+        }
+    }
+
+    synthesize_relation_names(doc, report);
+    for (id, semantic_model) in resources_with_ids_mut(doc, "semantic_models") {
+        if let Some(Value::Object(node_relation)) = semantic_model.get_mut("node_relation")
+            && node_relation.get("database").is_none_or(Value::is_null)
+        {
+            node_relation.insert("database".to_string(), Value::String(String::new()));
+            report.record_synthesized(
+                format!("semantic_models[{id:?}].node_relation.database"),
+                "manifest v10 requires a database, defaulted to an empty string",
+            );
+        }
+        if let Some(defaults) = semantic_model.get("defaults")
+            && !defaults.is_null()
+            && defaults
+                .get("agg_time_dimension")
+                .is_none_or(Value::is_null)
+        {
+            semantic_model.remove("defaults");
+            report.record_dropped(
+                format!("semantic_models[{id:?}].defaults"),
+                "manifest v10 requires defaults.agg_time_dimension",
+            );
+        }
+    }
+}
+
+// ------------------------------------------------------------------------------------------------
+// Helpers
+
+fn resources_mut<'a>(
+    doc: &'a mut Map<String, Value>,
+    section: &str,
+) -> impl Iterator<Item = &'a mut Map<String, Value>> {
+    resources_with_ids_mut(doc, section).map(|(_, resource)| resource)
+}
+
+fn resources_with_ids_mut<'a>(
+    doc: &'a mut Map<String, Value>,
+    section: &str,
+) -> impl Iterator<Item = (String, &'a mut Map<String, Value>)> {
+    doc.get_mut(section)
+        .and_then(Value::as_object_mut)
+        .into_iter()
+        .flat_map(|resources| resources.iter_mut())
+        .filter_map(|(id, resource)| Some((id.clone(), resource.as_object_mut()?)))
+}
+
+/// All filters of a metric: its own and those of the measures and metrics it
+/// is built from
+fn metric_filters_mut(metric: &mut Map<String, Value>) -> Vec<&mut Value> {
+    let mut filters = vec![];
+    let mut type_params_filters = vec![];
+    for (key, value) in metric.iter_mut() {
+        match key.as_str() {
+            "filter" => filters.push(value),
+            "type_params" => type_params_filters.push(value),
+            _ => {}
+        }
+    }
+    for type_params in type_params_filters
+        .into_iter()
+        .filter_map(Value::as_object_mut)
+    {
+        for (key, value) in type_params.iter_mut() {
+            match (key.as_str(), value) {
+                ("measure" | "numerator" | "denominator", Value::Object(input)) => {
+                    filters.extend(input.get_mut("filter"));
+                }
+                ("input_measures" | "metrics", Value::Array(inputs)) => {
+                    filters.extend(
+                        inputs
+                            .iter_mut()
+                            .filter_map(Value::as_object_mut)
+                            .filter_map(|input| input.get_mut("filter")),
+                    );
+                }
+                _ => {}
+            }
+        }
+    }
+    filters.retain(|filter| !filter.is_null());
+    filters
+}
+
+/// Fills in `node_relation.relation_name` from the database, schema and alias
+fn synthesize_relation_names(doc: &mut Map<String, Value>, report: &mut ConversionReport) {
+    for (id, semantic_model) in resources_with_ids_mut(doc, "semantic_models") {
+        let Some(Value::Object(node_relation)) = semantic_model.get_mut("node_relation") else {
+            continue;
+        };
+        if !node_relation
+            .get("relation_name")
+            .is_none_or(Value::is_null)
+        {
+            continue;
+        }
+        let relation_name = ["database", "schema_name", "alias"]
+            .iter()
+            .filter_map(|key| node_relation.get(*key).and_then(Value::as_str))
+            .filter(|part| !part.is_empty())
+            .collect::<Vec<_>>()
+            .join(".");
+        node_relation.insert("relation_name".to_string(), Value::String(relation_name));
+        report.record_synthesized(
+            format!("semantic_models[{id:?}].node_relation.relation_name"),
+            "derived from database, schema_name and alias",
+        );
+    }
+}
+
+fn synthesize_section(
+    doc: &mut Map<String, Value>,
+    section: &str,
+    to: ManifestVersion,
+    report: &mut ConversionReport,
+) {
+    if !doc.contains_key(section) {
+        doc.insert(section.to_string(), Value::Object(Map::new()));
+        report.record_synthesized(
+            section.to_string(),
+            format!("required by manifest {to}, added empty"),
+        );
+    }
+}
+
+/// Removes a resource section, its disabled entries and every edge to its
+/// resources in the graph maps
+fn drop_section(
+    doc: &mut Map<String, Value>,
+    section: &str,
+    resource_type: &str,
+    to: ManifestVersion,
+    report: &mut ConversionReport,
+) {
+    let reason = format!("{section} are not part of manifest {to}");
+    let mut dropped_ids = vec![];
+    if let Some(Value::Object(resources)) = doc.remove(section) {
+        for id in resources.keys() {
+            report.record_dropped(format!("{section}[{id:?}]"), reason.as_str());
+            dropped_ids.push(id.clone());
+        }
+    }
+
+    if let Some(Value::Object(disabled)) = doc.get_mut("disabled") {
+        disabled.retain(|id, entries| {
+            let is_dropped = entries.as_array().is_some_and(|entries| {
+                entries.iter().any(|entry| {
+                    entry.get("resource_type").and_then(Value::as_str) == Some(resource_type)
+                })
+            });
+            if is_dropped {
+                report.record_dropped(format!("disabled[{id:?}]"), reason.as_str());
+                dropped_ids.push(id.clone());
+            }
+            !is_dropped
+        });
+    }
+
+    if dropped_ids.is_empty() {
+        return;
+    }
+    for map in ["child_map", "parent_map", "group_map"] {
+        let Some(Value::Object(edges)) = doc.get_mut(map) else {
+            continue;
+        };
+        edges.retain(|id, _| !dropped_ids.contains(id));
+        for targets in edges.values_mut().filter_map(Value::as_array_mut) {
+            targets.retain(|target| {
+                target
+                    .as_str()
+                    .is_none_or(|target| !dropped_ids.iter().any(|id| id == target))
+            });
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::schemas::manifest::{DbtManifestV10, DbtManifestV11, DbtManifestV12};
+    use crate::schemas::serde::typed_struct_from_json_str;
+    use serde::de::DeserializeOwned;
+    use serde_json::json;
+
+    fn metadata(version: ManifestVersion) -> Value {
+        json!({
+            "dbt_schema_version": version.schema_url(),
+            "dbt_version": "2.0.0",
+            "generated_at": "2026-10-18T12:00:00Z",
+            "invocation_id": "00000000-0000-0000-0000-000000000000",
+            "env": {},
+            "project_name": "jaffle_shop",
+            "adapter_type": "snowflake",
+        })
+    }
+
+    fn v10_manifest() -> Value {
+        json!({
+            "metadata": metadata(ManifestVersion::V10),
+            "nodes": {
+                "model.jaffle_shop.orders": {"resource_type": "model", "name": "orders"},
+            },
+            "sources": {},
+            "macros": {},
+            "docs": {},
+            "semantic_models": {
+                "semantic_model.jaffle_shop.orders": {
+                    "name": "orders",
+                    "node_relation": {
+                        "alias": "orders",
+                        "schema_name": "analytics",
+                        "database": "db",
+                        "relation_name": "db.analytics.orders",
+                    },
+                },
+            },
+            "exposures": {},
+            "metrics": {
+                "metric.jaffle_shop.large_orders": {
+                    "name": "large_orders",
+                    "filter": {"where_sql_template": "{{ Dimension('order__amount') }} > 100"},
+                    "type_params": {
+                        "measure": {
+                            "name": "order_count",
+                            "filter": {"where_sql_template": "{{ Dimension('order__status') }} = 'done'"},
+                        },
+                        "metrics": [],
+                    },
+                },
+            },
+            "child_map": {"model.jaffle_shop.orders": []},
+            "parent_map": {"model.jaffle_shop.orders": []},
+            "group_map": {},
+            "disabled": {},
+            "selectors": {},
+            "groups": {},
+        })
+    }
+
+    /// A v10 manifest with every field its typed schema requires
+    fn typed_v10_manifest() -> Value {
+        json!({
+            "metadata": metadata(ManifestVersion::V10),
+            "nodes": {},
+            "sources": {},
+            "macros": {},
+            "docs": {},
+            "semantic_models": {},
+            "exposures": {},
+            "metrics": {
+                "metric.jaffle_shop.large_orders": {
+                    "name": "large_orders",
+                    "resource_type": "metric",
+                    "package_name": "jaffle_shop",
+                    "path": "metrics.yml",
+                    "original_file_path": "models/metrics.yml",
+                    "unique_id": "metric.jaffle_shop.large_orders",
+                    "fqn": ["jaffle_shop", "large_orders"],
+                    "description": "",
+                    "label": "Large orders",
+                    "type": "simple",
+                    "type_params": {
+                        "measure": {
+                            "name": "order_count",
+                            "filter": {"where_sql_template": "{{ Dimension('order__status') }} = 'done'"},
+                        },
+                        "input_measures": [],
+                        "metrics": [],
+                    },
+                    "filter": {"where_sql_template": "{{ Dimension('order__amount') }} > 100"},
+                    "metadata": null,
+                    "time_granularity": null,
+                    "config": {"enabled": true, "group": null, "meta": {}},
+                    "unrendered_config": {},
+                    "sources": [],
+                    "depends_on": {"macros": [], "nodes": []},
+                    "refs": [],
+                    "metrics": [],
+                    "created_at": 1760788800.0,
+                    "tags": [],
+                    "meta": {},
+                    "group": null,
+                },
+            },
+            "child_map": {},
+            "parent_map": {},
+            "group_map": {},
+            "disabled": {},
+            "selectors": {},
+            "groups": {},
+        })
+    }
+
+    fn typed<T: DeserializeOwned>(manifest: &Value) -> T {
+        typed_struct_from_json_str(&manifest.to_string(), None).unwrap()
+    }
+
+    fn v12_manifest_with_unit_test() -> Value {
+        let (mut manifest, _) = convert_manifest(v10_manifest(), ManifestVersion::V12).unwrap();
+        manifest["unit_tests"] = json!({
+            "unit_test.jaffle_shop.orders.test_orders": {"name": "test_orders"},
+        });
+        manifest["child_map"]["model.jaffle_shop.orders"] =
+            json!(["unit_test.jaffle_shop.orders.test_orders"]);
+        manifest["parent_map"]["unit_test.jaffle_shop.orders.test_orders"] =
+            json!(["model.jaffle_shop.orders"]);
+        manifest
+    }
+
+    #[test]
+    fn test_manifest_version_parsing() {
+        assert_eq!("v11".parse(), Ok(ManifestVersion::V11));
+        assert_eq!("12".parse(), Ok(ManifestVersion::V12));
+        assert!("v9".parse::<ManifestVersion>().is_err());
+        assert_eq!(
+            ManifestVersion::from_schema_url("https://schemas.getdbt.com/dbt/manifest/v20.json"),
+            None
+        );
+        assert_eq!(
+            ManifestVersion::from_schema_url(&ManifestVersion::V10.schema_url()),
+            Some(ManifestVersion::V10)
+        );
+        assert_eq!(
+            ManifestVersion::from_schema_url("https://example.com/v9.json"),
+            None
+        );
+    }
+
+    #[test]
+    fn test_upgrade_round_trip_is_lossless() {
+        let original = v10_manifest();
+        let (upgraded, report) = convert_manifest(original.clone(), ManifestVersion::V12).unwrap();
+        assert!(report.is_lossless());
+        assert_eq!(
+            report
+                .synthesized()
+                .map(|change| change.path.as_str())
+                .collect::<Vec<_>>(),
+            vec!["saved_queries", "unit_tests", "functions"]
+        );
+        assert_eq!(
+            upgraded["metrics"]["metric.jaffle_shop.large_orders"]["filter"],
+            json!({"where_filters": [
+                {"where_sql_template": "{{ Dimension('order__amount') }} > 100"}
+            ]})
+        );
+        assert_eq!(
+            upgraded["metrics"]["metric.jaffle_shop.large_orders"]["type_params"]["measure"]["filter"]
+                ["where_filters"][0]["where_sql_template"],
+            json!("{{ Dimension('order__status') }} = 'done'")
+        );
+        assert_eq!(manifest_version(&upgraded).unwrap(), ManifestVersion::V12,);
+
+        let (downgraded, report) = convert_manifest(upgraded, ManifestVersion::V10).unwrap();
+        assert!(report.is_lossless());
+        assert_eq!(downgraded, original);
+    }
+
+    #[test]
+    fn test_conversions_read_as_typed_manifests() {
+        let original = typed_v10_manifest();
+        let metric_id = "metric.jaffle_shop.large_orders";
+        let v10: DbtManifestV10 = typed(&original);
+        assert_eq!(
+            v10.metrics[metric_id]
+                .filter
+                .as_ref()
+                .unwrap()
+                .where_sql_template,
+            "{{ Dimension('order__amount') }} > 100"
+        );
+
+        let (manifest, _) = convert_manifest(original.clone(), ManifestVersion::V11).unwrap();
+        let v11: DbtManifestV11 = typed(&manifest);
+        let filter = v11.metrics[metric_id].filter.as_ref().unwrap();
+        assert_eq!(
+            filter.where_filters[0].where_sql_template,
+            "{{ Dimension('order__amount') }} > 100"
+        );
+
+        let (manifest, _) = convert_manifest(manifest, ManifestVersion::V12).unwrap();
+        let v12: DbtManifestV12 = typed(&manifest);
+        let measure = v12.metrics[metric_id].type_params.measure.as_ref().unwrap();
+        assert_eq!(
+            measure.filter.as_ref().unwrap().where_filters[0].where_sql_template,
+            "{{ Dimension('order__status') }} = 'done'"
+        );
+        assert!(v12.unit_tests.is_empty() && v12.saved_queries.is_empty());
+
+        let (manifest, report) = convert_manifest(manifest, ManifestVersion::V10).unwrap();
+        assert!(report.is_lossless());
+        let _: DbtManifestV10 = typed(&manifest);
+        assert_eq!(manifest, original);
+    }
+
+    #[test]
+    fn test_downgrade_reports_dropped_unit_tests() {
+        let original = v12_manifest_with_unit_test();
+        let (downgraded, report) =
+            convert_manifest(original.clone(), ManifestVersion::V11).unwrap();
+        assert_eq!(
+            report.dropped().collect::<Vec<_>>(),
+            vec![&ConversionChange {
+                kind: ConversionChangeKind::Dropped,
+                path: "unit_tests[\"unit_test.jaffle_shop.orders.test_orders\"]".to_string(),
+                reason: "unit_tests are not part of manifest v11".to_string(),
+            }]
+        );
+        assert!(downgraded.get("unit_tests").is_none());
+        assert!(downgraded.get("functions").is_none());
+        assert_eq!(
+            downgraded["child_map"]["model.jaffle_shop.orders"],
+            json!([])
+        );
+        assert!(
+            downgraded["parent_map"]
+                .get("unit_test.jaffle_shop.orders.test_orders")
+                .is_none()
+        );
+
+        // Everything but the dropped unit test survives the way back
+        let (upgraded, _) = convert_manifest(downgraded, ManifestVersion::V12).unwrap();
+        let mut expected = original;
+        expected["unit_tests"] = json!({});
+        expected["child_map"]["model.jaffle_shop.orders"] = json!([]);
+        expected["parent_map"]
+            .as_object_mut()
+            .unwrap()
+            .remove("unit_test.jaffle_shop.orders.test_orders");
+        assert_eq!(upgraded, expected);
+    }
+
+    #[test]
+    fn test_downgrade_to_v10_synthesizes_required_fields() {
+        let (mut manifest, _) = convert_manifest(v10_manifest(), ManifestVersion::V11).unwrap();
+        manifest["metrics"]["metric.jaffle_shop.large_orders"]["filter"] = json!({
+            "where_filters": [
+                {"where_sql_template": "a > 1"},
+                {"where_sql_template": "b < 2"},
+            ],
+        });
+        manifest["semantic_models"]["semantic_model.jaffle_shop.orders"]["node_relation"] = json!({
+            "alias": "orders",
+            "schema_name": "analytics",
+            "database": null,
+            "relation_name": null,
+        });
+        manifest["saved_queries"] = json!({
+            "saved_query.jaffle_shop.weekly_orders": {"name": "weekly_orders"},
+        });
+
+        let (downgraded, report) = convert_manifest(manifest, ManifestVersion::V10).unwrap();
+        assert_eq!(
+            downgraded["metrics"]["metric.jaffle_shop.large_orders"]["filter"],
+            json!({"where_sql_template": "(a > 1) AND (b < 2)"})
+        );
+        assert_eq!(
+            downgraded["semantic_models"]["semantic_model.jaffle_shop.orders"]["node_relation"],
+            json!({
+                "alias": "orders",
+                "schema_name": "analytics",
+                "database": "",
+                "relation_name": "analytics.orders",
+            })
+        );
+        assert!(downgraded.get("saved_queries").is_none());
+        assert_eq!(
+            report
+                .changes
+                .iter()
+                .map(|change| (change.kind, change.path.as_str()))
+                .collect::<Vec<_>>(),
+            vec![
+                (
+                    ConversionChangeKind::Dropped,
+                    "saved_queries[\"saved_query.jaffle_shop.weekly_orders\"]"
+                ),
+                (
+                    ConversionChangeKind::Synthesized,
+                    "metrics[\"metric.jaffle_shop.large_orders\"].filter"
+                ),
+                (
+                    ConversionChangeKind::Synthesized,
+                    "semantic_models[\"semantic_model.jaffle_shop.orders\"].node_relation.relation_name"
+                ),
+                (
+                    ConversionChangeKind::Synthesized,
+                    "semantic_models[\"semantic_model.jaffle_shop.orders\"].node_relation.database"
+                ),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_unsupported_manifest_version() {
+        for version in ["v9", "v20"] {
+            let mut manifest = v10_manifest();
+            manifest["metadata"]["dbt_schema_version"] = json!(format!(
+                "https://schemas.getdbt.com/dbt/manifest/{version}.json"
+            ));
+            let err = convert_manifest(manifest, ManifestVersion::V12).unwrap_err();
+            assert_eq!(err.code, ErrorCode::UnsupportedManifestVersion);
+        }
+    }
+}
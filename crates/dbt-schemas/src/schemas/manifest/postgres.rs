@@ -5,8 +5,11 @@ use serde_with::skip_serializing_none;
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, PartialEq, Eq, JsonSchema)]
 pub struct PostgresIndex {
-    columns: Vec<String>,
-    unique: Option<bool>,
+    /// The columns the index is built on
+    pub columns: Vec<String>,
+    /// Whether the index enforces uniqueness
+    pub unique: Option<bool>,
+    /// The index method (btree, hash, gist, ...)
     #[serde(rename = "type")]
-    _type: Option<String>,
+    pub index_type: Option<String>,
 }
@@ -17,8 +17,9 @@ use crate::schemas::project::WarehouseSpecificNodeConfig;
 use crate::schemas::serde::StringOrArrayOfStrings;
 use crate::schemas::{
     common::{
-        Access, DbtChecksum, DbtContract, DbtIncrementalStrategy, DbtMaterialization, Expect,
-        FreshnessDefinition, Given, IncludeExclude, NodeDependsOn, ResolvedQuoting, ScheduleConfig,
+        Access, ConstraintSupport, ConstraintType, DbtChecksum, DbtContract,
+        DbtIncrementalStrategy, DbtMaterialization, Expect, FreshnessDefinition, Given,
+        IncludeExclude, NodeDependsOn, ResolvedQuoting, ScheduleConfig,
     },
     macros::DbtMacro,
     manifest::common::DbtOwner,
@@ -2118,6 +2119,94 @@ pub struct DbtModel {
     pub __other__: BTreeMap<String, YmlValue>,
 }
 
+/// The support of one constraint of a model on the adapter it is materialized with
+#[skip_serializing_none]
+#[derive(Debug, Clone, Serialize)]
+pub struct ModelConstraintSupport {
+    /// The column of a column-level constraint, `None` for model-level constraints
+    pub column: Option<String>,
+    #[serde(rename = "type")]
+    pub type_: ConstraintType,
+    pub name: Option<String>,
+    pub support: ConstraintSupport,
+    /// The position of the constraint in its `constraints` list
+    #[serde(skip)]
+    pub index: usize,
+    /// Whether the constraint opted out of being reported with `warn_unsupported` or `warn_unenforced`
+    #[serde(skip)]
+    pub silenced: bool,
+}
+
+impl DbtModel {
+    /// The support of the model and column constraints of this model on an
+    /// adapter. Constraints are only applied with an enforced contract, so
+    /// this is empty otherwise. Custom constraints are rendered as written on
+    /// every adapter and are left out.
+    pub fn constraint_support(&self, adapter_type: AdapterType) -> Vec<ModelConstraintSupport> {
+        if !self
+            .__model_attr__
+            .contract
+            .as_ref()
+            .is_some_and(|contract| contract.enforced)
+        {
+            return vec![];
+        }
+        let silenced = |support: ConstraintSupport,
+                        warn_unsupported: Option<bool>,
+                        warn_unenforced: Option<bool>| match support {
+            ConstraintSupport::Enforced => false,
+            ConstraintSupport::NotEnforced => warn_unenforced == Some(false),
+            ConstraintSupport::NotSupported => warn_unsupported == Some(false),
+        };
+
+        let model_constraints =
+            self.__model_attr__
+                .constraints
+                .iter()
+                .enumerate()
+                .map(|(index, constraint)| {
+                    let support = ConstraintSupport::of(adapter_type, constraint.type_);
+                    ModelConstraintSupport {
+                        column: None,
+                        type_: constraint.type_,
+                        name: constraint.name.clone(),
+                        support,
+                        index,
+                        silenced: silenced(
+                            support,
+                            constraint.warn_unsupported,
+                            constraint.warn_unenforced,
+                        ),
+                    }
+                });
+        let column_constraints = self.__base_attr__.columns.values().flat_map(|column| {
+            column
+                .constraints
+                .iter()
+                .enumerate()
+                .map(|(index, constraint)| {
+                    let support = ConstraintSupport::of(adapter_type, constraint.type_);
+                    ModelConstraintSupport {
+                        column: Some(column.name.clone()),
+                        type_: constraint.type_,
+                        name: constraint.name.clone(),
+                        support,
+                        index,
+                        silenced: silenced(
+                            support,
+                            constraint.warn_unsupported,
+                            constraint.warn_unenforced,
+                        ),
+                    }
+                })
+        });
+        model_constraints
+            .chain(column_constraints)
+            .filter(|constraint| constraint.type_ != ConstraintType::Custom)
+            .collect()
+    }
+}
+
 #[skip_serializing_none]
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
 #[serde(rename_all = "snake_case")]
@@ -2181,6 +2270,7 @@ impl AdapterAttr {
                     copy_grants: config.copy_grants,
                     secure: config.secure,
                     transient: config.transient,
+                    cluster_by: config.cluster_by.clone(),
                 })))
             }
             AdapterType::Postgres => AdapterAttr::default(),
@@ -2258,6 +2348,7 @@ impl AdapterAttr {
                         copy_grants: config.copy_grants,
                         secure: config.secure,
                         transient: config.transient,
+                        cluster_by: config.cluster_by.clone(),
                     })))
                     .with_bigquery_attr(Some(Box::new(BigQueryAttr {
                         partition_by: config.partition_by.clone(),
@@ -2334,6 +2425,7 @@ pub struct SnowflakeAttr {
     pub copy_grants: Option<bool>,
     pub secure: Option<bool>,
     pub transient: Option<bool>,
+    pub cluster_by: Option<BigqueryClusterConfig>,
 }
 
 /// A resolved Databricks configuration
@@ -9,7 +9,7 @@ use dbt_serde_yaml::UntaggedEnumDeserialize;
 use merge::Merge;
 use serde_derive::Deserialize;
 use serde_derive::Serialize;
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 use std::convert::TryFrom;
 use std::fmt::{self, Debug, Display};
 use std::path::PathBuf;
@@ -447,6 +447,20 @@ pub struct RedshiftDbConfig {
     pub autocommit: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retries: Option<i64>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_timeout: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_cache_max_size: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_on: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_backoff: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_queries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency_limits: Option<BTreeMap<String, StringOrInteger>>,
     // Authentication Parameters (Password)
     #[serde(skip_serializing_if = "Option::is_none")]
     pub method: Option<String>,
@@ -487,6 +501,20 @@ pub struct SnowflakeDbConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connect_timeout: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_timeout: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_cache_max_size: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_on: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_backoff: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_queries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency_limits: Option<BTreeMap<String, StringOrInteger>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub reuse_connections: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub authenticator: Option<String>,
@@ -551,6 +579,20 @@ pub struct PostgresDbConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub retries: Option<StringOrInteger>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_timeout: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_cache_max_size: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_on: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_backoff: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_queries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency_limits: Option<BTreeMap<String, StringOrInteger>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub search_path: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub role: Option<String>,
@@ -637,6 +679,20 @@ pub struct BigqueryDbConfig {
     pub job_retry_deadline_seconds: Option<i64>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub target_name: Option<String>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_timeout: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_cache_max_size: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_on: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_backoff: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_queries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency_limits: Option<BTreeMap<String, StringOrInteger>>,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize, JsonSchema, Merge)]
@@ -710,6 +766,20 @@ pub struct DatabricksDbConfig {
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connect_timeout: Option<i32>,
     #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_timeout: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub statement_retries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub query_cache_max_size: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub retry_on: Option<Vec<String>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_backoff: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub max_concurrent_queries: Option<StringOrInteger>,
+    #[serde(skip_serializing_if = "Option::is_none")]
+    pub concurrency_limits: Option<BTreeMap<String, StringOrInteger>>,
+    #[serde(skip_serializing_if = "Option::is_none")]
     pub retry_all: Option<bool>,
     #[serde(skip_serializing_if = "Option::is_none")]
     pub connect_max_idle: Option<i32>,
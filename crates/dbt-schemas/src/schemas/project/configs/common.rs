@@ -10,7 +10,7 @@ use crate::default_to;
 use crate::schemas::common::Hooks;
 use crate::schemas::common::merge_meta;
 use crate::schemas::common::merge_tags;
-use crate::schemas::common::{DbtQuoting, ScheduleConfig};
+use crate::schemas::common::{DbtQuoting, QueryAttributionConfig, ScheduleConfig};
 use crate::schemas::manifest::GrantAccessToTarget;
 use crate::schemas::manifest::postgres::PostgresIndex;
 use crate::schemas::manifest::{BigqueryClusterConfig, PartitionConfig};
@@ -88,6 +88,30 @@ pub fn default_column_types(
     }
 }
 
+/// Helper function to handle default_to logic for query_attribution
+/// `enabled` is inherited when unset and `tags` are merged, child values winning
+pub fn default_query_attribution(
+    child_attribution: &mut Option<QueryAttributionConfig>,
+    parent_attribution: &Option<QueryAttributionConfig>,
+) {
+    let Some(parent_attribution) = parent_attribution else {
+        return;
+    };
+    let Some(attribution) = child_attribution else {
+        *child_attribution = Some(parent_attribution.clone());
+        return;
+    };
+    if attribution.enabled.is_none() {
+        attribution.enabled = parent_attribution.enabled;
+    }
+    if let Some(parent_tags) = &parent_attribution.tags {
+        let tags = attribution.tags.get_or_insert_with(BTreeMap::new);
+        for (key, value) in parent_tags {
+            tags.entry(key.clone()).or_insert_with(|| value.clone());
+        }
+    }
+}
+
 /// helper function to handle default_to for grants
 /// if the key of a grant starts with a + append the child grant to the parents, otherwise replace the parent grant
 pub fn default_to_grants(
@@ -18,6 +18,7 @@ use crate::schemas::common::DbtIncrementalStrategy;
 use crate::schemas::common::DbtMaterialization;
 use crate::schemas::common::DbtUniqueKey;
 use crate::schemas::common::PersistDocsConfig;
+use crate::schemas::common::QueryAttributionConfig;
 use crate::schemas::common::{Access, DbtQuoting, ScheduleConfig};
 use crate::schemas::common::{DocsConfig, OnConfigurationChange};
 use crate::schemas::common::{Hooks, OnSchemaChange};
@@ -28,6 +29,7 @@ use crate::schemas::project::configs::common::WarehouseSpecificNodeConfig;
 use crate::schemas::project::configs::common::default_column_types;
 use crate::schemas::project::configs::common::default_hooks;
 use crate::schemas::project::configs::common::default_meta_and_tags;
+use crate::schemas::project::configs::common::default_query_attribution;
 use crate::schemas::project::configs::common::default_quoting;
 use crate::schemas::project::configs::common::default_to_grants;
 use crate::schemas::project::dbt_project::DefaultTo;
@@ -236,6 +238,14 @@ pub struct ProjectModelConfig {
     pub predicates: Option<Vec<String>>,
     #[serde(rename = "+query_tag")]
     pub query_tag: Option<String>,
+    #[serde(
+        default,
+        rename = "+query_timeout",
+        deserialize_with = "u64_or_string_u64"
+    )]
+    pub query_timeout: Option<u64>,
+    #[serde(rename = "+query_attribution")]
+    pub query_attribution: Option<QueryAttributionConfig>,
     #[serde(rename = "+table_tag")]
     pub table_tag: Option<String>,
     #[serde(rename = "+row_access_policy")]
@@ -389,9 +399,15 @@ pub struct ModelConfig {
     pub static_analysis: Option<StaticAnalysisKind>,
     pub freshness: Option<ModelFreshness>,
     pub sql_header: Option<String>,
+    #[serde(default, deserialize_with = "u64_or_string_u64")]
+    pub query_timeout: Option<u64>,
+    pub query_attribution: Option<QueryAttributionConfig>,
     pub location: Option<String>,
     pub predicates: Option<Vec<String>>,
     pub description: Option<String>,
+    // Keys read with `dbt.config.get` in a Python model and their defaults, in call order
+    pub config_keys_used: Option<Vec<String>>,
+    pub config_keys_defaults: Option<Vec<YmlValue>>,
     // Adapter specific configs
     pub __warehouse_specific_config__: WarehouseSpecificNodeConfig,
 }
@@ -433,11 +449,15 @@ impl From<ProjectModelConfig> for ModelConfig {
             quoting: config.quoting,
             schema: config.schema,
             sql_header: config.sql_header,
+            query_timeout: config.query_timeout,
+            query_attribution: config.query_attribution,
             static_analysis: config.static_analysis,
             table_format: config.table_format,
             tags: config.tags.into_inner(),
             unique_key: config.unique_key,
             description: config.description,
+            config_keys_used: None,
+            config_keys_defaults: None,
             __warehouse_specific_config__: WarehouseSpecificNodeConfig {
                 adapter_properties: config.adapter_properties,
                 external_volume: config.external_volume,
@@ -553,6 +573,8 @@ impl From<ModelConfig> for ProjectModelConfig {
             quoting: config.quoting,
             schema: config.schema,
             sql_header: config.sql_header,
+            query_timeout: config.query_timeout,
+            query_attribution: config.query_attribution,
             static_analysis: config.static_analysis,
             table_format: config.table_format,
             tags: config.tags.into(),
@@ -631,6 +653,9 @@ impl From<ModelConfig> for ProjectModelConfig {
             description: config.description,
             primary_key: config.__warehouse_specific_config__.primary_key,
             category: config.__warehouse_specific_config__.category,
+            // `config_keys_used` and `config_keys_defaults` are not carried over:
+            // they are recorded from the `dbt.config.get` calls in a Python
+            // model's code, which a project config cannot set
             __additional_properties__: BTreeMap::new(),
         }
     }
@@ -689,9 +714,13 @@ impl DefaultTo<ModelConfig> for ModelConfig {
             static_analysis,
             freshness,
             sql_header,
+            query_timeout,
+            query_attribution,
             location,
             predicates,
             description,
+            config_keys_used,
+            config_keys_defaults,
         } = self;
 
         // Handle flattened configs
@@ -714,6 +743,9 @@ impl DefaultTo<ModelConfig> for ModelConfig {
         let column_types = default_column_types(column_types, &parent.column_types);
         #[allow(unused, clippy::let_unit_value)]
         let grants = default_to_grants(grants, &parent.grants);
+        #[allow(unused, clippy::let_unit_value)]
+        let query_attribution =
+            default_query_attribution(query_attribution, &parent.query_attribution);
 
         // Handle Omissible fields for hierarchical overrides
         handle_omissible_override(schema, &parent.schema);
@@ -749,9 +781,12 @@ impl DefaultTo<ModelConfig> for ModelConfig {
                 static_analysis,
                 freshness,
                 sql_header,
+                query_timeout,
                 location,
                 predicates,
                 description,
+                config_keys_used,
+                config_keys_defaults,
             ]
         );
     }
@@ -821,9 +856,13 @@ impl ModelConfig {
             && self.static_analysis == other.static_analysis
             && self.freshness == other.freshness
             && self.sql_header == other.sql_header
+            && self.query_timeout == other.query_timeout
+            && self.query_attribution == other.query_attribution
             && self.location == other.location
             && self.predicates == other.predicates
             && self.description == other.description
+            && self.config_keys_used == other.config_keys_used
+            && self.config_keys_defaults == other.config_keys_defaults
             && self.__warehouse_specific_config__ == other.__warehouse_specific_config__
     }
 }
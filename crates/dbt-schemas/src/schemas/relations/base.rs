@@ -3,6 +3,7 @@ use crate::dbt_types::RelationType;
 use crate::filter::RunFilter;
 use crate::schemas::common::ResolvedQuoting;
 
+use dbt_common::adapter::{AdapterType, IdentifierLimits};
 use dbt_common::constants::DBT_CTE_PREFIX;
 use dbt_common::{FsResult, current_function_name};
 use dbt_frontend_common::FullyQualifiedName;
@@ -730,6 +731,22 @@ pub trait BaseRelation: BaseRelationProperties + Any + Send + Sync + fmt::Debug
         )
     }
 
+    /// iceberg_table_config_changeset
+    fn iceberg_table_config_changeset(&self, _args: &[Value]) -> Result<Value, MinijinjaError> {
+        jinja_err!(
+            MinijinjaErrorKind::InvalidOperation,
+            "Only available for snowflake"
+        )
+    }
+
+    /// table_config_changeset
+    fn table_config_changeset(&self, _args: &[Value]) -> Result<Value, MinijinjaError> {
+        jinja_err!(
+            MinijinjaErrorKind::InvalidOperation,
+            "Only available for redshift"
+        )
+    }
+
     /// from_config
     #[allow(clippy::wrong_self_convention)]
     fn from_config(&self, _args: &[Value]) -> Result<Value, MinijinjaError> {
@@ -739,9 +756,38 @@ pub trait BaseRelation: BaseRelationProperties + Any + Send + Sync + fmt::Debug
         )
     }
 
+    /// Identifier limits of the warehouse of the relation
+    fn identifier_limits(&self) -> Result<IdentifierLimits, MinijinjaError> {
+        match self
+            .adapter_type()
+            .and_then(|adapter_type| adapter_type.parse::<AdapterType>().ok())
+        {
+            Some(adapter_type) => Ok(adapter_type.identifier_limits()),
+            None => jinja_err!(
+                MinijinjaErrorKind::InvalidOperation,
+                "identifier limits are unknown for adapter type {:?}",
+                self.adapter_type()
+            ),
+        }
+    }
+
     /// Get max name length
-    fn relation_max_name_length(&self, _args: &[Value]) -> Result<Value, MinijinjaError> {
-        unimplemented!("Available only for postgres and redshift")
+    fn relation_max_name_length(&self, args: &[Value]) -> Result<Value, MinijinjaError> {
+        let args = ArgParser::new(args, None);
+        check_num_args(current_function_name!(), &args, 0, 0)?;
+        Ok(Value::from(self.identifier_limits()?.relation))
+    }
+
+    /// The identifier of the relation followed by `suffix`, truncated with a
+    /// hash of the full name when it exceeds the relation name limit
+    fn truncated_identifier(&self, args: &[Value]) -> Result<Value, MinijinjaError> {
+        let mut args = ArgParser::new(args, None);
+        check_num_args(current_function_name!(), &args, 1, 1)?;
+        let suffix: String = args.get("suffix")?;
+        Ok(Value::from(
+            self.identifier_limits()?
+                .truncate_with_hash(&self.identifier_as_str()?, &suffix),
+        ))
     }
 
     fn is_hive_metastore(&self) -> Value {
@@ -750,7 +796,18 @@ pub trait BaseRelation: BaseRelationProperties + Any + Send + Sync + fmt::Debug
 
     /// materialized_view_config_changeset
     fn materialized_view_config_changeset(&self, _args: &[Value]) -> Result<Value, MinijinjaError> {
-        unimplemented!("Available only for BigQuery and Redshift")
+        unimplemented!("Available only for BigQuery, Redshift and Snowflake")
+    }
+
+    /// get_materialized_view_config_change_collection
+    fn get_materialized_view_config_change_collection(
+        &self,
+        _args: &[Value],
+    ) -> Result<Value, MinijinjaError> {
+        jinja_err!(
+            MinijinjaErrorKind::InvalidOperation,
+            "Only available for postgres"
+        )
     }
 }
 
@@ -1,5 +1,6 @@
 use chrono::{DateTime, Utc};
 use dbt_common::FsResult;
+use dbt_common::tracing::metrics::QueryStats;
 use serde::{Deserialize, Serialize};
 use serde_with::skip_serializing_none;
 use std::{collections::BTreeMap, path::Path, sync::Arc};
@@ -41,7 +42,9 @@ pub struct TimingInfo {
 #[derive(Debug, Clone, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub struct BatchResults {
+    /// `(event_time_start, event_time_end)` of each batch that succeeded
     pub successful: Vec<(String, String)>,
+    /// `(event_time_start, event_time_end)` of each batch that failed
     pub failed: Vec<(String, String)>,
 }
 
@@ -73,6 +76,8 @@ pub struct ContextRunResult {
     pub execution_time: f64,
     /// Adapter-specific response information.
     pub adapter_response: BTreeMap<String, YmlValue>,
+    /// Warehouse execution statistics summed over every query the node executed.
+    pub query_stats: Option<QueryStats>,
     /// Execution message (e.g., error message).
     pub message: Option<String>,
     /// Information about failures (often used for tests).
@@ -106,6 +111,7 @@ impl From<ContextRunResult> for RunResultOutput {
             thread_id: result.thread_id,
             execution_time: result.execution_time,
             adapter_response: result.adapter_response,
+            query_stats: result.query_stats,
             message: result.message,
             failures: result.failures,
             unique_id,
@@ -132,6 +138,9 @@ pub struct RunResultOutput {
     pub execution_time: f64,
     /// Adapter-specific response information.
     pub adapter_response: BTreeMap<String, YmlValue>,
+    /// Warehouse execution statistics summed over every query the node executed.
+    #[serde(default)]
+    pub query_stats: Option<QueryStats>,
     /// Execution message (e.g., error message).
     pub message: Option<String>,
     /// Information about failures (often used for tests).
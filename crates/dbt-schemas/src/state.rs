@@ -183,6 +183,7 @@ pub struct DbtPackage {
     pub dbt_properties: Vec<DbtAsset>,
     pub analysis_files: Vec<DbtAsset>,
     pub model_sql_files: Vec<DbtAsset>,
+    pub model_python_files: Vec<DbtAsset>,
     pub function_sql_files: Vec<DbtAsset>,
     pub macro_files: Vec<DbtAsset>,
     pub test_files: Vec<DbtAsset>,
@@ -25,6 +25,7 @@ impl ProtoTelemetryEvent for QueryExecuted {
             QueryOutcome::Success => SpanStatus::succeeded().into(),
             QueryOutcome::Error => SpanStatus::failed(self.query_error_adapter_message()).into(),
             QueryOutcome::Canceled => SpanStatus::failed("canceled").into(),
+            QueryOutcome::TimedOut => SpanStatus::failed("timed out").into(),
             QueryOutcome::Unspecified => None,
         }
     }
@@ -68,6 +69,15 @@ struct QueryExecutedJsonPayload {
     sql: String,
     query_description: Option<String>,
     query_error_adapter_message: Option<String>,
+    attempt: Option<u32>,
+    cache_hit: Option<bool>,
+    bytes_processed: Option<u64>,
+    bytes_billed: Option<u64>,
+    slot_ms: Option<u64>,
+    rows_inserted: Option<u64>,
+    rows_updated: Option<u64>,
+    rows_deleted: Option<u64>,
+    permit_wait_ms: Option<u64>,
 }
 
 impl ArrowSerializableTelemetryEvent for QueryExecuted {
@@ -89,6 +99,15 @@ impl ArrowSerializableTelemetryEvent for QueryExecuted {
                 sql: self.sql.clone(),
                 query_description: self.query_description.clone(),
                 query_error_adapter_message: self.query_error_adapter_message.clone(),
+                attempt: self.attempt,
+                cache_hit: self.cache_hit,
+                bytes_processed: self.bytes_processed,
+                bytes_billed: self.bytes_billed,
+                slot_ms: self.slot_ms,
+                rows_inserted: self.rows_inserted,
+                rows_updated: self.rows_updated,
+                rows_deleted: self.rows_deleted,
+                permit_wait_ms: self.permit_wait_ms,
             })
             .unwrap_or_else(|_| {
                 panic!(
@@ -161,6 +180,15 @@ impl ArrowSerializableTelemetryEvent for QueryExecuted {
                         Self::full_name()
                     )
                 })?,
+            attempt: json_payload.attempt,
+            cache_hit: json_payload.cache_hit,
+            bytes_processed: json_payload.bytes_processed,
+            bytes_billed: json_payload.bytes_billed,
+            slot_ms: json_payload.slot_ms,
+            rows_inserted: json_payload.rows_inserted,
+            rows_updated: json_payload.rows_updated,
+            rows_deleted: json_payload.rows_deleted,
+            permit_wait_ms: json_payload.permit_wait_ms,
         })
     }
 }
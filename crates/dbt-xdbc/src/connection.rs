@@ -29,6 +29,15 @@ use crate::{Backend, Statement};
 mod builder;
 pub use builder::*;
 
+/// State dbt tracks about the session of a [Connection], on top of the driver's.
+#[derive(Debug, Default, Clone)]
+pub struct Session {
+    /// Whether a transaction was begun and not committed or rolled back yet
+    pub transaction_open: bool,
+    /// `application_name` dbt last set on the session, if any
+    pub application_name: Option<String>,
+}
+
 /// XDBC Connection.
 ///
 /// Connections provide methods for query execution, managing prepared
@@ -45,6 +54,11 @@ pub trait Connection: Send {
     /// Updates the node_id associated with this [Connection]
     fn update_node_id(&mut self, node_id: Option<String>);
 
+    /// The [Session] state of this [Connection], if it tracks one
+    fn session(&mut self) -> Option<&mut Session> {
+        None
+    }
+
     /// Cancel the in-progress operation on a connection.
     fn cancel(&mut self) -> Result<()>;
 
@@ -376,6 +390,7 @@ pub(crate) struct AdbcConnection(
     pub(crate) Backend,
     pub(crate) ManagedAdbcConnection,
     pub(crate) Option<Arc<Semaphore>>,
+    pub(crate) Session,
 );
 
 impl fmt::Debug for AdbcConnection {
@@ -508,6 +523,10 @@ impl Connection for AdbcConnection {
     fn update_node_id(&mut self, _node_id: Option<String>) {
         // noop: AdbcConnection does not use the node id
     }
+
+    fn session(&mut self) -> Option<&mut Session> {
+        Some(&mut self.3)
+    }
 }
 
 /// ODBC Connection.
@@ -30,8 +30,10 @@ use crate::connection::OdbcConnection;
 #[cfg(feature = "odbc")]
 use crate::odbc::OdbcEnv;
 use crate::{
-    Backend, Connection, connection::AdbcConnection, semaphore::Semaphore, snowflake,
-    str_from_sqlstate,
+    Backend, Connection,
+    connection::{AdbcConnection, Session},
+    semaphore::Semaphore,
+    snowflake, str_from_sqlstate,
 };
 
 mod builder;
@@ -441,7 +443,7 @@ impl InnerAdbcDatabase {
                 res
             })
         }?;
-        let conn = AdbcConnection(self.backend, conn, semaphore);
+        let conn = AdbcConnection(self.backend, conn, semaphore, Session::default());
         drop(span);
         Ok(Box::new(conn))
     }
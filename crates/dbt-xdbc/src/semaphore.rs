@@ -1,5 +1,10 @@
+use std::cmp::Reverse;
+use std::collections::BinaryHeap;
 use std::fmt;
 use std::sync::atomic::{AtomicU32, Ordering};
+use std::time::{Duration, Instant};
+
+use parking_lot::{Condvar, Mutex};
 
 /// General-case semaphore implementation.
 ///
@@ -249,6 +254,142 @@ impl Drop for PermitGuardAll<'_> {
     }
 }
 
+/// Counting semaphore that hands out permits by priority.
+///
+/// When permits are contended, the waiter with the highest priority acquires
+/// the next released permit. Waiters of equal priority are served in arrival
+/// order. Unlike [Semaphore], it is built on a mutex and a condition variable
+/// because the ordering of waiters has to be tracked.
+pub struct PrioritySemaphore {
+    max: u32,
+    state: Mutex<PriorityState>,
+    released: Condvar,
+}
+
+struct PriorityState {
+    available: u32,
+    /// Waiting tickets ordered by priority, then by arrival.
+    waiting: BinaryHeap<(u32, Reverse<u64>)>,
+    next_ticket: u64,
+}
+
+impl PrioritySemaphore {
+    pub fn new(count: u32) -> Self {
+        debug_assert!(count > 0, "Semaphore must allow for at least one permit");
+        Self {
+            max: count,
+            state: Mutex::new(PriorityState {
+                available: count,
+                waiting: BinaryHeap::new(),
+                next_ticket: 0,
+            }),
+            released: Condvar::new(),
+        }
+    }
+
+    /// Get the number of available permits the semaphore started with.
+    pub fn max(&self) -> u32 {
+        self.max
+    }
+
+    /// Get the number of permits that are currently available.
+    pub fn available(&self) -> u32 {
+        self.state.lock().available
+    }
+
+    /// Get the number of threads blocked waiting for a permit.
+    pub fn waiting(&self) -> usize {
+        self.state.lock().waiting.len()
+    }
+
+    /// Acquire a permit with the given priority, blocking until one is
+    /// available and no waiter with a higher priority is queued.
+    #[must_use]
+    pub fn acquire(&self, priority: u32) -> PriorityPermit<'_> {
+        let start = Instant::now();
+        let mut state = self.state.lock();
+        if state.available > 0 && state.waiting.is_empty() {
+            state.available -= 1;
+            return PriorityPermit {
+                semaphore: self,
+                wait: Duration::ZERO,
+            };
+        }
+        let ticket = (priority, Reverse(state.next_ticket));
+        state.next_ticket += 1;
+        state.waiting.push(ticket);
+        while state.available == 0 || state.waiting.peek() != Some(&ticket) {
+            self.released.wait(&mut state);
+        }
+        state.waiting.pop();
+        state.available -= 1;
+        // Another permit may still be available for the next waiter in line.
+        if state.available > 0 && !state.waiting.is_empty() {
+            self.released.notify_all();
+        }
+        PriorityPermit {
+            semaphore: self,
+            wait: start.elapsed(),
+        }
+    }
+
+    /// Try to acquire a permit without blocking.
+    ///
+    /// Fails if no permit is available or other threads are already waiting.
+    #[must_use]
+    pub fn try_acquire(&self) -> Option<PriorityPermit<'_>> {
+        let mut state = self.state.lock();
+        if state.available > 0 && state.waiting.is_empty() {
+            state.available -= 1;
+            Some(PriorityPermit {
+                semaphore: self,
+                wait: Duration::ZERO,
+            })
+        } else {
+            None
+        }
+    }
+
+    fn release(&self) {
+        let mut state = self.state.lock();
+        state.available += 1;
+        if !state.waiting.is_empty() {
+            // Every waiter checks whether it is at the head of the queue.
+            self.released.notify_all();
+        }
+    }
+}
+
+impl fmt::Debug for PrioritySemaphore {
+    fn fmt(&self, f: &mut core::fmt::Formatter<'_>) -> core::fmt::Result {
+        let state = self.state.lock();
+        f.debug_struct("PrioritySemaphore")
+            .field("max", &self.max)
+            .field("available", &state.available)
+            .field("waiting", &state.waiting.len())
+            .finish()
+    }
+}
+
+/// A guard that releases a [PrioritySemaphore] permit when dropped.
+pub struct PriorityPermit<'a> {
+    semaphore: &'a PrioritySemaphore,
+    wait: Duration,
+}
+
+impl PriorityPermit<'_> {
+    /// How long the caller was blocked before the permit was granted.
+    pub fn wait(&self) -> Duration {
+        self.wait
+    }
+}
+
+impl Drop for PriorityPermit<'_> {
+    fn drop(&mut self) {
+        self.semaphore.release()
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -377,6 +518,37 @@ mod tests {
         let _ = rx.recv();
     }
 
+    #[test]
+    fn test_priority_semaphore_serves_highest_priority_first() {
+        let semaphore = Arc::new(PrioritySemaphore::new(1));
+        let order = Arc::new(parking_lot::Mutex::new(Vec::new()));
+
+        let permit = semaphore.acquire(0);
+        assert_eq!(permit.wait(), Duration::ZERO);
+        assert!(semaphore.try_acquire().is_none());
+
+        let mut handles = vec![];
+        // The low priority waiter queues up first.
+        for (queued, priority) in [1, 5].into_iter().enumerate() {
+            let sem = semaphore.clone();
+            let order = order.clone();
+            handles.push(thread::spawn(move || {
+                let permit = sem.acquire(priority);
+                order.lock().push(priority);
+                assert!(permit.wait() > Duration::ZERO);
+            }));
+            while semaphore.waiting() <= queued {
+                thread::yield_now();
+            }
+        }
+        drop(permit);
+        for handle in handles {
+            handle.join().unwrap();
+        }
+        assert_eq!(*order.lock(), vec![5, 1]);
+        assert_eq!(semaphore.available(), 1);
+    }
+
     #[test]
     #[should_panic]
     fn test_semaphore_zero_permits() {
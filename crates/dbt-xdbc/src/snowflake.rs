@@ -70,3 +70,8 @@ pub mod auth_type {
 pub const CLIENT_ID: &str = "adbc.snowflake.sql.client_option.client_id";
 pub const CLIENT_SECRET: &str = "adbc.snowflake.sql.client_option.client_secret";
 pub const REFRESH_TOKEN: &str = "adbc.snowflake.sql.client_option.refresh_token";
+
+// Names of Statement options ---------------------------------------------
+
+/// `QUERY_TAG` of a single statement, overriding the session's.
+pub const QUERY_TAG: &str = "adbc.snowflake.statement.query_tag";
@@ -14,6 +14,14 @@ pub enum Token<'source> {
     RAngle,
     Comma,
     Colon,
+    /// Statement terminator: ;.
+    ///
+    /// Only produced when [LexerOptions::statements] is enabled.
+    Semicolon,
+    /// A line or block comment, including its delimiters.
+    ///
+    /// Only produced when [LexerOptions::statements] is enabled.
+    Comment(&'source str),
     /// Word includes keywords, identifiers, quoted identifiers, string literals,
     /// numeric literals and similar continuous pieces of source text.
     ///
@@ -35,6 +43,8 @@ impl PartialEq for Token<'_> {
             (Token::RAngle, Token::RAngle) => true,
             (Token::Comma, Token::Comma) => true,
             (Token::Colon, Token::Colon) => true,
+            (Token::Semicolon, Token::Semicolon) => true,
+            (Token::Comment(a), Token::Comment(b)) => a == b,
             (Token::Word(a), Token::Word(b)) => a.eq_ignore_ascii_case(b),
             _ => false,
         }
@@ -52,6 +62,8 @@ impl fmt::Display for Token<'_> {
             Token::RAngle => write!(f, ">"),
             Token::Comma => write!(f, ","),
             Token::Colon => write!(f, ":"),
+            Token::Semicolon => write!(f, ";"),
+            Token::Comment(c) => write!(f, "{c}"),
             Token::Word(w) => write!(f, "{w}"),
         }
     }
@@ -104,18 +116,69 @@ impl TryFrom<u8> for QuotingStyle {
     }
 }
 
+/// How dollar-quoted string constants are recognized.
+#[derive(Debug, Default, Copy, Clone, PartialEq, Eq)]
+pub enum DollarQuoting {
+    /// `$` has no special meaning.
+    #[default]
+    None,
+    /// Only `$$...$$` like in Snowflake.
+    Anonymous,
+    /// `$$...$$` and `$tag$...$tag$` like in PostgreSQL.
+    Tagged,
+}
+
+/// Lexical rules that vary between SQL dialects.
+///
+/// The default options are meant for type expressions and recognize only
+/// the delimiters that can appear in them. Enabling `statements` makes the
+/// tokenizer suitable for whole SQL scripts.
+#[derive(Debug, Default, Copy, Clone)]
+pub struct LexerOptions {
+    /// Produce [Token::Semicolon] and [Token::Comment] tokens.
+    pub statements: bool,
+    /// Backslash escapes the next character inside quotes.
+    pub backslash_escapes: bool,
+    pub dollar_quoting: DollarQuoting,
+    /// `#` starts a line comment like in BigQuery.
+    pub hash_comments: bool,
+    /// Block comments can be nested like in PostgreSQL.
+    pub nested_block_comments: bool,
+    /// `'''...'''` and `"""..."""` string literals like in BigQuery.
+    pub triple_quotes: bool,
+}
+
 fn is_whitespace(c: u8) -> bool {
     c == b' ' || c == b'\t' || c == b'\n' || c == b'\r'
 }
 
+fn is_ident_byte(c: u8) -> bool {
+    c.is_ascii_alphanumeric() || c == b'_' || c == b'$' || !c.is_ascii()
+}
+
+#[derive(Clone)]
 pub struct Tokenizer<'source> {
     input: &'source str,
     position: usize,
+    options: LexerOptions,
 }
 
 impl<'source> Tokenizer<'source> {
     pub fn new(input: &'source str) -> Self {
-        Tokenizer { input, position: 0 }
+        Self::with_options(input, LexerOptions::default())
+    }
+
+    pub fn with_options(input: &'source str, options: LexerOptions) -> Self {
+        Tokenizer {
+            input,
+            position: 0,
+            options,
+        }
+    }
+
+    /// The byte offset of the next unconsumed character of the input.
+    pub fn position(&self) -> usize {
+        self.position
     }
 
     /// Looks at the current byte without consuming it.
@@ -142,8 +205,22 @@ impl<'source> Tokenizer<'source> {
     }
 
     fn rest_of_quoted_word(&mut self, start: usize, quote: u8) -> Token<'source> {
+        self.rest_of_quoted_word_with(start, quote, self.options.backslash_escapes)
+    }
+
+    fn rest_of_quoted_word_with(
+        &mut self,
+        start: usize,
+        quote: u8,
+        backslash_escapes: bool,
+    ) -> Token<'source> {
         // We have already consumed the opening quote.
         while let Some(b) = self._next_byte() {
+            if backslash_escapes && b == b'\\' {
+                // Skip the escaped byte, whatever it is.
+                self._next_byte();
+                continue;
+            }
             if b == quote {
                 // SQL is weird: two consecutive quotes inside a quoted
                 // identifier escape the quote character, so we need to peek.
@@ -154,7 +231,6 @@ impl<'source> Tokenizer<'source> {
                     self.position += 1;
                     continue;
                 }
-                // TODO: handle \{quote} escape sequences
                 // This is the closing quote, because it's not escaped.
                 //
                 // SAFETY: this is a valid UTF8 slice because breaks only
@@ -174,6 +250,93 @@ impl<'source> Tokenizer<'source> {
         Token::Word(word)
     }
 
+    fn rest_of_triple_quoted_word(&mut self, start: usize, quote: u8) -> Token<'source> {
+        // We have already consumed the opening triple quote.
+        let closing = [quote; 3];
+        while let Some(b) = self._next_byte() {
+            if self.options.backslash_escapes && b == b'\\' {
+                self._next_byte();
+                continue;
+            }
+            if b == quote && self.input.as_bytes()[self.position - 1..].starts_with(&closing) {
+                self.position += 2;
+                break;
+            }
+        }
+        Token::Word(&self.input[start..self.position])
+    }
+
+    /// Length of the dollar-quote tag (e.g. `$$` or `$body$`) starting at the
+    /// current position, if any.
+    fn dollar_tag_len(&self) -> Option<usize> {
+        let rest = &self.input.as_bytes()[self.position..];
+        if rest.first() != Some(&b'$') {
+            return None;
+        }
+        match self.options.dollar_quoting {
+            DollarQuoting::None => None,
+            DollarQuoting::Anonymous => (rest.get(1) == Some(&b'$')).then_some(2),
+            DollarQuoting::Tagged => {
+                // A tag follows the rules of unquoted identifiers, without `$`.
+                let tag_len = rest[1..]
+                    .iter()
+                    .take_while(|&&c| c != b'$' && is_ident_byte(c))
+                    .count();
+                if tag_len > 0 && rest[1].is_ascii_digit() {
+                    return None;
+                }
+                (rest.get(1 + tag_len) == Some(&b'$')).then_some(tag_len + 2)
+            }
+        }
+    }
+
+    fn rest_of_dollar_quoted_word(&mut self, start: usize, tag_len: usize) -> Token<'source> {
+        let tag = &self.input[start..start + tag_len];
+        let body = start + tag_len;
+        self.position = match self.input[body..].find(tag) {
+            Some(offset) => body + offset + tag_len,
+            // No closing tag: the rest of the input is part of the string.
+            None => self.input.len(),
+        };
+        Token::Word(&self.input[start..self.position])
+    }
+
+    fn at_comment_start(&self) -> bool {
+        let rest = &self.input.as_bytes()[self.position..];
+        rest.starts_with(b"--")
+            || rest.starts_with(b"/*")
+            || (self.options.hash_comments && rest.first() == Some(&b'#'))
+    }
+
+    fn comment(&mut self, start: usize) -> Token<'source> {
+        let bytes = self.input.as_bytes();
+        if bytes[start..].starts_with(b"/*") {
+            self.position = start + 2;
+            let mut depth = 1;
+            while self.position < bytes.len() {
+                let rest = &bytes[self.position..];
+                if rest.starts_with(b"*/") {
+                    self.position += 2;
+                    depth -= 1;
+                    if depth == 0 {
+                        break;
+                    }
+                } else if self.options.nested_block_comments && rest.starts_with(b"/*") {
+                    self.position += 2;
+                    depth += 1;
+                } else {
+                    self.position += 1;
+                }
+            }
+        } else {
+            // Line comments end right before the newline.
+            self.position = self.input[start..]
+                .find('\n')
+                .map_or(self.input.len(), |offset| start + offset);
+        }
+        Token::Comment(&self.input[start..self.position])
+    }
+
     fn rest_of_word(&mut self, start: usize) -> Option<Token<'source>> {
         // We have already consumed the first non-whitespace, non-delimiter byte of the word.
         while let Some(b) = self._peek_byte() {
@@ -182,12 +345,29 @@ impl<'source> Tokenizer<'source> {
                 b',' | b':' => break,
                 b'\'' | b'"' | b'`' => break,
                 _ if is_whitespace(b) => break,
+                _ if self.options.statements
+                    && (b == b';'
+                        || self.at_comment_start()
+                        || (!is_ident_byte(self.input.as_bytes()[self.position - 1])
+                            && self.dollar_tag_len().is_some())) =>
+                {
+                    break;
+                }
                 _ => {
                     self.position += 1;
                     continue;
                 }
             }
         }
+        if self.options.statements
+            && self.position == start + 1
+            && self.input.as_bytes()[start].eq_ignore_ascii_case(&b'e')
+            && self._peek_byte() == Some(b'\'')
+        {
+            // PostgreSQL escape string constant: E'...' always allows backslash escapes.
+            self.position += 1;
+            return Some(self.rest_of_quoted_word_with(start, b'\'', true));
+        }
         // SAFETY: this is a valid UTF8 slice because breaks only
         // occur on whitespece or delimiter ASCII characters.
         let word = &self.input[start..self.position];
@@ -202,6 +382,23 @@ impl<'source> Tokenizer<'source> {
     pub fn next(&mut self) -> Option<Token<'source>> {
         self.skip_whitespace();
         let start = self.position;
+        if self.options.statements && start < self.input.len() {
+            if self.at_comment_start() {
+                return Some(self.comment(start));
+            }
+            if let Some(tag_len) = self.dollar_tag_len() {
+                return Some(self.rest_of_dollar_quoted_word(start, tag_len));
+            }
+        }
+        if self.options.triple_quotes {
+            let rest = &self.input.as_bytes()[start..];
+            for quote in [b'\'', b'"'] {
+                if rest.starts_with(&[quote; 3]) {
+                    self.position += 3;
+                    return Some(self.rest_of_triple_quoted_word(start, quote));
+                }
+            }
+        }
         let token = if let Some(b) = self._next_byte() {
             match b {
                 b'(' => Token::LParen,
@@ -212,6 +409,7 @@ impl<'source> Tokenizer<'source> {
                 b'>' => Token::RAngle,
                 b',' => Token::Comma,
                 b':' => Token::Colon,
+                b';' if self.options.statements => Token::Semicolon,
                 b'\'' | b'"' | b'`' => self.rest_of_quoted_word(start, b),
                 _ => return self.rest_of_word(start),
             }
@@ -430,4 +628,74 @@ mod tests {
             );
         }
     }
+
+    fn all_statement_tokens<'source>(
+        input: &'source str,
+        options: LexerOptions,
+    ) -> Vec<Token<'source>> {
+        let options = LexerOptions {
+            statements: true,
+            ..options
+        };
+        let mut tokenizer = Tokenizer::with_options(input, options);
+        let mut tokens = Vec::new();
+        while let Some(tok) = tokenizer.next() {
+            tokens.push(tok);
+        }
+        tokens
+    }
+
+    #[test]
+    fn test_tokenizing_of_statements() {
+        let default = LexerOptions::default();
+        assert_eq!(
+            all_statement_tokens("select 1; -- done\n/* c; */select';'", default),
+            vec![
+                Token::Word("select"),
+                Token::Word("1"),
+                Token::Semicolon,
+                Token::Comment("-- done"),
+                Token::Comment("/* c; */"),
+                Token::Word("select"),
+                Token::Word("';'"),
+            ]
+        );
+
+        let postgres = LexerOptions {
+            dollar_quoting: DollarQuoting::Tagged,
+            nested_block_comments: true,
+            ..default
+        };
+        assert_eq!(
+            all_statement_tokens("as $fn$ a; $$ b $fn$;x=$1", postgres),
+            vec![
+                Token::Word("as"),
+                Token::Word("$fn$ a; $$ b $fn$"),
+                Token::Semicolon,
+                Token::Word("x=$1"),
+            ]
+        );
+        assert_eq!(
+            all_statement_tokens(r#"/* a /* b; */ c; */ E'\';'"#, postgres),
+            vec![
+                Token::Comment("/* a /* b; */ c; */"),
+                Token::Word(r#"E'\';'"#),
+            ]
+        );
+
+        let bigquery = LexerOptions {
+            backslash_escapes: true,
+            hash_comments: true,
+            triple_quotes: true,
+            ..default
+        };
+        assert_eq!(
+            all_statement_tokens("'it\\'s;' # c;\n'''a';'''", bigquery),
+            vec![
+                Token::Word(r#"'it\'s;'"#),
+                Token::Comment("# c;"),
+                Token::Word("'''a';'''"),
+            ]
+        );
+    }
 }
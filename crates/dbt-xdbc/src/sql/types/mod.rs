@@ -1333,7 +1333,9 @@ impl<'source> Parser<'source> {
             | Token::LAngle
             | Token::RAngle
             | Token::Comma
-            | Token::Colon => {
+            | Token::Colon
+            | Token::Semicolon
+            | Token::Comment(_) => {
                 return Err(ParseError::Unexpected(tok));
             }
             Token::Word(w) => {
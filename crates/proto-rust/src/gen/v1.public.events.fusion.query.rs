@@ -58,6 +58,36 @@ pub struct QueryExecuted {
     /// Legacy dbt core event code: E016 or E017
     #[prost(string, tag = "11")]
     pub dbt_core_event_code: ::prost::alloc::string::String,
+    /// 1-based attempt number when the query is retried after a retryable error.
+    /// Unset for queries executed without a retry policy.
+    #[prost(uint32, optional, tag = "12")]
+    pub attempt: ::core::option::Option<u32>,
+    /// Whether the result was served from the local query cache. Unset when the
+    /// query cache is not used for the query.
+    #[prost(bool, optional, tag = "13")]
+    pub cache_hit: ::core::option::Option<bool>,
+    /// Bytes scanned by the query (BigQuery).
+    #[prost(uint64, optional, tag = "14")]
+    pub bytes_processed: ::core::option::Option<u64>,
+    /// Bytes billed for the query (BigQuery).
+    #[prost(uint64, optional, tag = "15")]
+    pub bytes_billed: ::core::option::Option<u64>,
+    /// Slot milliseconds consumed by the query (BigQuery).
+    #[prost(uint64, optional, tag = "16")]
+    pub slot_ms: ::core::option::Option<u64>,
+    /// Rows inserted by a DML statement.
+    #[prost(uint64, optional, tag = "17")]
+    pub rows_inserted: ::core::option::Option<u64>,
+    /// Rows updated by a DML statement.
+    #[prost(uint64, optional, tag = "18")]
+    pub rows_updated: ::core::option::Option<u64>,
+    /// Rows deleted by a DML statement.
+    #[prost(uint64, optional, tag = "19")]
+    pub rows_deleted: ::core::option::Option<u64>,
+    /// Milliseconds the statement waited for a concurrency permit of its
+    /// backend. Unset when the backend has no concurrency limit.
+    #[prost(uint64, optional, tag = "20")]
+    pub permit_wait_ms: ::core::option::Option<u64>,
 }
 impl crate::StaticName for QueryExecuted {
     const FULL_NAME: &'static str = "v1.public.events.fusion.query.QueryExecuted";
@@ -88,6 +118,8 @@ pub enum QueryOutcome {
     Error = 2,
     /// Query was canceled (e.g. user-initiated cancellation).
     Canceled = 3,
+    /// Query was canceled because it exceeded the configured `query_timeout`.
+    TimedOut = 4,
 }
 impl QueryOutcome {
     /// String value of the enum field names used in the ProtoBuf definition.
@@ -100,6 +132,7 @@ impl QueryOutcome {
             Self::Success => "QUERY_OUTCOME_SUCCESS",
             Self::Error => "QUERY_OUTCOME_ERROR",
             Self::Canceled => "QUERY_OUTCOME_CANCELED",
+            Self::TimedOut => "QUERY_OUTCOME_TIMED_OUT",
         }
     }
     /// Creates an enum from field names used in the ProtoBuf definition.
@@ -109,6 +142,7 @@ impl QueryOutcome {
             "QUERY_OUTCOME_SUCCESS" => Some(Self::Success),
             "QUERY_OUTCOME_ERROR" => Some(Self::Error),
             "QUERY_OUTCOME_CANCELED" => Some(Self::Canceled),
+            "QUERY_OUTCOME_TIMED_OUT" => Some(Self::TimedOut),
             _ => None,
         }
     }
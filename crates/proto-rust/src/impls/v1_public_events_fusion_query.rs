@@ -10,6 +10,7 @@ impl AsRef<str> for QueryOutcome {
             Self::Success => "success",
             Self::Error => "error",
             Self::Canceled => "canceled",
+            Self::TimedOut => "timed_out",
         }
     }
 }